@@ -0,0 +1,137 @@
+# Model of Griffin-π as published in https://eprint.iacr.org/2022/403 (final version), including the
+# parameter generation, written independently of the Rust code in src/griffin/spec. Running it prints the
+# known answers of the test griffin_spec_known_answers.
+import hashlib
+from math import comb, ceil, gcd
+
+P128 = 2**128 - 45 * 2**40 + 1
+P62 = 4611624995532046337
+
+def get_d(p):
+    d = 3
+    while gcd(d, p - 1) != 1:
+        d += 1
+    return d
+
+def get_rounds(t, d, sec=128):
+    for r in range(1, 100):
+        if comb(r * (d + t) + 1, 1 + t * r) ** 2 >= 2 ** sec and comb(d ** r + 1 + r, 1 + r) ** 2 >= 2 ** sec:
+            return ceil(1.2 * max(6, 1 + r))
+
+class Shake:
+    def __init__(self, p):
+        seed = b'Griffin'
+        limbs = (p.bit_length() + 63) // 64
+        for i in range(limbs):
+            seed += ((p >> (64 * i)) & (2**64 - 1)).to_bytes(8, 'little')
+        self.stream = hashlib.shake_128(seed)
+        self.pos = 0
+        self.buf = b''
+    def read(self, n):
+        while self.pos + n > len(self.buf):
+            self.buf = self.stream.digest(max(2 * len(self.buf), 4096))
+        out = self.buf[self.pos:self.pos + n]
+        self.pos += n
+        return out
+
+def field_element(shake, p, nonzero=False):
+    nbytes = (p.bit_length() + 7) // 8
+    while True:
+        x = int.from_bytes(shake.read(nbytes), 'little')
+        if x < p and (x != 0 or not nonzero):
+            return x
+
+def legendre(a, p):
+    return pow(a % p, (p - 1) // 2, p)
+
+def m4():
+    return [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+
+def matrix(t):
+    if t == 3:
+        return [[2, 1, 1], [1, 2, 1], [1, 1, 2]]
+    assert t % 4 == 0
+    if t == 4:
+        return m4()
+    M = [[0] * t for _ in range(t)]
+    for bi in range(t // 4):
+        for bj in range(t // 4):
+            f = 2 if bi == bj else 1
+            for i in range(4):
+                for j in range(4):
+                    M[4 * bi + i][4 * bj + j] = f * m4()[i][j]
+    return M
+
+def inverse(M, p):
+    m = len(M)
+    A = [[x % p for x in M[i]] + [1 if i == j else 0 for j in range(m)] for i in range(m)]
+    for c in range(m):
+        piv = next(i for i in range(c, m) if A[i][c])
+        A[c], A[piv] = A[piv], A[c]
+        inv = pow(A[c][c], -1, p)
+        A[c] = [x * inv % p for x in A[c]]
+        for i in range(m):
+            if i != c and A[i][c]:
+                f = A[i][c]
+                A[i] = [(a - f * b) % p for a, b in zip(A[i], A[c])]
+    return [row[m:] for row in A]
+
+def params(p, t):
+    d = get_d(p)
+    rounds = get_rounds(t, d)
+    shake = Shake(p)
+    rc = [[field_element(shake, p) for _ in range(t)] for _ in range(rounds - 1)]
+    rc.append([0] * t)
+    while True:
+        alpha = field_element(shake, p, True)
+        beta = field_element(shake, p, True)
+        while alpha == beta:
+            beta = field_element(shake, p, True)
+        if legendre(alpha * alpha - 4 * beta, p) == p - 1:
+            break
+    alphas = [0, 0] + [(i - 1) * alpha % p for i in range(2, t)]
+    betas = [0, 0] + [(i - 1) ** 2 * beta % p for i in range(2, t)]
+    M = matrix(t)
+    return dict(p=p, t=t, d=d, inv_d=pow(d, -1, p - 1), rounds=rounds, rc=rc, alphas=alphas, betas=betas, mat=M, inv_mat=inverse(M, p))
+
+def mat_mul(M, x, p):
+    return [sum(a * b for a, b in zip(row, x)) % p for row in M]
+
+def non_linear(par, x):
+    p, t, d = par['p'], par['t'], par['d']
+    y = list(x)
+    y[0] = pow(x[0], par['inv_d'], p)
+    y[1] = pow(x[1], d, p)
+    for i in range(2, t):
+        l = ((i - 1) * y[0] + y[1] + (x[i - 1] if i > 2 else 0)) % p
+        y[i] = x[i] * (l * l + par['alphas'][i] * l + par['betas'][i]) % p
+    return y
+
+def permutation(par, state):
+    p = par['p']
+    state = mat_mul(par['mat'], state, p)
+    for r in range(par['rounds']):
+        state = non_linear(par, state)
+        state = mat_mul(par['mat'], state, p)
+        state = [(s + c) % p for s, c in zip(state, par['rc'][r])]
+    return state
+
+def hash(par, rate, inputs):
+    assert len(inputs) % rate == 0
+    state = [0] * par['t']
+    for i in range(0, len(inputs), rate):
+        for j in range(rate):
+            state[j] = (state[j] + inputs[i + j]) % par['p']
+        state = permutation(par, state)
+    return state[:rate]
+
+
+
+if __name__ == '__main__':
+    # known answers of the test griffin_spec_known_answers in src/lib.rs
+    for p, t in [(P128, 4), (P62, 4)]:
+        par = params(p, t)
+        print('p%d_t%d_c3 permutation(0..%d):' % (p.bit_length(), t, t), permutation(par, list(range(t))))
+        print('p%d_t%d_c3 hash([1, 2]):' % (p.bit_length(), t), hash(par, 1, [1, 2]))
+    print('p128_t12_c4 permutation(0..12):', permutation(params(P128, 12), list(range(12))))
+    print('p62_t12_c4 hash(1..=16):', hash(params(P62, 12), 8, list(range(1, 17))))
@@ -0,0 +1,138 @@
+# Model of Poseidon (https://eprint.iacr.org/2019/458) including its parameter generation, following
+# calc_round_numbers.py and generate_params_poseidon.sage of the reference implementation
+# (https://extgit.iaik.tugraz.at/krypto/hadeshash) and written independently of the Rust code in
+# src/poseidon. Running it prints the known answers of the test poseidon_known_answers.
+from math import ceil, comb, floor, gcd, log
+from sympy import Poly, symbols, Matrix
+
+P128 = 2**128 - 45 * 2**40 + 1
+P62 = 4611624995532046337
+P64 = 2**64 - 2**32 + 1
+
+# round numbers, ported from calc_round_numbers.py
+def sat_inequiv_alpha(p, t, R_F, R_P, alpha, M):
+    n = ceil(log(p, 2))
+    R_F_1 = 6 if M <= ((floor(log(p, 2) - ((alpha - 1) / 2.0))) * (t + 1)) else 10
+    R_F_2 = 1 + ceil(log(2, alpha) * min(M, n)) + ceil(log(t, alpha)) - R_P
+    R_F_3 = (log(2, alpha) * min(M, log(p, 2))) - R_P
+    R_F_4 = t - 1 + log(2, alpha) * min(M / float(t + 1), log(p, 2) / float(2)) - R_P
+    R_F_5 = (t - 2 + (M / float(2 * log(alpha, 2))) - R_P) / float(t - 1)
+    R_F_max = max(ceil(R_F_1), ceil(R_F_2), ceil(R_F_3), ceil(R_F_4), ceil(R_F_5))
+    r_temp = floor(t / 3.0)
+    over = (R_F - 1) * t + R_P + r_temp + r_temp * (R_F / 2.0) + R_P + alpha
+    under = r_temp * (R_F / 2.0) + R_P + alpha
+    binom_log = log(comb(int(over), int(under)), 2)
+    cost_gb4 = ceil(2 * binom_log)
+    return (R_F >= R_F_max) and (cost_gb4 >= M)
+
+def find_round_numbers(p, t, alpha, M=128):
+    best = None
+    for R_P_t in range(1, 500):
+        for R_F_t in range(4, 100):
+            if R_F_t % 2 == 0 and sat_inequiv_alpha(p, t, R_F_t, R_P_t, alpha, M):
+                R_F = R_F_t + 2
+                R_P = int(ceil(float(R_P_t) * 1.075))
+                cost = t * R_F + R_P
+                if best is None or cost < best[2]:
+                    best = (R_F, R_P, cost)
+    return best[:2]
+
+def get_alpha(p):
+    a = 3
+    while gcd(a, p - 1) != 1:
+        a += 1
+    return a
+
+class Grain:
+    def __init__(self, n, t, R_F, R_P):
+        bits = '01' + '0000' + bin(n)[2:].zfill(12) + bin(t)[2:].zfill(12) + bin(R_F)[2:].zfill(10) + bin(R_P)[2:].zfill(10) + '1' * 30
+        self.state = [int(b) for b in bits]
+        for _ in range(160):
+            self.step()
+    def step(self):
+        s = self.state
+        new = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0]
+        s.pop(0)
+        s.append(new)
+        return new
+    def bit(self):
+        while True:
+            b1 = self.step()
+            b2 = self.step()
+            if b1 == 1:
+                return b2
+    def bits(self, n):
+        return int(''.join(str(self.bit()) for _ in range(n)), 2)
+
+def charpoly_irreducible(M, p):
+    x = symbols('x')
+    cp = Matrix(M).charpoly(x).as_expr()
+    return Poly(cp, x, modulus=p).is_irreducible
+
+def mat_pow(M, k, p):
+    t = len(M)
+    R = [[int(i == j) for j in range(t)] for i in range(t)]
+    for _ in range(k):
+        R = [[sum(R[i][l] * M[l][j] for l in range(t)) % p for j in range(t)] for i in range(t)]
+    return R
+
+def is_secure(M, p):
+    # sufficient condition against infinitely long invariant subspace trails: the minimal polynomials of
+    # M^1, ..., M^(2t) are irreducible of maximal degree (then they equal the characteristic polynomials)
+    t = len(M)
+    return all(charpoly_irreducible(mat_pow(M, k, p), p) for k in range(1, 2 * t + 1))
+
+def params(p, t):
+    n = p.bit_length()
+    alpha = get_alpha(p)
+    R_F, R_P = find_round_numbers(p, t, alpha)
+    grain = Grain(n, t, R_F, R_P)
+    rc = []
+    for _ in range((R_F + R_P) * t):
+        v = grain.bits(n)
+        while v >= p:
+            v = grain.bits(n)
+        rc.append(v)
+    while True:
+        rand = [grain.bits(n) % p for _ in range(2 * t)]
+        while len(set(rand)) != len(rand):
+            rand = [grain.bits(n) % p for _ in range(2 * t)]
+        xs, ys = rand[:t], rand[t:]
+        M = [[pow(xs[i] + ys[j], -1, p) for j in range(t)] for i in range(t)]
+        if is_secure(M, p):
+            break
+    rc = [rc[r * t:(r + 1) * t] for r in range(R_F + R_P)]
+    return dict(p=p, t=t, alpha=alpha, R_F=R_F, R_P=R_P, rc=rc, mds=M)
+
+def is_full(par, r):
+    return r < par['R_F'] // 2 or r >= par['R_F'] // 2 + par['R_P']
+
+def apply_round(par, state, r):
+    p = par['p']
+    state = [(s + c) % p for s, c in zip(state, par['rc'][r])]
+    if is_full(par, r):
+        state = [pow(s, par['alpha'], p) for s in state]
+    else:
+        state[0] = pow(state[0], par['alpha'], p)
+    return [sum(a * b for a, b in zip(row, state)) % p for row in par['mds']]
+
+def permutation(par, state):
+    for r in range(par['R_F'] + par['R_P']):
+        state = apply_round(par, state, r)
+    return state
+
+def hash(par, rate, inputs):
+    assert len(inputs) % rate == 0
+    state = [0] * par['t']
+    for i in range(0, len(inputs), rate):
+        for j in range(rate):
+            state[j] = (state[j] + inputs[i + j]) % par['p']
+        state = permutation(par, state)
+    return state[:rate]
+
+
+if __name__ == '__main__':
+    # known answers of the test poseidon_known_answers in src/lib.rs
+    print('p64_t9_c1 permutation(0..9):', permutation(params(P64, 9), list(range(9))))
+    print('p128_t4_c3 hash([1, 2]):', hash(params(P128, 4), 1, [1, 2]))
+    print('p62_t9_c1 hash(1..=8):', hash(params(P62, 9), 8, list(range(1, 9))))
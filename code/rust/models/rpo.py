@@ -0,0 +1,59 @@
+# Model of Rescue Prime Optimized (https://eprint.iacr.org/2022/1577) including the generation of its round
+# constants, written independently of the Rust code in src/rescue/p64_m12_c4_s128_rpo.rs. Running it prints
+# the known answers of the test rpo_known_answers.
+import hashlib
+from math import ceil
+p = 2**64 - 2**32 + 1
+m, c, sec, N = 12, 4, 128, 7
+alpha = 7
+inv_alpha = pow(alpha, -1, p - 1)
+row0 = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8]
+MDS = [[row0[(j - i) % m] for j in range(m)] for i in range(m)]
+bpi = ceil(len(bin(p)[2:]) / 8) + 1
+bs = hashlib.shake_256(('RPO(%i,%i,%i,%i)' % (p, m, c, sec)).encode('ascii')).digest(bpi * 2 * m * N)
+RC = [int.from_bytes(bs[bpi * i:bpi * (i + 1)], 'little') % p for i in range(2 * m * N)]
+
+def mat(x):
+    return [sum(a * b for a, b in zip(r, x)) % p for r in MDS]
+
+def apply_round(s, r):
+    rc = RC[r * 2 * m:(r + 1) * 2 * m]
+    s = mat(s); s = [(a + b) % p for a, b in zip(s, rc[:m])]; s = [pow(a, alpha, p) for a in s]
+    s = mat(s); s = [(a + b) % p for a, b in zip(s, rc[m:])]; s = [pow(a, inv_alpha, p) for a in s]
+    return s
+
+def permutation(s):
+    for r in range(N):
+        s = apply_round(s, r)
+    return s
+
+def hash(inputs, rate=m - c):
+    s = [0] * m
+    for i in range(0, len(inputs), rate):
+        for j in range(rate):
+            s[j] = (s[j] + inputs[i + j]) % p
+        s = permutation(s)
+    return s[:rate]
+
+def miden_hash_elements(elements):
+    # sponge of Rpo256::hash_elements in miden-crypto: the capacity consists of the first four elements of the
+    # state, the first of which flags a padded input, and the digest of the four elements after the capacity
+    s = [0] * m
+    if len(elements) % (m - c) != 0:
+        s[0] = 1
+    padded = list(elements)
+    if len(padded) % (m - c) != 0:
+        padded += [1] + [0] * ((m - c) - len(padded) % (m - c) - 1)
+    for i in range(0, len(padded), m - c):
+        s[c:] = padded[i:i + m - c]
+        s = permutation(s)
+    return s[c:2 * c]
+
+
+if __name__ == '__main__':
+    # known answers of the test rpo_known_answers in src/lib.rs
+    print('permutation(0..12):', permutation(list(range(m))))
+    print('hash(1..=8):', hash(list(range(1, 9))))
+    # the first two test vectors of Rpo256::hash_elements in miden-crypto (test hash_test_vectors)
+    print('miden hash_elements([0]):', miden_hash_elements([0]))
+    print('miden hash_elements([0, 1]):', miden_hash_elements([0, 1]))
@@ -0,0 +1,277 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path};
+use winterfell::{Air, ByteReader, ByteWriter, Deserializable, DeserializationError, ProofOptions, Serializable, SliceReader, StarkProof, VerifierError};
+
+// A proof bundle contains everything the verifier needs to check a proof without ever seeing the
+// video: the name of the STARK variant, the proof options, the serialized public inputs and the
+// proof itself.
+//
+// Layout: MAGIC | VERSION | variant (u32 length + utf8) | options | public inputs (u32 length +
+// bytes) | proof (u32 length + bytes)
+
+const MAGIC: &[u8; 4] = b"MTPB";
+const VERSION: u8 = 1;
+
+// ================================================================================================
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum BundleError {
+    Io(io::Error),
+    /// The bundle (or a part of it) could not be deserialized
+    Malformed(String),
+    /// The bundle was created for a STARK variant the verifier does not know
+    UnknownVariant(String),
+    /// The proof was generated with other proof options than the ones of its STARK variant
+    Options(String),
+    /// The proof was rejected by the verifier
+    Verification(VerifierError),
+    /// The public inputs of the proof do not belong to the committed video
+    Commitment(String),
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Io(err) => write!(f, "i/o error: {}", err),
+            BundleError::Malformed(msg) => write!(f, "malformed proof bundle: {}", msg),
+            BundleError::UnknownVariant(variant) => write!(f, "unknown STARK variant '{}'", variant),
+            BundleError::Options(msg) => write!(f, "proof options rejected: {}", msg),
+            BundleError::Verification(err) => write!(f, "proof verification failed: {}", err),
+            BundleError::Commitment(msg) => write!(f, "commitment check failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BundleError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BundleError {
+    fn from(err: io::Error) -> Self {
+        BundleError::Io(err)
+    }
+}
+
+impl From<DeserializationError> for BundleError {
+    fn from(err: DeserializationError) -> Self {
+        BundleError::Malformed(err.to_string())
+    }
+}
+
+// ================================================================================================
+// PROOF BUNDLE
+// ================================================================================================
+
+pub struct ProofBundle {
+    pub variant: String,
+    pub options: ProofOptions,
+    pub pub_inputs: Vec<u8>,
+    pub proof: StarkProof,
+}
+
+impl ProofBundle {
+    pub fn new<P: Serializable>(variant: &str, options: ProofOptions, pub_inputs: &P, proof: StarkProof) -> Self {
+        ProofBundle {
+            variant: String::from(variant),
+            options,
+            pub_inputs: pub_inputs.to_bytes(),
+            proof,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.write_u8_slice(MAGIC);
+        result.write_u8(VERSION);
+        write_bytes(&mut result, self.variant.as_bytes());
+        self.options.write_into(&mut result);
+        write_bytes(&mut result, &self.pub_inputs);
+        write_bytes(&mut result, &self.proof.to_bytes());
+        result
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+        let mut source = SliceReader::new(bytes);
+        if source.read_u8_array::<4>()? != *MAGIC {
+            return Err(BundleError::Malformed(String::from("not a proof bundle")));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(BundleError::Malformed(format!("unsupported bundle version {}", version)));
+        }
+        let variant = read_string(&mut source)?;
+        let options = ProofOptions::read_from(&mut source)?;
+        let pub_inputs = read_bytes(&mut source)?;
+        let proof = StarkProof::from_bytes(&read_bytes(&mut source)?)?;
+        if source.has_more_bytes() {
+            return Err(BundleError::Malformed(String::from("trailing bytes after the proof")));
+        }
+        if *proof.options() != options {
+            return Err(BundleError::Malformed(String::from("proof options of the bundle and the proof differ")));
+        }
+        Ok(ProofBundle { variant, options, pub_inputs, proof })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), BundleError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self, BundleError> {
+        ProofBundle::from_bytes(&fs::read(path)?)
+    }
+
+    pub fn get_pub_inputs<P: Deserializable>(&self) -> Result<P, BundleError> {
+        let mut source = SliceReader::new(&self.pub_inputs);
+        let result = P::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(BundleError::Malformed(String::from("trailing bytes after the public inputs")));
+        }
+        Ok(result)
+    }
+
+    /// Checks that the proof was generated with the `expected` options of its STARK variant.
+    pub fn check_options(&self, expected: &ProofOptions) -> Result<(), BundleError> {
+        if self.options != *expected {
+            return Err(BundleError::Options(format!("{} queries with blowup factor {}, expected {} queries with blowup factor {}",
+                self.options.num_queries(), self.options.blowup_factor(), expected.num_queries(), expected.blowup_factor())));
+        }
+        Ok(())
+    }
+
+    /// Verifies the proof of the bundle against its public inputs for the AIR `A`.
+    pub fn verify<A: Air>(self) -> Result<(), BundleError> where A::PublicInputs: Deserializable {
+        let pub_inputs = self.get_pub_inputs::<A::PublicInputs>()?;
+        winterfell::verify::<A>(self.proof, pub_inputs).map_err(BundleError::Verification)
+    }
+}
+
+// ================================================================================================
+// SEGMENT MANIFEST
+// ================================================================================================
+
+// A manifest ties the proof bundles of the segments of a video (see stark::family::stark_f_segment)
+// to the frames they cover. The paths of the bundles are relative to the directory of the manifest.
+//
+// Layout: MANIFEST_MAGIC | VERSION | variant (u32 length + utf8) | frame size (u64) | number of
+// segments (u32) | per segment: first frame (u64) | number of frames (u64) | path of the proof bundle
+// (u32 length + utf8)
+
+const MANIFEST_MAGIC: &[u8; 4] = b"MTPM";
+
+pub struct SegmentEntry {
+    pub first_frame: usize,
+    pub num_frames: usize,
+    pub proof: String,
+}
+
+pub struct SegmentManifest {
+    pub variant: String,
+    /// number of pixels of one frame
+    pub frame_size: usize,
+    pub segments: Vec<SegmentEntry>,
+}
+
+impl SegmentManifest {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.write_u8_slice(MANIFEST_MAGIC);
+        result.write_u8(VERSION);
+        write_bytes(&mut result, self.variant.as_bytes());
+        result.write_u64(self.frame_size as u64);
+        result.write_u32(self.segments.len() as u32);
+        for segment in self.segments.iter() {
+            result.write_u64(segment.first_frame as u64);
+            result.write_u64(segment.num_frames as u64);
+            write_bytes(&mut result, segment.proof.as_bytes());
+        }
+        result
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+        let mut source = SliceReader::new(bytes);
+        if source.read_u8_array::<4>()? != *MANIFEST_MAGIC {
+            return Err(BundleError::Malformed(String::from("not a segment manifest")));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(BundleError::Malformed(format!("unsupported manifest version {}", version)));
+        }
+        let variant = read_string(&mut source)?;
+        let frame_size = source.read_u64()? as usize;
+        let num_segments = source.read_u32()? as usize;
+        let mut segments = vec![];
+        let mut next_frame = 0usize;
+        for _ in 0..num_segments {
+            let first_frame = source.read_u64()? as usize;
+            let num_frames = source.read_u64()? as usize;
+            let proof = read_string(&mut source)?;
+            // the proof bundles lie next to the manifest, absolute paths or paths leaving its directory are rejected
+            let mut components = Path::new(&proof).components();
+            if !matches!((components.next(), components.next()), (Some(Component::Normal(_)), None)) {
+                return Err(BundleError::Malformed(format!("proof bundle {} of segment {} is not a file name", proof, segments.len())));
+            }
+            // the segments cover consecutive frames starting with the first one
+            if first_frame != next_frame || num_frames == 0 {
+                return Err(BundleError::Malformed(format!("segment {} does not continue at frame {}", segments.len(), next_frame)));
+            }
+            next_frame = next_frame.checked_add(num_frames).filter(|n| n.checked_mul(frame_size).is_some())
+                .ok_or_else(|| BundleError::Malformed(format!("segment {} exceeds the largest number of frames", segments.len())))?;
+            segments.push(SegmentEntry { first_frame, num_frames, proof });
+        }
+        if source.has_more_bytes() {
+            return Err(BundleError::Malformed(String::from("trailing bytes after the segments")));
+        }
+        Ok(SegmentManifest { variant, frame_size, segments })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), BundleError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self, BundleError> {
+        SegmentManifest::from_bytes(&fs::read(path)?)
+    }
+
+    /// Reads the proof bundles of the segments in order, checking that the bundles belong to the variant
+    /// of the manifest. The segments cover consecutive frames starting with the first one, see `from_bytes`.
+    pub fn read_bundles(&self, dir: &Path) -> Result<Vec<ProofBundle>, BundleError> {
+        let mut result = vec![];
+        for segment in self.segments.iter() {
+            let bundle = ProofBundle::read(&dir.join(&segment.proof))?;
+            if bundle.variant != self.variant {
+                return Err(BundleError::Malformed(format!("proof bundle {} is not a proof of {}", segment.proof, self.variant)));
+            }
+            result.push(bundle);
+        }
+        Ok(result)
+    }
+}
+
+// ================================================================================================
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_bytes(target: &mut Vec<u8>, bytes: &[u8]) {
+    target.write_u32(bytes.len() as u32);
+    target.write_u8_slice(bytes);
+}
+
+fn read_bytes<R: ByteReader>(source: &mut R) -> Result<Vec<u8>, BundleError> {
+    let len = source.read_u32()? as usize;
+    Ok(source.read_u8_vec(len)?)
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, BundleError> {
+    String::from_utf8(read_bytes(source)?).map_err(|_| BundleError::Malformed(String::from("string is not valid utf8")))
+}
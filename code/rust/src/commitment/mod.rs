@@ -0,0 +1,430 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use winterfell::math::{FieldElement, StarkField};
+use winterfell::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader};
+use crate::bundle::BundleError;
+use crate::permutation::{HashPermutation, Hasher};
+use crate::utils::{next_power_of_two, read_bool, write_bool};
+
+// The public `hash` of a proof is a commitment to the video. If the prover salted it with a secret
+// salt, the commitment hides the video. An opening reveals the video and the salt, e.g. to an
+// auditor, who recomputes the commitment natively and compares it to the `hash` of the proof.
+//
+// Layout: MAGIC | VERSION | variant (u32 length + utf8) | salted | salt (u32 length + elements) |
+// pixels (u32 length + little-endian u16 values)
+
+const MAGIC: &[u8; 4] = b"MTPO";
+const COMMITMENT_MAGIC: &[u8; 4] = b"MTPC";
+const SERIES_MAGIC: &[u8; 4] = b"MTPS";
+const VERSION: u8 = 1;
+
+// ================================================================================================
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum CommitmentError {
+    Io(io::Error),
+    /// The opening could not be deserialized
+    Malformed(String),
+    /// The opening was created for another STARK variant than the proof
+    VariantMismatch { opening: String, proof: String },
+    /// The opening does not match the commitment of the proof
+    Mismatch(String),
+    /// The proof bundle to open could not be read
+    Bundle(BundleError),
+    /// A Merkle authentication path of a frame does not lead to the root of the frame commitment
+    Path(String),
+    /// A proof does not refer to the video of a fixed commitment
+    Statement(String),
+}
+
+impl fmt::Display for CommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitmentError::Io(err) => write!(f, "i/o error: {}", err),
+            CommitmentError::Malformed(msg) => write!(f, "malformed opening: {}", msg),
+            CommitmentError::VariantMismatch { opening, proof } => write!(f,
+                "opening of a '{}' commitment cannot open a proof of '{}'", opening, proof),
+            CommitmentError::Mismatch(msg) => write!(f, "opening does not match the commitment: {}", msg),
+            CommitmentError::Bundle(err) => write!(f, "{}", err),
+            CommitmentError::Path(msg) => write!(f, "invalid authentication path: {}", msg),
+            CommitmentError::Statement(msg) => write!(f, "proof does not refer to the committed video: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for CommitmentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CommitmentError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CommitmentError {
+    fn from(err: io::Error) -> Self {
+        CommitmentError::Io(err)
+    }
+}
+
+impl From<BundleError> for CommitmentError {
+    fn from(err: BundleError) -> Self {
+        CommitmentError::Bundle(err)
+    }
+}
+
+impl From<DeserializationError> for CommitmentError {
+    fn from(err: DeserializationError) -> Self {
+        CommitmentError::Malformed(err.to_string())
+    }
+}
+
+// ================================================================================================
+// OPENING
+// ================================================================================================
+
+pub struct Opening<B: StarkField> {
+    pub variant: String,
+    pub pixels: Vec<u16>,
+    pub salt: Option<Vec<B>>,
+}
+
+impl<B: StarkField> Opening<B> {
+    pub fn new(variant: &str, pixels: &Vec<u16>, salt: Option<&[B]>) -> Self {
+        Opening {
+            variant: String::from(variant),
+            pixels: pixels.clone(),
+            salt: salt.map(|s| s.to_vec()),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.write_u8_slice(MAGIC);
+        result.write_u8(VERSION);
+        result.write_u32(self.variant.len() as u32);
+        result.write_u8_slice(self.variant.as_bytes());
+        write_bool(&mut result, self.salt.is_some());
+        let salt = self.salt.clone().unwrap_or_default();
+        result.write_u32(salt.len() as u32);
+        for elem in salt {
+            result.write(elem);
+        }
+        result.write_u32(self.pixels.len() as u32);
+        for pixel in self.pixels.iter() {
+            result.write_u8_slice(&pixel.to_le_bytes());
+        }
+        result
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CommitmentError> {
+        let mut source = SliceReader::new(bytes);
+        if source.read_u8_array::<4>()? != *MAGIC {
+            return Err(CommitmentError::Malformed(String::from("not an opening")));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(CommitmentError::Malformed(format!("unsupported opening version {}", version)));
+        }
+        let len = source.read_u32()? as usize;
+        let variant = String::from_utf8(source.read_u8_vec(len)?)
+            .map_err(|_| CommitmentError::Malformed(String::from("variant name is not valid utf8")))?;
+        let salted = read_bool(&mut source)?;
+        let mut salt = vec![];
+        for _ in 0..source.read_u32()? {
+            salt.push(B::read_from(&mut source)?);
+        }
+        if !salted && !salt.is_empty() {
+            return Err(CommitmentError::Malformed(String::from("unsalted opening contains a salt")));
+        }
+        let len = source.read_u32()? as usize;
+        let pixels = source.read_u8_vec(2 * len)?.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect();
+        if source.has_more_bytes() {
+            return Err(CommitmentError::Malformed(String::from("trailing bytes after the pixels")));
+        }
+        Ok(Opening { variant, pixels, salt: if salted { Some(salt) } else { None } })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), CommitmentError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Checks the opening against the public inputs `hash`, `salted` and `input_length` of a proof,
+    /// where `commit` is the native commitment function of the STARK variant.
+    pub fn verify<const N: usize>(&self, hash: &[B; N], salted: bool, input_length: usize, commit: fn(&Vec<u16>, Option<&[B; N]>) -> [B; N]) -> Result<(), CommitmentError> {
+        // the salt is only passed to `commit` after its length has been checked
+        self.verify_digest(hash, salted, input_length, |pixels, salt| commit(pixels, salt.map(|s| s.try_into().unwrap())).to_vec())
+    }
+
+    /// Same as `verify` for STARKs whose hash length is only known at runtime (see stark::family).
+    pub fn verify_digest(&self, hash: &[B], salted: bool, input_length: usize, commit: impl Fn(&Vec<u16>, Option<&[B]>) -> Vec<B>) -> Result<(), CommitmentError> {
+        if self.salt.is_some() != salted {
+            return Err(CommitmentError::Mismatch(format!("the commitment is {}salted, the opening is not", if salted { "" } else { "not " })));
+        }
+        if self.pixels.len() != input_length {
+            return Err(CommitmentError::Mismatch(format!("the proof covers {} pixels, the opening has {}", input_length, self.pixels.len())));
+        }
+        if let Some(salt) = &self.salt {
+            if salt.len() != hash.len() {
+                return Err(CommitmentError::Mismatch(format!("expected a salt of {} elements, got {}", hash.len(), salt.len())));
+            }
+        }
+        if commit(&self.pixels, self.salt.as_deref()) != hash {
+            return Err(CommitmentError::Mismatch(String::from("video and salt do not hash to the commitment")));
+        }
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// FIXED COMMITMENT
+// ================================================================================================
+
+// A commitment to a video fixed once, e.g. taken from the first proof handed to a customer, which
+// later proofs refer to: a proof is a statement about the committed video if its public hash equals
+// the fixed one. This requires both to compute the commitment the same way (same hash instance, pixel
+// packing and padding), which `scheme` names. The hash is kept as serialized field elements.
+//
+// Layout: COMMITMENT_MAGIC | VERSION | scheme (u32 length + utf8) | salted | input length (u64) |
+// hash (u32 length + bytes)
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoCommitment {
+    pub scheme: String,
+    pub input_length: usize,
+    pub salted: bool,
+    pub hash: Vec<u8>,
+}
+
+impl VideoCommitment {
+    pub fn new<B: StarkField>(scheme: &str, hash: &[B], input_length: usize, salted: bool) -> Self {
+        let mut bytes = vec![];
+        for elem in hash {
+            bytes.write(*elem);
+        }
+        VideoCommitment { scheme: String::from(scheme), input_length, salted, hash: bytes }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.write_u8_slice(COMMITMENT_MAGIC);
+        result.write_u8(VERSION);
+        result.write_u32(self.scheme.len() as u32);
+        result.write_u8_slice(self.scheme.as_bytes());
+        write_bool(&mut result, self.salted);
+        result.write_u64(self.input_length as u64);
+        result.write_u32(self.hash.len() as u32);
+        result.write_u8_slice(&self.hash);
+        result
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CommitmentError> {
+        let mut source = SliceReader::new(bytes);
+        if source.read_u8_array::<4>()? != *COMMITMENT_MAGIC {
+            return Err(CommitmentError::Malformed(String::from("not a commitment")));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(CommitmentError::Malformed(format!("unsupported commitment version {}", version)));
+        }
+        let len = source.read_u32()? as usize;
+        let scheme = String::from_utf8(source.read_u8_vec(len)?)
+            .map_err(|_| CommitmentError::Malformed(String::from("scheme name is not valid utf8")))?;
+        let salted = read_bool(&mut source)?;
+        let input_length = source.read_u64()? as usize;
+        let len = source.read_u32()? as usize;
+        let hash = source.read_u8_vec(len)?;
+        if source.has_more_bytes() {
+            return Err(CommitmentError::Malformed(String::from("trailing bytes after the hash")));
+        }
+        Ok(VideoCommitment { scheme, input_length, salted, hash })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), CommitmentError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self, CommitmentError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Checks that the commitment in the public inputs of a proof is this commitment.
+    pub fn check(&self, statement: &VideoCommitment) -> Result<(), CommitmentError> {
+        if statement.scheme != self.scheme {
+            return Err(CommitmentError::Statement(format!("the proof commits with '{}', the video was committed with '{}'", statement.scheme, self.scheme)));
+        }
+        if statement.input_length != self.input_length {
+            return Err(CommitmentError::Statement(format!("the proof covers {} pixels, the committed video has {}", statement.input_length, self.input_length)));
+        }
+        if statement.salted != self.salted {
+            return Err(CommitmentError::Statement(format!("the commitment is {}salted, the one of the proof is not", if self.salted { "" } else { "not " })));
+        }
+        if statement.hash != self.hash {
+            return Err(CommitmentError::Statement(String::from("the hash of the proof differs from the committed hash")));
+        }
+        Ok(())
+    }
+}
+
+// ================================================================================================
+// FRAME COMMITMENT
+// ================================================================================================
+
+// Instead of one sponge over all pixels, every frame is hashed on its own (padded with 1 || 0*) and a
+// Merkle tree with the 2-to-1 compression H(left || right) is built over the frame digests. The root
+// commits to the video, the authentication path of a frame proves that its digest belongs to the
+// root without hashing the other frames (see stark::family::stark_f_frames). The leaves are filled
+// up with zero digests to a power of two.
+
+pub struct FrameTree<H: HashPermutation> {
+    // levels[0] are the leaves, the last level is the root
+    levels: Vec<Vec<H::Digest>>,
+    num_frames: usize,
+}
+
+impl<H: HashPermutation> FrameTree<H> {
+    /// Builds the tree over the frames of `frame_size` pixels.
+    pub fn new(pixels: &[u16], frame_size: usize) -> Self {
+        assert!(frame_size > 0 && !pixels.is_empty() && pixels.len() % frame_size == 0, "the video has to consist of whole frames");
+        Self::from_leaves(pixels.chunks(frame_size).map(|frame| hash_frame::<H>(frame)).collect())
+    }
+
+    /// Builds the tree over the given frame digests, e.g. computed frame by frame with `hash_frame`.
+    pub fn from_leaves(leaves: Vec<H::Digest>) -> Self {
+        let num_frames = leaves.len();
+        let mut level = leaves;
+        level.resize(next_power_of_two(num_frames), get_zero_digest::<H>());
+        let mut levels = vec![level];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels.last().unwrap().chunks(2).map(|pair| compress::<H>(&pair[0], &pair[1])).collect();
+            levels.push(next);
+        }
+        FrameTree { levels, num_frames }
+    }
+
+    pub fn root(&self) -> H::Digest {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn num_frames(&self) -> usize {
+        self.num_frames
+    }
+
+    pub fn get_leaf(&self, index: usize) -> H::Digest {
+        assert!(index < self.num_frames);
+        self.levels[0][index]
+    }
+
+    /// Authentication path of frame `index`: the siblings from the leaf up to the root.
+    pub fn get_path(&self, index: usize) -> Vec<H::Digest> {
+        assert!(index < self.num_frames);
+        self.levels[..self.levels.len() - 1].iter().enumerate().map(|(depth, level)| level[(index >> depth) ^ 1]).collect()
+    }
+}
+
+/// Digest of one frame, a leaf of the frame tree.
+pub fn hash_frame<H: HashPermutation>(frame: &[u16]) -> H::Digest {
+    let mut hasher = Hasher::<H>::new().padded();
+    hasher.update(frame);
+    hasher.finalize().expect("a padded input fills whole blocks")
+}
+
+/// Checks that `path` leads from the digest of frame `index` of a video of `num_frames` frames to `root`.
+pub fn verify_frame_path<H: HashPermutation>(root: &H::Digest, num_frames: usize, index: usize, digest: &H::Digest, path: &[H::Digest]) -> Result<(), CommitmentError> {
+    if index >= num_frames {
+        return Err(CommitmentError::Path(format!("frame {} is not one of the {} frames", index, num_frames)));
+    }
+    let depth = next_power_of_two(num_frames).trailing_zeros() as usize;
+    if path.len() != depth {
+        return Err(CommitmentError::Path(format!("expected {} nodes for frame {}, got {}", depth, index, path.len())));
+    }
+    let mut node = *digest;
+    for (level, sibling) in path.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 { compress::<H>(&node, sibling) } else { compress::<H>(sibling, &node) };
+    }
+    if node != *root {
+        return Err(CommitmentError::Path(format!("frame {} does not lead to the root", index)));
+    }
+    Ok(())
+}
+
+// 2-to-1 compression of two nodes
+fn compress<H: HashPermutation>(left: &H::Digest, right: &H::Digest) -> H::Digest {
+    let mut input = left.as_ref().to_vec();
+    input.extend_from_slice(right.as_ref());
+    H::hash(&input)
+}
+
+fn get_zero_digest<H: HashPermutation>() -> H::Digest {
+    H::Digest::try_from(&vec![H::BaseField::ZERO; H::RATE][..]).unwrap()
+}
+
+// ================================================================================================
+// TIME SERIES
+// ================================================================================================
+
+// Per-frame statistics of a range of frames proven by stark::family::stark_f_series, written by the
+// prover next to the proof. The proof only contains the digest of the series, the file opens it.
+//
+// Layout: SERIES_MAGIC | VERSION | variant (u32 length + utf8) | first frame (u64) |
+// frames (u32 length + serialized statistics)
+
+pub struct TimeSeries<S: Serializable + Deserializable> {
+    pub variant: String,
+    pub first_frame: usize,
+    pub frames: Vec<S>,
+}
+
+impl<S: Serializable + Deserializable> TimeSeries<S> {
+    pub fn new(variant: &str, first_frame: usize, frames: Vec<S>) -> Self {
+        TimeSeries { variant: String::from(variant), first_frame, frames }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = vec![];
+        result.write_u8_slice(SERIES_MAGIC);
+        result.write_u8(VERSION);
+        result.write_u32(self.variant.len() as u32);
+        result.write_u8_slice(self.variant.as_bytes());
+        result.write_u64(self.first_frame as u64);
+        result.write_u32(self.frames.len() as u32);
+        for frame in self.frames.iter() {
+            frame.write_into(&mut result);
+        }
+        result
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CommitmentError> {
+        let mut source = SliceReader::new(bytes);
+        if source.read_u8_array::<4>()? != *SERIES_MAGIC {
+            return Err(CommitmentError::Malformed(String::from("not a time series")));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(CommitmentError::Malformed(format!("unsupported time series version {}", version)));
+        }
+        let len = source.read_u32()? as usize;
+        let variant = String::from_utf8(source.read_u8_vec(len)?)
+            .map_err(|_| CommitmentError::Malformed(String::from("variant name is not valid utf8")))?;
+        let first_frame = source.read_u64()? as usize;
+        let mut frames = vec![];
+        for _ in 0..source.read_u32()? {
+            frames.push(S::read_from(&mut source)?);
+        }
+        if source.has_more_bytes() {
+            return Err(CommitmentError::Malformed(String::from("trailing bytes after the frames")));
+        }
+        Ok(TimeSeries { variant, first_frame, frames })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), CommitmentError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+}
@@ -10,3 +10,7 @@ pub mod p62_t12_c4_s100;
 pub mod p62_t20_c4_s100;
 pub mod p62_t36_c4_s100;
 pub mod p62_t68_c4_s100;
+
+// Griffin-π following the final specification, the modules above implement a preliminary version
+#[allow(dead_code)]
+pub mod spec;
@@ -9,6 +9,8 @@
 //   rate and capacity do not influence parameter generation and can be chosen freely.
 
 use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
 
 pub type Elem = BaseElement;
 
@@ -1047,6 +1049,14 @@ pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
     griffin_hash(input_sequence)
 }
 
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
 // ALGORITHMS for computing Griffin
 // ================================================================================================
 
@@ -1209,4 +1219,61 @@ fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_W
         }
     }
     state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+// Griffin-π as published in https://eprint.iacr.org/2022/403. The instances share the API of the
+// preliminary variant, i.e. a STARK switches over by changing its `use crate::griffin::...` line, e.g.
+// to `use crate::griffin::spec::p128_t4_c3_s128::...`. Note that the published round numbers are
+// higher (14 rounds for t = 4, 10 rounds for t >= 12), so the hash cycle of the STARK (CYCLE_LENGTH
+// and its masks) has to fit NUM_ROUNDS of the new instance.
+
+pub mod p128_t4_c2_s128;
+pub mod p128_t4_c3_s128;
+pub mod p128_t12_c4_s128;
+pub mod p128_t20_c4_s128;
+pub mod p128_t36_c4_s128;
+pub mod p128_t68_c4_s128;
+pub mod p62_t4_c2_s128;
+pub mod p62_t4_c3_s128;
+pub mod p62_t12_c4_s128;
+pub mod p62_t20_c4_s128;
+pub mod p62_t36_c4_s128;
+pub mod p62_t68_c4_s128;
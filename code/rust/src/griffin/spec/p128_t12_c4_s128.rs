@@ -0,0 +1,717 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 12
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 12;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u128 = 226854911280625642308916371969163307691;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(276479423123262501563991828337417781249),
+    Elem::new(74436767763955288882613184552381710336),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(116972063629072596815535004296599830529),
+    Elem::new(31901471898837980949691364808163590144),
+    Elem::new(244577951224424520614300463529254191105),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(287113247089541828547222283273472311297),
+    Elem::new(10633823966279326983230454936054530048),
+    Elem::new(329648542954659136480144103017690431489),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(159507359494189904748456824040817950720),
+    Elem::new(308380895022100482513683193145581371393),
+    Elem::new(31901471898837980949691364808163590144),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(193270192276457167923215266422101947829),
+    Elem::new(89938288378372256346609030201429208698),
+    Elem::new(88361201198252942658668279150731877291),
+    Elem::new(246234940206676189864811856655532630667),
+    Elem::new(22296539765034463025033269326118572979),
+    Elem::new(284142384843261002980388657984492297652),
+    Elem::new(66655852058006458249439144890439771595),
+    Elem::new(15248908930456844198433238539531275683),
+    Elem::new(22904831736968272388440091026815532301),
+    Elem::new(228952370785523807041212207749444474465),
+    Elem::new(199509320527700703527179460974645456831),
+    Elem::new(120675339497845834171013581027601532003),
+    Elem::new(34788710169365956384585601114712576176),
+    Elem::new(159062502885509721301337193606876516310),
+    Elem::new(129274730093234882853952116461057447531),
+    Elem::new(35218481700550534904174476227377646500),
+    Elem::new(70949201535599811535944502469634699324),
+    Elem::new(57277929195174499600606226076696555432),
+    Elem::new(301296759298417091340840431516009215994),
+    Elem::new(217545829781217642828243081658498814770),
+    Elem::new(307500551733782623752263529036082741615),
+    Elem::new(42301038520057081507068032353922797283),
+    Elem::new(195819002574491571746625040232984575568),
+    Elem::new(24258495994004520560356481885310465059),
+    Elem::new(171796579667114939373634552022931136470),
+    Elem::new(281878342109676169417207005865425655439),
+    Elem::new(111962742339112980600458322753318803109),
+    Elem::new(121296503184411856038790305238325931233),
+    Elem::new(233520083802991021136721045311209298192),
+    Elem::new(218291312275319019208438948242188571505),
+    Elem::new(143084577179518795629240813529253199596),
+    Elem::new(23851861200245790022731011746161842728),
+    Elem::new(252171952243140594562115723453938172130),
+    Elem::new(288061330868820134473221125243370670434),
+    Elem::new(25213866509844090232073845620026947867),
+    Elem::new(223522724438008164501861352568747777269),
+    Elem::new(117231353689221751685407708209562627842),
+    Elem::new(194267486291889818682891476485965854158),
+    Elem::new(173992412060492663257788773668391978997),
+    Elem::new(318736031519928345520095316108406726402),
+    Elem::new(192898707399869993500163143006171916606),
+    Elem::new(29894060149136976301003892370016875547),
+    Elem::new(44152199764232760635977318743008815217),
+    Elem::new(263529978301192505725772006798995593478),
+    Elem::new(298401726355155521033429976913318162656),
+    Elem::new(326421386912883980175669964529653583173),
+    Elem::new(224888606167322461065541351548468058912),
+    Elem::new(98981387340771652536150884680190272787),
+    Elem::new(309681257180184910755237210344396220060),
+    Elem::new(333053738240604947524602899519740518617),
+    Elem::new(280122968575498626664677171189712778179),
+    Elem::new(281881787799096717504154846478436489429),
+    Elem::new(68083987648383250840412523789317904991),
+    Elem::new(283201506418571171467820727261500834352),
+    Elem::new(51568991020334728164468145072827132668),
+    Elem::new(88886231470706823738671344758292586745),
+    Elem::new(165550592447995962674142199240248307398),
+    Elem::new(196197351484247700312169619109916969627),
+    Elem::new(72151407836487561439573787275968401773),
+    Elem::new(208842973331604314497091115378049288388),
+    Elem::new(320198368154175384068956932699005883107),
+    Elem::new(165475637620910460878667544760875216210),
+    Elem::new(312954689296395338564750253976294687862),
+    Elem::new(109658675746804963466423046877073557465),
+    Elem::new(153239262637422354702644803726754760143),
+    Elem::new(43583610728192963663523091968205456876),
+    Elem::new(325715331864914919826569115212179353478),
+    Elem::new(333484592163757737748338315862933970496),
+    Elem::new(171003654548322155205195242379290022747),
+    Elem::new(331323748751065644468544085367686659861),
+    Elem::new(223087657953282655568949634572652966082),
+    Elem::new(125701598562618209585795547745669712076),
+    Elem::new(320072218788402658931855040811115266664),
+    Elem::new(205671799023855477310365297318816880661),
+    Elem::new(203021420898946445107486315964248213176),
+    Elem::new(182588132642157624450055423671141452356),
+    Elem::new(258368342771773134539779917551687321350),
+    Elem::new(43722200886398374604960960872759531024),
+    Elem::new(181672410568337994216393667763524541330),
+    Elem::new(106537458221838972515478222836386126537),
+    Elem::new(235042639125939320917150349714466328444),
+    Elem::new(8474171262532203626594138011979245225),
+    Elem::new(61993116039099558133056048733637966171),
+    Elem::new(307683394961725209663960072834304676713),
+    Elem::new(132011508556421186304279178579181762346),
+    Elem::new(223939632912525278632055300348977228049),
+    Elem::new(28234823444754912333131168811472429841),
+    Elem::new(109123998930527403870802904018139929309),
+    Elem::new(194182217316060920120535748670631084752),
+    Elem::new(10391116156617211721710029274964759942),
+    Elem::new(300154962803685331904641565521419886580),
+    Elem::new(78566860864187509600489004868972108305),
+    Elem::new(220340917830336656085792505557276253537),
+    Elem::new(92465722953879597226963946947746454944),
+    Elem::new(318657719330952662545391612924727506488),
+    Elem::new(161013454367776897053489039717502069153),
+    Elem::new(100033480504449150461983539633696644327),
+    Elem::new(175115649755765970217532718584975810150),
+    Elem::new(89831215511699325738037144992065095612),
+    Elem::new(325307821334455856988644831730248406165),
+    Elem::new(21325139288408165941153152621709844705),
+    Elem::new(162906992299243621514156344714594397923),
+    Elem::new(195286264111384177071115344571142277236),
+    Elem::new(166997325572540501259166580695303463557),
+    Elem::new(121347115604717302741662168734346571714),
+    Elem::new(112877997970734940520135007052409467848),
+    Elem::new(303657583879775251590390446435643817237),
+    Elem::new(146994626928799704841350735501154361928),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(28594903424512098851583506138593520622),
+    Elem::new(57189806849024197703167012277187041244),
+    Elem::new(85784710273536296554750518415780561866),
+    Elem::new(114379613698048395406334024554374082488),
+    Elem::new(142974517122560494257917530692967603110),
+    Elem::new(171569420547072593109501036831561123732),
+    Elem::new(200164323971584691961084542970154644354),
+    Elem::new(228759227396096790812668049108748164976),
+    Elem::new(257354130820608889664251555247341685598),
+    Elem::new(285949034245120988515835061385935206220),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(299275356464007655881510134838474523491),
+    Elem::new(176254325093215233135916865492663209353),
+    Elem::new(311501639729499658689969307870055980660),
+    Elem::new(24452566530984005616918346063162914338),
+    Elem::new(335954206260483664306887653933218894998),
+    Elem::new(225159458155183244369753557618989038029),
+    Elem::new(32350689136021209268890615074218304968),
+    Elem::new(97810266123936022467673384252651657352),
+    Elem::new(81255822197989220502727307200544133644),
+    Elem::new(322969724279119266837426941871640695381),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1325 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 20
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 20;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u128 = 226854911280625642308916371969163307691;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(163051967482949680409533642352836127403),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(21267647932558653966460909872109060096),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(205587263348066988342455462097054247596),
+    Elem::new(262300991168223398919684555089345074518),
+    Elem::new(233944127258145193631070008593199661057),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(141784319550391026443072732480727067307),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(219765695303106090986762735345126954326),
+    Elem::new(311925503010860258174760011457599548076),
+    Elem::new(290657855078301604208299101585490487979),
+    Elem::new(77981375752715064543690002864399887019),
+    Elem::new(262300991168223398919684555089345074518),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(193270192276457167923215266422101947829),
+    Elem::new(89938288378372256346609030201429208698),
+    Elem::new(88361201198252942658668279150731877291),
+    Elem::new(246234940206676189864811856655532630667),
+    Elem::new(22296539765034463025033269326118572979),
+    Elem::new(284142384843261002980388657984492297652),
+    Elem::new(66655852058006458249439144890439771595),
+    Elem::new(15248908930456844198433238539531275683),
+    Elem::new(22904831736968272388440091026815532301),
+    Elem::new(228952370785523807041212207749444474465),
+    Elem::new(199509320527700703527179460974645456831),
+    Elem::new(120675339497845834171013581027601532003),
+    Elem::new(34788710169365956384585601114712576176),
+    Elem::new(159062502885509721301337193606876516310),
+    Elem::new(129274730093234882853952116461057447531),
+    Elem::new(35218481700550534904174476227377646500),
+    Elem::new(70949201535599811535944502469634699324),
+    Elem::new(57277929195174499600606226076696555432),
+    Elem::new(301296759298417091340840431516009215994),
+    Elem::new(217545829781217642828243081658498814770),
+    Elem::new(307500551733782623752263529036082741615),
+    Elem::new(42301038520057081507068032353922797283),
+    Elem::new(195819002574491571746625040232984575568),
+    Elem::new(24258495994004520560356481885310465059),
+    Elem::new(171796579667114939373634552022931136470),
+    Elem::new(281878342109676169417207005865425655439),
+    Elem::new(111962742339112980600458322753318803109),
+    Elem::new(121296503184411856038790305238325931233),
+    Elem::new(233520083802991021136721045311209298192),
+    Elem::new(218291312275319019208438948242188571505),
+    Elem::new(143084577179518795629240813529253199596),
+    Elem::new(23851861200245790022731011746161842728),
+    Elem::new(252171952243140594562115723453938172130),
+    Elem::new(288061330868820134473221125243370670434),
+    Elem::new(25213866509844090232073845620026947867),
+    Elem::new(223522724438008164501861352568747777269),
+    Elem::new(117231353689221751685407708209562627842),
+    Elem::new(194267486291889818682891476485965854158),
+    Elem::new(173992412060492663257788773668391978997),
+    Elem::new(318736031519928345520095316108406726402),
+    Elem::new(192898707399869993500163143006171916606),
+    Elem::new(29894060149136976301003892370016875547),
+    Elem::new(44152199764232760635977318743008815217),
+    Elem::new(263529978301192505725772006798995593478),
+    Elem::new(298401726355155521033429976913318162656),
+    Elem::new(326421386912883980175669964529653583173),
+    Elem::new(224888606167322461065541351548468058912),
+    Elem::new(98981387340771652536150884680190272787),
+    Elem::new(309681257180184910755237210344396220060),
+    Elem::new(333053738240604947524602899519740518617),
+    Elem::new(280122968575498626664677171189712778179),
+    Elem::new(281881787799096717504154846478436489429),
+    Elem::new(68083987648383250840412523789317904991),
+    Elem::new(283201506418571171467820727261500834352),
+    Elem::new(51568991020334728164468145072827132668),
+    Elem::new(88886231470706823738671344758292586745),
+    Elem::new(165550592447995962674142199240248307398),
+    Elem::new(196197351484247700312169619109916969627),
+    Elem::new(72151407836487561439573787275968401773),
+    Elem::new(208842973331604314497091115378049288388),
+    Elem::new(320198368154175384068956932699005883107),
+    Elem::new(165475637620910460878667544760875216210),
+    Elem::new(312954689296395338564750253976294687862),
+    Elem::new(109658675746804963466423046877073557465),
+    Elem::new(153239262637422354702644803726754760143),
+    Elem::new(43583610728192963663523091968205456876),
+    Elem::new(325715331864914919826569115212179353478),
+    Elem::new(333484592163757737748338315862933970496),
+    Elem::new(171003654548322155205195242379290022747),
+    Elem::new(331323748751065644468544085367686659861),
+    Elem::new(223087657953282655568949634572652966082),
+    Elem::new(125701598562618209585795547745669712076),
+    Elem::new(320072218788402658931855040811115266664),
+    Elem::new(205671799023855477310365297318816880661),
+    Elem::new(203021420898946445107486315964248213176),
+    Elem::new(182588132642157624450055423671141452356),
+    Elem::new(258368342771773134539779917551687321350),
+    Elem::new(43722200886398374604960960872759531024),
+    Elem::new(181672410568337994216393667763524541330),
+    Elem::new(106537458221838972515478222836386126537),
+    Elem::new(235042639125939320917150349714466328444),
+    Elem::new(8474171262532203626594138011979245225),
+    Elem::new(61993116039099558133056048733637966171),
+    Elem::new(307683394961725209663960072834304676713),
+    Elem::new(132011508556421186304279178579181762346),
+    Elem::new(223939632912525278632055300348977228049),
+    Elem::new(28234823444754912333131168811472429841),
+    Elem::new(109123998930527403870802904018139929309),
+    Elem::new(194182217316060920120535748670631084752),
+    Elem::new(10391116156617211721710029274964759942),
+    Elem::new(300154962803685331904641565521419886580),
+    Elem::new(78566860864187509600489004868972108305),
+    Elem::new(220340917830336656085792505557276253537),
+    Elem::new(92465722953879597226963946947746454944),
+    Elem::new(318657719330952662545391612924727506488),
+    Elem::new(161013454367776897053489039717502069153),
+    Elem::new(100033480504449150461983539633696644327),
+    Elem::new(175115649755765970217532718584975810150),
+    Elem::new(89831215511699325738037144992065095612),
+    Elem::new(325307821334455856988644831730248406165),
+    Elem::new(21325139288408165941153152621709844705),
+    Elem::new(162906992299243621514156344714594397923),
+    Elem::new(195286264111384177071115344571142277236),
+    Elem::new(166997325572540501259166580695303463557),
+    Elem::new(121347115604717302741662168734346571714),
+    Elem::new(112877997970734940520135007052409467848),
+    Elem::new(303657583879775251590390446435643817237),
+    Elem::new(146994626928799704841350735501154361928),
+    Elem::new(28594903424512098851583506138593520622),
+    Elem::new(299275356464007655881510134838474523491),
+    Elem::new(78101153087640491749073246775902990076),
+    Elem::new(240294924540625777778662922745801078339),
+    Elem::new(90324103834855513973304289806797230425),
+    Elem::new(122138260260477418873004398061597497346),
+    Elem::new(161220989234743861978948904267395894401),
+    Elem::new(286219776602769306493968886442846371913),
+    Elem::new(13780154113615207596582030948078058582),
+    Elem::new(186136766462855393051588323368586905577),
+    Elem::new(296174149728344562531152095514391567819),
+    Elem::new(43388839796767319844663224326462184487),
+    Elem::new(323049692348528574450855181318457287277),
+    Elem::new(131334532474103621216007307729236485728),
+    Elem::new(212060237440149026788543622219443002796),
+    Elem::new(301810188375912394594007793824191024421),
+    Elem::new(317534419677078828303788072514304531249),
+    Elem::new(298710765877099897723038559397943925502),
+    Elem::new(134550159233177186708903442939253124591),
+    Elem::new(147643311876978813573368768267214372535),
+    Elem::new(124862653766117491709215641839965759940),
+    Elem::new(86537683575134491667743219636028344878),
+    Elem::new(243595184354483015560559608730633431860),
+    Elem::new(113025083467499662420311387768304774414),
+    Elem::new(120337609070965874638663634813003268091),
+    Elem::new(136426174002604664472801411372503939947),
+    Elem::new(200761367488829319605018138160904568248),
+    Elem::new(280685368371573408578824400994673887734),
+    Elem::new(120368077871983467407900742038065025312),
+    Elem::new(87838646623856759411937580806620613365),
+    Elem::new(158042161324447052685484269629937161094),
+    Elem::new(147996771694254585935319053242289104192),
+    Elem::new(218108730610111055038761219424559555564),
+    Elem::new(65645712459116637193594727122177426272),
+    Elem::new(276802604931729096760485969060659522910),
+    Elem::new(28816417677026177969499266124889182100),
+    Elem::new(176333493557536293352583477927485415421),
+    Elem::new(206653384308255558659828957878289665448),
+    Elem::new(116775177844985082045443789313982753764),
+    Elem::new(157720199508081859581561312958542135335),
+    Elem::new(93465771945780822693733522391093270302),
+    Elem::new(290059141952343713756875545646450259495),
+    Elem::new(331870678099319522669729491060830666730),
+    Elem::new(99123269251320261757388228082491191514),
+    Elem::new(232732832665751090150501950200651152814),
+    Elem::new(319828811127540718335381310576356954027),
+    Elem::new(177313851454226627354062519900463721667),
+    Elem::new(303484554671463075197368254998128180139),
+    Elem::new(307757975287037755724081937239644157529),
+    Elem::new(164140076952876395208071839667662119796),
+    Elem::new(98596549906899035764322917461098931383),
+    Elem::new(191433848108810111099413340260230430720),
+    Elem::new(71224785754398168344962619210331526064),
+    Elem::new(244538353388889013059808537129550290356),
+    Elem::new(115807658571872039364791525930616613786),
+    Elem::new(166916783399402815832452843748265122018),
+    Elem::new(189488287432030881462468056933528251168),
+    Elem::new(176487494087075292429657518534917765602),
+    Elem::new(128741651084128867843386829658719003048),
+    Elem::new(166471943365818482019790115193862068129),
+    Elem::new(123881176547268653253946703856680539842),
+    Elem::new(109889438860681257082182829928783759788),
+    Elem::new(22276107235781258940130149640950604059),
+    Elem::new(20530365746799677701149368445226282902),
+    Elem::new(19609056057950388965432981617193828417),
+    Elem::new(117238023167098465923406723727177233482),
+    Elem::new(330137787546758616124066638600381627457),
+    Elem::new(236767896443275468215623443080154858615),
+    Elem::new(254985414731380213490839086733330580462),
+    Elem::new(221973104839052901505103677332113934966),
+    Elem::new(309039388598229364795941184872598517694),
+    Elem::new(73956442410120821964579852649272436899),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(133456489063800684892074846702806736079),
+    Elem::new(266912978127601369784149693405613472158),
+    Elem::new(60087100270463591212849982154675246700),
+    Elem::new(193543589334264276104924828857481982779),
+    Elem::new(327000078398064960996999675560288718858),
+    Elem::new(120174200540927182425699964309350493400),
+    Elem::new(253630689604727867317774811012157229479),
+    Elem::new(46804811747590088746475099761219004021),
+    Elem::new(180261300811390773638549946464025740100),
+    Elem::new(313717789875191458530624793166832476179),
+    Elem::new(106891912018053679959325081915894250721),
+    Elem::new(240348401081854364851399928618700986800),
+    Elem::new(33522523224716586280100217367762761342),
+    Elem::new(166979012288517271172175064070569497421),
+    Elem::new(300435501352317956064249910773376233500),
+    Elem::new(93609623495180177492950199522438008042),
+    Elem::new(227066112558980862385025046225244744121),
+    Elem::new(20240234701843083813725334974306518663),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(276874266991908899421689626372139910699),
+    Elem::new(86649967204820207296634831627324758185),
+    Elem::new(109891834480610850551584731673044465532),
+    Elem::new(6317501898342365723164768555554071203),
+    Elem::new(116209336378953216274749500228598536735),
+    Elem::new(99284971001504938742964368738432900591),
+    Elem::new(295826772686935996591183932038802124308),
+    Elem::new(25270007593369462892659074222216284812),
+    Elem::new(308461776483620728037513469149910266714),
+    Elem::new(124554978594874401635623442960649185403),
+    Elem::new(154114347769007410613738111561922963953),
+    Elem::new(56857517085081291508482916999986640827),
+    Elem::new(173066853464034507783232417228585177562),
+    Elem::new(162459989984928595974612054293973612621),
+    Elem::new(25036926647763556082621828196151946004),
+    Elem::new(101080030373477851570636296888865139248),
+    Elem::new(50306934241133018975280902418368230816),
+    Elem::new(213000005171667521759930202738406182245),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
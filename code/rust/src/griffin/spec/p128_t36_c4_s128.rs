@@ -0,0 +1,3309 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 36
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 36;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u128 = 226854911280625642308916371969163307691;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(178648242633492693318271642925716104807),
+    Elem::new(302000600642332886323744920183948653364),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(80817062143722885072551457514014428365),
+    Elem::new(93577650903258077452428003437279864422),
+    Elem::new(4253529586511730793292181974421812020),
+    Elem::new(242451186431168655217654372542043285095),
+    Elem::new(293493541469309424737160556235105029326),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(221183538498610001251193462669934224999),
+    Elem::new(319014718988379809496913648081635901441),
+    Elem::new(140366476354887116178642005155919796634),
+    Elem::new(199915890566051347284732552797825164903),
+    Elem::new(51042355038140769519506183693061744231),
+    Elem::new(191408831393027885698148188848981540864),
+    Elem::new(97831180489769808245720185411701676442),
+    Elem::new(242451186431168655217654372542043285095),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(193270192276457167923215266422101947829),
+    Elem::new(89938288378372256346609030201429208698),
+    Elem::new(88361201198252942658668279150731877291),
+    Elem::new(246234940206676189864811856655532630667),
+    Elem::new(22296539765034463025033269326118572979),
+    Elem::new(284142384843261002980388657984492297652),
+    Elem::new(66655852058006458249439144890439771595),
+    Elem::new(15248908930456844198433238539531275683),
+    Elem::new(22904831736968272388440091026815532301),
+    Elem::new(228952370785523807041212207749444474465),
+    Elem::new(199509320527700703527179460974645456831),
+    Elem::new(120675339497845834171013581027601532003),
+    Elem::new(34788710169365956384585601114712576176),
+    Elem::new(159062502885509721301337193606876516310),
+    Elem::new(129274730093234882853952116461057447531),
+    Elem::new(35218481700550534904174476227377646500),
+    Elem::new(70949201535599811535944502469634699324),
+    Elem::new(57277929195174499600606226076696555432),
+    Elem::new(301296759298417091340840431516009215994),
+    Elem::new(217545829781217642828243081658498814770),
+    Elem::new(307500551733782623752263529036082741615),
+    Elem::new(42301038520057081507068032353922797283),
+    Elem::new(195819002574491571746625040232984575568),
+    Elem::new(24258495994004520560356481885310465059),
+    Elem::new(171796579667114939373634552022931136470),
+    Elem::new(281878342109676169417207005865425655439),
+    Elem::new(111962742339112980600458322753318803109),
+    Elem::new(121296503184411856038790305238325931233),
+    Elem::new(233520083802991021136721045311209298192),
+    Elem::new(218291312275319019208438948242188571505),
+    Elem::new(143084577179518795629240813529253199596),
+    Elem::new(23851861200245790022731011746161842728),
+    Elem::new(252171952243140594562115723453938172130),
+    Elem::new(288061330868820134473221125243370670434),
+    Elem::new(25213866509844090232073845620026947867),
+    Elem::new(223522724438008164501861352568747777269),
+    Elem::new(117231353689221751685407708209562627842),
+    Elem::new(194267486291889818682891476485965854158),
+    Elem::new(173992412060492663257788773668391978997),
+    Elem::new(318736031519928345520095316108406726402),
+    Elem::new(192898707399869993500163143006171916606),
+    Elem::new(29894060149136976301003892370016875547),
+    Elem::new(44152199764232760635977318743008815217),
+    Elem::new(263529978301192505725772006798995593478),
+    Elem::new(298401726355155521033429976913318162656),
+    Elem::new(326421386912883980175669964529653583173),
+    Elem::new(224888606167322461065541351548468058912),
+    Elem::new(98981387340771652536150884680190272787),
+    Elem::new(309681257180184910755237210344396220060),
+    Elem::new(333053738240604947524602899519740518617),
+    Elem::new(280122968575498626664677171189712778179),
+    Elem::new(281881787799096717504154846478436489429),
+    Elem::new(68083987648383250840412523789317904991),
+    Elem::new(283201506418571171467820727261500834352),
+    Elem::new(51568991020334728164468145072827132668),
+    Elem::new(88886231470706823738671344758292586745),
+    Elem::new(165550592447995962674142199240248307398),
+    Elem::new(196197351484247700312169619109916969627),
+    Elem::new(72151407836487561439573787275968401773),
+    Elem::new(208842973331604314497091115378049288388),
+    Elem::new(320198368154175384068956932699005883107),
+    Elem::new(165475637620910460878667544760875216210),
+    Elem::new(312954689296395338564750253976294687862),
+    Elem::new(109658675746804963466423046877073557465),
+    Elem::new(153239262637422354702644803726754760143),
+    Elem::new(43583610728192963663523091968205456876),
+    Elem::new(325715331864914919826569115212179353478),
+    Elem::new(333484592163757737748338315862933970496),
+    Elem::new(171003654548322155205195242379290022747),
+    Elem::new(331323748751065644468544085367686659861),
+    Elem::new(223087657953282655568949634572652966082),
+    Elem::new(125701598562618209585795547745669712076),
+    Elem::new(320072218788402658931855040811115266664),
+    Elem::new(205671799023855477310365297318816880661),
+    Elem::new(203021420898946445107486315964248213176),
+    Elem::new(182588132642157624450055423671141452356),
+    Elem::new(258368342771773134539779917551687321350),
+    Elem::new(43722200886398374604960960872759531024),
+    Elem::new(181672410568337994216393667763524541330),
+    Elem::new(106537458221838972515478222836386126537),
+    Elem::new(235042639125939320917150349714466328444),
+    Elem::new(8474171262532203626594138011979245225),
+    Elem::new(61993116039099558133056048733637966171),
+    Elem::new(307683394961725209663960072834304676713),
+    Elem::new(132011508556421186304279178579181762346),
+    Elem::new(223939632912525278632055300348977228049),
+    Elem::new(28234823444754912333131168811472429841),
+    Elem::new(109123998930527403870802904018139929309),
+    Elem::new(194182217316060920120535748670631084752),
+    Elem::new(10391116156617211721710029274964759942),
+    Elem::new(300154962803685331904641565521419886580),
+    Elem::new(78566860864187509600489004868972108305),
+    Elem::new(220340917830336656085792505557276253537),
+    Elem::new(92465722953879597226963946947746454944),
+    Elem::new(318657719330952662545391612924727506488),
+    Elem::new(161013454367776897053489039717502069153),
+    Elem::new(100033480504449150461983539633696644327),
+    Elem::new(175115649755765970217532718584975810150),
+    Elem::new(89831215511699325738037144992065095612),
+    Elem::new(325307821334455856988644831730248406165),
+    Elem::new(21325139288408165941153152621709844705),
+    Elem::new(162906992299243621514156344714594397923),
+    Elem::new(195286264111384177071115344571142277236),
+    Elem::new(166997325572540501259166580695303463557),
+    Elem::new(121347115604717302741662168734346571714),
+    Elem::new(112877997970734940520135007052409467848),
+    Elem::new(303657583879775251590390446435643817237),
+    Elem::new(146994626928799704841350735501154361928),
+    Elem::new(28594903424512098851583506138593520622),
+    Elem::new(299275356464007655881510134838474523491),
+    Elem::new(78101153087640491749073246775902990076),
+    Elem::new(240294924540625777778662922745801078339),
+    Elem::new(90324103834855513973304289806797230425),
+    Elem::new(122138260260477418873004398061597497346),
+    Elem::new(161220989234743861978948904267395894401),
+    Elem::new(286219776602769306493968886442846371913),
+    Elem::new(13780154113615207596582030948078058582),
+    Elem::new(186136766462855393051588323368586905577),
+    Elem::new(296174149728344562531152095514391567819),
+    Elem::new(43388839796767319844663224326462184487),
+    Elem::new(323049692348528574450855181318457287277),
+    Elem::new(131334532474103621216007307729236485728),
+    Elem::new(212060237440149026788543622219443002796),
+    Elem::new(301810188375912394594007793824191024421),
+    Elem::new(317534419677078828303788072514304531249),
+    Elem::new(298710765877099897723038559397943925502),
+    Elem::new(134550159233177186708903442939253124591),
+    Elem::new(147643311876978813573368768267214372535),
+    Elem::new(124862653766117491709215641839965759940),
+    Elem::new(86537683575134491667743219636028344878),
+    Elem::new(243595184354483015560559608730633431860),
+    Elem::new(113025083467499662420311387768304774414),
+    Elem::new(120337609070965874638663634813003268091),
+    Elem::new(136426174002604664472801411372503939947),
+    Elem::new(200761367488829319605018138160904568248),
+    Elem::new(280685368371573408578824400994673887734),
+    Elem::new(120368077871983467407900742038065025312),
+    Elem::new(87838646623856759411937580806620613365),
+    Elem::new(158042161324447052685484269629937161094),
+    Elem::new(147996771694254585935319053242289104192),
+    Elem::new(218108730610111055038761219424559555564),
+    Elem::new(65645712459116637193594727122177426272),
+    Elem::new(276802604931729096760485969060659522910),
+    Elem::new(28816417677026177969499266124889182100),
+    Elem::new(176333493557536293352583477927485415421),
+    Elem::new(206653384308255558659828957878289665448),
+    Elem::new(116775177844985082045443789313982753764),
+    Elem::new(157720199508081859581561312958542135335),
+    Elem::new(93465771945780822693733522391093270302),
+    Elem::new(290059141952343713756875545646450259495),
+    Elem::new(331870678099319522669729491060830666730),
+    Elem::new(99123269251320261757388228082491191514),
+    Elem::new(232732832665751090150501950200651152814),
+    Elem::new(319828811127540718335381310576356954027),
+    Elem::new(177313851454226627354062519900463721667),
+    Elem::new(303484554671463075197368254998128180139),
+    Elem::new(307757975287037755724081937239644157529),
+    Elem::new(164140076952876395208071839667662119796),
+    Elem::new(98596549906899035764322917461098931383),
+    Elem::new(191433848108810111099413340260230430720),
+    Elem::new(71224785754398168344962619210331526064),
+    Elem::new(244538353388889013059808537129550290356),
+    Elem::new(115807658571872039364791525930616613786),
+    Elem::new(166916783399402815832452843748265122018),
+    Elem::new(189488287432030881462468056933528251168),
+    Elem::new(176487494087075292429657518534917765602),
+    Elem::new(128741651084128867843386829658719003048),
+    Elem::new(166471943365818482019790115193862068129),
+    Elem::new(123881176547268653253946703856680539842),
+    Elem::new(109889438860681257082182829928783759788),
+    Elem::new(22276107235781258940130149640950604059),
+    Elem::new(20530365746799677701149368445226282902),
+    Elem::new(19609056057950388965432981617193828417),
+    Elem::new(117238023167098465923406723727177233482),
+    Elem::new(330137787546758616124066638600381627457),
+    Elem::new(236767896443275468215623443080154858615),
+    Elem::new(254985414731380213490839086733330580462),
+    Elem::new(221973104839052901505103677332113934966),
+    Elem::new(309039388598229364795941184872598517694),
+    Elem::new(73956442410120821964579852649272436899),
+    Elem::new(133456489063800684892074846702806736079),
+    Elem::new(276874266991908899421689626372139910699),
+    Elem::new(119074718727603753227679960792086132678),
+    Elem::new(53011613049975229075420449383957724062),
+    Elem::new(135773962640558243301043443890678121740),
+    Elem::new(62093735779692879321310188565801846061),
+    Elem::new(252881261831179621801611817877219938582),
+    Elem::new(285536252518656457799460182327930435512),
+    Elem::new(60564730295821775444474462508592401603),
+    Elem::new(133110543656937590042735117650581667126),
+    Elem::new(85276050568477895725326104359898418506),
+    Elem::new(233759232501042410148950496939135325117),
+    Elem::new(144704486085440488971533266701968339656),
+    Elem::new(331827942816518354920145872038904193825),
+    Elem::new(22080566267877013080103642232977064530),
+    Elem::new(37585270943895454798238322921700795378),
+    Elem::new(62999017688303233070342273633411646685),
+    Elem::new(245073542573618696023372165596277026874),
+    Elem::new(141633144506478377425705092661165233845),
+    Elem::new(207006467670843850576746884615014439992),
+    Elem::new(274622366603832221990166982279309273064),
+    Elem::new(9147851743232651626330400943950643212),
+    Elem::new(30134177736266333149179957780475530794),
+    Elem::new(241089647936153414449522997117390332419),
+    Elem::new(248133880023063365325584958627754048009),
+    Elem::new(303889341690414791448296283458363928994),
+    Elem::new(103603341563076460256887187871395872760),
+    Elem::new(190336127929610210382842579894322732948),
+    Elem::new(216020142459678044144760155162423998437),
+    Elem::new(133511264353821266546081095598150306719),
+    Elem::new(32548339266555666761681958645388114894),
+    Elem::new(287080523558927075964145176677488421827),
+    Elem::new(51392573711795668538447231680850510064),
+    Elem::new(323486622632516821957762844723917635389),
+    Elem::new(301753801320054572583652003852740927558),
+    Elem::new(104041817542497747451851172382304636506),
+    Elem::new(278713190227561081673761889108863015150),
+    Elem::new(303431832336152934060870110807497064158),
+    Elem::new(281573333179354663430642888688360892599),
+    Elem::new(129261906047052105463761280184833204964),
+    Elem::new(246879977261246332320079242860052149323),
+    Elem::new(170691129154578141762348804682791145029),
+    Elem::new(321652981742732558784855304627837722609),
+    Elem::new(331830483606477378304798709679862374649),
+    Elem::new(219336526027455494355996756807939014553),
+    Elem::new(116781222138314841507492529830514529749),
+    Elem::new(87224217064588810437207797680496542016),
+    Elem::new(178349498772477949134906463281670357532),
+    Elem::new(200128933003570150956866526269387888880),
+    Elem::new(40849844204811726342692130425541843109),
+    Elem::new(45247809481485177309830063728522922898),
+    Elem::new(325221970582773342228123335383678515914),
+    Elem::new(311106402608058505447355456146539366715),
+    Elem::new(21093977466794996676925779822133377692),
+    Elem::new(61662971281232070615717691420184336703),
+    Elem::new(13167714395338662490957273680138630627),
+    Elem::new(57656037979180683272620327738282731306),
+    Elem::new(30626216909948737216401803129951249794),
+    Elem::new(186510669810901653563493597139597335104),
+    Elem::new(200218473919326912385715110162316382783),
+    Elem::new(98285427278743248400400106343683953844),
+    Elem::new(34262500791459461458571914456373852594),
+    Elem::new(176673391915911211579922228554680110542),
+    Elem::new(35471385550730046470816712649937028154),
+    Elem::new(24721872345789662139778960664901474084),
+    Elem::new(1835518103071279159138976549616417705),
+    Elem::new(190875760072916398225939616296731736392),
+    Elem::new(12938981936196836031352259225160979763),
+    Elem::new(300930264080649511233386346811398649761),
+    Elem::new(303955978288170123694224869772921518060),
+    Elem::new(110970558513279807025918370718704998101),
+    Elem::new(157035743160486901577556879599133920976),
+    Elem::new(155076341437876131638945524438560213946),
+    Elem::new(249959158407935072316892672272971821160),
+    Elem::new(276202320662997328196115442408000517087),
+    Elem::new(323657515782456779487927405725684628290),
+    Elem::new(278102169356224232655131457723521522496),
+    Elem::new(204220703694001816935302153209821326592),
+    Elem::new(162988445590492501160058458710996627497),
+    Elem::new(317837433146773588553052798621503809546),
+    Elem::new(147770828226166399840405244047762128110),
+    Elem::new(47408826949613704128150438214605569198),
+    Elem::new(165634052928640144721084611627160060234),
+    Elem::new(27254089102071961953983607888176571430),
+    Elem::new(19789740608439133722518748499687765204),
+    Elem::new(219614259106564924449489283304247898556),
+    Elem::new(58335719532915774915718588663174343991),
+    Elem::new(257331811697746172676502554510436160192),
+    Elem::new(112718334487476144219121112837541470500),
+    Elem::new(19930987575656515008022697826472507443),
+    Elem::new(67061687996931941907211319373712631124),
+    Elem::new(165713036600419363943819420170086405950),
+    Elem::new(88250261684881191475743504575674858488),
+    Elem::new(286251550110014464320835042628188427721),
+    Elem::new(165027307370639300297105767561419204870),
+    Elem::new(38550150754512675914087512481111685892),
+    Elem::new(67715118976074989530759996363110631903),
+    Elem::new(190135858438174531167885725169912321737),
+    Elem::new(91376311255042524143597727518395055422),
+    Elem::new(316168727454991163944278961106354251252),
+    Elem::new(272120300245118728446671264908310276714),
+    Elem::new(280083289310543169359784967045061961998),
+    Elem::new(114191819286419042889263967301992551603),
+    Elem::new(80521124001938927214010919078807359762),
+    Elem::new(292307899399915916335080685933050763818),
+    Elem::new(274663627176299888593873498197195564750),
+    Elem::new(15784232907965354838109907998822175703),
+    Elem::new(266162174766828722630327836003182081819),
+    Elem::new(48941184304569319905418442557272997459),
+    Elem::new(91504370797997213595251546052310520194),
+    Elem::new(299713657593702790521204579198774697845),
+    Elem::new(1220477076482412327539630917469615046),
+    Elem::new(314585798044275110543144058547552285884),
+    Elem::new(289102726791551633571471845315690674855),
+    Elem::new(25483316960526329419601019870122891659),
+    Elem::new(82089390074146286604244442695819710177),
+    Elem::new(253769666153022944538996959868169996385),
+    Elem::new(107210353252912884053851375477414692292),
+    Elem::new(86758133357808729338818675831897392637),
+    Elem::new(50276114482643053474368623707565851507),
+    Elem::new(123947370858469923147526183370480671657),
+    Elem::new(9317843052877232657696192882566770619),
+    Elem::new(137370039446691858657259913959498082591),
+    Elem::new(280833844039596488952592038280434676207),
+    Elem::new(283195246138516569859756073821778134835),
+    Elem::new(336288838053778634399423464300729554843),
+    Elem::new(281712038606463401103198003077698991660),
+    Elem::new(330297716663820995043154931920747360109),
+    Elem::new(238966585338442836245194159532998815509),
+    Elem::new(174658283277636644249785325889099531803),
+    Elem::new(192017632525473607789150727829909692388),
+    Elem::new(17838924178260703189846065794787092396),
+    Elem::new(241853576884739313481084171275998422646),
+    Elem::new(8486505151715111077890744281578270104),
+    Elem::new(104934133706454631665339192102585878610),
+    Elem::new(251734900726555131170054080335499270034),
+    Elem::new(181055782111128094682096984113328801283),
+    Elem::new(32119907439305056554258073133514629273),
+    Elem::new(126010821077323087110507072762567909866),
+    Elem::new(49972122377317660236004656114775288559),
+    Elem::new(240074817125733287177194959159714057001),
+    Elem::new(237462093155111398577827577289899285304),
+    Elem::new(241827411450979224163636299867416791304),
+    Elem::new(264015790747820301913865136547863931437),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(337231276116606389147918218564165318865),
+    Elem::new(334180185312274314832461879174585676193),
+    Elem::new(331129094507942240517005539785006033521),
+    Elem::new(328078003703610166201549200395426390849),
+    Elem::new(325026912899278091886092861005846748177),
+    Elem::new(321975822094946017570636521616267105505),
+    Elem::new(318924731290613943255180182226687462833),
+    Elem::new(315873640486281868939723842837107820161),
+    Elem::new(312822549681949794624267503447528177489),
+    Elem::new(309771458877617720308811164057948534817),
+    Elem::new(306720368073285645993354824668368892145),
+    Elem::new(303669277268953571677898485278789249473),
+    Elem::new(300618186464621497362442145889209606801),
+    Elem::new(297567095660289423046985806499629964129),
+    Elem::new(294516004855957348731529467110050321457),
+    Elem::new(291464914051625274416073127720470678785),
+    Elem::new(288413823247293200100616788330891036113),
+    Elem::new(285362732442961125785160448941311393441),
+    Elem::new(282311641638629051469704109551731750769),
+    Elem::new(279260550834296977154247770162152108097),
+    Elem::new(276209460029964902838791430772572465425),
+    Elem::new(273158369225632828523335091382992822753),
+    Elem::new(270107278421300754207878751993413180081),
+    Elem::new(267056187616968679892422412603833537409),
+    Elem::new(264005096812636605576966073214253894737),
+    Elem::new(260954006008304531261509733824674252065),
+    Elem::new(257902915203972456946053394435094609393),
+    Elem::new(254851824399640382630597055045514966721),
+    Elem::new(251800733595308308315140715655935324049),
+    Elem::new(248749642790976233999684376266355681377),
+    Elem::new(245698551986644159684228036876776038705),
+    Elem::new(242647461182312085368771697487196396033),
+    Elem::new(239596370377980011053315358097616753361),
+    Elem::new(236545279573647936737859018708037110689),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(211342333841430779190207453677570247885),
+    Elem::new(164804601523846189834080698802791068466),
+    Elem::new(200669169968184695394994293329407423280),
+    Elem::new(318936039174446295872948237257419312327),
+    Elem::new(179322842221692527804567972633081774070),
+    Elem::new(122111946030861854653228057410139770046),
+    Elem::new(147303350601954276418928491588593300255),
+    Elem::new(254897055934969793101669275168442364697),
+    Elem::new(104610695108969941238075850195942001835),
+    Elem::new(36726635044893184291522774624837173206),
+    Elem::new(51244875742739522262010048455127878810),
+    Elem::new(148165417202508955149537671686814118647),
+    Elem::new(327488259424201482954105644319895892717),
+    Elem::new(248931035486878642212339408400628239483),
+    Elem::new(252776112311478896387613521882756120482),
+    Elem::new(339023489898002245479927984766279535714),
+    Elem::new(167390801325510226025908239097453523642),
+    Elem::new(78160413514941301488928842830023045803),
+    Elem::new(71332326466295471868989795963988102197),
+    Elem::new(146906540179572737166091098499348692824),
+    Elem::new(304883054654773097380232750436104817684),
+    Elem::new(204979502970958089048040193820511515240),
+    Elem::new(187478252049066175632887986606313747029),
+    Elem::new(252379301889097357134776128793511513051),
+    Elem::new(59400285570113170090330062428359851769),
+    Elem::new(289105936933990541426298903418348686257),
+    Elem::new(260931522138852544215933535855988093441),
+    Elem::new(315159408105637641922608517695023034858),
+    Elem::new(111507227913407371082949290981708548971),
+    Elem::new(330539715404038658623704971623534558854),
+    Elem::new(291692136735654577618126443713011141433),
+    Elem::new(335246858829193591529588265203883258245),
+    Elem::new(120921514763717236894715878142405947753),
+    Elem::new(329280838381102440640258398436069133031),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,381 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 4
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 14;
+pub const STATE_WIDTH: usize = 4;
+pub const CAPACITY: usize = 2;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u128 = 226854911280625642308916371969163307691;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(255211775190703847597530918465308721152),
+    Elem::new(42535295865117307932921819744218120193),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(170141183460469231731687278976872480769),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(255211775190703847597530918465308721152),
+    Elem::new(42535295865117307932921819744218120193),
+    Elem::new(42535295865117307932921819744218120192),
+    Elem::new(212676479325586539664609098721090600961),
+    Elem::new(170141183460469231731687278976872480769),
+    Elem::new(212676479325586539664609098721090600960),
+    Elem::new(297747071055821155530452738209526841345),
+    Elem::new(42535295865117307932921819744218120192),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(193270192276457167923215266422101947829),
+    Elem::new(89938288378372256346609030201429208698),
+    Elem::new(88361201198252942658668279150731877291),
+    Elem::new(246234940206676189864811856655532630667),
+    Elem::new(22296539765034463025033269326118572979),
+    Elem::new(284142384843261002980388657984492297652),
+    Elem::new(66655852058006458249439144890439771595),
+    Elem::new(15248908930456844198433238539531275683),
+    Elem::new(22904831736968272388440091026815532301),
+    Elem::new(228952370785523807041212207749444474465),
+    Elem::new(199509320527700703527179460974645456831),
+    Elem::new(120675339497845834171013581027601532003),
+    Elem::new(34788710169365956384585601114712576176),
+    Elem::new(159062502885509721301337193606876516310),
+    Elem::new(129274730093234882853952116461057447531),
+    Elem::new(35218481700550534904174476227377646500),
+    Elem::new(70949201535599811535944502469634699324),
+    Elem::new(57277929195174499600606226076696555432),
+    Elem::new(301296759298417091340840431516009215994),
+    Elem::new(217545829781217642828243081658498814770),
+    Elem::new(307500551733782623752263529036082741615),
+    Elem::new(42301038520057081507068032353922797283),
+    Elem::new(195819002574491571746625040232984575568),
+    Elem::new(24258495994004520560356481885310465059),
+    Elem::new(171796579667114939373634552022931136470),
+    Elem::new(281878342109676169417207005865425655439),
+    Elem::new(111962742339112980600458322753318803109),
+    Elem::new(121296503184411856038790305238325931233),
+    Elem::new(233520083802991021136721045311209298192),
+    Elem::new(218291312275319019208438948242188571505),
+    Elem::new(143084577179518795629240813529253199596),
+    Elem::new(23851861200245790022731011746161842728),
+    Elem::new(252171952243140594562115723453938172130),
+    Elem::new(288061330868820134473221125243370670434),
+    Elem::new(25213866509844090232073845620026947867),
+    Elem::new(223522724438008164501861352568747777269),
+    Elem::new(117231353689221751685407708209562627842),
+    Elem::new(194267486291889818682891476485965854158),
+    Elem::new(173992412060492663257788773668391978997),
+    Elem::new(318736031519928345520095316108406726402),
+    Elem::new(192898707399869993500163143006171916606),
+    Elem::new(29894060149136976301003892370016875547),
+    Elem::new(44152199764232760635977318743008815217),
+    Elem::new(263529978301192505725772006798995593478),
+    Elem::new(298401726355155521033429976913318162656),
+    Elem::new(326421386912883980175669964529653583173),
+    Elem::new(224888606167322461065541351548468058912),
+    Elem::new(98981387340771652536150884680190272787),
+    Elem::new(309681257180184910755237210344396220060),
+    Elem::new(333053738240604947524602899519740518617),
+    Elem::new(280122968575498626664677171189712778179),
+    Elem::new(281881787799096717504154846478436489429),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(72151407836487561439573787275968401773),
+    Elem::new(144302815672975122879147574551936803546),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(208842973331604314497091115378049288388),
+    Elem::new(154807159484540331061615345604707230478),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
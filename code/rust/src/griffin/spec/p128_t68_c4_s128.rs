@@ -0,0 +1,10349 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 68
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 68;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u128 = 226854911280625642308916371969163307691;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(99249023685273718510150912736508947115),
+    Elem::new(54350655827649893469844547450945375801),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(120516671617832372476611822608618007211),
+    Elem::new(14178431955039102644307273248072706730),
+    Elem::new(96885951692767201402766367195163495994),
+    Elem::new(229217983273132159416300917510508758813),
+    Elem::new(333193150943418912141220921329708608172),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(274116351130755984456607282796072330127),
+    Elem::new(252848703198197330490146372923963270031),
+    Elem::new(153599679512923611979995460187454322916),
+    Elem::new(186682687408014851483379097766290638621),
+    Elem::new(103975167670286752724920003819199849359),
+    Elem::new(125242815602845406691380913691308909454),
+    Elem::new(111064383647806304047073640443236202724),
+    Elem::new(229217983273132159416300917510508758813),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(193270192276457167923215266422101947829),
+    Elem::new(89938288378372256346609030201429208698),
+    Elem::new(88361201198252942658668279150731877291),
+    Elem::new(246234940206676189864811856655532630667),
+    Elem::new(22296539765034463025033269326118572979),
+    Elem::new(284142384843261002980388657984492297652),
+    Elem::new(66655852058006458249439144890439771595),
+    Elem::new(15248908930456844198433238539531275683),
+    Elem::new(22904831736968272388440091026815532301),
+    Elem::new(228952370785523807041212207749444474465),
+    Elem::new(199509320527700703527179460974645456831),
+    Elem::new(120675339497845834171013581027601532003),
+    Elem::new(34788710169365956384585601114712576176),
+    Elem::new(159062502885509721301337193606876516310),
+    Elem::new(129274730093234882853952116461057447531),
+    Elem::new(35218481700550534904174476227377646500),
+    Elem::new(70949201535599811535944502469634699324),
+    Elem::new(57277929195174499600606226076696555432),
+    Elem::new(301296759298417091340840431516009215994),
+    Elem::new(217545829781217642828243081658498814770),
+    Elem::new(307500551733782623752263529036082741615),
+    Elem::new(42301038520057081507068032353922797283),
+    Elem::new(195819002574491571746625040232984575568),
+    Elem::new(24258495994004520560356481885310465059),
+    Elem::new(171796579667114939373634552022931136470),
+    Elem::new(281878342109676169417207005865425655439),
+    Elem::new(111962742339112980600458322753318803109),
+    Elem::new(121296503184411856038790305238325931233),
+    Elem::new(233520083802991021136721045311209298192),
+    Elem::new(218291312275319019208438948242188571505),
+    Elem::new(143084577179518795629240813529253199596),
+    Elem::new(23851861200245790022731011746161842728),
+    Elem::new(252171952243140594562115723453938172130),
+    Elem::new(288061330868820134473221125243370670434),
+    Elem::new(25213866509844090232073845620026947867),
+    Elem::new(223522724438008164501861352568747777269),
+    Elem::new(117231353689221751685407708209562627842),
+    Elem::new(194267486291889818682891476485965854158),
+    Elem::new(173992412060492663257788773668391978997),
+    Elem::new(318736031519928345520095316108406726402),
+    Elem::new(192898707399869993500163143006171916606),
+    Elem::new(29894060149136976301003892370016875547),
+    Elem::new(44152199764232760635977318743008815217),
+    Elem::new(263529978301192505725772006798995593478),
+    Elem::new(298401726355155521033429976913318162656),
+    Elem::new(326421386912883980175669964529653583173),
+    Elem::new(224888606167322461065541351548468058912),
+    Elem::new(98981387340771652536150884680190272787),
+    Elem::new(309681257180184910755237210344396220060),
+    Elem::new(333053738240604947524602899519740518617),
+    Elem::new(280122968575498626664677171189712778179),
+    Elem::new(281881787799096717504154846478436489429),
+    Elem::new(68083987648383250840412523789317904991),
+    Elem::new(283201506418571171467820727261500834352),
+    Elem::new(51568991020334728164468145072827132668),
+    Elem::new(88886231470706823738671344758292586745),
+    Elem::new(165550592447995962674142199240248307398),
+    Elem::new(196197351484247700312169619109916969627),
+    Elem::new(72151407836487561439573787275968401773),
+    Elem::new(208842973331604314497091115378049288388),
+    Elem::new(320198368154175384068956932699005883107),
+    Elem::new(165475637620910460878667544760875216210),
+    Elem::new(312954689296395338564750253976294687862),
+    Elem::new(109658675746804963466423046877073557465),
+    Elem::new(153239262637422354702644803726754760143),
+    Elem::new(43583610728192963663523091968205456876),
+    Elem::new(325715331864914919826569115212179353478),
+    Elem::new(333484592163757737748338315862933970496),
+    Elem::new(171003654548322155205195242379290022747),
+    Elem::new(331323748751065644468544085367686659861),
+    Elem::new(223087657953282655568949634572652966082),
+    Elem::new(125701598562618209585795547745669712076),
+    Elem::new(320072218788402658931855040811115266664),
+    Elem::new(205671799023855477310365297318816880661),
+    Elem::new(203021420898946445107486315964248213176),
+    Elem::new(182588132642157624450055423671141452356),
+    Elem::new(258368342771773134539779917551687321350),
+    Elem::new(43722200886398374604960960872759531024),
+    Elem::new(181672410568337994216393667763524541330),
+    Elem::new(106537458221838972515478222836386126537),
+    Elem::new(235042639125939320917150349714466328444),
+    Elem::new(8474171262532203626594138011979245225),
+    Elem::new(61993116039099558133056048733637966171),
+    Elem::new(307683394961725209663960072834304676713),
+    Elem::new(132011508556421186304279178579181762346),
+    Elem::new(223939632912525278632055300348977228049),
+    Elem::new(28234823444754912333131168811472429841),
+    Elem::new(109123998930527403870802904018139929309),
+    Elem::new(194182217316060920120535748670631084752),
+    Elem::new(10391116156617211721710029274964759942),
+    Elem::new(300154962803685331904641565521419886580),
+    Elem::new(78566860864187509600489004868972108305),
+    Elem::new(220340917830336656085792505557276253537),
+    Elem::new(92465722953879597226963946947746454944),
+    Elem::new(318657719330952662545391612924727506488),
+    Elem::new(161013454367776897053489039717502069153),
+    Elem::new(100033480504449150461983539633696644327),
+    Elem::new(175115649755765970217532718584975810150),
+    Elem::new(89831215511699325738037144992065095612),
+    Elem::new(325307821334455856988644831730248406165),
+    Elem::new(21325139288408165941153152621709844705),
+    Elem::new(162906992299243621514156344714594397923),
+    Elem::new(195286264111384177071115344571142277236),
+    Elem::new(166997325572540501259166580695303463557),
+    Elem::new(121347115604717302741662168734346571714),
+    Elem::new(112877997970734940520135007052409467848),
+    Elem::new(303657583879775251590390446435643817237),
+    Elem::new(146994626928799704841350735501154361928),
+    Elem::new(28594903424512098851583506138593520622),
+    Elem::new(299275356464007655881510134838474523491),
+    Elem::new(78101153087640491749073246775902990076),
+    Elem::new(240294924540625777778662922745801078339),
+    Elem::new(90324103834855513973304289806797230425),
+    Elem::new(122138260260477418873004398061597497346),
+    Elem::new(161220989234743861978948904267395894401),
+    Elem::new(286219776602769306493968886442846371913),
+    Elem::new(13780154113615207596582030948078058582),
+    Elem::new(186136766462855393051588323368586905577),
+    Elem::new(296174149728344562531152095514391567819),
+    Elem::new(43388839796767319844663224326462184487),
+    Elem::new(323049692348528574450855181318457287277),
+    Elem::new(131334532474103621216007307729236485728),
+    Elem::new(212060237440149026788543622219443002796),
+    Elem::new(301810188375912394594007793824191024421),
+    Elem::new(317534419677078828303788072514304531249),
+    Elem::new(298710765877099897723038559397943925502),
+    Elem::new(134550159233177186708903442939253124591),
+    Elem::new(147643311876978813573368768267214372535),
+    Elem::new(124862653766117491709215641839965759940),
+    Elem::new(86537683575134491667743219636028344878),
+    Elem::new(243595184354483015560559608730633431860),
+    Elem::new(113025083467499662420311387768304774414),
+    Elem::new(120337609070965874638663634813003268091),
+    Elem::new(136426174002604664472801411372503939947),
+    Elem::new(200761367488829319605018138160904568248),
+    Elem::new(280685368371573408578824400994673887734),
+    Elem::new(120368077871983467407900742038065025312),
+    Elem::new(87838646623856759411937580806620613365),
+    Elem::new(158042161324447052685484269629937161094),
+    Elem::new(147996771694254585935319053242289104192),
+    Elem::new(218108730610111055038761219424559555564),
+    Elem::new(65645712459116637193594727122177426272),
+    Elem::new(276802604931729096760485969060659522910),
+    Elem::new(28816417677026177969499266124889182100),
+    Elem::new(176333493557536293352583477927485415421),
+    Elem::new(206653384308255558659828957878289665448),
+    Elem::new(116775177844985082045443789313982753764),
+    Elem::new(157720199508081859581561312958542135335),
+    Elem::new(93465771945780822693733522391093270302),
+    Elem::new(290059141952343713756875545646450259495),
+    Elem::new(331870678099319522669729491060830666730),
+    Elem::new(99123269251320261757388228082491191514),
+    Elem::new(232732832665751090150501950200651152814),
+    Elem::new(319828811127540718335381310576356954027),
+    Elem::new(177313851454226627354062519900463721667),
+    Elem::new(303484554671463075197368254998128180139),
+    Elem::new(307757975287037755724081937239644157529),
+    Elem::new(164140076952876395208071839667662119796),
+    Elem::new(98596549906899035764322917461098931383),
+    Elem::new(191433848108810111099413340260230430720),
+    Elem::new(71224785754398168344962619210331526064),
+    Elem::new(244538353388889013059808537129550290356),
+    Elem::new(115807658571872039364791525930616613786),
+    Elem::new(166916783399402815832452843748265122018),
+    Elem::new(189488287432030881462468056933528251168),
+    Elem::new(176487494087075292429657518534917765602),
+    Elem::new(128741651084128867843386829658719003048),
+    Elem::new(166471943365818482019790115193862068129),
+    Elem::new(123881176547268653253946703856680539842),
+    Elem::new(109889438860681257082182829928783759788),
+    Elem::new(22276107235781258940130149640950604059),
+    Elem::new(20530365746799677701149368445226282902),
+    Elem::new(19609056057950388965432981617193828417),
+    Elem::new(117238023167098465923406723727177233482),
+    Elem::new(330137787546758616124066638600381627457),
+    Elem::new(236767896443275468215623443080154858615),
+    Elem::new(254985414731380213490839086733330580462),
+    Elem::new(221973104839052901505103677332113934966),
+    Elem::new(309039388598229364795941184872598517694),
+    Elem::new(73956442410120821964579852649272436899),
+    Elem::new(133456489063800684892074846702806736079),
+    Elem::new(276874266991908899421689626372139910699),
+    Elem::new(119074718727603753227679960792086132678),
+    Elem::new(53011613049975229075420449383957724062),
+    Elem::new(135773962640558243301043443890678121740),
+    Elem::new(62093735779692879321310188565801846061),
+    Elem::new(252881261831179621801611817877219938582),
+    Elem::new(285536252518656457799460182327930435512),
+    Elem::new(60564730295821775444474462508592401603),
+    Elem::new(133110543656937590042735117650581667126),
+    Elem::new(85276050568477895725326104359898418506),
+    Elem::new(233759232501042410148950496939135325117),
+    Elem::new(144704486085440488971533266701968339656),
+    Elem::new(331827942816518354920145872038904193825),
+    Elem::new(22080566267877013080103642232977064530),
+    Elem::new(37585270943895454798238322921700795378),
+    Elem::new(62999017688303233070342273633411646685),
+    Elem::new(245073542573618696023372165596277026874),
+    Elem::new(141633144506478377425705092661165233845),
+    Elem::new(207006467670843850576746884615014439992),
+    Elem::new(274622366603832221990166982279309273064),
+    Elem::new(9147851743232651626330400943950643212),
+    Elem::new(30134177736266333149179957780475530794),
+    Elem::new(241089647936153414449522997117390332419),
+    Elem::new(248133880023063365325584958627754048009),
+    Elem::new(303889341690414791448296283458363928994),
+    Elem::new(103603341563076460256887187871395872760),
+    Elem::new(190336127929610210382842579894322732948),
+    Elem::new(216020142459678044144760155162423998437),
+    Elem::new(133511264353821266546081095598150306719),
+    Elem::new(32548339266555666761681958645388114894),
+    Elem::new(287080523558927075964145176677488421827),
+    Elem::new(51392573711795668538447231680850510064),
+    Elem::new(323486622632516821957762844723917635389),
+    Elem::new(301753801320054572583652003852740927558),
+    Elem::new(104041817542497747451851172382304636506),
+    Elem::new(278713190227561081673761889108863015150),
+    Elem::new(303431832336152934060870110807497064158),
+    Elem::new(281573333179354663430642888688360892599),
+    Elem::new(129261906047052105463761280184833204964),
+    Elem::new(246879977261246332320079242860052149323),
+    Elem::new(170691129154578141762348804682791145029),
+    Elem::new(321652981742732558784855304627837722609),
+    Elem::new(331830483606477378304798709679862374649),
+    Elem::new(219336526027455494355996756807939014553),
+    Elem::new(116781222138314841507492529830514529749),
+    Elem::new(87224217064588810437207797680496542016),
+    Elem::new(178349498772477949134906463281670357532),
+    Elem::new(200128933003570150956866526269387888880),
+    Elem::new(40849844204811726342692130425541843109),
+    Elem::new(45247809481485177309830063728522922898),
+    Elem::new(325221970582773342228123335383678515914),
+    Elem::new(311106402608058505447355456146539366715),
+    Elem::new(21093977466794996676925779822133377692),
+    Elem::new(61662971281232070615717691420184336703),
+    Elem::new(13167714395338662490957273680138630627),
+    Elem::new(57656037979180683272620327738282731306),
+    Elem::new(30626216909948737216401803129951249794),
+    Elem::new(186510669810901653563493597139597335104),
+    Elem::new(200218473919326912385715110162316382783),
+    Elem::new(98285427278743248400400106343683953844),
+    Elem::new(34262500791459461458571914456373852594),
+    Elem::new(176673391915911211579922228554680110542),
+    Elem::new(35471385550730046470816712649937028154),
+    Elem::new(24721872345789662139778960664901474084),
+    Elem::new(1835518103071279159138976549616417705),
+    Elem::new(190875760072916398225939616296731736392),
+    Elem::new(12938981936196836031352259225160979763),
+    Elem::new(300930264080649511233386346811398649761),
+    Elem::new(303955978288170123694224869772921518060),
+    Elem::new(110970558513279807025918370718704998101),
+    Elem::new(157035743160486901577556879599133920976),
+    Elem::new(155076341437876131638945524438560213946),
+    Elem::new(249959158407935072316892672272971821160),
+    Elem::new(276202320662997328196115442408000517087),
+    Elem::new(323657515782456779487927405725684628290),
+    Elem::new(278102169356224232655131457723521522496),
+    Elem::new(204220703694001816935302153209821326592),
+    Elem::new(162988445590492501160058458710996627497),
+    Elem::new(317837433146773588553052798621503809546),
+    Elem::new(147770828226166399840405244047762128110),
+    Elem::new(47408826949613704128150438214605569198),
+    Elem::new(165634052928640144721084611627160060234),
+    Elem::new(27254089102071961953983607888176571430),
+    Elem::new(19789740608439133722518748499687765204),
+    Elem::new(219614259106564924449489283304247898556),
+    Elem::new(58335719532915774915718588663174343991),
+    Elem::new(257331811697746172676502554510436160192),
+    Elem::new(112718334487476144219121112837541470500),
+    Elem::new(19930987575656515008022697826472507443),
+    Elem::new(67061687996931941907211319373712631124),
+    Elem::new(165713036600419363943819420170086405950),
+    Elem::new(88250261684881191475743504575674858488),
+    Elem::new(286251550110014464320835042628188427721),
+    Elem::new(165027307370639300297105767561419204870),
+    Elem::new(38550150754512675914087512481111685892),
+    Elem::new(67715118976074989530759996363110631903),
+    Elem::new(190135858438174531167885725169912321737),
+    Elem::new(91376311255042524143597727518395055422),
+    Elem::new(316168727454991163944278961106354251252),
+    Elem::new(272120300245118728446671264908310276714),
+    Elem::new(280083289310543169359784967045061961998),
+    Elem::new(114191819286419042889263967301992551603),
+    Elem::new(80521124001938927214010919078807359762),
+    Elem::new(292307899399915916335080685933050763818),
+    Elem::new(274663627176299888593873498197195564750),
+    Elem::new(15784232907965354838109907998822175703),
+    Elem::new(266162174766828722630327836003182081819),
+    Elem::new(48941184304569319905418442557272997459),
+    Elem::new(91504370797997213595251546052310520194),
+    Elem::new(299713657593702790521204579198774697845),
+    Elem::new(1220477076482412327539630917469615046),
+    Elem::new(314585798044275110543144058547552285884),
+    Elem::new(289102726791551633571471845315690674855),
+    Elem::new(25483316960526329419601019870122891659),
+    Elem::new(82089390074146286604244442695819710177),
+    Elem::new(253769666153022944538996959868169996385),
+    Elem::new(107210353252912884053851375477414692292),
+    Elem::new(86758133357808729338818675831897392637),
+    Elem::new(50276114482643053474368623707565851507),
+    Elem::new(123947370858469923147526183370480671657),
+    Elem::new(9317843052877232657696192882566770619),
+    Elem::new(137370039446691858657259913959498082591),
+    Elem::new(280833844039596488952592038280434676207),
+    Elem::new(283195246138516569859756073821778134835),
+    Elem::new(336288838053778634399423464300729554843),
+    Elem::new(281712038606463401103198003077698991660),
+    Elem::new(330297716663820995043154931920747360109),
+    Elem::new(238966585338442836245194159532998815509),
+    Elem::new(174658283277636644249785325889099531803),
+    Elem::new(192017632525473607789150727829909692388),
+    Elem::new(17838924178260703189846065794787092396),
+    Elem::new(241853576884739313481084171275998422646),
+    Elem::new(8486505151715111077890744281578270104),
+    Elem::new(104934133706454631665339192102585878610),
+    Elem::new(251734900726555131170054080335499270034),
+    Elem::new(181055782111128094682096984113328801283),
+    Elem::new(32119907439305056554258073133514629273),
+    Elem::new(126010821077323087110507072762567909866),
+    Elem::new(49972122377317660236004656114775288559),
+    Elem::new(240074817125733287177194959159714057001),
+    Elem::new(237462093155111398577827577289899285304),
+    Elem::new(241827411450979224163636299867416791304),
+    Elem::new(264015790747820301913865136547863931437),
+    Elem::new(337231276116606389147918218564165318865),
+    Elem::new(211342333841430779190207453677570247885),
+    Elem::new(48148513457498567132424773206800098195),
+    Elem::new(309212334506970391672512917275460144230),
+    Elem::new(289342172300147383492656188460486869009),
+    Elem::new(182671820710030951814971318264412641980),
+    Elem::new(80473623041911243103582694998623785897),
+    Elem::new(96669382961747730649847037912173096662),
+    Elem::new(337763736667482579172317565408182057923),
+    Elem::new(15404727411674204855759162211609587577),
+    Elem::new(278376832910253086686338614594527379718),
+    Elem::new(167378805278290394118416630866828702158),
+    Elem::new(126961472722669509421588416885570853959),
+    Elem::new(31002308149592200086276962253477149777),
+    Elem::new(223213573380349517712137020929420538995),
+    Elem::new(13806567367071674638654601407678916384),
+    Elem::new(162429373309323117084745457953061280942),
+    Elem::new(286778022838435608888855320072343235473),
+    Elem::new(177547865369140915758061527464553237222),
+    Elem::new(294552921294578460491416023916320224501),
+    Elem::new(75213441765189066759106149205400650258),
+    Elem::new(105774470831754746539984056638422193047),
+    Elem::new(25428708498177204382563227282401642574),
+    Elem::new(223781082245146256250385766579085592511),
+    Elem::new(33741497007535781169217194463968578933),
+    Elem::new(17296422861393861214017873849165847300),
+    Elem::new(134139185041916678880884502058261329981),
+    Elem::new(260910156701336397047505375554068372693),
+    Elem::new(40133612817657823777613645977632053261),
+    Elem::new(317339078697614215280104051539909648110),
+    Elem::new(330572288096997136494999455549618631710),
+    Elem::new(15014010920469317512865404517650469966),
+    Elem::new(320942387820460599730733616342741730753),
+    Elem::new(263896057806896244302135310358025652253),
+    Elem::new(219653628551792417199427460828407279853),
+    Elem::new(203466992068703566383757561157115544172),
+    Elem::new(272623053214389430291330628492281538662),
+    Elem::new(238929968301900933054576455543679516514),
+    Elem::new(252775390609526399883340451233511775320),
+    Elem::new(7507491126537517140784815358391542320),
+    Elem::new(18439572065842524582981815798383447092),
+    Elem::new(73303167981995789503899690609470847933),
+    Elem::new(278250126290690543334994483588408940182),
+    Elem::new(6625757675397790995690914288170382380),
+    Elem::new(202610576846660982043929332107796779787),
+    Elem::new(212492791923220810345477174613827816231),
+    Elem::new(283510022997943622803603505600595272278),
+    Elem::new(122443044226100896650561336154830634813),
+    Elem::new(104679828625824251595177860459865378084),
+    Elem::new(8778623053325411528869633132889203483),
+    Elem::new(28618818062135512180295017897335072369),
+    Elem::new(309806925999942608202789619442938644544),
+    Elem::new(297822611113160491449939477071899382439),
+    Elem::new(262387085798094814678177584827097298298),
+    Elem::new(15778165854378793963324137538398450405),
+    Elem::new(55266825022251314336734046389891159241),
+    Elem::new(123557197252580166867144586974777028099),
+    Elem::new(16482380493726978933095957778647192081),
+    Elem::new(146730399524852632838933513976435275271),
+    Elem::new(242125639342276893971296567611799787828),
+    Elem::new(240037566327550684533368141031520665976),
+    Elem::new(5132149287628128252437281389735974544),
+    Elem::new(270615220019933639081974833079362107418),
+    Elem::new(120020349142548784302685514691314835467),
+    Elem::new(118932430244284265108378813497339775298),
+    Elem::new(230423365592039772444034034176592013071),
+    Elem::new(25397555193698550071967783167003735340),
+    Elem::new(175242944376470135634954251218601572128),
+    Elem::new(72591421639622650621019413423847945598),
+    Elem::new(81010351984341088078843752881832075380),
+    Elem::new(146240734088894606981927033490499341419),
+    Elem::new(182720236255271762743281160305544068086),
+    Elem::new(287458756405663819896409499599497823084),
+    Elem::new(30448506302102990211078085105620506992),
+    Elem::new(29218593752446041017386468113780047030),
+    Elem::new(270962847354385325014808238584784592952),
+    Elem::new(53179420525705510367815153655408171771),
+    Elem::new(319852174387684756101009734784975429281),
+    Elem::new(253257783151320516546021659758810718445),
+    Elem::new(156310623945441999771547293503406804983),
+    Elem::new(293573654012811488024432978209839724938),
+    Elem::new(118650392835045629806952440758267995368),
+    Elem::new(51689548294594463940251727122988168934),
+    Elem::new(16655440040449578620501664522248950972),
+    Elem::new(40293164302615809324024657067260180330),
+    Elem::new(7818018903082905723717251929322409495),
+    Elem::new(216023706017049869309252647182277994795),
+    Elem::new(61543629753303766953978856981736528242),
+    Elem::new(98684478843768677850661732846275567470),
+    Elem::new(190277433165117097173234932831070092340),
+    Elem::new(199455238259079095381422643903010633326),
+    Elem::new(317490229705682741687508785558140618354),
+    Elem::new(114641429362865479648835004349457746011),
+    Elem::new(160508839675469912767915320258431569690),
+    Elem::new(57787707312213200074925766209564095087),
+    Elem::new(111092775042289456104569266631393702755),
+    Elem::new(252391539366617430300880986174835160999),
+    Elem::new(240653845441642830240797087329174246563),
+    Elem::new(231441118446751627097724232327341768603),
+    Elem::new(296884285178768980963463223183369900667),
+    Elem::new(148462487701233119060063186275833581874),
+    Elem::new(170341383342222323651766069090429088209),
+    Elem::new(19294721131780065480707021980820140299),
+    Elem::new(227820215846965115216429292601291617607),
+    Elem::new(132404769762829062625020678143100508567),
+    Elem::new(274537834578343581274108529225108695703),
+    Elem::new(281728368080457456654587468177967170978),
+    Elem::new(186107610732590961647488089769894443723),
+    Elem::new(187231374988552002717591706727037269351),
+    Elem::new(44413710496813709336111454134234337105),
+    Elem::new(295540220347336382002565851188558027176),
+    Elem::new(307623297309289978773123532702525383417),
+    Elem::new(217053237728706879206906434417010207457),
+    Elem::new(173002911040402763664326994679545039737),
+    Elem::new(212247147192252948393623423758606442354),
+    Elem::new(268252316358339546914360844400176262856),
+    Elem::new(44125552204860682804403419265151485051),
+    Elem::new(24145734692498813049014788975029376765),
+    Elem::new(285214642715345482792783351513711144538),
+    Elem::new(167881962796982582555342056851856088803),
+    Elem::new(275197945266964326614066627050223567352),
+    Elem::new(322812089962435880171275647799181739627),
+    Elem::new(19616496668813965504291829980529616674),
+    Elem::new(272705607796805123372294280648021453369),
+    Elem::new(221232680364083040555325704796343662503),
+    Elem::new(299476531852074029886163745818556688130),
+    Elem::new(46597567773897928960980838138314290166),
+    Elem::new(288750161935841589930790112292370341920),
+    Elem::new(216546078390155047643925493812695283354),
+    Elem::new(241334811811419081275361234134807015158),
+    Elem::new(108945092276029063308486688506775360862),
+    Elem::new(62106933944739586044948230792887099383),
+    Elem::new(158060627520812481014827352611596167342),
+    Elem::new(158817920131669965244436384181666748598),
+    Elem::new(45597179603316489141243414425848593863),
+    Elem::new(230025953767866393300353436400067014152),
+    Elem::new(194947832872391380058881105676866034662),
+    Elem::new(117649251045998916323080478682126745178),
+    Elem::new(327486915833681921006798229477797514215),
+    Elem::new(215853273620382954828654451700500984526),
+    Elem::new(324217965033183381704090972782436573386),
+    Elem::new(130909627919452722456024450803374284457),
+    Elem::new(158636411922416707328127438126991129174),
+    Elem::new(7149750736671858018180467789848469165),
+    Elem::new(101471743640590347328955007252839641218),
+    Elem::new(190239007473741655895527210871061926707),
+    Elem::new(200678917424261571398141064623106811967),
+    Elem::new(64116793157695567497923757514560046640),
+    Elem::new(305091038901026279341218928665359927074),
+    Elem::new(241371476485500843054514694883945144393),
+    Elem::new(226887522121185142839091222518001451769),
+    Elem::new(312832016094336574122177272430832220628),
+    Elem::new(319107979908926922397569055901693287194),
+    Elem::new(221572855530016997221990951636596732752),
+    Elem::new(309426715256057730555355852609722676512),
+    Elem::new(268236943023526062108568877368859402056),
+    Elem::new(272280585980262612873788399623233501900),
+    Elem::new(226553268535802870081668378471926782136),
+    Elem::new(315844949999804068386180650380836602188),
+    Elem::new(84057170604691423610995863801094385185),
+    Elem::new(322154135743459660200790310089104320292),
+    Elem::new(165147974372267600051942276797641033828),
+    Elem::new(185311251785433814590725026968293813757),
+    Elem::new(18186939533791887356411855597951066118),
+    Elem::new(79828312952413413880156879498628668290),
+    Elem::new(27097308950579675910338920449347765347),
+    Elem::new(171367289722537320112337936578983056225),
+    Elem::new(178296967041959666791789659879969077578),
+    Elem::new(1496049393352924372130942579880383689),
+    Elem::new(329259541113518013813713561041981642083),
+    Elem::new(322134772380105540592422036869833262323),
+    Elem::new(258176824085999194641679174580021749633),
+    Elem::new(277132793076187597040177024155562038578),
+    Elem::new(77039890277396366117012660779796478042),
+    Elem::new(121546387708848796802020061392442764777),
+    Elem::new(76009634104148828368556969397122396648),
+    Elem::new(339633087825310973653169118635948378414),
+    Elem::new(188077033246190924565678908414760423076),
+    Elem::new(141465458656937880149236118839450086978),
+    Elem::new(192714458276299222335905825949933469028),
+    Elem::new(189622848914639458847825109071801763514),
+    Elem::new(14647822333320687805159959509195151768),
+    Elem::new(148453104432678939235036330066622550437),
+    Elem::new(21013537501673021994755393738082282697),
+    Elem::new(212785962161299143649130506933474797559),
+    Elem::new(232509170733544269399587907994188154597),
+    Elem::new(102429703892838434549710678458886460709),
+    Elem::new(232049282565919267712970904684251330683),
+    Elem::new(250545093460741486670054264678762808386),
+    Elem::new(175615491553212989889832718882848586875),
+    Elem::new(156217746865049553550135196820361373568),
+    Elem::new(52605452794422036273035988693654265430),
+    Elem::new(225579767339566464119526992768819449732),
+    Elem::new(75624728987904833779236558595525325726),
+    Elem::new(148160772908040279420643027276652572412),
+    Elem::new(112057550849181224834156141411811425119),
+    Elem::new(105919102062159020677559506983130433824),
+    Elem::new(16280661785448747070677700292321538963),
+    Elem::new(215592668474748734354897854146194304450),
+    Elem::new(153234405184796392998437258715592965543),
+    Elem::new(163330917553307412109140432685629760642),
+    Elem::new(83199300284326624583288074632628894303),
+    Elem::new(321790151447178332736557272443419423483),
+    Elem::new(254714208946198212428546908171826699688),
+    Elem::new(180381821149692228224123400154687944871),
+    Elem::new(158609298649789751635599473334784889329),
+    Elem::new(180562863070923850123689048050157510162),
+    Elem::new(53242586812771999732171061109215765725),
+    Elem::new(91151146514877463901082554914677676096),
+    Elem::new(258024528627468925555614388633974906479),
+    Elem::new(161588503102645310502646971579300118737),
+    Elem::new(73090673501322481718921498854962612625),
+    Elem::new(15392500049394702091435902084653679537),
+    Elem::new(242949546768083198524395678601028565400),
+    Elem::new(217986785082942553798943146128480232277),
+    Elem::new(300484560191598423586946461838233002554),
+    Elem::new(175766705110284877580595905372411813181),
+    Elem::new(199304108336149087271979138886967970799),
+    Elem::new(123987222420552308428430762681346399780),
+    Elem::new(280035608208812923942386868326087833917),
+    Elem::new(64859254188073507937772065971938780672),
+    Elem::new(159305084023182276095739072112861349318),
+    Elem::new(275132459286546803729775524617860841812),
+    Elem::new(205924125277341753446039071191566458962),
+    Elem::new(278457467197118948647899303439062930549),
+    Elem::new(307569117453465807657684731520349522017),
+    Elem::new(338127836376888452635819572361458428797),
+    Elem::new(327480999092913245481412407649875509187),
+    Elem::new(204529461196877994476475346033381701886),
+    Elem::new(85843373306246345391767381251665066861),
+    Elem::new(300666777251560824527817041263504913887),
+    Elem::new(170022422240009735227023938716020975550),
+    Elem::new(275598000322430468854819648230495167408),
+    Elem::new(49190047570047021404001193491010079281),
+    Elem::new(289658571982826930821155140647318297537),
+    Elem::new(173918021647744636588089283344950005582),
+    Elem::new(265649136622958197234820857363312511628),
+    Elem::new(203731581076766696720685559208566309032),
+    Elem::new(284668461758777999861665691784925333340),
+    Elem::new(87941265448811814951845342294684563573),
+    Elem::new(87841472411637825746230600313835759259),
+    Elem::new(220147886081742922900576891797799429671),
+    Elem::new(114328836446809406349980220801488521192),
+    Elem::new(325997397623154111095444923591390940603),
+    Elem::new(147929607045733961378107435759879419758),
+    Elem::new(299483669927227270658796014717396672841),
+    Elem::new(175729533223625413266450862027566104257),
+    Elem::new(324012805830136830693898245380406816057),
+    Elem::new(22005626775300871781144098892595845230),
+    Elem::new(270949185383121413976880316202441099065),
+    Elem::new(242753450090130638551682063290300529715),
+    Elem::new(281785779236063329673559313751671416410),
+    Elem::new(128863164493906953139684862897500594506),
+    Elem::new(125593839106943460343277601522021313936),
+    Elem::new(263547242920284433772776994843860658220),
+    Elem::new(135991320651996835294460530514313528813),
+    Elem::new(12351603258593051125004064636591725610),
+    Elem::new(153130801556360913547482519445681832232),
+    Elem::new(298657443694642451930775667950700305157),
+    Elem::new(195257809856441755179417629319713592847),
+    Elem::new(19420473115057386523331792147036067147),
+    Elem::new(137679070389359903446251963400403111325),
+    Elem::new(115453783779681226561287854468972935708),
+    Elem::new(51650888874766551902435549339231853687),
+    Elem::new(21082643086758255549170003806241839748),
+    Elem::new(160928505012176975632948812088186659887),
+    Elem::new(103377474288759447481244864765731685896),
+    Elem::new(162574327076690050608920658628028015148),
+    Elem::new(146669397689249513924406441163947212107),
+    Elem::new(175398295720364951737354612135429421974),
+    Elem::new(16939144481473651594454653971550567835),
+    Elem::new(52057008454103064632836759418697294405),
+    Elem::new(127448952826674825049862005320657136714),
+    Elem::new(17304621598011848736917613510373276413),
+    Elem::new(262289336714104391770916120732401842180),
+    Elem::new(144418673631544061375367726876045057557),
+    Elem::new(316209211448857743898060973619128449360),
+    Elem::new(146030828891304582876123593665313541990),
+    Elem::new(215960301713696999795022094662247347278),
+    Elem::new(212559538009679684769449620680787425732),
+    Elem::new(189535193626715525408120134683179568504),
+    Elem::new(177228004977356871562571866277139568912),
+    Elem::new(65442005398194977352466775461842657392),
+    Elem::new(139699209203295730612635602767069043138),
+    Elem::new(274740633506370691313443262119037156696),
+    Elem::new(241606885711815439353264900367505712774),
+    Elem::new(259967003944003119467993754847215815750),
+    Elem::new(156359949953142513475331463787613843142),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(15108982798005006009510766385152400432),
+    Elem::new(30217965596010012019021532770304800864),
+    Elem::new(45326948394015018028532299155457201296),
+    Elem::new(60435931192020024038043065540609601728),
+    Elem::new(75544913990025030047553831925762002160),
+    Elem::new(90653896788030036057064598310914402592),
+    Elem::new(105762879586035042066575364696066803024),
+    Elem::new(120871862384040048076086131081219203456),
+    Elem::new(135980845182045054085596897466371603888),
+    Elem::new(151089827980050060095107663851524004320),
+    Elem::new(166198810778055066104618430236676404752),
+    Elem::new(181307793576060072114129196621828805184),
+    Elem::new(196416776374065078123639963006981205616),
+    Elem::new(211525759172070084133150729392133606048),
+    Elem::new(226634741970075090142661495777286006480),
+    Elem::new(241743724768080096152172262162438406912),
+    Elem::new(256852707566085102161683028547590807344),
+    Elem::new(271961690364090108171193794932743207776),
+    Elem::new(287070673162095114180704561317895608208),
+    Elem::new(302179655960100120190215327703048008640),
+    Elem::new(317288638758105126199726094088200409072),
+    Elem::new(332397621556110132209236860473352809504),
+    Elem::new(7224237433176674755373068904760248399),
+    Elem::new(22333220231181680764883835289912648831),
+    Elem::new(37442203029186686774394601675065049263),
+    Elem::new(52551185827191692783905368060217449695),
+    Elem::new(67660168625196698793416134445369850127),
+    Elem::new(82769151423201704802926900830522250559),
+    Elem::new(97878134221206710812437667215674650991),
+    Elem::new(112987117019211716821948433600827051423),
+    Elem::new(128096099817216722831459199985979451855),
+    Elem::new(143205082615221728840969966371131852287),
+    Elem::new(158314065413226734850480732756284252719),
+    Elem::new(173423048211231740859991499141436653151),
+    Elem::new(188532031009236746869502265526589053583),
+    Elem::new(203641013807241752879013031911741454015),
+    Elem::new(218749996605246758888523798296893854447),
+    Elem::new(233858979403251764898034564682046254879),
+    Elem::new(248967962201256770907545331067198655311),
+    Elem::new(264076944999261776917056097452351055743),
+    Elem::new(279185927797266782926566863837503456175),
+    Elem::new(294294910595271788936077630222655856607),
+    Elem::new(309403893393276794945588396607808257039),
+    Elem::new(324512876191281800955099162992960657471),
+    Elem::new(339621858989286806964609929378113057903),
+    Elem::new(14448474866353349510746137809520496798),
+    Elem::new(29557457664358355520256904194672897230),
+    Elem::new(44666440462363361529767670579825297662),
+    Elem::new(59775423260368367539278436964977698094),
+    Elem::new(74884406058373373548789203350130098526),
+    Elem::new(89993388856378379558299969735282498958),
+    Elem::new(105102371654383385567810736120434899390),
+    Elem::new(120211354452388391577321502505587299822),
+    Elem::new(135320337250393397586832268890739700254),
+    Elem::new(150429320048398403596343035275892100686),
+    Elem::new(165538302846403409605853801661044501118),
+    Elem::new(180647285644408415615364568046196901550),
+    Elem::new(195756268442413421624875334431349301982),
+    Elem::new(210865251240418427634386100816501702414),
+    Elem::new(225974234038423433643896867201654102846),
+    Elem::new(241083216836428439653407633586806503278),
+    Elem::new(256192199634433445662918399971958903710),
+    Elem::new(271301182432438451672429166357111304142),
+    Elem::new(286410165230443457681939932742263704574),
+    Elem::new(301519148028448463691450699127416105006),
+    Elem::new(316628130826453469700961465512568505438),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(123974644275678435559119017146211072341),
+    Elem::new(155616210181775278773101510631099327827),
+    Elem::new(94924697718290529641947480454664766458),
+    Elem::new(282182473806162651629031484570652349771),
+    Elem::new(36824804603514717807604407071572154692),
+    Elem::new(39416423952223655104415363864914104295),
+    Elem::new(289957331852289463519464354950678198580),
+    Elem::new(107882794461835216126002264421374514473),
+    Elem::new(173757545622737839850778208184492975048),
+    Elem::new(147299218414058871230417628286288618768),
+    Elem::new(28507812835798310264920524726761445633),
+    Elem::new(157665695808894620417661455459656417180),
+    Elem::new(194490500412409338225265862531228571872),
+    Elem::new(138982226646342463687733745941477909709),
+    Elem::new(331423241431632460268439663644149392228),
+    Elem::new(91248810926402401040634499731753096355),
+    Elem::new(99023668972529212931067370111778945164),
+    Elem::new(14465448649074432476363716830481977118),
+    Elem::new(177856516876976523139898097841607153754),
+    Elem::new(248914506735297021458295955191409513535),
+    Elem::new(227639418224035927431557288879889056461),
+    Elem::new(114031251343193241059682098907045782532),
+    Elem::new(248372373013707425806044943226624653285),
+    Elem::new(290380416314640018207271263884880707183),
+    Elem::new(240055381245991018263361060881813944226),
+    Elem::new(97397267807760425974314334217424364414),
+    Elem::new(202688442920886704803505641845456929284),
+    Elem::new(215646539664431391287560425812166677299),
+    Elem::new(136271558038394485426478686117553608459),
+    Elem::new(304845864963714450683634980715362684301),
+    Elem::new(40804726598514360132280193698103981751),
+    Elem::new(24712876784671140699163440973267423883),
+    Elem::new(256570315522184792384284722540853010697),
+    Elem::new(55812308969178388260894922493370819119),
+    Elem::new(103003590967528855255743156738310772223),
+    Elem::new(57861794596297729905454867321927908472),
+    Elem::new(260669286776423475673404612197967189403),
+    Elem::new(30861333666029165632843275458938691942),
+    Elem::new(49002669106991726710519973012332339163),
+    Elem::new(315093293099311158906434704858148131066),
+    Elem::new(148568471801110535293838355088896144577),
+    Elem::new(229992939054266782799480039612066302770),
+    Elem::new(219084327937841437959985200473913644108),
+    Elem::new(115842638451834500775353837674438168591),
+    Elem::new(260550237517184434708960509167384837756),
+    Elem::new(312924758212952776297430656999008690066),
+    Elem::new(272966200539139525540764281169309725521),
+    Elem::new(140674564495744682438961381678287944121),
+    Elem::new(256332217003706710455396516479688307403),
+    Elem::new(279656791142087146126695127619765853830),
+    Elem::new(210648286910885989452857215098520583402),
+    Elem::new(49306704310103240433882778915952496119),
+    Elem::new(135914410260677362533146377025806553518),
+    Elem::new(130189037841669892287273451474337794062),
+    Elem::new(32130587053080829696264002261546217751),
+    Elem::new(182021424815848638223492587341176786122),
+    Elem::new(239579184209034854405584648759484537638),
+    Elem::new(204803865232639478242540186516469472299),
+    Elem::new(77695467886662509734359200612131590105),
+    Elem::new(198536359092042412344416249000215852593),
+    Elem::new(227044171927840722609336773726977298226),
+    Elem::new(163218906394057440529120774792415927004),
+    Elem::new(7060562490692566103768252196531738927),
+    Elem::new(98851507138684562796653763893069695532),
+    Elem::new(98309373417094967144402751928284835282),
+    Elem::new(5434161325923779147015216302177158177),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,717 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 12
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 12;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u64 = 3074416663688030891;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(3746945308869787649),
+    Elem::new(1008792967772635136),
+    Elem::new(4467511714421669889),
+    Elem::new(432339843331129344),
+    Elem::new(2594039059986776064),
+    Elem::new(1585246092214140929),
+    Elem::new(432339843331129344),
+    Elem::new(3314605465538658305),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(576453124441505792),
+    Elem::new(3891058589980164097),
+    Elem::new(144113281110376448),
+    Elem::new(4467511714421669889),
+    Elem::new(2882265622207528961),
+    Elem::new(2161699216655646720),
+    Elem::new(4179285152200916993),
+    Elem::new(432339843331129344),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(2071722648418834641),
+    Elem::new(1586901890446016958),
+    Elem::new(3824829552892426876),
+    Elem::new(2543593806275418052),
+    Elem::new(3669151338574908189),
+    Elem::new(2221524466867933697),
+    Elem::new(2830170808532427166),
+    Elem::new(1057859636557703482),
+    Elem::new(3490746798021769456),
+    Elem::new(3647367935163621161),
+    Elem::new(3225544762727867427),
+    Elem::new(286965460539839530),
+    Elem::new(1032390334331988808),
+    Elem::new(245059505437273107),
+    Elem::new(3453874580159071004),
+    Elem::new(3286048023411560860),
+    Elem::new(2007580226569421867),
+    Elem::new(446595467050909644),
+    Elem::new(2436737834894195293),
+    Elem::new(1646722098017337532),
+    Elem::new(3346886426670130835),
+    Elem::new(3308033238962836517),
+    Elem::new(326507468649334382),
+    Elem::new(1808556822867976510),
+    Elem::new(2081746040188032921),
+    Elem::new(2508609541335786286),
+    Elem::new(150227862901750705),
+    Elem::new(1709355918944687876),
+    Elem::new(2335460843462398862),
+    Elem::new(365286094832626939),
+    Elem::new(2763286569323227919),
+    Elem::new(3170023010137160363),
+    Elem::new(1865208414160324961),
+    Elem::new(4090960253986246110),
+    Elem::new(4402908894844351359),
+    Elem::new(752532882426383493),
+    Elem::new(529677355847549900),
+    Elem::new(38182123718055981),
+    Elem::new(1913507900258867587),
+    Elem::new(4304370844128366579),
+    Elem::new(3563362134558423066),
+    Elem::new(2713759220747561868),
+    Elem::new(63974977344228194),
+    Elem::new(1317864373757011939),
+    Elem::new(4379564020505062815),
+    Elem::new(4368250060658144772),
+    Elem::new(1776341791203496164),
+    Elem::new(3594878956966223277),
+    Elem::new(1017066420558047883),
+    Elem::new(2619611047181590837),
+    Elem::new(3949052186930213505),
+    Elem::new(2432849115995716848),
+    Elem::new(1091958553205817774),
+    Elem::new(1689416414759729762),
+    Elem::new(1407614619689994029),
+    Elem::new(2308574993809812385),
+    Elem::new(1362827124356357904),
+    Elem::new(2042045716386579869),
+    Elem::new(487999357076371087),
+    Elem::new(3547210226373456903),
+    Elem::new(2846587894506840971),
+    Elem::new(1733719468370343526),
+    Elem::new(4067076850625626174),
+    Elem::new(4174885223669747132),
+    Elem::new(3923798044411197604),
+    Elem::new(377595123260479167),
+    Elem::new(1446075482775499447),
+    Elem::new(2550392471462323807),
+    Elem::new(2312236609447485627),
+    Elem::new(713939454602308906),
+    Elem::new(2512870603666975086),
+    Elem::new(116716082108764048),
+    Elem::new(3781620822180257350),
+    Elem::new(2169547454436368051),
+    Elem::new(3625359537429622664),
+    Elem::new(2118306690568417539),
+    Elem::new(2610702102045488741),
+    Elem::new(2772813951535163165),
+    Elem::new(2063309020796579275),
+    Elem::new(4359420030140015161),
+    Elem::new(2668095771819519282),
+    Elem::new(3442541907229477330),
+    Elem::new(4266756624406903154),
+    Elem::new(3510906821902857706),
+    Elem::new(287010134101245045),
+    Elem::new(3309539759346423885),
+    Elem::new(945693643835593925),
+    Elem::new(3555091689058518299),
+    Elem::new(2927673049806859978),
+    Elem::new(4098185469324491316),
+    Elem::new(3489814911403181128),
+    Elem::new(220540255267339199),
+    Elem::new(4139340725567028118),
+    Elem::new(1548480980813734027),
+    Elem::new(2298914862449802169),
+    Elem::new(4556792648804349620),
+    Elem::new(2296242064444356285),
+    Elem::new(1126359872898810201),
+    Elem::new(251662903927159679),
+    Elem::new(3935712161597517163),
+    Elem::new(257892127770215538),
+    Elem::new(777209357307230199),
+    Elem::new(93024973145112139),
+    Elem::new(2314358077983414280),
+    Elem::new(3067457186816974111),
+    Elem::new(2207098530178714425),
+    Elem::new(218230594902051893),
+    Elem::new(1724981161651221651),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(3076134431684260128),
+    Elem::new(1540643867836473919),
+    Elem::new(5153303988687710),
+    Elem::new(3081287735672947838),
+    Elem::new(1545797171825161629),
+    Elem::new(10306607977375420),
+    Elem::new(3086441039661635548),
+    Elem::new(1550950475813849339),
+    Elem::new(15459911966063130),
+    Elem::new(3091594343650323258),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(3681317841589670609),
+    Elem::new(890396379762543425),
+    Elem::new(850485605582711122),
+    Elem::new(3561585519050173700),
+    Elem::new(4412071124632884822),
+    Elem::new(3401942422330844488),
+    Elem::new(531199412144052698),
+    Elem::new(411467089604555789),
+    Elem::new(3042745454712353761),
+    Elem::new(3813409511935400277),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
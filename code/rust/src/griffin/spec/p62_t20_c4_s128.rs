@@ -0,0 +1,1325 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 20
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 20;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u64 = 3074416663688030891;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(4035171871090540545),
+    Elem::new(2209736977025772203),
+    Elem::new(2978341142947779926),
+    Elem::new(288226562220752896),
+    Elem::new(2882265622207528960),
+    Elem::new(2786190101467277996),
+    Elem::new(3554794267389285718),
+    Elem::new(3170492184428281857),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(1921510414805019307),
+    Elem::new(1056830728142760619),
+    Elem::new(1633283852584266411),
+    Elem::new(2978341142947779926),
+    Elem::new(4227322912571042476),
+    Elem::new(3939096350350289579),
+    Elem::new(1056830728142760619),
+    Elem::new(3554794267389285718),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(2071722648418834641),
+    Elem::new(1586901890446016958),
+    Elem::new(3824829552892426876),
+    Elem::new(2543593806275418052),
+    Elem::new(3669151338574908189),
+    Elem::new(2221524466867933697),
+    Elem::new(2830170808532427166),
+    Elem::new(1057859636557703482),
+    Elem::new(3490746798021769456),
+    Elem::new(3647367935163621161),
+    Elem::new(3225544762727867427),
+    Elem::new(286965460539839530),
+    Elem::new(1032390334331988808),
+    Elem::new(245059505437273107),
+    Elem::new(3453874580159071004),
+    Elem::new(3286048023411560860),
+    Elem::new(2007580226569421867),
+    Elem::new(446595467050909644),
+    Elem::new(2436737834894195293),
+    Elem::new(1646722098017337532),
+    Elem::new(3346886426670130835),
+    Elem::new(3308033238962836517),
+    Elem::new(326507468649334382),
+    Elem::new(1808556822867976510),
+    Elem::new(2081746040188032921),
+    Elem::new(2508609541335786286),
+    Elem::new(150227862901750705),
+    Elem::new(1709355918944687876),
+    Elem::new(2335460843462398862),
+    Elem::new(365286094832626939),
+    Elem::new(2763286569323227919),
+    Elem::new(3170023010137160363),
+    Elem::new(1865208414160324961),
+    Elem::new(4090960253986246110),
+    Elem::new(4402908894844351359),
+    Elem::new(752532882426383493),
+    Elem::new(529677355847549900),
+    Elem::new(38182123718055981),
+    Elem::new(1913507900258867587),
+    Elem::new(4304370844128366579),
+    Elem::new(3563362134558423066),
+    Elem::new(2713759220747561868),
+    Elem::new(63974977344228194),
+    Elem::new(1317864373757011939),
+    Elem::new(4379564020505062815),
+    Elem::new(4368250060658144772),
+    Elem::new(1776341791203496164),
+    Elem::new(3594878956966223277),
+    Elem::new(1017066420558047883),
+    Elem::new(2619611047181590837),
+    Elem::new(3949052186930213505),
+    Elem::new(2432849115995716848),
+    Elem::new(1091958553205817774),
+    Elem::new(1689416414759729762),
+    Elem::new(1407614619689994029),
+    Elem::new(2308574993809812385),
+    Elem::new(1362827124356357904),
+    Elem::new(2042045716386579869),
+    Elem::new(487999357076371087),
+    Elem::new(3547210226373456903),
+    Elem::new(2846587894506840971),
+    Elem::new(1733719468370343526),
+    Elem::new(4067076850625626174),
+    Elem::new(4174885223669747132),
+    Elem::new(3923798044411197604),
+    Elem::new(377595123260479167),
+    Elem::new(1446075482775499447),
+    Elem::new(2550392471462323807),
+    Elem::new(2312236609447485627),
+    Elem::new(713939454602308906),
+    Elem::new(2512870603666975086),
+    Elem::new(116716082108764048),
+    Elem::new(3781620822180257350),
+    Elem::new(2169547454436368051),
+    Elem::new(3625359537429622664),
+    Elem::new(2118306690568417539),
+    Elem::new(2610702102045488741),
+    Elem::new(2772813951535163165),
+    Elem::new(2063309020796579275),
+    Elem::new(4359420030140015161),
+    Elem::new(2668095771819519282),
+    Elem::new(3442541907229477330),
+    Elem::new(4266756624406903154),
+    Elem::new(3510906821902857706),
+    Elem::new(287010134101245045),
+    Elem::new(3309539759346423885),
+    Elem::new(945693643835593925),
+    Elem::new(3555091689058518299),
+    Elem::new(2927673049806859978),
+    Elem::new(4098185469324491316),
+    Elem::new(3489814911403181128),
+    Elem::new(220540255267339199),
+    Elem::new(4139340725567028118),
+    Elem::new(1548480980813734027),
+    Elem::new(2298914862449802169),
+    Elem::new(4556792648804349620),
+    Elem::new(2296242064444356285),
+    Elem::new(1126359872898810201),
+    Elem::new(251662903927159679),
+    Elem::new(3935712161597517163),
+    Elem::new(257892127770215538),
+    Elem::new(777209357307230199),
+    Elem::new(93024973145112139),
+    Elem::new(2314358077983414280),
+    Elem::new(3067457186816974111),
+    Elem::new(2207098530178714425),
+    Elem::new(218230594902051893),
+    Elem::new(1724981161651221651),
+    Elem::new(3076134431684260128),
+    Elem::new(3681317841589670609),
+    Elem::new(3486641674535667888),
+    Elem::new(1028685303049295991),
+    Elem::new(230756187494669981),
+    Elem::new(2206159610590983009),
+    Elem::new(4194456620692579209),
+    Elem::new(659201880892351698),
+    Elem::new(800513309210625139),
+    Elem::new(1625156743513251821),
+    Elem::new(3859582217814166462),
+    Elem::new(4152725063426547938),
+    Elem::new(290232538029898036),
+    Elem::new(3481917903246526845),
+    Elem::new(1157746487273089377),
+    Elem::new(441038378903703917),
+    Elem::new(4301692302428850024),
+    Elem::new(4248714236502150862),
+    Elem::new(4167147503287744741),
+    Elem::new(4140962070923997535),
+    Elem::new(4385747682524948589),
+    Elem::new(2811963008352379425),
+    Elem::new(3283305594679243412),
+    Elem::new(4176202313100331422),
+    Elem::new(2614504315496557299),
+    Elem::new(878223322919791208),
+    Elem::new(3972755110070993217),
+    Elem::new(1451551422854460671),
+    Elem::new(3258256461895471534),
+    Elem::new(548851826371882701),
+    Elem::new(4355232949779801050),
+    Elem::new(2691258400079071723),
+    Elem::new(574904660045057698),
+    Elem::new(1245873505240080063),
+    Elem::new(797927202235816008),
+    Elem::new(1329015176717159972),
+    Elem::new(2618599974118303681),
+    Elem::new(1815120555600918199),
+    Elem::new(2631796523862218395),
+    Elem::new(1378672015787515652),
+    Elem::new(3411031317922850312),
+    Elem::new(2802842455134450232),
+    Elem::new(3391156432073430164),
+    Elem::new(310012547571979446),
+    Elem::new(1826630810287261654),
+    Elem::new(4589180914121752483),
+    Elem::new(577337837970743305),
+    Elem::new(3617889720526493804),
+    Elem::new(3121855014556577088),
+    Elem::new(2988713778258905941),
+    Elem::new(3604787874801964561),
+    Elem::new(4359549444242957269),
+    Elem::new(3326975444090281667),
+    Elem::new(1623758211719834100),
+    Elem::new(3503824440509898522),
+    Elem::new(2930836698137454451),
+    Elem::new(3309637648099505179),
+    Elem::new(1808659838358820712),
+    Elem::new(1160986363415375987),
+    Elem::new(1086918237927522137),
+    Elem::new(588889541777868834),
+    Elem::new(158619765232654377),
+    Elem::new(1451372033441334044),
+    Elem::new(75798217445680825),
+    Elem::new(2373993509364673766),
+    Elem::new(1151947910755763089),
+    Elem::new(1550064348079184090),
+    Elem::new(3829676426722559989),
+    Elem::new(496437325126032431),
+    Elem::new(657846258100119610),
+    Elem::new(3415274732760692459),
+    Elem::new(3535569474228919971),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(2253400916002092321),
+    Elem::new(4506801832004184642),
+    Elem::new(2148577752474230626),
+    Elem::new(4401978668476322947),
+    Elem::new(2043754588946368931),
+    Elem::new(4297155504948461252),
+    Elem::new(1938931425418507236),
+    Elem::new(4192332341420599557),
+    Elem::new(1834108261890645541),
+    Elem::new(4087509177892737862),
+    Elem::new(1729285098362783846),
+    Elem::new(3982686014364876167),
+    Elem::new(1624461934834922151),
+    Elem::new(3877862850837014472),
+    Elem::new(1519638771307060456),
+    Elem::new(3773039687309152777),
+    Elem::new(1414815607779198761),
+    Elem::new(3668216523781291082),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(1642979967635196965),
+    Elem::new(1960294875008741523),
+    Elem::new(951944722120633674),
+    Elem::new(3229554504502919755),
+    Elem::new(4181499226623553429),
+    Elem::new(3807778888482534696),
+    Elem::new(2108393490079863556),
+    Elem::new(3694968026947586346),
+    Elem::new(3955877503553656729),
+    Elem::new(2891121919898074705),
+    Elem::new(500701275980840274),
+    Elem::new(1396240567333999773),
+    Elem::new(966114798425506865),
+    Elem::new(3821948964787407887),
+    Elem::new(740493075355610165),
+    Elem::new(944997121194206373),
+    Elem::new(4435461102303196511),
+    Elem::new(1988635027618487905),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3309 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 36
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 36;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u64 = 3074416663688030891;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(2421103122654324327),
+    Elem::new(4092817183534691124),
+    Elem::new(2709329684875077223),
+    Elem::new(1095260936438861005),
+    Elem::new(1268196873771312742),
+    Elem::new(57645312444150580),
+    Elem::new(3285782809316583015),
+    Elem::new(3977526558646389966),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(2997556247095830119),
+    Elem::new(4323398433311293441),
+    Elem::new(1902295310656969114),
+    Elem::new(2709329684875077223),
+    Elem::new(691743749329806951),
+    Elem::new(2594039059986776064),
+    Elem::new(1325842186215463322),
+    Elem::new(3285782809316583015),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(2071722648418834641),
+    Elem::new(1586901890446016958),
+    Elem::new(3824829552892426876),
+    Elem::new(2543593806275418052),
+    Elem::new(3669151338574908189),
+    Elem::new(2221524466867933697),
+    Elem::new(2830170808532427166),
+    Elem::new(1057859636557703482),
+    Elem::new(3490746798021769456),
+    Elem::new(3647367935163621161),
+    Elem::new(3225544762727867427),
+    Elem::new(286965460539839530),
+    Elem::new(1032390334331988808),
+    Elem::new(245059505437273107),
+    Elem::new(3453874580159071004),
+    Elem::new(3286048023411560860),
+    Elem::new(2007580226569421867),
+    Elem::new(446595467050909644),
+    Elem::new(2436737834894195293),
+    Elem::new(1646722098017337532),
+    Elem::new(3346886426670130835),
+    Elem::new(3308033238962836517),
+    Elem::new(326507468649334382),
+    Elem::new(1808556822867976510),
+    Elem::new(2081746040188032921),
+    Elem::new(2508609541335786286),
+    Elem::new(150227862901750705),
+    Elem::new(1709355918944687876),
+    Elem::new(2335460843462398862),
+    Elem::new(365286094832626939),
+    Elem::new(2763286569323227919),
+    Elem::new(3170023010137160363),
+    Elem::new(1865208414160324961),
+    Elem::new(4090960253986246110),
+    Elem::new(4402908894844351359),
+    Elem::new(752532882426383493),
+    Elem::new(529677355847549900),
+    Elem::new(38182123718055981),
+    Elem::new(1913507900258867587),
+    Elem::new(4304370844128366579),
+    Elem::new(3563362134558423066),
+    Elem::new(2713759220747561868),
+    Elem::new(63974977344228194),
+    Elem::new(1317864373757011939),
+    Elem::new(4379564020505062815),
+    Elem::new(4368250060658144772),
+    Elem::new(1776341791203496164),
+    Elem::new(3594878956966223277),
+    Elem::new(1017066420558047883),
+    Elem::new(2619611047181590837),
+    Elem::new(3949052186930213505),
+    Elem::new(2432849115995716848),
+    Elem::new(1091958553205817774),
+    Elem::new(1689416414759729762),
+    Elem::new(1407614619689994029),
+    Elem::new(2308574993809812385),
+    Elem::new(1362827124356357904),
+    Elem::new(2042045716386579869),
+    Elem::new(487999357076371087),
+    Elem::new(3547210226373456903),
+    Elem::new(2846587894506840971),
+    Elem::new(1733719468370343526),
+    Elem::new(4067076850625626174),
+    Elem::new(4174885223669747132),
+    Elem::new(3923798044411197604),
+    Elem::new(377595123260479167),
+    Elem::new(1446075482775499447),
+    Elem::new(2550392471462323807),
+    Elem::new(2312236609447485627),
+    Elem::new(713939454602308906),
+    Elem::new(2512870603666975086),
+    Elem::new(116716082108764048),
+    Elem::new(3781620822180257350),
+    Elem::new(2169547454436368051),
+    Elem::new(3625359537429622664),
+    Elem::new(2118306690568417539),
+    Elem::new(2610702102045488741),
+    Elem::new(2772813951535163165),
+    Elem::new(2063309020796579275),
+    Elem::new(4359420030140015161),
+    Elem::new(2668095771819519282),
+    Elem::new(3442541907229477330),
+    Elem::new(4266756624406903154),
+    Elem::new(3510906821902857706),
+    Elem::new(287010134101245045),
+    Elem::new(3309539759346423885),
+    Elem::new(945693643835593925),
+    Elem::new(3555091689058518299),
+    Elem::new(2927673049806859978),
+    Elem::new(4098185469324491316),
+    Elem::new(3489814911403181128),
+    Elem::new(220540255267339199),
+    Elem::new(4139340725567028118),
+    Elem::new(1548480980813734027),
+    Elem::new(2298914862449802169),
+    Elem::new(4556792648804349620),
+    Elem::new(2296242064444356285),
+    Elem::new(1126359872898810201),
+    Elem::new(251662903927159679),
+    Elem::new(3935712161597517163),
+    Elem::new(257892127770215538),
+    Elem::new(777209357307230199),
+    Elem::new(93024973145112139),
+    Elem::new(2314358077983414280),
+    Elem::new(3067457186816974111),
+    Elem::new(2207098530178714425),
+    Elem::new(218230594902051893),
+    Elem::new(1724981161651221651),
+    Elem::new(3076134431684260128),
+    Elem::new(3681317841589670609),
+    Elem::new(3486641674535667888),
+    Elem::new(1028685303049295991),
+    Elem::new(230756187494669981),
+    Elem::new(2206159610590983009),
+    Elem::new(4194456620692579209),
+    Elem::new(659201880892351698),
+    Elem::new(800513309210625139),
+    Elem::new(1625156743513251821),
+    Elem::new(3859582217814166462),
+    Elem::new(4152725063426547938),
+    Elem::new(290232538029898036),
+    Elem::new(3481917903246526845),
+    Elem::new(1157746487273089377),
+    Elem::new(441038378903703917),
+    Elem::new(4301692302428850024),
+    Elem::new(4248714236502150862),
+    Elem::new(4167147503287744741),
+    Elem::new(4140962070923997535),
+    Elem::new(4385747682524948589),
+    Elem::new(2811963008352379425),
+    Elem::new(3283305594679243412),
+    Elem::new(4176202313100331422),
+    Elem::new(2614504315496557299),
+    Elem::new(878223322919791208),
+    Elem::new(3972755110070993217),
+    Elem::new(1451551422854460671),
+    Elem::new(3258256461895471534),
+    Elem::new(548851826371882701),
+    Elem::new(4355232949779801050),
+    Elem::new(2691258400079071723),
+    Elem::new(574904660045057698),
+    Elem::new(1245873505240080063),
+    Elem::new(797927202235816008),
+    Elem::new(1329015176717159972),
+    Elem::new(2618599974118303681),
+    Elem::new(1815120555600918199),
+    Elem::new(2631796523862218395),
+    Elem::new(1378672015787515652),
+    Elem::new(3411031317922850312),
+    Elem::new(2802842455134450232),
+    Elem::new(3391156432073430164),
+    Elem::new(310012547571979446),
+    Elem::new(1826630810287261654),
+    Elem::new(4589180914121752483),
+    Elem::new(577337837970743305),
+    Elem::new(3617889720526493804),
+    Elem::new(3121855014556577088),
+    Elem::new(2988713778258905941),
+    Elem::new(3604787874801964561),
+    Elem::new(4359549444242957269),
+    Elem::new(3326975444090281667),
+    Elem::new(1623758211719834100),
+    Elem::new(3503824440509898522),
+    Elem::new(2930836698137454451),
+    Elem::new(3309637648099505179),
+    Elem::new(1808659838358820712),
+    Elem::new(1160986363415375987),
+    Elem::new(1086918237927522137),
+    Elem::new(588889541777868834),
+    Elem::new(158619765232654377),
+    Elem::new(1451372033441334044),
+    Elem::new(75798217445680825),
+    Elem::new(2373993509364673766),
+    Elem::new(1151947910755763089),
+    Elem::new(1550064348079184090),
+    Elem::new(3829676426722559989),
+    Elem::new(496437325126032431),
+    Elem::new(657846258100119610),
+    Elem::new(3415274732760692459),
+    Elem::new(3535569474228919971),
+    Elem::new(2253400916002092321),
+    Elem::new(1642979967635196965),
+    Elem::new(2365548529436761191),
+    Elem::new(481965585807369200),
+    Elem::new(1014188434529536610),
+    Elem::new(2618183851478207374),
+    Elem::new(833390687139556225),
+    Elem::new(2894966827051300912),
+    Elem::new(4037324789648995847),
+    Elem::new(2441896499566937471),
+    Elem::new(953192132645206670),
+    Elem::new(237827687449434762),
+    Elem::new(3212814826684070368),
+    Elem::new(1714347597980433856),
+    Elem::new(2255740266666629666),
+    Elem::new(218157324941089419),
+    Elem::new(4005297969462217696),
+    Elem::new(3570893735755532721),
+    Elem::new(1228184788255496145),
+    Elem::new(3034142764149155426),
+    Elem::new(3387071336849399926),
+    Elem::new(2772292151735852106),
+    Elem::new(2515833101560844840),
+    Elem::new(1423682773181667858),
+    Elem::new(1000507203081169520),
+    Elem::new(1280715070890995452),
+    Elem::new(182238018844394403),
+    Elem::new(1204788963984753476),
+    Elem::new(848410641354156372),
+    Elem::new(917938383632158334),
+    Elem::new(1958713240490387051),
+    Elem::new(3151726119789920696),
+    Elem::new(3778353114092250288),
+    Elem::new(3650040118153489412),
+    Elem::new(4036084595098989038),
+    Elem::new(2485407012291906317),
+    Elem::new(2107306070518020349),
+    Elem::new(3293773686582960203),
+    Elem::new(3878387531294366597),
+    Elem::new(1585231004636126445),
+    Elem::new(377943368526035653),
+    Elem::new(2671256294633669620),
+    Elem::new(3474157787857279906),
+    Elem::new(2206634366159930746),
+    Elem::new(3904034891749310873),
+    Elem::new(2196703743747495368),
+    Elem::new(2109972802922738437),
+    Elem::new(886611592759935071),
+    Elem::new(4319473602182306227),
+    Elem::new(1726506008508963777),
+    Elem::new(2754931078095060132),
+    Elem::new(1296150652313694739),
+    Elem::new(1336692951408360347),
+    Elem::new(3845872881868006544),
+    Elem::new(3153083661201654346),
+    Elem::new(4579669296247082212),
+    Elem::new(2844938222185289970),
+    Elem::new(2624294709798535302),
+    Elem::new(2912795632135892863),
+    Elem::new(864372040761823269),
+    Elem::new(2665674432123628648),
+    Elem::new(2490234299953608502),
+    Elem::new(3712382337424924786),
+    Elem::new(1248531270071733939),
+    Elem::new(985195305289304206),
+    Elem::new(2554625161470238256),
+    Elem::new(37123543495265191),
+    Elem::new(2394541926732106036),
+    Elem::new(3687861712799498856),
+    Elem::new(323836133208596031),
+    Elem::new(2859909075427576084),
+    Elem::new(2848966483854403247),
+    Elem::new(2080569473922350077),
+    Elem::new(2353596498287469158),
+    Elem::new(3549884726363551565),
+    Elem::new(2515123243543701959),
+    Elem::new(968380205472443477),
+    Elem::new(246181868290625694),
+    Elem::new(3976501826122920999),
+    Elem::new(2902639036156893234),
+    Elem::new(1009898844420846510),
+    Elem::new(3315746525316923591),
+    Elem::new(2579604730849528764),
+    Elem::new(1745379669734682730),
+    Elem::new(3859424782248529999),
+    Elem::new(3214507105919559792),
+    Elem::new(1741764039242201418),
+    Elem::new(2350480785610492649),
+    Elem::new(1038344430072945102),
+    Elem::new(1463371911368166166),
+    Elem::new(3778539355543848895),
+    Elem::new(782660044271481738),
+    Elem::new(113535637405104827),
+    Elem::new(3677876889217620191),
+    Elem::new(947842647997252215),
+    Elem::new(361872803873112939),
+    Elem::new(740207209106058419),
+    Elem::new(229870210216199015),
+    Elem::new(1885835819775384134),
+    Elem::new(3063097634844204654),
+    Elem::new(46901677448089731),
+    Elem::new(3848742312022834301),
+    Elem::new(2538534176083502977),
+    Elem::new(1448142115755931250),
+    Elem::new(4224957955026224503),
+    Elem::new(2210458504956915752),
+    Elem::new(83615684077199810),
+    Elem::new(2293979488297385770),
+    Elem::new(3217116539612809075),
+    Elem::new(445382291873965255),
+    Elem::new(1497481951780856845),
+    Elem::new(2391570298619593948),
+    Elem::new(1935306642059137038),
+    Elem::new(1585384650452981032),
+    Elem::new(2957406424328579670),
+    Elem::new(210496067784378228),
+    Elem::new(2455214752419398427),
+    Elem::new(4531644796204950989),
+    Elem::new(2825000100634470586),
+    Elem::new(3161792173691147444),
+    Elem::new(3738199824083268371),
+    Elem::new(1247983699013432341),
+    Elem::new(1519099780344010514),
+    Elem::new(3795167737978428843),
+    Elem::new(4583321017559080212),
+    Elem::new(1412476314138689200),
+    Elem::new(3520052766839092933),
+    Elem::new(4305277640856085989),
+    Elem::new(254315397621118225),
+    Elem::new(4537917551790501785),
+    Elem::new(997773209764804235),
+    Elem::new(3062269141919172049),
+    Elem::new(1621778495499643848),
+    Elem::new(3694586410153120660),
+    Elem::new(4007489586118490414),
+    Elem::new(654411855262984760),
+    Elem::new(3535651263121604187),
+    Elem::new(44356512686575568),
+    Elem::new(553948804687102093),
+    Elem::new(2417074434734323487),
+    Elem::new(2049264741994872950),
+    Elem::new(2301344890574103580),
+    Elem::new(1630141487755219339),
+    Elem::new(3399971577300605154),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(4017183718429104225),
+    Elem::new(3422742441326162113),
+    Elem::new(2828301164223220001),
+    Elem::new(2233859887120277889),
+    Elem::new(1639418610017335777),
+    Elem::new(1044977332914393665),
+    Elem::new(450536055811451553),
+    Elem::new(4467719774240555778),
+    Elem::new(3873278497137613666),
+    Elem::new(3278837220034671554),
+    Elem::new(2684395942931729442),
+    Elem::new(2089954665828787330),
+    Elem::new(1495513388725845218),
+    Elem::new(901072111622903106),
+    Elem::new(306630834519960994),
+    Elem::new(4323814552949065219),
+    Elem::new(3729373275846123107),
+    Elem::new(3134931998743180995),
+    Elem::new(2540490721640238883),
+    Elem::new(1946049444537296771),
+    Elem::new(1351608167434354659),
+    Elem::new(757166890331412547),
+    Elem::new(162725613228470435),
+    Elem::new(4179909331657574660),
+    Elem::new(3585468054554632548),
+    Elem::new(2991026777451690436),
+    Elem::new(2396585500348748324),
+    Elem::new(1802144223245806212),
+    Elem::new(1207702946142864100),
+    Elem::new(613261669039921988),
+    Elem::new(18820391936979876),
+    Elem::new(4036004110366084101),
+    Elem::new(3441562833263141989),
+    Elem::new(2847121556160199877),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(2313004673017686116),
+    Elem::new(28768701006651790),
+    Elem::new(2370542075030989696),
+    Elem::new(115074804026607160),
+    Elem::new(2485616879057596856),
+    Elem::new(258918309059866110),
+    Elem::new(2658229085097507596),
+    Elem::new(460299216106428640),
+    Elem::new(2888378693150721916),
+    Elem::new(719217525166294750),
+    Elem::new(3176065703217239816),
+    Elem::new(1035673236239464440),
+    Elem::new(3521290115297061296),
+    Elem::new(1409666349325937710),
+    Elem::new(3924051929390186356),
+    Elem::new(1841196864425714560),
+    Elem::new(4384351145496614996),
+    Elem::new(2330264781538794990),
+    Elem::new(290562768084300879),
+    Elem::new(2876870100665179000),
+    Elem::new(865936788217336679),
+    Elem::new(3481012821804866590),
+    Elem::new(1498848210363676059),
+    Elem::new(4142692944957857760),
+    Elem::new(2189297034523319019),
+    Elem::new(250285474592106173),
+    Elem::new(2937283260696265559),
+    Elem::new(1027040401771704503),
+    Elem::new(3742806888882515679),
+    Elem::new(1861332730964606413),
+    Elem::new(4605867919082069379),
+    Elem::new(2753162462170811903),
+    Elem::new(914841355762880322),
+    Elem::new(3702529595390320973),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
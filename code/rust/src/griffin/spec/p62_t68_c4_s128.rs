@@ -0,0 +1,10349 @@
+// Griffin-π definition according to the final specification of the paper (https://eprint.iacr.org/2022/403)
+// Inspired by https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Round constants, alpha and beta sampled from SHAKE128 seeded with "Griffin" || p (p as little-endian
+// u64 limbs), the number of rounds is ceil(1.2 * max(6, 1 + R_GB)) with the Groebner basis bound R_GB
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 68
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// Griffin CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 10;
+pub const STATE_WIDTH: usize = 68;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const D: u32 = 3;
+const INV_D: u64 = 3074416663688030891;
+
+const MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(8),
+    Elem::new(12),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(1),
+    Elem::new(3),
+    Elem::new(5),
+    Elem::new(7),
+    Elem::new(2),
+    Elem::new(6),
+    Elem::new(10),
+    Elem::new(14),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(1),
+    Elem::new(1),
+    Elem::new(4),
+    Elem::new(6),
+    Elem::new(2),
+    Elem::new(2),
+    Elem::new(8),
+    Elem::new(12),
+];
+
+const INV_MAT: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(1345057290363513515),
+    Elem::new(3810995656029954959),
+    Elem::new(4067197044670624200),
+    Elem::new(1633283852584266411),
+    Elem::new(192151041480501930),
+    Elem::new(4387448780471460752),
+    Elem::new(32025173580083655),
+    Elem::new(4515549474791795372),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(2177711803445688548),
+    Elem::new(1889485241224935652),
+    Elem::new(544427950861422137),
+    Elem::new(4067197044670624200),
+    Elem::new(4483524301211711717),
+    Elem::new(160125867900418275),
+    Elem::new(4579599821951962682),
+    Elem::new(32025173580083655),
+];
+
+// the round constants of the last round are zero
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(2071722648418834641),
+    Elem::new(1586901890446016958),
+    Elem::new(3824829552892426876),
+    Elem::new(2543593806275418052),
+    Elem::new(3669151338574908189),
+    Elem::new(2221524466867933697),
+    Elem::new(2830170808532427166),
+    Elem::new(1057859636557703482),
+    Elem::new(3490746798021769456),
+    Elem::new(3647367935163621161),
+    Elem::new(3225544762727867427),
+    Elem::new(286965460539839530),
+    Elem::new(1032390334331988808),
+    Elem::new(245059505437273107),
+    Elem::new(3453874580159071004),
+    Elem::new(3286048023411560860),
+    Elem::new(2007580226569421867),
+    Elem::new(446595467050909644),
+    Elem::new(2436737834894195293),
+    Elem::new(1646722098017337532),
+    Elem::new(3346886426670130835),
+    Elem::new(3308033238962836517),
+    Elem::new(326507468649334382),
+    Elem::new(1808556822867976510),
+    Elem::new(2081746040188032921),
+    Elem::new(2508609541335786286),
+    Elem::new(150227862901750705),
+    Elem::new(1709355918944687876),
+    Elem::new(2335460843462398862),
+    Elem::new(365286094832626939),
+    Elem::new(2763286569323227919),
+    Elem::new(3170023010137160363),
+    Elem::new(1865208414160324961),
+    Elem::new(4090960253986246110),
+    Elem::new(4402908894844351359),
+    Elem::new(752532882426383493),
+    Elem::new(529677355847549900),
+    Elem::new(38182123718055981),
+    Elem::new(1913507900258867587),
+    Elem::new(4304370844128366579),
+    Elem::new(3563362134558423066),
+    Elem::new(2713759220747561868),
+    Elem::new(63974977344228194),
+    Elem::new(1317864373757011939),
+    Elem::new(4379564020505062815),
+    Elem::new(4368250060658144772),
+    Elem::new(1776341791203496164),
+    Elem::new(3594878956966223277),
+    Elem::new(1017066420558047883),
+    Elem::new(2619611047181590837),
+    Elem::new(3949052186930213505),
+    Elem::new(2432849115995716848),
+    Elem::new(1091958553205817774),
+    Elem::new(1689416414759729762),
+    Elem::new(1407614619689994029),
+    Elem::new(2308574993809812385),
+    Elem::new(1362827124356357904),
+    Elem::new(2042045716386579869),
+    Elem::new(487999357076371087),
+    Elem::new(3547210226373456903),
+    Elem::new(2846587894506840971),
+    Elem::new(1733719468370343526),
+    Elem::new(4067076850625626174),
+    Elem::new(4174885223669747132),
+    Elem::new(3923798044411197604),
+    Elem::new(377595123260479167),
+    Elem::new(1446075482775499447),
+    Elem::new(2550392471462323807),
+    Elem::new(2312236609447485627),
+    Elem::new(713939454602308906),
+    Elem::new(2512870603666975086),
+    Elem::new(116716082108764048),
+    Elem::new(3781620822180257350),
+    Elem::new(2169547454436368051),
+    Elem::new(3625359537429622664),
+    Elem::new(2118306690568417539),
+    Elem::new(2610702102045488741),
+    Elem::new(2772813951535163165),
+    Elem::new(2063309020796579275),
+    Elem::new(4359420030140015161),
+    Elem::new(2668095771819519282),
+    Elem::new(3442541907229477330),
+    Elem::new(4266756624406903154),
+    Elem::new(3510906821902857706),
+    Elem::new(287010134101245045),
+    Elem::new(3309539759346423885),
+    Elem::new(945693643835593925),
+    Elem::new(3555091689058518299),
+    Elem::new(2927673049806859978),
+    Elem::new(4098185469324491316),
+    Elem::new(3489814911403181128),
+    Elem::new(220540255267339199),
+    Elem::new(4139340725567028118),
+    Elem::new(1548480980813734027),
+    Elem::new(2298914862449802169),
+    Elem::new(4556792648804349620),
+    Elem::new(2296242064444356285),
+    Elem::new(1126359872898810201),
+    Elem::new(251662903927159679),
+    Elem::new(3935712161597517163),
+    Elem::new(257892127770215538),
+    Elem::new(777209357307230199),
+    Elem::new(93024973145112139),
+    Elem::new(2314358077983414280),
+    Elem::new(3067457186816974111),
+    Elem::new(2207098530178714425),
+    Elem::new(218230594902051893),
+    Elem::new(1724981161651221651),
+    Elem::new(3076134431684260128),
+    Elem::new(3681317841589670609),
+    Elem::new(3486641674535667888),
+    Elem::new(1028685303049295991),
+    Elem::new(230756187494669981),
+    Elem::new(2206159610590983009),
+    Elem::new(4194456620692579209),
+    Elem::new(659201880892351698),
+    Elem::new(800513309210625139),
+    Elem::new(1625156743513251821),
+    Elem::new(3859582217814166462),
+    Elem::new(4152725063426547938),
+    Elem::new(290232538029898036),
+    Elem::new(3481917903246526845),
+    Elem::new(1157746487273089377),
+    Elem::new(441038378903703917),
+    Elem::new(4301692302428850024),
+    Elem::new(4248714236502150862),
+    Elem::new(4167147503287744741),
+    Elem::new(4140962070923997535),
+    Elem::new(4385747682524948589),
+    Elem::new(2811963008352379425),
+    Elem::new(3283305594679243412),
+    Elem::new(4176202313100331422),
+    Elem::new(2614504315496557299),
+    Elem::new(878223322919791208),
+    Elem::new(3972755110070993217),
+    Elem::new(1451551422854460671),
+    Elem::new(3258256461895471534),
+    Elem::new(548851826371882701),
+    Elem::new(4355232949779801050),
+    Elem::new(2691258400079071723),
+    Elem::new(574904660045057698),
+    Elem::new(1245873505240080063),
+    Elem::new(797927202235816008),
+    Elem::new(1329015176717159972),
+    Elem::new(2618599974118303681),
+    Elem::new(1815120555600918199),
+    Elem::new(2631796523862218395),
+    Elem::new(1378672015787515652),
+    Elem::new(3411031317922850312),
+    Elem::new(2802842455134450232),
+    Elem::new(3391156432073430164),
+    Elem::new(310012547571979446),
+    Elem::new(1826630810287261654),
+    Elem::new(4589180914121752483),
+    Elem::new(577337837970743305),
+    Elem::new(3617889720526493804),
+    Elem::new(3121855014556577088),
+    Elem::new(2988713778258905941),
+    Elem::new(3604787874801964561),
+    Elem::new(4359549444242957269),
+    Elem::new(3326975444090281667),
+    Elem::new(1623758211719834100),
+    Elem::new(3503824440509898522),
+    Elem::new(2930836698137454451),
+    Elem::new(3309637648099505179),
+    Elem::new(1808659838358820712),
+    Elem::new(1160986363415375987),
+    Elem::new(1086918237927522137),
+    Elem::new(588889541777868834),
+    Elem::new(158619765232654377),
+    Elem::new(1451372033441334044),
+    Elem::new(75798217445680825),
+    Elem::new(2373993509364673766),
+    Elem::new(1151947910755763089),
+    Elem::new(1550064348079184090),
+    Elem::new(3829676426722559989),
+    Elem::new(496437325126032431),
+    Elem::new(657846258100119610),
+    Elem::new(3415274732760692459),
+    Elem::new(3535569474228919971),
+    Elem::new(2253400916002092321),
+    Elem::new(1642979967635196965),
+    Elem::new(2365548529436761191),
+    Elem::new(481965585807369200),
+    Elem::new(1014188434529536610),
+    Elem::new(2618183851478207374),
+    Elem::new(833390687139556225),
+    Elem::new(2894966827051300912),
+    Elem::new(4037324789648995847),
+    Elem::new(2441896499566937471),
+    Elem::new(953192132645206670),
+    Elem::new(237827687449434762),
+    Elem::new(3212814826684070368),
+    Elem::new(1714347597980433856),
+    Elem::new(2255740266666629666),
+    Elem::new(218157324941089419),
+    Elem::new(4005297969462217696),
+    Elem::new(3570893735755532721),
+    Elem::new(1228184788255496145),
+    Elem::new(3034142764149155426),
+    Elem::new(3387071336849399926),
+    Elem::new(2772292151735852106),
+    Elem::new(2515833101560844840),
+    Elem::new(1423682773181667858),
+    Elem::new(1000507203081169520),
+    Elem::new(1280715070890995452),
+    Elem::new(182238018844394403),
+    Elem::new(1204788963984753476),
+    Elem::new(848410641354156372),
+    Elem::new(917938383632158334),
+    Elem::new(1958713240490387051),
+    Elem::new(3151726119789920696),
+    Elem::new(3778353114092250288),
+    Elem::new(3650040118153489412),
+    Elem::new(4036084595098989038),
+    Elem::new(2485407012291906317),
+    Elem::new(2107306070518020349),
+    Elem::new(3293773686582960203),
+    Elem::new(3878387531294366597),
+    Elem::new(1585231004636126445),
+    Elem::new(377943368526035653),
+    Elem::new(2671256294633669620),
+    Elem::new(3474157787857279906),
+    Elem::new(2206634366159930746),
+    Elem::new(3904034891749310873),
+    Elem::new(2196703743747495368),
+    Elem::new(2109972802922738437),
+    Elem::new(886611592759935071),
+    Elem::new(4319473602182306227),
+    Elem::new(1726506008508963777),
+    Elem::new(2754931078095060132),
+    Elem::new(1296150652313694739),
+    Elem::new(1336692951408360347),
+    Elem::new(3845872881868006544),
+    Elem::new(3153083661201654346),
+    Elem::new(4579669296247082212),
+    Elem::new(2844938222185289970),
+    Elem::new(2624294709798535302),
+    Elem::new(2912795632135892863),
+    Elem::new(864372040761823269),
+    Elem::new(2665674432123628648),
+    Elem::new(2490234299953608502),
+    Elem::new(3712382337424924786),
+    Elem::new(1248531270071733939),
+    Elem::new(985195305289304206),
+    Elem::new(2554625161470238256),
+    Elem::new(37123543495265191),
+    Elem::new(2394541926732106036),
+    Elem::new(3687861712799498856),
+    Elem::new(323836133208596031),
+    Elem::new(2859909075427576084),
+    Elem::new(2848966483854403247),
+    Elem::new(2080569473922350077),
+    Elem::new(2353596498287469158),
+    Elem::new(3549884726363551565),
+    Elem::new(2515123243543701959),
+    Elem::new(968380205472443477),
+    Elem::new(246181868290625694),
+    Elem::new(3976501826122920999),
+    Elem::new(2902639036156893234),
+    Elem::new(1009898844420846510),
+    Elem::new(3315746525316923591),
+    Elem::new(2579604730849528764),
+    Elem::new(1745379669734682730),
+    Elem::new(3859424782248529999),
+    Elem::new(3214507105919559792),
+    Elem::new(1741764039242201418),
+    Elem::new(2350480785610492649),
+    Elem::new(1038344430072945102),
+    Elem::new(1463371911368166166),
+    Elem::new(3778539355543848895),
+    Elem::new(782660044271481738),
+    Elem::new(113535637405104827),
+    Elem::new(3677876889217620191),
+    Elem::new(947842647997252215),
+    Elem::new(361872803873112939),
+    Elem::new(740207209106058419),
+    Elem::new(229870210216199015),
+    Elem::new(1885835819775384134),
+    Elem::new(3063097634844204654),
+    Elem::new(46901677448089731),
+    Elem::new(3848742312022834301),
+    Elem::new(2538534176083502977),
+    Elem::new(1448142115755931250),
+    Elem::new(4224957955026224503),
+    Elem::new(2210458504956915752),
+    Elem::new(83615684077199810),
+    Elem::new(2293979488297385770),
+    Elem::new(3217116539612809075),
+    Elem::new(445382291873965255),
+    Elem::new(1497481951780856845),
+    Elem::new(2391570298619593948),
+    Elem::new(1935306642059137038),
+    Elem::new(1585384650452981032),
+    Elem::new(2957406424328579670),
+    Elem::new(210496067784378228),
+    Elem::new(2455214752419398427),
+    Elem::new(4531644796204950989),
+    Elem::new(2825000100634470586),
+    Elem::new(3161792173691147444),
+    Elem::new(3738199824083268371),
+    Elem::new(1247983699013432341),
+    Elem::new(1519099780344010514),
+    Elem::new(3795167737978428843),
+    Elem::new(4583321017559080212),
+    Elem::new(1412476314138689200),
+    Elem::new(3520052766839092933),
+    Elem::new(4305277640856085989),
+    Elem::new(254315397621118225),
+    Elem::new(4537917551790501785),
+    Elem::new(997773209764804235),
+    Elem::new(3062269141919172049),
+    Elem::new(1621778495499643848),
+    Elem::new(3694586410153120660),
+    Elem::new(4007489586118490414),
+    Elem::new(654411855262984760),
+    Elem::new(3535651263121604187),
+    Elem::new(44356512686575568),
+    Elem::new(553948804687102093),
+    Elem::new(2417074434734323487),
+    Elem::new(2049264741994872950),
+    Elem::new(2301344890574103580),
+    Elem::new(1630141487755219339),
+    Elem::new(3399971577300605154),
+    Elem::new(4017183718429104225),
+    Elem::new(2313004673017686116),
+    Elem::new(1479071767106228145),
+    Elem::new(1718441686512905092),
+    Elem::new(3485569346305215085),
+    Elem::new(3975036421958300877),
+    Elem::new(1405625966126747899),
+    Elem::new(1710903227430866585),
+    Elem::new(1075162341519539852),
+    Elem::new(42322469959015092),
+    Elem::new(1959794444613742216),
+    Elem::new(390643530553414326),
+    Elem::new(3552772851525042855),
+    Elem::new(961823527155203989),
+    Elem::new(647765052822754114),
+    Elem::new(2234307218653227268),
+    Elem::new(3768660668436418314),
+    Elem::new(2594484636844314563),
+    Elem::new(4125336681535986239),
+    Elem::new(350458109014522599),
+    Elem::new(1669341156183802412),
+    Elem::new(3980122680655638642),
+    Elem::new(3448031224556167496),
+    Elem::new(420431357753783763),
+    Elem::new(4476574688590136142),
+    Elem::new(1271214229465241700),
+    Elem::new(1650749477244353783),
+    Elem::new(3898064201458303492),
+    Elem::new(2280784372038969897),
+    Elem::new(2505214803824023091),
+    Elem::new(3760416500982921968),
+    Elem::new(496993063152914555),
+    Elem::new(236897866179092691),
+    Elem::new(2930810382200963318),
+    Elem::new(2180140021389532187),
+    Elem::new(4016763114790323314),
+    Elem::new(3063308995485625235),
+    Elem::new(11856454881243090),
+    Elem::new(1513562202458072494),
+    Elem::new(3228816563796052859),
+    Elem::new(674651772989854500),
+    Elem::new(2946042994804835077),
+    Elem::new(563451242689407877),
+    Elem::new(2911548322559573670),
+    Elem::new(2613184487874250163),
+    Elem::new(4220067674494934985),
+    Elem::new(3986334745775882287),
+    Elem::new(3515279486937238422),
+    Elem::new(1962186752597509151),
+    Elem::new(1128617718231974558),
+    Elem::new(3153043942271436985),
+    Elem::new(3400552965245933838),
+    Elem::new(1591305599177573283),
+    Elem::new(3930496450375281979),
+    Elem::new(1188018159257912446),
+    Elem::new(90679664674086668),
+    Elem::new(2366987822040777864),
+    Elem::new(276599440394499385),
+    Elem::new(2735248262172810680),
+    Elem::new(2292767770018984426),
+    Elem::new(2896764157134959897),
+    Elem::new(1498878134389728990),
+    Elem::new(1227037488538197619),
+    Elem::new(4455076660766011785),
+    Elem::new(3167735171751535962),
+    Elem::new(1437109757736901298),
+    Elem::new(2595616730993444142),
+    Elem::new(3530150244893788494),
+    Elem::new(2561172485699213701),
+    Elem::new(1119786195282176104),
+    Elem::new(672197402188784987),
+    Elem::new(2944576333839760019),
+    Elem::new(3658595757957101864),
+    Elem::new(3426751995534738450),
+    Elem::new(4106234694301478797),
+    Elem::new(4054747522769783021),
+    Elem::new(767057935990885023),
+    Elem::new(2849084315842128421),
+    Elem::new(361924507842646277),
+    Elem::new(890765405154402406),
+    Elem::new(369779938360137761),
+    Elem::new(1738647156396201656),
+    Elem::new(1417355960539957969),
+    Elem::new(4591374006413347460),
+    Elem::new(391300245839867132),
+    Elem::new(1419852744205061491),
+    Elem::new(537449992340537396),
+    Elem::new(2058297932394092902),
+    Elem::new(4106719784701188908),
+    Elem::new(2405972805009563959),
+    Elem::new(4557912518674729112),
+    Elem::new(831495439541487794),
+    Elem::new(2565461905000350169),
+    Elem::new(3147897928295541130),
+    Elem::new(653102344512831685),
+    Elem::new(3907255065888493419),
+    Elem::new(1183924169329148846),
+    Elem::new(1030477060368204440),
+    Elem::new(1057815692517849741),
+    Elem::new(3737034277114315917),
+    Elem::new(3375340681027882791),
+    Elem::new(2871970985498755871),
+    Elem::new(4059428922529477058),
+    Elem::new(3568173825467775047),
+    Elem::new(1424621681474546556),
+    Elem::new(757922487495780556),
+    Elem::new(1250628443622113699),
+    Elem::new(767807390756761545),
+    Elem::new(547160278720209665),
+    Elem::new(1114979681254198500),
+    Elem::new(4456102906255547102),
+    Elem::new(4060237960665777528),
+    Elem::new(575756677646460065),
+    Elem::new(158369570002316872),
+    Elem::new(1074387851832141949),
+    Elem::new(59675738694039472),
+    Elem::new(1697569514966658382),
+    Elem::new(2779409596672166236),
+    Elem::new(1245695627084647858),
+    Elem::new(4315017533346080962),
+    Elem::new(3211757295304341849),
+    Elem::new(3297047555719807685),
+    Elem::new(3645525161383490891),
+    Elem::new(3027353024681841640),
+    Elem::new(895215218381417111),
+    Elem::new(1306982548319028408),
+    Elem::new(2367942467366007981),
+    Elem::new(1074694748366856273),
+    Elem::new(325462378270780531),
+    Elem::new(4589548957378311549),
+    Elem::new(1170347267529032323),
+    Elem::new(296981692274299594),
+    Elem::new(2995930248060130012),
+    Elem::new(1888499235218057697),
+    Elem::new(1271707654888072997),
+    Elem::new(2190411002769129832),
+    Elem::new(2450728464799163470),
+    Elem::new(850385868229511038),
+    Elem::new(890613890639036687),
+    Elem::new(813728928115381683),
+    Elem::new(1304434120682889009),
+    Elem::new(637426259920611943),
+    Elem::new(2843884742400662189),
+    Elem::new(1027914765467394171),
+    Elem::new(4252014252183225928),
+    Elem::new(4314112405238554272),
+    Elem::new(2349038172243638375),
+    Elem::new(1235044359048421715),
+    Elem::new(3663082694280961947),
+    Elem::new(4537013338062767127),
+    Elem::new(1903893990310378639),
+    Elem::new(3154463371628316914),
+    Elem::new(532985044356369534),
+    Elem::new(3160689164604060952),
+    Elem::new(1646937013116118027),
+    Elem::new(1423817706449906739),
+    Elem::new(906248020332290703),
+    Elem::new(3999410921600781147),
+    Elem::new(977950778889301765),
+    Elem::new(2602928934452624338),
+    Elem::new(940408449753713695),
+    Elem::new(2133122595851868562),
+    Elem::new(2464638639498384267),
+    Elem::new(1263754762097106155),
+    Elem::new(2473666953611007770),
+    Elem::new(1414681732831541497),
+    Elem::new(1905371724315157130),
+    Elem::new(1015437918476746753),
+    Elem::new(491310879990612715),
+    Elem::new(3846953058918013088),
+    Elem::new(2614189360004774299),
+    Elem::new(1688865652754855770),
+    Elem::new(3496224451624610155),
+    Elem::new(2900207978031386579),
+    Elem::new(2771958281926475635),
+    Elem::new(493707967954215574),
+    Elem::new(1289145211626926603),
+    Elem::new(1885219990689056190),
+    Elem::new(3700627086234330930),
+    Elem::new(3133138833044249591),
+    Elem::new(4063164685182166767),
+    Elem::new(2652239714675551766),
+    Elem::new(1560442488621592745),
+    Elem::new(346441830570411777),
+    Elem::new(2029689005346437521),
+    Elem::new(840716852283565770),
+    Elem::new(4185966300982140014),
+    Elem::new(3306307337609657065),
+    Elem::new(3324951480735957384),
+    Elem::new(1949618733541334441),
+    Elem::new(1048323546496004498),
+    Elem::new(1091002769184478671),
+    Elem::new(1606780391655355130),
+    Elem::new(1847475828328211740),
+    Elem::new(3350538289785775151),
+    Elem::new(2178791927953951647),
+    Elem::new(600325142100260331),
+    Elem::new(2864354018574052946),
+    Elem::new(346150330650399431),
+    Elem::new(2107502791441984971),
+    Elem::new(1626188344645322340),
+    Elem::new(3524050338498069239),
+    Elem::new(4303753634650723483),
+    Elem::new(3563906271108969934),
+    Elem::new(2994810468998562772),
+    Elem::new(1680485351041034279),
+    Elem::new(1722015703846455582),
+    Elem::new(521293996776911768),
+    Elem::new(2536745191145063153),
+    Elem::new(2285731460087229130),
+    Elem::new(1219456580652559642),
+    Elem::new(2158735031394342178),
+    Elem::new(2962384539215290849),
+    Elem::new(1593233755544446791),
+    Elem::new(2473301266223459489),
+    Elem::new(901648632618412036),
+    Elem::new(4089994908887822240),
+    Elem::new(2037792855257690532),
+    Elem::new(1715487841017740657),
+    Elem::new(2555661928957209175),
+    Elem::new(1682252492579118126),
+    Elem::new(2603035822463890963),
+    Elem::new(2030821145286933212),
+    Elem::new(4065718687102772522),
+    Elem::new(2751963331097272204),
+    Elem::new(2226644655418519724),
+    Elem::new(4351072475397913505),
+    Elem::new(2459148971951383317),
+    Elem::new(4246212478538598632),
+    Elem::new(4412293262327230319),
+    Elem::new(3651448873899572677),
+    Elem::new(3376871216735673728),
+    Elem::new(3560115385471745722),
+    Elem::new(2380028617429374172),
+    Elem::new(1902675648992544658),
+    Elem::new(1995481239116528580),
+    Elem::new(852483484587753265),
+    Elem::new(3440007717087939477),
+    Elem::new(2182687091786445271),
+    Elem::new(1825096425196236246),
+    Elem::new(2174218101192817589),
+    Elem::new(3331675857517084618),
+    Elem::new(1371578568060498687),
+    Elem::new(654300271635023259),
+    Elem::new(3289546890503039565),
+    Elem::new(3107159652909342947),
+    Elem::new(2876019635451125878),
+    Elem::new(4122095193926600538),
+    Elem::new(40272059776950274),
+    Elem::new(3471070221426320615),
+    Elem::new(2596216924356958512),
+    Elem::new(701702907779649378),
+    Elem::new(3540459818572796671),
+    Elem::new(2130426244585222241),
+    Elem::new(2929438721304661002),
+    Elem::new(3207109265777362785),
+    Elem::new(3798779739655053723),
+    Elem::new(325732748447884072),
+    Elem::new(1005436820414548334),
+    Elem::new(3750593389433708884),
+    Elem::new(4286147319390591248),
+    Elem::new(1130727106670593148),
+    Elem::new(1048571888180008081),
+    Elem::new(1543018526576862437),
+    Elem::new(1268424401599081383),
+    Elem::new(2686041032764045968),
+    Elem::new(3060846157566981327),
+    Elem::new(2256929785932353543),
+    Elem::new(3573183945784766753),
+    Elem::new(677032671285423163),
+    Elem::new(3357434255478636360),
+    Elem::new(945004993970432606),
+    Elem::new(1883543763873038890),
+    Elem::new(1472865171881600148),
+    Elem::new(4255172957865226619),
+    Elem::new(968605841257006339),
+    Elem::new(2267484714470389468),
+    Elem::new(2057252718762488047),
+    Elem::new(4155966598891758703),
+    Elem::new(689278509264776235),
+    Elem::new(2214112528000079385),
+    Elem::new(3629893945628868850),
+    Elem::new(3774376801104492135),
+    Elem::new(3366187683847042660),
+    Elem::new(1480152638618887764),
+    Elem::new(151006638947005272),
+    Elem::new(2519023861077316657),
+    Elem::new(680142229861407245),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(0),
+];
+
+// ALPHAS[i] = (i - 1) * alpha and BETAS[i] = (i - 1)^2 * beta for i >= 2
+const ALPHAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(704586484256979134),
+    Elem::new(1409172968513958268),
+    Elem::new(2113759452770937402),
+    Elem::new(2818345937027916536),
+    Elem::new(3522932421284895670),
+    Elem::new(4227518905541874804),
+    Elem::new(320480394266807601),
+    Elem::new(1025066878523786735),
+    Elem::new(1729653362780765869),
+    Elem::new(2434239847037745003),
+    Elem::new(3138826331294724137),
+    Elem::new(3843412815551703271),
+    Elem::new(4547999299808682405),
+    Elem::new(640960788533615202),
+    Elem::new(1345547272790594336),
+    Elem::new(2050133757047573470),
+    Elem::new(2754720241304552604),
+    Elem::new(3459306725561531738),
+    Elem::new(4163893209818510872),
+    Elem::new(256854698543443669),
+    Elem::new(961441182800422803),
+    Elem::new(1666027667057401937),
+    Elem::new(2370614151314381071),
+    Elem::new(3075200635571360205),
+    Elem::new(3779787119828339339),
+    Elem::new(4484373604085318473),
+    Elem::new(577335092810251270),
+    Elem::new(1281921577067230404),
+    Elem::new(1986508061324209538),
+    Elem::new(2691094545581188672),
+    Elem::new(3395681029838167806),
+    Elem::new(4100267514095146940),
+    Elem::new(193229002820079737),
+    Elem::new(897815487077058871),
+    Elem::new(1602401971334038005),
+    Elem::new(2306988455591017139),
+    Elem::new(3011574939847996273),
+    Elem::new(3716161424104975407),
+    Elem::new(4420747908361954541),
+    Elem::new(513709397086887338),
+    Elem::new(1218295881343866472),
+    Elem::new(1922882365600845606),
+    Elem::new(2627468849857824740),
+    Elem::new(3332055334114803874),
+    Elem::new(4036641818371783008),
+    Elem::new(129603307096715805),
+    Elem::new(834189791353694939),
+    Elem::new(1538776275610674073),
+    Elem::new(2243362759867653207),
+    Elem::new(2947949244124632341),
+    Elem::new(3652535728381611475),
+    Elem::new(4357122212638590609),
+    Elem::new(450083701363523406),
+    Elem::new(1154670185620502540),
+    Elem::new(1859256669877481674),
+    Elem::new(2563843154134460808),
+    Elem::new(3268429638391439942),
+    Elem::new(3973016122648419076),
+    Elem::new(65977611373351873),
+    Elem::new(770564095630331007),
+    Elem::new(1475150579887310141),
+    Elem::new(2179737064144289275),
+    Elem::new(2884323548401268409),
+    Elem::new(3588910032658247543),
+    Elem::new(4293496516915226677),
+    Elem::new(386458005640159474),
+];
+
+const BETAS: [Elem; STATE_WIDTH] = [
+    Elem::new(0),
+    Elem::new(0),
+    Elem::new(3221552631292751389),
+    Elem::new(3662960534106912882),
+    Elem::new(1324223708442484479),
+    Elem::new(816967149831512517),
+    Elem::new(2141190858273996996),
+    Elem::new(685269838237891579),
+    Elem::new(1060829085255242603),
+    Elem::new(3267868599326050068),
+    Elem::new(2694763384918267637),
+    Elem::new(3953138437563941647),
+    Elem::new(2431368761731025761),
+    Elem::new(2741079352951566316),
+    Elem::new(270645215693516975),
+    Elem::new(4243316341020970412),
+    Elem::new(824217742337787616),
+    Elem::new(3848224406240107598),
+    Elem::new(4092086341663837684),
+    Elem::new(1555803548608977874),
+    Elem::new(851001022607574505),
+    Elem::new(1977678763659627577),
+    Elem::new(324211776233090753),
+    Elem::new(502225055860010370),
+    Elem::new(2511718602540386428),
+    Elem::new(1741067420742172590),
+    Elem::new(2801896505997415193),
+    Elem::new(1082580862774067900),
+    Elem::new(1194745486604177048),
+    Elem::new(3138390377487742637),
+    Elem::new(2301890539892718330),
+    Elem::new(3296870969351150464),
+    Elem::new(1511706670330992702),
+    Elem::new(1558022638364291381),
+    Elem::new(3435818873451046501),
+    Elem::new(2533470380059211725),
+    Elem::new(3462602153720833390),
+    Elem::new(1611589198903865159),
+    Elem::new(1592056511140353369),
+    Elem::new(3404004090430298020),
+    Elem::new(2435806941241652775),
+    Elem::new(3299090059106463971),
+    Elem::new(1382228448492685271),
+    Elem::new(1296847104932363012),
+    Elem::new(3042946028425497194),
+    Elem::new(2008900223440041480),
+    Elem::new(2806334685508042207),
+    Elem::new(823624419097453038),
+    Elem::new(672394419740320310),
+    Elem::new(2352644687436644023),
+    Elem::new(1252750226654377840),
+    Elem::new(1984336032925568098),
+    Elem::new(4547402106250214797),
+    Elem::new(4330323451096271600),
+    Elem::new(1333100067463738507),
+    Elem::new(167356950884661855),
+    Elem::new(833094101359041644),
+    Elem::new(3330311518886877874),
+    Elem::new(3047384207936124208),
+    Elem::new(4595937164038826983),
+    Elem::new(3364345391662939862),
+    Elem::new(3964233886340509182),
+    Elem::new(1783977652539488606),
+    Elem::new(1435201685791924471),
+    Elem::new(2917905986097816777),
+    Elem::new(1620465557925119187),
+    Elem::new(2154505396805878038),
+    Elem::new(4520025502740093330),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    griffin_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    griffin_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Griffin
+// ================================================================================================
+
+pub fn griffin_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        griffin_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn griffin_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use (the ones of the last round are zero)
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // the first round multiplies the state additionally with the matrix before getting started
+    if tmp_round == 0 {
+        matrix_mul(MAT, state);
+    }
+
+    // now one round
+    // non linear layer
+    state[0] = state[0].exp(INV_D);
+    state[1] = state[1].exp(D.into());
+    let mut x_prev = Elem::ZERO; // x_{i-1} enters L_i only for i > 2
+    for i in 2..STATE_WIDTH {
+        let l = l_i(i, state[0], state[1], x_prev);
+        x_prev = state[i];
+        state[i] = state[i] * (l * l + ALPHAS[i] * l + BETAS[i]);
+    }
+    // matrix multiplication
+    matrix_mul(MAT, state);
+    // adding round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+}
+
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round_plus_absorb<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        left[i] += pixels[i];
+    }
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for the first round of Griffin-π
+/// performs M S M c
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the left side: multiply by MAT
+    let mut left = [E::ZERO; STATE_WIDTH];
+    left.copy_from_slice(current_slice);
+    matrix_mul(MAT, &mut left);
+
+    // now we can enforce one round
+    enforce_round(result_slice, &left, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for one other round of Griffin-π
+/// performs S M c
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // approach from the right side: subtract constants, then multiply by INV_MAT
+    let mut right = [E::ZERO; STATE_WIDTH];
+    right.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        right[i] -= round_constants[i];
+    }
+    matrix_mul(INV_MAT, &mut right);
+
+    // now we can enforce the nonlinear layer
+    enforce_non_linear(result_slice, current_slice, &right, flag);
+}
+
+/// the constraints have degree D, as the inverse power map is enforced on its output y[0]
+fn enforce_non_linear<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    x: &[E],
+    y: &[E],
+    flag: E,
+) {
+    result_slice[0] += flag * (y[0].exp(D.into()) - x[0]);
+    result_slice[1] += flag * (y[1] - x[1].exp(D.into()));
+    for i in 2..STATE_WIDTH {
+        let x_prev = if i == 2 { E::ZERO } else { x[i - 1] };
+        let l = l_i(i, y[0], y[1], x_prev);
+        result_slice[i] += flag * (x[i] * (l * l + E::from(ALPHAS[i]) * l + E::from(BETAS[i])) - y[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..STATE_WIDTH {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// linear form L_i(y_0, y_1, x_{i-1}) = (i - 1) * y_0 + y_1 + x_{i-1}
+fn l_i<E: FieldElement>(i: usize, y0: E, y1: E, x_prev: E) -> E {
+    E::from((i - 1) as u32) * y0 + y1 + x_prev
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round_plus_absorb(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the non-linear layer has degree D and degree 3 for the elements i >= 2
+        TransitionConstraintDegree::with_cycles(usize::max(D as usize, 3), cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,280 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Loading of real thermal videos. A video is a list of frames of `frame_size` pixels each, which
+// gets flattened into one pixel vector (same layout as the randomly generated inputs).
+
+// ================================================================================================
+// INPUT FORMATS AND ERRORS
+// ================================================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Plain little-endian u16 dump of all frames
+    Raw,
+    /// Binary PGM (P5), either one file with concatenated images or a directory of .pgm files
+    Pgm,
+    /// NumPy .npy array with dtype uint16
+    Npy,
+}
+
+impl InputFormat {
+    pub fn from_path(path: &Path) -> InputFormat {
+        if path.is_dir() {
+            return InputFormat::Pgm;
+        }
+        match path.extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_ascii_lowercase()) {
+            Some(ext) if ext == "pgm" => InputFormat::Pgm,
+            Some(ext) if ext == "npy" => InputFormat::Npy,
+            _ => InputFormat::Raw,
+        }
+    }
+}
+
+impl FromStr for InputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "raw" => Ok(InputFormat::Raw),
+            "pgm" => Ok(InputFormat::Pgm),
+            "npy" => Ok(InputFormat::Npy),
+            _ => Err(format!("unknown input format '{}', expected one of: raw, pgm, npy", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    /// The file content does not follow the expected format
+    Malformed(String),
+    /// The pixels of the input cannot be split into frames of the expected size
+    FrameSize { expected: usize, actual: usize },
+    /// The number of pixels is not a multiple of the frame size
+    IncompleteFrame { frame_size: usize, num_pixels: usize },
+    /// The input does not contain a single pixel
+    Empty,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "i/o error: {}", err),
+            InputError::Malformed(msg) => write!(f, "malformed input: {}", msg),
+            InputError::FrameSize { expected, actual } => write!(f,
+                "frame size mismatch: expected frames of {} pixels, but got {} pixels", expected, actual),
+            InputError::IncompleteFrame { frame_size, num_pixels } => write!(f,
+                "input of {} pixels does not consist of whole frames of {} pixels", num_pixels, frame_size),
+            InputError::Empty => write!(f, "input does not contain any pixels"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+// ================================================================================================
+// LOADING
+// ================================================================================================
+
+/// Loads a video from `path`. The format is derived from the path if none is given.
+pub fn load_video(path: &Path, format: Option<InputFormat>, frame_size: usize) -> Result<Vec<u16>, InputError> {
+    let format = format.unwrap_or_else(|| InputFormat::from_path(path));
+    match format {
+        InputFormat::Raw => read_raw(&fs::read(path)?, frame_size),
+        InputFormat::Npy => read_npy(&fs::read(path)?, frame_size),
+        InputFormat::Pgm => {
+            if !path.is_dir() {
+                return read_pgm(&fs::read(path)?, frame_size);
+            }
+            let mut files: Vec<PathBuf> = fs::read_dir(path)?
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<Result<Vec<PathBuf>, io::Error>>()?;
+            files.retain(|file| file.is_file() && InputFormat::from_path(file) == InputFormat::Pgm);
+            files.sort();
+            let mut result = vec![];
+            for file in files.iter() {
+                result.extend(read_pgm(&fs::read(file)?, frame_size)?);
+            }
+            check_frames(result, frame_size)
+        }
+    }
+}
+
+/// Little-endian u16 dump of all frames without any header.
+pub fn read_raw(bytes: &[u8], frame_size: usize) -> Result<Vec<u16>, InputError> {
+    if bytes.len() % 2 != 0 {
+        return Err(InputError::Malformed(format!("raw input has an odd number of bytes ({})", bytes.len())));
+    }
+    let pixels = bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect();
+    check_frames(pixels, frame_size)
+}
+
+/// Sequence of binary PGM (P5) images. 8-bit images are widened, 16-bit images are big-endian.
+pub fn read_pgm(bytes: &[u8], frame_size: usize) -> Result<Vec<u16>, InputError> {
+    let mut result = vec![];
+    let mut pos = 0;
+    while skip_whitespace_and_comments(bytes, pos) < bytes.len() {
+        pos = skip_whitespace_and_comments(bytes, pos);
+        if bytes.len() < pos + 2 || &bytes[pos..pos + 2] != b"P5" {
+            return Err(InputError::Malformed(format!("expected PGM magic number P5 at byte {}", pos)));
+        }
+        pos += 2;
+        let (width, next) = read_pgm_number(bytes, pos)?;
+        let (height, next) = read_pgm_number(bytes, next)?;
+        let (maxval, next) = read_pgm_number(bytes, next)?;
+        if maxval == 0 || maxval > u16::MAX as usize {
+            return Err(InputError::Malformed(format!("invalid PGM maxval {}", maxval)));
+        }
+        let num_pixels = width.checked_mul(height)
+            .ok_or_else(|| InputError::Malformed(format!("PGM frame of {}x{} pixels is too large", width, height)))?;
+        if num_pixels != frame_size {
+            return Err(InputError::FrameSize { expected: frame_size, actual: num_pixels });
+        }
+        // exactly one whitespace character separates the header from the raster
+        pos = next + 1;
+        let bytes_per_pixel = if maxval > u8::MAX as usize { 2 } else { 1 };
+        let raster_len = num_pixels.checked_mul(bytes_per_pixel)
+            .ok_or_else(|| InputError::Malformed(format!("PGM frame of {}x{} pixels is too large", width, height)))?;
+        let raster_end = pos.checked_add(raster_len).filter(|end| *end <= bytes.len())
+            .ok_or_else(|| InputError::Malformed(format!("PGM raster is truncated: expected {} bytes, got {}",
+                                                         raster_len, bytes.len().saturating_sub(pos))))?;
+        let raster = &bytes[pos..raster_end];
+        if bytes_per_pixel == 2 {
+            result.extend(raster.chunks_exact(2).map(|b| u16::from_be_bytes([b[0], b[1]])));
+        } else {
+            result.extend(raster.iter().map(|b| *b as u16));
+        }
+        pos += raster_len;
+    }
+    check_frames(result, frame_size)
+}
+
+/// NumPy .npy file (format version 1, 2 or 3) holding a C-ordered uint16 array.
+pub fn read_npy(bytes: &[u8], frame_size: usize) -> Result<Vec<u16>, InputError> {
+    if bytes.len() < 10 || &bytes[0..6] != b"\x93NUMPY" {
+        return Err(InputError::Malformed(String::from("missing NumPy magic string")));
+    }
+    let (header_len, header_start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 => {
+            if bytes.len() < 12 {
+                return Err(InputError::Malformed(String::from("truncated NumPy header")));
+            }
+            (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12)
+        }
+        version => return Err(InputError::Malformed(format!("unsupported NumPy format version {}", version))),
+    };
+    if bytes.len() < header_start + header_len {
+        return Err(InputError::Malformed(String::from("truncated NumPy header")));
+    }
+    let header = String::from_utf8_lossy(&bytes[header_start..header_start + header_len]);
+
+    let descr = get_npy_header_value(&header, "descr")?;
+    let little_endian = match descr {
+        "<u2" | "=u2" => true,
+        ">u2" => false,
+        other => return Err(InputError::Malformed(format!("unsupported NumPy dtype {}, expected uint16", other))),
+    };
+    if get_npy_header_value(&header, "fortran_order")? != "False" {
+        return Err(InputError::Malformed(String::from("Fortran ordered NumPy arrays are not supported")));
+    }
+    let shape = get_npy_header_value(&header, "shape")?;
+    let dims = shape.trim_start_matches('(').trim_end_matches(')').split(',').map(|d| d.trim()).filter(|d| !d.is_empty())
+        .map(|d| d.parse::<usize>().map_err(|_| InputError::Malformed(format!("invalid NumPy dimension '{}'", d))))
+        .collect::<Result<Vec<usize>, InputError>>()?;
+    let num_bytes = dims.iter().try_fold(2usize, |acc, d| acc.checked_mul(*d))
+        .ok_or_else(|| InputError::Malformed(format!("NumPy shape {} is too large", shape)))?;
+    // arrays of shape (frames, height, width) must have frames of the configured resolution
+    if dims.len() >= 2 {
+        // cannot overflow, as the product of all dimensions fits
+        let frame = dims[dims.len() - 2] * dims[dims.len() - 1];
+        if frame != frame_size {
+            return Err(InputError::FrameSize { expected: frame_size, actual: frame });
+        }
+    }
+
+    let data = &bytes[header_start + header_len..];
+    if data.len() != num_bytes {
+        return Err(InputError::Malformed(format!("NumPy data has {} bytes, but the shape requires {}", data.len(), num_bytes)));
+    }
+    let pixels = data.chunks_exact(2).map(|b| {
+        if little_endian { u16::from_le_bytes([b[0], b[1]]) } else { u16::from_be_bytes([b[0], b[1]]) }
+    }).collect();
+    check_frames(pixels, frame_size)
+}
+
+// ================================================================================================
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_frames(pixels: Vec<u16>, frame_size: usize) -> Result<Vec<u16>, InputError> {
+    if pixels.is_empty() {
+        return Err(InputError::Empty);
+    }
+    if pixels.len() % frame_size != 0 {
+        return Err(InputError::IncompleteFrame { frame_size, num_pixels: pixels.len() });
+    }
+    Ok(pixels)
+}
+
+fn skip_whitespace_and_comments(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() {
+        if bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        } else if bytes[pos] == b'#' {
+            while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+        } else {
+            break;
+        }
+    }
+    pos
+}
+
+fn read_pgm_number(bytes: &[u8], pos: usize) -> Result<(usize, usize), InputError> {
+    let begin = skip_whitespace_and_comments(bytes, pos);
+    let mut end = begin;
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+    if begin == end {
+        return Err(InputError::Malformed(format!("expected a number in the PGM header at byte {}", begin)));
+    }
+    let number = std::str::from_utf8(&bytes[begin..end]).unwrap().parse::<usize>()
+        .map_err(|_| InputError::Malformed(format!("number in the PGM header at byte {} is too large", begin)))?;
+    Ok((number, end))
+}
+
+fn get_npy_header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, InputError> {
+    let quoted = [format!("'{}'", key), format!("\"{}\"", key)];
+    let pos = quoted.iter().filter_map(|k| header.find(k.as_str()).map(|p| p + k.len())).next()
+        .ok_or_else(|| InputError::Malformed(format!("NumPy header has no entry '{}'", key)))?;
+    let rest = header[pos..].trim_start();
+    let rest = rest.strip_prefix(':').ok_or_else(|| InputError::Malformed(format!("invalid NumPy header entry '{}'", key)))?;
+    let rest = rest.trim_start();
+    // the value is either a quoted string, a tuple or a literal ending with ',' or '}'
+    let end = match rest.chars().next() {
+        Some(quote) if quote == '\'' || quote == '"' => rest[1..].find(quote).map(|e| e + 2),
+        Some('(') => rest.find(')').map(|e| e + 1),
+        _ => rest.find(|c| c == ',' || c == '}'),
+    }.ok_or_else(|| InputError::Malformed(format!("invalid NumPy header entry '{}'", key)))?;
+    Ok(rest[..end].trim().trim_matches(|c| c == '\'' || c == '"'))
+}
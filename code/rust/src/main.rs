@@ -1,28 +1,31 @@
 #[macro_use]
 extern crate lazy_static;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::collections::HashMap;
 use log::{info, trace};
 use env_logger;
 
 use std::time::Instant;
+use std::path::{Path, PathBuf};
 
+mod bundle;
+mod commitment;
+mod input;
 mod utils;
 mod stark;
 mod rescue;
 mod griffin;
+mod poseidon;
+mod permutation;
 
-use winterfell::math::{FieldElement};
-use winterfell::{FieldExtension, Prover, Trace};
-
-#[cfg(feature = "master_thesis_full")]
-const COMPILE_VARIANT: &str = "master_thesis_full";
-#[cfg(feature = "master_thesis_half")]
-const COMPILE_VARIANT: &str = "master_thesis_half";
-#[cfg(feature = "master_thesis_quarter")]
-const COMPILE_VARIANT: &str = "master_thesis_quarter";
-#[cfg(feature = "master_thesis_test")]
-const COMPILE_VARIANT: &str = "master_thesis_test";
+use winterfell::math::{FieldElement, StarkField};
+use winterfell::{Deserializable, Serializable};
+use winter_rand_utils::rand_value;
+use winterfell::{Air, FieldExtension, ProofOptions, Prover, StarkProof, Trace};
+use bundle::{BundleError, ProofBundle, SegmentEntry, SegmentManifest};
+use commitment::{CommitmentError, Opening, TimeSeries, VideoCommitment};
+use permutation::{HashPermutation, StarkBaseField};
+use stark::family::stark_g::{FrameConfig, Roi};
 
 
 /// STARKs Benchmark
@@ -30,9 +33,12 @@ const COMPILE_VARIANT: &str = "master_thesis_test";
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 pub struct Args {
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
     /// STARK variant to benchmark
     #[clap(short, long, validator=stark_choice_validator)]
-    pub stark: String,
+    pub stark: Option<String>,
 
     /// Length of the input pixel vector
     #[clap(short, long, default_value_t = 110016)]
@@ -45,6 +51,128 @@ pub struct Args {
     /// Input random number generation: upper bound (exclusive)
     #[clap(short, long, default_value_t = 20000)]
     pub end: u16,
+
+    /// Video to use as input instead of random numbers (raw little-endian u16, binary PGM or .npy)
+    #[clap(short, long, parse(from_os_str))]
+    pub input: Option<PathBuf>,
+
+    /// Format of the input video (raw, pgm or npy), derived from the file extension if omitted
+    #[clap(long)]
+    pub format: Option<input::InputFormat>,
+
+    /// Width of a video frame in pixels
+    #[clap(long, default_value_t = stark::family::stark_g::FRAME_WIDTH)]
+    pub frame_width: usize,
+
+    /// Height of a video frame in pixels
+    #[clap(long, default_value_t = stark::family::stark_g::FRAME_HEIGHT)]
+    pub frame_height: usize,
+
+    /// Region of interest of STARK G as x,y,width,height (repeatable, the first four pixels of a frame if omitted)
+    #[clap(long)]
+    pub roi: Vec<Roi>,
+
+    /// Bit depth of the pixels, defines the lookup table of STARK D, E and E-opt
+    #[clap(long, default_value_t = utils::MAX_BIT_DEPTH)]
+    pub bit_depth: usize,
+
+    /// Public threshold of stark_threshold, the proof shows whether a pixel inside the ROIs exceeds it
+    #[clap(long, default_value_t = 0)]
+    pub threshold: u16,
+
+    /// Publish the number of pixels inside the ROIs above the threshold with stark_threshold
+    #[clap(long)]
+    pub count: bool,
+
+    /// Bin edge of the histogram of stark_histogram (repeatable, ascending), bin k holds the pixels in [edge k - 1, edge k)
+    #[clap(long)]
+    pub bin_edge: Vec<u16>,
+
+    /// Rank k of an order statistic (the k-th smallest pixel) published by the STARK E scenarios (repeatable)
+    #[clap(long)]
+    pub rank: Vec<usize>,
+
+    /// Percentile in [0, 100] published by the STARK E scenarios as the order statistic of its nearest rank (repeatable)
+    #[clap(long)]
+    pub percentile: Vec<f64>,
+
+    /// First frame of the range of frames proven by stark_f_frames and stark_f_series
+    #[clap(long, default_value_t = 0)]
+    pub first_frame: usize,
+
+    /// Number of frames proven by stark_f_frames and stark_f_series
+    #[clap(long, default_value_t = 1)]
+    pub num_frames: usize,
+
+    /// Number of frames proven per segment by stark_f_segmented
+    #[clap(long, default_value_t = 1)]
+    pub segment_frames: usize,
+
+    /// Number of threads proving segments concurrently (all cores if 0)
+    #[clap(long, default_value_t = 0)]
+    pub threads: usize,
+
+    /// Memory budget in MiB for proving segments concurrently, limits the number of threads
+    #[clap(long, default_value_t = 8192)]
+    pub memory_budget: usize,
+
+    #[clap(skip)]
+    pub video: Option<Vec<u16>>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Generate the proof and write it to a proof bundle instead of verifying it
+    Prove {
+        /// Path of the proof bundle to write
+        #[clap(short, long, parse(from_os_str))]
+        output: PathBuf,
+        /// Salt the commitment to the video with a random secret and write its opening to this path
+        #[clap(long, parse(from_os_str))]
+        opening: Option<PathBuf>,
+        /// Salt the commitment with the salt of this opening instead of a random one
+        #[clap(long, parse(from_os_str))]
+        salt_from: Option<PathBuf>,
+        /// Check that the proof refers to the video of this commitment written by the commit subcommand
+        #[clap(long, parse(from_os_str))]
+        commitment: Option<PathBuf>,
+        /// Write the per-frame statistics proven by stark_f_series to this path
+        #[clap(long, parse(from_os_str))]
+        series: Option<PathBuf>,
+    },
+    /// Verify a proof bundle written by the prove subcommand (no input video required)
+    Verify {
+        /// Path of the proof bundle to verify
+        #[clap(parse(from_os_str))]
+        proof: PathBuf,
+        /// Check that the proof refers to the video of this commitment written by the commit subcommand
+        #[clap(long, parse(from_os_str))]
+        commitment: Option<PathBuf>,
+    },
+    /// Verify a proof bundle and fix the commitment to its video, which later proofs refer to
+    Commit {
+        /// Path of the proof bundle to take the commitment from
+        #[clap(parse(from_os_str))]
+        proof: PathBuf,
+        /// Path of the commitment to write
+        #[clap(short, long, parse(from_os_str))]
+        output: PathBuf,
+    },
+    /// Verify the proof bundles listed in a segment manifest written by the prove subcommand
+    VerifySegments {
+        /// Path of the segment manifest to verify
+        #[clap(parse(from_os_str))]
+        manifest: PathBuf,
+    },
+    /// Verify a proof bundle and check that an opening reveals the video it commits to
+    Open {
+        /// Path of the proof bundle to verify
+        #[clap(parse(from_os_str))]
+        proof: PathBuf,
+        /// Path of the opening (or the time series of stark_f_series) written by the prove subcommand
+        #[clap(parse(from_os_str))]
+        opening: PathBuf,
+    },
 }
 
 pub fn stark_choice_validator(v: &str) -> Result<(), String> {
@@ -58,6 +186,9 @@ pub fn stark_choice_validator(v: &str) -> Result<(), String> {
     Err(String::from("The value must be one of:\n\n    ") + &the_options)
 }
 
+type OpenFn = fn(&ProofBundle, &[u8]) -> Result<(), CommitmentError>;
+type CommitmentFn = fn(&ProofBundle) -> Result<VideoCommitment, BundleError>;
+
 lazy_static! {
     static ref STARK_OPTIONS: HashMap<&'static str, fn(&Args)> = {
         let mut m = HashMap::new();
@@ -65,6 +196,8 @@ lazy_static! {
         m.insert("stark_a_62", stark_a_62 as fn(&Args));
         m.insert("stark_a_griffin", stark_a_griffin as fn(&Args));
         m.insert("stark_a_griffin_62", stark_a_griffin_62 as fn(&Args));
+        m.insert("stark_a_poseidon", stark_a_poseidon as fn(&Args));
+        m.insert("stark_a_poseidon_62", stark_a_poseidon_62 as fn(&Args));
         m.insert("stark_b", stark_b as fn(&Args));
         m.insert("stark_b_62", stark_b_62 as fn(&Args));
         m.insert("stark_b_griffin", stark_b_griffin as fn(&Args));
@@ -75,8 +208,11 @@ lazy_static! {
         m.insert("stark_d_griffin", stark_d_griffin as fn(&Args));
         m.insert("stark_e", stark_e as fn(&Args));
         m.insert("stark_e_62", stark_e_62 as fn(&Args));
+        m.insert("stark_e_64_rpo", stark_e_64_rpo as fn(&Args));
         m.insert("stark_e_griffin", stark_e_griffin as fn(&Args));
         m.insert("stark_e_griffin_62", stark_e_griffin_62 as fn(&Args));
+        m.insert("stark_e_m10_c2", stark_e_m10_c2 as fn(&Args));
+        m.insert("stark_e_m13_c5_62", stark_e_m13_c5_62 as fn(&Args));
         m.insert("stark_e_opt", stark_e_opt as fn(&Args));
         m.insert("stark_e_opt_62", stark_e_opt_62 as fn(&Args));
         m.insert("stark_e_opt_griffin", stark_e_opt_griffin as fn(&Args));
@@ -84,8 +220,12 @@ lazy_static! {
         m.insert("stark_f", stark_f as fn(&Args));
         m.insert("stark_f_62", stark_f_62 as fn(&Args));
         m.insert("stark_f_64", stark_f_64 as fn(&Args));
+        m.insert("stark_f_64_rpo", stark_f_64_rpo as fn(&Args));
+        m.insert("stark_f_frames", stark_f_frames as fn(&Args));
         m.insert("stark_f_griffin", stark_f_griffin as fn(&Args));
         m.insert("stark_f_griffin_62", stark_f_griffin_62 as fn(&Args));
+        m.insert("stark_f_m10_c2", stark_f_m10_c2 as fn(&Args));
+        m.insert("stark_f_m13_c5_62", stark_f_m13_c5_62 as fn(&Args));
         m.insert("stark_f_opt_m2", stark_f_opt_m2 as fn(&Args));
         m.insert("stark_f_opt_m2_62", stark_f_opt_m2_62 as fn(&Args));
         m.insert("stark_f_opt_m2_griffin", stark_f_opt_m2_griffin as fn(&Args));
@@ -98,18 +238,665 @@ lazy_static! {
         m.insert("stark_f_opt_m8_62", stark_f_opt_m8_62 as fn(&Args));
         m.insert("stark_f_opt_m8_griffin", stark_f_opt_m8_griffin as fn(&Args));
         m.insert("stark_f_opt_m8_griffin_62", stark_f_opt_m8_griffin_62 as fn(&Args));
+        m.insert("stark_f_poseidon", stark_f_poseidon as fn(&Args));
+        m.insert("stark_f_poseidon_62", stark_f_poseidon_62 as fn(&Args));
+        m.insert("stark_f_poseidon_64", stark_f_poseidon_64 as fn(&Args));
+        m.insert("stark_f_segmented", stark_f_segmented as fn(&Args));
+        m.insert("stark_f_series", stark_f_series as fn(&Args));
         m.insert("stark_g", stark_g as fn(&Args));
         m.insert("stark_g_62", stark_g_62 as fn(&Args));
         m.insert("stark_g_griffin", stark_g_griffin as fn(&Args));
         m.insert("stark_g_griffin_62", stark_g_griffin_62 as fn(&Args));
+        m.insert("stark_g_m10_c2", stark_g_m10_c2 as fn(&Args));
+        m.insert("stark_g_m13_c5_62", stark_g_m13_c5_62 as fn(&Args));
+        m.insert("stark_histogram", stark_histogram as fn(&Args));
+        m.insert("stark_threshold", stark_threshold as fn(&Args));
+        m
+    };
+
+    // the verifier only accepts proofs generated with the proof options of their variant, such that a
+    // bundle cannot lower the security level by choosing its own options
+    static ref STARK_PROOF_OPTIONS: HashMap<&'static str, ProofOptions> = {
+        let mut m = HashMap::new();
+        m.insert("stark_a", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_a_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_a_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_a_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_a_poseidon", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_a_poseidon_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_b", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_b_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_b_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_b_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_c", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_c_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_d", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_d_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_e", utils::get_proof_options(16, FieldExtension::None));
+        m.insert("stark_e_62", utils::get_proof_options(16, FieldExtension::Quadratic));
+        m.insert("stark_e_64_rpo", utils::get_proof_options(16, FieldExtension::Quadratic));
+        m.insert("stark_e_griffin", utils::get_proof_options(16, FieldExtension::None));
+        m.insert("stark_e_griffin_62", utils::get_proof_options(16, FieldExtension::Quadratic));
+        m.insert("stark_e_m10_c2", utils::get_proof_options(16, FieldExtension::None));
+        m.insert("stark_e_m13_c5_62", utils::get_proof_options(16, FieldExtension::Quadratic));
+        m.insert("stark_e_opt", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_e_opt_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_e_opt_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_e_opt_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_64", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_64_rpo", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_frames", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_m10_c2", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_m13_c5_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m2", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m2_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m2_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m2_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m4", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m4_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m4_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m4_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m8", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m8_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_opt_m8_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_opt_m8_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_poseidon", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_poseidon_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_f_poseidon_64", utils::get_proof_options(16, FieldExtension::Quadratic));
+        m.insert("stark_f_segmented", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_f_series", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_g", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_g_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_g_griffin", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_g_griffin_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_g_m10_c2", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_g_m13_c5_62", utils::get_proof_options(8, FieldExtension::Quadratic));
+        m.insert("stark_histogram", utils::get_proof_options(8, FieldExtension::None));
+        m.insert("stark_threshold", utils::get_proof_options(8, FieldExtension::None));
+        m
+    };
+
+    static ref STARK_VERIFIERS: HashMap<&'static str, fn(ProofBundle) -> Result<(), BundleError>> = {
+        let mut m = HashMap::new();
+        m.insert("stark_a", ProofBundle::verify::<stark::stark_a::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_a_62", ProofBundle::verify::<stark::stark_a_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_a_griffin", ProofBundle::verify::<stark::stark_a_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_a_griffin_62", ProofBundle::verify::<stark::stark_a_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_a_poseidon", ProofBundle::verify::<stark::stark_a_poseidon::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_a_poseidon_62", ProofBundle::verify::<stark::stark_a_poseidon_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_b", ProofBundle::verify::<stark::stark_b::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_b_62", ProofBundle::verify::<stark::stark_b_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_b_griffin", ProofBundle::verify::<stark::stark_b_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_b_griffin_62", ProofBundle::verify::<stark::stark_b_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_c", ProofBundle::verify::<stark::stark_c::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_c_griffin", ProofBundle::verify::<stark::stark_c_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_d", ProofBundle::verify::<stark::stark_d::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_d_griffin", ProofBundle::verify::<stark::stark_d_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e", ProofBundle::verify::<stark::stark_e::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_62", ProofBundle::verify::<stark::stark_e_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_64_rpo", ProofBundle::verify::<stark::stark_e_64_rpo::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_griffin", ProofBundle::verify::<stark::stark_e_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_griffin_62", ProofBundle::verify::<stark::stark_e_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_m10_c2", ProofBundle::verify::<stark::stark_e_m10_c2::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_m13_c5_62", ProofBundle::verify::<stark::stark_e_m13_c5_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_opt", ProofBundle::verify::<stark::stark_e_opt::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_opt_62", ProofBundle::verify::<stark::stark_e_opt_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_opt_griffin", ProofBundle::verify::<stark::stark_e_opt_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_e_opt_griffin_62", ProofBundle::verify::<stark::stark_e_opt_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f", ProofBundle::verify::<stark::stark_f::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_62", ProofBundle::verify::<stark::stark_f_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_64", ProofBundle::verify::<stark::stark_f_64::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_64_rpo", ProofBundle::verify::<stark::stark_f_64_rpo::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_frames", verify_frames_bundle as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_griffin", ProofBundle::verify::<stark::stark_f_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_griffin_62", ProofBundle::verify::<stark::stark_f_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_m10_c2", ProofBundle::verify::<stark::stark_f_m10_c2::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_m13_c5_62", ProofBundle::verify::<stark::stark_f_m13_c5_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m2", ProofBundle::verify::<stark::stark_f_opt_m2::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m2_62", ProofBundle::verify::<stark::stark_f_opt_m2_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m2_griffin", ProofBundle::verify::<stark::stark_f_opt_m2_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m2_griffin_62", ProofBundle::verify::<stark::stark_f_opt_m2_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m4", ProofBundle::verify::<stark::stark_f_opt_m4::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m4_62", ProofBundle::verify::<stark::stark_f_opt_m4_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m4_griffin", ProofBundle::verify::<stark::stark_f_opt_m4_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m4_griffin_62", ProofBundle::verify::<stark::stark_f_opt_m4_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m8", ProofBundle::verify::<stark::stark_f_opt_m8::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m8_62", ProofBundle::verify::<stark::stark_f_opt_m8_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m8_griffin", ProofBundle::verify::<stark::stark_f_opt_m8_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_opt_m8_griffin_62", ProofBundle::verify::<stark::stark_f_opt_m8_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_poseidon", ProofBundle::verify::<stark::stark_f_poseidon::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_poseidon_62", ProofBundle::verify::<stark::stark_f_poseidon_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_poseidon_64", ProofBundle::verify::<stark::stark_f_poseidon_64::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_f_series", verify_series_bundle as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g", ProofBundle::verify::<stark::stark_g::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g_62", ProofBundle::verify::<stark::stark_g_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g_griffin", ProofBundle::verify::<stark::stark_g_griffin::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g_griffin_62", ProofBundle::verify::<stark::stark_g_griffin_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g_m10_c2", ProofBundle::verify::<stark::stark_g_m10_c2::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_g_m13_c5_62", ProofBundle::verify::<stark::stark_g_m13_c5_62::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_histogram", ProofBundle::verify::<stark::stark_histogram::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m.insert("stark_threshold", ProofBundle::verify::<stark::stark_threshold::TheAir> as fn(ProofBundle) -> Result<(), BundleError>);
+        m
+    };
+
+    static ref STARK_OPENINGS: HashMap<&'static str, OpenFn> = {
+        let mut m = HashMap::new();
+        m.insert("stark_a", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a::verify_opening)) as OpenFn);
+        m.insert("stark_a_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a_62::verify_opening)) as OpenFn);
+        m.insert("stark_a_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_a_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_a_poseidon", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a_poseidon::verify_opening)) as OpenFn);
+        m.insert("stark_a_poseidon_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_a_poseidon_62::verify_opening)) as OpenFn);
+        m.insert("stark_b", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_b::verify_opening)) as OpenFn);
+        m.insert("stark_b_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_b_62::verify_opening)) as OpenFn);
+        m.insert("stark_b_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_b_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_b_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_b_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_c", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_c::verify_opening)) as OpenFn);
+        m.insert("stark_c_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_c_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_d", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_d::verify_opening)) as OpenFn);
+        m.insert("stark_d_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_d_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_e", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e::verify_opening)) as OpenFn);
+        m.insert("stark_e_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_62::verify_opening)) as OpenFn);
+        m.insert("stark_e_64_rpo", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_64_rpo::verify_opening)) as OpenFn);
+        m.insert("stark_e_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_e_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_e_m10_c2", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_m10_c2::verify_opening)) as OpenFn);
+        m.insert("stark_e_m13_c5_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_m13_c5_62::verify_opening)) as OpenFn);
+        m.insert("stark_e_opt", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_opt::verify_opening)) as OpenFn);
+        m.insert("stark_e_opt_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_opt_62::verify_opening)) as OpenFn);
+        m.insert("stark_e_opt_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_opt_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_e_opt_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_e_opt_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_f", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f::verify_opening)) as OpenFn);
+        m.insert("stark_f_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_64", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_64::verify_opening)) as OpenFn);
+        m.insert("stark_f_64_rpo", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_64_rpo::verify_opening)) as OpenFn);
+        m.insert("stark_f_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_f_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_m10_c2", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_m10_c2::verify_opening)) as OpenFn);
+        m.insert("stark_f_m13_c5_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_m13_c5_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m2", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m2::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m2_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m2_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m2_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m2_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m2_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m2_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m4", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m4::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m4_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m4_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m4_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m4_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m4_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m4_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m8", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m8::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m8_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m8_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m8_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m8_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_f_opt_m8_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_opt_m8_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_poseidon", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_poseidon::verify_opening)) as OpenFn);
+        m.insert("stark_f_poseidon_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_poseidon_62::verify_opening)) as OpenFn);
+        m.insert("stark_f_poseidon_64", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_f_poseidon_64::verify_opening)) as OpenFn);
+        m.insert("stark_f_series", open_series_bundle as OpenFn);
+        m.insert("stark_g", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g::verify_opening)) as OpenFn);
+        m.insert("stark_g_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g_62::verify_opening)) as OpenFn);
+        m.insert("stark_g_griffin", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g_griffin::verify_opening)) as OpenFn);
+        m.insert("stark_g_griffin_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g_griffin_62::verify_opening)) as OpenFn);
+        m.insert("stark_g_m10_c2", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g_m10_c2::verify_opening)) as OpenFn);
+        m.insert("stark_g_m13_c5_62", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_g_m13_c5_62::verify_opening)) as OpenFn);
+        m.insert("stark_histogram", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_histogram::verify_opening)) as OpenFn);
+        m.insert("stark_threshold", (|bundle, bytes| open_bundle(bundle, bytes, stark::stark_threshold::verify_opening)) as OpenFn);
+        m
+    };
+
+    // the commitment to the video in the public inputs of a proof, named by the way it is computed:
+    // the variant for A-D, the hash instance for E, E-opt and G, the padded hash for F
+    static ref STARK_COMMITMENTS: HashMap<&'static str, CommitmentFn> = {
+        let mut m = HashMap::new();
+        m.insert("stark_a", (|bundle| get_commitment::<stark::stark_a::PubInputs, _>(bundle, "stark_a", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_a_62", (|bundle| get_commitment::<stark::stark_a_62::PubInputs, _>(bundle, "stark_a_62", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_a_griffin", (|bundle| get_commitment::<stark::stark_a_griffin::PubInputs, _>(bundle, "stark_a_griffin", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_a_griffin_62", (|bundle| get_commitment::<stark::stark_a_griffin_62::PubInputs, _>(bundle, "stark_a_griffin_62", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_a_poseidon", (|bundle| get_commitment::<stark::stark_a_poseidon::PubInputs, _>(bundle, "stark_a_poseidon", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_a_poseidon_62", (|bundle| get_commitment::<stark::stark_a_poseidon_62::PubInputs, _>(bundle, "stark_a_poseidon_62", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_b", (|bundle| get_commitment::<stark::stark_b::PubInputs, _>(bundle, "stark_b", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_b_62", (|bundle| get_commitment::<stark::stark_b_62::PubInputs, _>(bundle, "stark_b_62", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_b_griffin", (|bundle| get_commitment::<stark::stark_b_griffin::PubInputs, _>(bundle, "stark_b_griffin", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_b_griffin_62", (|bundle| get_commitment::<stark::stark_b_griffin_62::PubInputs, _>(bundle, "stark_b_griffin_62", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_c", (|bundle| get_commitment::<stark::stark_c::PubInputs, _>(bundle, "stark_c", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_c_griffin", (|bundle| get_commitment::<stark::stark_c_griffin::PubInputs, _>(bundle, "stark_c_griffin", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_d", (|bundle| get_commitment::<stark::stark_d::PubInputs, _>(bundle, "stark_d", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_d_griffin", (|bundle| get_commitment::<stark::stark_d_griffin::PubInputs, _>(bundle, "stark_d_griffin", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e", (|bundle| get_commitment::<stark::stark_e::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_62", (|bundle| get_commitment::<stark::stark_e_62::PubInputs, _>(bundle, "rescue::p62_m9_c1_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_64_rpo", (|bundle| get_commitment::<stark::stark_e_64_rpo::PubInputs, _>(bundle, "rescue::p64_m12_c4_s128_rpo", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_griffin", (|bundle| get_commitment::<stark::stark_e_griffin::PubInputs, _>(bundle, "griffin::p128_t12_c4_s100", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_griffin_62", (|bundle| get_commitment::<stark::stark_e_griffin_62::PubInputs, _>(bundle, "griffin::p62_t12_c4_s100", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_m10_c2", (|bundle| get_commitment::<stark::stark_e_m10_c2::PubInputs, _>(bundle, "rescue::p128_m10_c2_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_m13_c5_62", (|bundle| get_commitment::<stark::stark_e_m13_c5_62::PubInputs, _>(bundle, "rescue::p62_m13_c5_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_opt", (|bundle| get_commitment::<stark::stark_e_opt::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_opt_62", (|bundle| get_commitment::<stark::stark_e_opt_62::PubInputs, _>(bundle, "rescue::p62_m9_c1_s128", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_opt_griffin", (|bundle| get_commitment::<stark::stark_e_opt_griffin::PubInputs, _>(bundle, "griffin::p128_t12_c4_s100", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_e_opt_griffin_62", (|bundle| get_commitment::<stark::stark_e_opt_griffin_62::PubInputs, _>(bundle, "griffin::p62_t12_c4_s100", |p| (&p.hash_pixels[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f", (|bundle| get_commitment::<stark::stark_f::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_62", (|bundle| get_commitment::<stark::stark_f_62::PubInputs, _>(bundle, "rescue::p62_m9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_64", (|bundle| get_commitment::<stark::stark_f_64::PubInputs, _>(bundle, "rescue::p64_m9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_64_rpo", (|bundle| get_commitment::<stark::stark_f_64_rpo::PubInputs, _>(bundle, "rescue::p64_m12_c4_s128_rpo padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_frames", (|bundle| get_commitment::<stark::stark_f_frames::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128 frame tree", |p| (&p.root[..], p.num_frames * p.frame_size, false))) as CommitmentFn);
+        m.insert("stark_f_griffin", (|bundle| get_commitment::<stark::stark_f_griffin::PubInputs, _>(bundle, "griffin::p128_t12_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_griffin_62", (|bundle| get_commitment::<stark::stark_f_griffin_62::PubInputs, _>(bundle, "griffin::p62_t12_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_m10_c2", (|bundle| get_commitment::<stark::stark_f_m10_c2::PubInputs, _>(bundle, "rescue::p128_m10_c2_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_m13_c5_62", (|bundle| get_commitment::<stark::stark_f_m13_c5_62::PubInputs, _>(bundle, "rescue::p62_m13_c5_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m2", (|bundle| get_commitment::<stark::stark_f_opt_m2::PubInputs, _>(bundle, "rescue::p128_m17_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m2_62", (|bundle| get_commitment::<stark::stark_f_opt_m2_62::PubInputs, _>(bundle, "rescue::p62_m17_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m2_griffin", (|bundle| get_commitment::<stark::stark_f_opt_m2_griffin::PubInputs, _>(bundle, "griffin::p128_t20_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m2_griffin_62", (|bundle| get_commitment::<stark::stark_f_opt_m2_griffin_62::PubInputs, _>(bundle, "griffin::p62_t20_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m4", (|bundle| get_commitment::<stark::stark_f_opt_m4::PubInputs, _>(bundle, "rescue::p128_m33_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m4_62", (|bundle| get_commitment::<stark::stark_f_opt_m4_62::PubInputs, _>(bundle, "rescue::p62_m33_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m4_griffin", (|bundle| get_commitment::<stark::stark_f_opt_m4_griffin::PubInputs, _>(bundle, "griffin::p128_t36_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m4_griffin_62", (|bundle| get_commitment::<stark::stark_f_opt_m4_griffin_62::PubInputs, _>(bundle, "griffin::p62_t36_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m8", (|bundle| get_commitment::<stark::stark_f_opt_m8::PubInputs, _>(bundle, "rescue::p128_m65_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m8_62", (|bundle| get_commitment::<stark::stark_f_opt_m8_62::PubInputs, _>(bundle, "rescue::p62_m65_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m8_griffin", (|bundle| get_commitment::<stark::stark_f_opt_m8_griffin::PubInputs, _>(bundle, "griffin::p128_t68_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_opt_m8_griffin_62", (|bundle| get_commitment::<stark::stark_f_opt_m8_griffin_62::PubInputs, _>(bundle, "griffin::p62_t68_c4_s100 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_poseidon", (|bundle| get_commitment::<stark::stark_f_poseidon::PubInputs, _>(bundle, "poseidon::p128_t9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_poseidon_62", (|bundle| get_commitment::<stark::stark_f_poseidon_62::PubInputs, _>(bundle, "poseidon::p62_t9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_poseidon_64", (|bundle| get_commitment::<stark::stark_f_poseidon_64::PubInputs, _>(bundle, "poseidon::p64_t9_c1_s128 padded", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_f_series", (|bundle| get_commitment::<stark::stark_f_series::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128 frame tree", |p| (&p.root[..], p.num_frames * p.frame_size, false))) as CommitmentFn);
+        m.insert("stark_g", (|bundle| get_commitment::<stark::stark_g::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_g_62", (|bundle| get_commitment::<stark::stark_g_62::PubInputs, _>(bundle, "rescue::p62_m9_c1_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_g_griffin", (|bundle| get_commitment::<stark::stark_g_griffin::PubInputs, _>(bundle, "griffin::p128_t12_c4_s100", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_g_griffin_62", (|bundle| get_commitment::<stark::stark_g_griffin_62::PubInputs, _>(bundle, "griffin::p62_t12_c4_s100", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_g_m10_c2", (|bundle| get_commitment::<stark::stark_g_m10_c2::PubInputs, _>(bundle, "rescue::p128_m10_c2_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_g_m13_c5_62", (|bundle| get_commitment::<stark::stark_g_m13_c5_62::PubInputs, _>(bundle, "rescue::p62_m13_c5_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_histogram", (|bundle| get_commitment::<stark::stark_histogram::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
+        m.insert("stark_threshold", (|bundle| get_commitment::<stark::stark_threshold::PubInputs, _>(bundle, "rescue::p128_m9_c1_s128", |p| (&p.hash[..], p.input_length, p.salted))) as CommitmentFn);
         m
     };
 }
 
 fn main() {
     env_logger::init();
-    let args: Args = Args::parse();
-    STARK_OPTIONS[&*args.stark](&args);
+    let mut args: Args = Args::parse();
+    if let Some(Command::Verify { proof, commitment }) = &args.command {
+        std::process::exit(match commitment {
+            Some(commitment) => verify_bundle_commitment(proof, commitment),
+            None => verify_bundle(proof),
+        });
+    }
+    if let Some(Command::Commit { proof, output }) = &args.command {
+        std::process::exit(commit_bundle(proof, output));
+    }
+    if let Some(Command::VerifySegments { manifest }) = &args.command {
+        std::process::exit(verify_manifest(manifest));
+    }
+    if let Some(Command::Open { proof, opening }) = &args.command {
+        std::process::exit(open_bundle_file(proof, opening));
+    }
+    let stark = match &args.stark {
+        Some(stark) => stark.clone(),
+        None => {
+            eprintln!("No STARK variant selected, use -s to select one (see -h for help)");
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = &args.input {
+        match input::load_video(path, args.format, get_frame(&args).frame_size()) {
+            Ok(video) => {
+                args.length = video.len();
+                args.video = Some(video);
+            }
+            Err(err) => {
+                eprintln!("Failed to load the input video {}: {}", path.display(), err);
+                std::process::exit(1);
+            }
+        }
+    }
+    STARK_OPTIONS[&*stark](&args);
+}
+
+/// Verifies the proof right away, or writes it to a proof bundle when running the prove
+/// subcommand. Returns the time spent in ms.
+pub fn finish_proof<A: Air>(args: &Args, options: ProofOptions, proof: StarkProof, public_inputs: A::PublicInputs) -> u128 {
+    let now = Instant::now();
+    if let Some(Command::Prove { output, commitment, .. }) = &args.command {
+        let bundle = ProofBundle::new(args.stark.as_ref().unwrap(), options, &public_inputs, proof);
+        if let Some(commitment) = commitment {
+            if let Err(err) = check_commitment(&bundle, commitment) {
+                eprintln!("The proof does not refer to the video committed to in {}: {}", commitment.display(), err);
+                std::process::exit(1);
+            }
+        }
+        if let Err(err) = bundle.write(output) {
+            eprintln!("Failed to write the proof bundle {}: {}", output.display(), err);
+            std::process::exit(1);
+        }
+        let write_time = now.elapsed().as_millis();
+        trace!("Proof bundle written to {} in {} ms!", output.display(), write_time);
+        return write_time;
+    }
+    assert!(winterfell::verify::<A>(proof, public_inputs).is_ok());
+    let verifier_time = now.elapsed().as_millis();
+    trace!("Proof verified successfully in {} ms!", verifier_time);
+    verifier_time
+}
+
+/// Verifies a proof bundle of stark_f_frames: first the authentication paths of the frame digests to the
+/// committed root, then the proof.
+pub fn verify_frames_bundle(bundle: ProofBundle) -> Result<(), BundleError> {
+    let pub_inputs = bundle.get_pub_inputs::<stark::stark_f_frames::PubInputs>()?;
+    stark::stark_f_frames::verify_paths(&pub_inputs).map_err(|err| BundleError::Commitment(err.to_string()))?;
+    bundle.verify::<stark::stark_f_frames::TheAir>()
+}
+
+/// Verifies a proof bundle of stark_f_series: first the authentication paths of the frame digests to the
+/// committed root, then the proof.
+pub fn verify_series_bundle(bundle: ProofBundle) -> Result<(), BundleError> {
+    let pub_inputs = bundle.get_pub_inputs::<stark::stark_f_series::PubInputs>()?;
+    stark::stark_f_series::verify_paths(&pub_inputs).map_err(|err| BundleError::Commitment(err.to_string()))?;
+    bundle.verify::<stark::stark_f_series::TheAir>()
+}
+
+/// Checks that a time series written by the prove subcommand of stark_f_series is the one the digest
+/// of a proof bundle commits to.
+pub fn open_series_bundle(bundle: &ProofBundle, bytes: &[u8]) -> Result<(), CommitmentError> {
+    let series = TimeSeries::<stark::stark_f_series::FrameStats>::from_bytes(bytes)?;
+    if series.variant != bundle.variant {
+        return Err(CommitmentError::VariantMismatch { opening: series.variant, proof: bundle.variant.clone() });
+    }
+    let pub_inputs = bundle.get_pub_inputs::<stark::stark_f_series::PubInputs>()?;
+    if series.first_frame as u128 != pub_inputs.first_frame.as_int() {
+        return Err(CommitmentError::Mismatch(format!("the time series starts at frame {}, the proof at frame {}", series.first_frame, pub_inputs.first_frame.as_int())));
+    }
+    stark::stark_f_series::verify_series(&pub_inputs, &series.frames)
+}
+
+/// Verifies a proof bundle and returns the exit code of the binary.
+pub fn verify_bundle(path: &Path) -> i32 {
+    let result = ProofBundle::read(path).and_then(|bundle| {
+        trace!("Verifying proof bundle of {} (conjectured security: {} bits) ..", bundle.variant, bundle.proof.security_level(true));
+        let now = Instant::now();
+        let verifier = STARK_VERIFIERS.get(bundle.variant.as_str()).ok_or_else(|| BundleError::UnknownVariant(bundle.variant.clone()))?;
+        bundle.check_options(&STARK_PROOF_OPTIONS[bundle.variant.as_str()])?;
+        let variant = bundle.variant.clone();
+        verifier(bundle)?;
+        info!("{};{}", variant, now.elapsed().as_millis());
+        Ok(variant)
+    });
+    match result {
+        Ok(variant) => {
+            println!("Proof of {} verified successfully", variant);
+            0
+        }
+        Err(err) => {
+            eprintln!("Verification of {} failed: {}", path.display(), err);
+            1
+        }
+    }
+}
+
+/// Writes the proofs of the segments of a video to proof bundles next to the manifest `output`,
+/// which ties them to the frames they cover. Returns the time spent in ms.
+pub fn write_segments<P: Serializable>(args: &Args, output: &Path, frame_size: usize, options: ProofOptions, segments: Vec<(StarkProof, P)>) -> u128 {
+    let now = Instant::now();
+    let variant = args.stark.as_ref().unwrap();
+    let num_frames = args.length / frame_size;
+    let mut manifest = SegmentManifest { variant: variant.clone(), frame_size, segments: vec![] };
+    for (idx, (proof, pub_inputs)) in segments.into_iter().enumerate() {
+        let first_frame = idx * args.segment_frames;
+        let file_name = format!("{}.{}", output.file_name().unwrap().to_string_lossy(), idx);
+        let path = output.with_file_name(&file_name);
+        if let Err(err) = ProofBundle::new(variant, options.clone(), &pub_inputs, proof).write(&path) {
+            eprintln!("Failed to write the proof bundle {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+        manifest.segments.push(SegmentEntry { first_frame, num_frames: usize::min(args.segment_frames, num_frames - first_frame), proof: file_name });
+    }
+    if let Err(err) = manifest.write(output) {
+        eprintln!("Failed to write the segment manifest {}: {}", output.display(), err);
+        std::process::exit(1);
+    }
+    let write_time = now.elapsed().as_millis();
+    trace!("Segment manifest and {} proof bundles written to {} in {} ms!", manifest.segments.len(), output.display(), write_time);
+    write_time
+}
+
+/// Verifies the proof bundles of a segment manifest and checks that they link up to a chain over the
+/// whole video. Returns the exit code of the binary.
+pub fn verify_manifest(path: &Path) -> i32 {
+    let result = SegmentManifest::read(path).map_err(Box::<dyn std::error::Error>::from).and_then(|manifest| {
+        if manifest.variant != "stark_f_segmented" {
+            return Err(BundleError::UnknownVariant(manifest.variant).into());
+        }
+        let now = Instant::now();
+        let bundles = manifest.read_bundles(path.parent().unwrap_or_else(|| Path::new("")))?;
+        let mut segments = vec![];
+        for (entry, bundle) in manifest.segments.iter().zip(bundles) {
+            bundle.check_options(&STARK_PROOF_OPTIONS[manifest.variant.as_str()])?;
+            let pub_inputs = bundle.get_pub_inputs::<stark::stark_f_segment::PubInputs>()?;
+            // the segment covers the frames of its entry
+            if pub_inputs.segment_length.as_int() as usize != entry.num_frames * manifest.frame_size {
+                return Err(BundleError::Malformed(format!("proof bundle {} does not cover {} frames", entry.proof, entry.num_frames)).into());
+            }
+            segments.push((bundle.proof, pub_inputs));
+        }
+        let outputs = stark::stark_f_segment::verify_segments(segments)?;
+        info!("{};{}", manifest.variant, now.elapsed().as_millis());
+        Ok((manifest, outputs))
+    });
+    match result {
+        Ok((manifest, outputs)) => {
+            println!("Proofs of {} segments ({} frames) of {} verified successfully", manifest.segments.len(), outputs.input_length / manifest.frame_size, manifest.variant);
+            println!("hash: {:?}, sum: {}, average: {}, variance: {}", outputs.hash, outputs.sum, outputs.avg_rounded, outputs.variance);
+            0
+        }
+        Err(err) => {
+            eprintln!("Verification of {} failed: {}", path.display(), err);
+            1
+        }
+    }
+}
+
+/// Returns the number of threads proving segments concurrently: all cores (or --threads), but at most
+/// as many as segments of `segment_memory` bytes fit into --memory-budget, and at least one.
+pub fn get_num_workers(args: &Args, segment_memory: usize) -> usize {
+    let num_threads = match args.threads {
+        0 => std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+        threads => threads,
+    };
+    let budget = args.memory_budget * 1024 * 1024;
+    if segment_memory > budget {
+        trace!("A segment needs about {} MiB, which exceeds the memory budget of {} MiB", segment_memory / (1024 * 1024), args.memory_budget);
+    }
+    usize::max(1, usize::min(num_threads, budget / usize::max(1, segment_memory)))
+}
+
+/// Verifies a proof bundle and checks that an opening reveals the video committed to in its
+/// public inputs. Returns the exit code of the binary.
+pub fn open_bundle_file(proof: &Path, opening: &Path) -> i32 {
+    let exit_code = verify_bundle(proof);
+    if exit_code != 0 {
+        return exit_code;
+    }
+    let result = ProofBundle::read(proof).map_err(CommitmentError::from).and_then(|bundle| {
+        let bytes = std::fs::read(opening)?;
+        let open = STARK_OPENINGS.get(bundle.variant.as_str()).ok_or_else(|| BundleError::UnknownVariant(bundle.variant.clone()))?;
+        open(&bundle, &bytes)
+    });
+    match result {
+        Ok(()) => {
+            println!("Opening {} matches the commitment of the proof", opening.display());
+            0
+        }
+        Err(err) => {
+            eprintln!("Opening {} failed: {}", opening.display(), err);
+            1
+        }
+    }
+}
+
+/// Verifies a proof bundle and checks that it refers to the video of a fixed commitment. Returns the
+/// exit code of the binary.
+pub fn verify_bundle_commitment(proof: &Path, commitment: &Path) -> i32 {
+    let exit_code = verify_bundle(proof);
+    if exit_code != 0 {
+        return exit_code;
+    }
+    match ProofBundle::read(proof).map_err(CommitmentError::from).and_then(|bundle| check_commitment(&bundle, commitment)) {
+        Ok(()) => {
+            println!("Proof {} refers to the video committed to in {}", proof.display(), commitment.display());
+            0
+        }
+        Err(err) => {
+            eprintln!("Commitment check of {} failed: {}", proof.display(), err);
+            1
+        }
+    }
+}
+
+/// Verifies a proof bundle and writes the commitment to its video. Returns the exit code of the binary.
+pub fn commit_bundle(proof: &Path, output: &Path) -> i32 {
+    let exit_code = verify_bundle(proof);
+    if exit_code != 0 {
+        return exit_code;
+    }
+    let result = ProofBundle::read(proof).map_err(CommitmentError::from).and_then(|bundle| {
+        let commitment = get_bundle_commitment(&bundle)?;
+        commitment.write(output)?;
+        Ok(commitment)
+    });
+    match result {
+        Ok(commitment) => {
+            println!("Commitment ({}, {} pixels) written to {}", commitment.scheme, commitment.input_length, output.display());
+            0
+        }
+        Err(err) => {
+            eprintln!("Failed to write the commitment of {}: {}", proof.display(), err);
+            1
+        }
+    }
+}
+
+/// Checks that the public inputs of a proof bundle commit to the video of the commitment at `path`.
+pub fn check_commitment(bundle: &ProofBundle, path: &Path) -> Result<(), CommitmentError> {
+    let commitment = VideoCommitment::read(path)?;
+    commitment.check(&get_bundle_commitment(bundle)?)
+}
+
+pub fn get_bundle_commitment(bundle: &ProofBundle) -> Result<VideoCommitment, BundleError> {
+    let get = STARK_COMMITMENTS.get(bundle.variant.as_str()).ok_or_else(|| BundleError::UnknownVariant(bundle.variant.clone()))?;
+    get(bundle)
+}
+
+/// Returns the commitment in the public inputs of a proof bundle, `get` selects hash, input length and
+/// salt flag of the public inputs.
+pub fn get_commitment<P: Deserializable, B: StarkBaseField>(bundle: &ProofBundle, scheme: &str, get: fn(&P) -> (&[B], B, bool)) -> Result<VideoCommitment, BundleError> {
+    let pub_inputs = bundle.get_pub_inputs::<P>()?;
+    let (hash, input_length, salted) = get(&pub_inputs);
+    Ok(VideoCommitment::new(scheme, hash, input_length.as_u128() as usize, salted))
+}
+
+/// Checks an opening against the commitment in the public inputs of a proof bundle, using the
+/// opening check of the STARK variant.
+pub fn open_bundle<P: Deserializable, B: StarkField>(bundle: &ProofBundle, bytes: &[u8], verify_opening: fn(&P, &Opening<B>) -> Result<(), CommitmentError>) -> Result<(), CommitmentError> {
+    let opening = Opening::<B>::from_bytes(bytes)?;
+    if opening.variant != bundle.variant {
+        return Err(CommitmentError::VariantMismatch { opening: opening.variant, proof: bundle.variant.clone() });
+    }
+    let pub_inputs = bundle.get_pub_inputs::<P>()?;
+    verify_opening(&pub_inputs, &opening)
+}
+
+/// Returns a random secret salt for the commitment when running the prove subcommand with an
+/// opening, and no salt otherwise.
+pub fn get_salt<B: StarkField, const N: usize>(args: &Args) -> Option<[B; N]> {
+    match &args.command {
+        Some(Command::Prove { salt_from: Some(path), .. }) => {
+            let salt = std::fs::read(path).map_err(CommitmentError::from).and_then(|bytes| Opening::<B>::from_bytes(&bytes)).and_then(|opening| {
+                let salt = opening.salt.ok_or_else(|| CommitmentError::Malformed(String::from("the opening is not salted")))?;
+                <[B; N]>::try_from(salt).map_err(|salt| CommitmentError::Malformed(format!("expected a salt of {} elements, got {}", N, salt.len())))
+            });
+            match salt {
+                Ok(salt) => Some(salt),
+                Err(err) => {
+                    eprintln!("Failed to read the salt from {}: {}", path.display(), err);
+                    std::process::exit(2);
+                }
+            }
+        },
+        Some(Command::Prove { opening: Some(_), .. }) => Some([B::ZERO; N].map(|_| rand_value())),
+        _ => None,
+    }
+}
+
+/// Writes the opening of the commitment when running the prove subcommand with an opening.
+pub fn write_opening<B: StarkField, const N: usize>(args: &Args, pixels: &Vec<u16>, salt: Option<&[B; N]>) {
+    if let Some(Command::Prove { opening: Some(path), .. }) = &args.command {
+        let opening = Opening::new(args.stark.as_ref().unwrap(), pixels, salt.map(|s| &s[..]));
+        if let Err(err) = opening.write(path) {
+            eprintln!("Failed to write the opening {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+        trace!("Opening of the commitment written to {}!", path.display());
+    }
+}
+
+/// Returns the frame and the ROIs given by the command line, exits if they are invalid.
+pub fn get_frame(args: &Args) -> FrameConfig {
+    let rois = if args.roi.is_empty() { FrameConfig::default().rois } else { args.roi.clone() };
+    match FrameConfig::new(args.frame_width, args.frame_height, rois) {
+        Ok(frame) => frame,
+        Err(err) => {
+            eprintln!("Invalid frame configuration: {}", err);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Returns the ascending ranks of the order statistics requested by the command line, including the nearest
+/// ranks of the percentiles, exits if one is out of range.
+pub fn get_ranks(args: &Args, check_ranks: fn(&[usize], usize) -> Result<(), String>) -> Vec<usize> {
+    let mut ranks = args.rank.clone();
+    for percentile in &args.percentile {
+        if !(0.0..=100.0).contains(percentile) {
+            eprintln!("Invalid percentile {}, must be in [0, 100]", percentile);
+            std::process::exit(2);
+        }
+        ranks.push(utils::get_percentile_rank(*percentile, args.length));
+    }
+    ranks.sort_unstable();
+    ranks.dedup();
+    if let Err(err) = check_ranks(&ranks, args.length) {
+        eprintln!("Invalid ranks: {}", err);
+        std::process::exit(2);
+    }
+    ranks
+}
+
+/// Returns the largest value of the lookup table for the bit depth given by the command line, exits if it
+/// is invalid or the input video (or random range) has pixels outside of the table.
+pub fn get_max_value(args: &Args, step: usize) -> usize {
+    if args.bit_depth == 0 || args.bit_depth > utils::MAX_BIT_DEPTH {
+        eprintln!("Invalid bit depth {}, must be in [1, {}]", args.bit_depth, utils::MAX_BIT_DEPTH);
+        std::process::exit(2);
+    }
+    let max_value = utils::get_lookup_max_value(args.bit_depth, step);
+    let max_pixel = match &args.video {
+        Some(video) => video.iter().copied().max().unwrap_or(0),
+        None => args.end.saturating_sub(1),
+    };
+    if max_pixel as usize > max_value {
+        eprintln!("Pixel value {} exceeds the largest value {} of the lookup table for bit depth {}", max_pixel, max_value, args.bit_depth);
+        std::process::exit(2);
+    }
+    max_value
+}
+
+/// Returns the loaded input video, or random values if no video was given.
+pub fn get_input_values<E: From<u16>>(args: &Args) -> (Vec<u16>, Vec<E>) {
+    match &args.video {
+        Some(video) => {
+            let pixels_base = video.iter().map(|p| E::from(*p)).collect();
+            (video.clone(), pixels_base)
+        }
+        None => utils::get_rand_values::<E>(args.begin, args.end, args.length),
+    }
 }
 
 pub fn stark_a(args: &Args) {
@@ -118,19 +905,21 @@ pub fn stark_a(args: &Args) {
     use stark::stark_a as Stark;
     use rescue::p128_m4_c3_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -143,27 +932,29 @@ pub fn stark_a(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_a"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_a_62(args: &Args) {
@@ -172,19 +963,21 @@ pub fn stark_a_62(args: &Args) {
     use stark::stark_a_62 as Stark;
     use rescue::p62_m4_c3_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -197,27 +990,29 @@ pub fn stark_a_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_a_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_a_griffin(args: &Args) {
@@ -226,19 +1021,21 @@ pub fn stark_a_griffin(args: &Args) {
     use stark::stark_a_griffin as Stark;
     use griffin::p128_t4_c3_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -251,27 +1048,29 @@ pub fn stark_a_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_a_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_a_griffin_62(args: &Args) {
@@ -280,19 +1079,21 @@ pub fn stark_a_griffin_62(args: &Args) {
     use stark::stark_a_griffin_62 as Stark;
     use griffin::p62_t4_c3_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -305,27 +1106,145 @@ pub fn stark_a_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_a_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_a_poseidon(args: &Args) {
+    let name = "STARK A (Poseidon)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_a_poseidon as Stark;
+    use poseidon::p128_t4_c3_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_a_poseidon"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_a_poseidon_62(args: &Args) {
+    let name = "STARK A (Poseidon) 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_a_poseidon_62 as Stark;
+    use poseidon::p62_t4_c3_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_a_poseidon_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some() };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_b(args: &Args) {
@@ -334,19 +1253,21 @@ pub fn stark_b(args: &Args) {
     use stark::stark_b as Stark;
     use rescue::p128_m4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -359,27 +1280,29 @@ pub fn stark_b(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_b"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_b_62(args: &Args) {
@@ -388,19 +1311,21 @@ pub fn stark_b_62(args: &Args) {
     use stark::stark_b_62 as Stark;
     use rescue::p62_m4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -413,27 +1338,29 @@ pub fn stark_b_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_b_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_b_griffin(args: &Args) {
@@ -442,19 +1369,21 @@ pub fn stark_b_griffin(args: &Args) {
     use stark::stark_b_griffin as Stark;
     use griffin::p128_t4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -467,27 +1396,29 @@ pub fn stark_b_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_b_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_b_griffin_62(args: &Args) {
@@ -496,19 +1427,21 @@ pub fn stark_b_griffin_62(args: &Args) {
     use stark::stark_b_griffin_62 as Stark;
     use griffin::p62_t4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -521,27 +1454,29 @@ pub fn stark_b_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_b_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_c(args: &Args) {
@@ -550,11 +1485,12 @@ pub fn stark_c(args: &Args) {
     use stark::stark_c as Stark;
     use rescue::p128_m4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, _) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
     let mut pixels_base = vec![];
     for i in 0..(args.length / 8) {
         let mut result: u128 = 0;
@@ -564,13 +1500,14 @@ pub fn stark_c(args: &Args) {
         pixels_base.push(BaseElement::new(result));
     }
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -583,27 +1520,29 @@ pub fn stark_c(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_c"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_c_griffin(args: &Args) {
@@ -612,11 +1551,12 @@ pub fn stark_c_griffin(args: &Args) {
     use stark::stark_c_griffin as Stark;
     use griffin::p128_t4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, _) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
     let mut pixels_base = vec![];
     for i in 0..(args.length / 8) {
         let mut result: u128 = 0;
@@ -626,13 +1566,14 @@ pub fn stark_c_griffin(args: &Args) {
         pixels_base.push(BaseElement::new(result));
     }
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -645,27 +1586,29 @@ pub fn stark_c_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_c_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some() };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_d(args: &Args) {
@@ -674,11 +1617,13 @@ pub fn stark_d(args: &Args) {
     use stark::stark_d as Stark;
     use rescue::p128_m4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, _) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
     let mut pixels_base = vec![];
     for i in 0..(args.length / 8) {
         let mut result: u128 = 0;
@@ -688,18 +1633,22 @@ pub fn stark_d(args: &Args) {
         pixels_base.push(BaseElement::new(result));
     }
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16, &hash);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -712,22 +1661,21 @@ pub fn stark_d(args: &Args) {
     // AET computes the same hash value as the native hash function
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_d"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), max_value: BaseElement::new(max_value as u128) };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_d_griffin(args: &Args) {
@@ -736,11 +1684,13 @@ pub fn stark_d_griffin(args: &Args) {
     use stark::stark_d_griffin as Stark;
     use griffin::p128_t4_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, _) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
     let mut pixels_base = vec![];
     for i in 0..(args.length / 8) {
         let mut result: u128 = 0;
@@ -750,18 +1700,22 @@ pub fn stark_d_griffin(args: &Args) {
         pixels_base.push(BaseElement::new(result));
     }
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16, &hash);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -774,22 +1728,21 @@ pub fn stark_d_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_d_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128) };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), max_value: BaseElement::new(max_value as u128) };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_e(args: &Args) {
@@ -798,34 +1751,27 @@ pub fn stark_e(args: &Args) {
     use stark::stark_e as Stark;
     use rescue::p128_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = 4 * args.length + Stark::SIZE_OF_T - 1;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[i] = trace.get(Stark::T_S_HASH.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -835,19 +1781,24 @@ pub fn stark_e(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -858,38 +1809,40 @@ pub fn stark_e(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(16, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_e"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_e_62(args: &Args) {
@@ -898,34 +1851,27 @@ pub fn stark_e_62(args: &Args) {
     use stark::stark_e_62 as Stark;
     use rescue::p62_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = 4 * args.length + Stark::SIZE_OF_T - 1;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[i] = trace.get(Stark::T_S_HASH.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -935,19 +1881,24 @@ pub fn stark_e_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -958,38 +1909,140 @@ pub fn stark_e_62(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(16, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_e_62"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_e_64_rpo(args: &Args) {
+    let name = "STARK E 64 (RPO)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_e_64_rpo as Stark;
+    use rescue::p64_m12_c4_s128_rpo as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
+    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
+    let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_pixels_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    #[allow(unused_assignments)]
+    let mut med_high_trace = BaseElement::ZERO;
+    #[allow(unused_assignments)]
+    let mut med_low_trace = BaseElement::ZERO;
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
+    let max_trace = trace.get(Stark::T_MAX.begin(), stat_result_step);
+    assert_eq!(sum_trace, manual_stats.sum_e);
+    assert_eq!(var_trace, manual_stats.var_e);
+    assert_eq!(min_trace, manual_stats.min_e);
+    assert_eq!(max_trace, manual_stats.max_e);
+    assert_eq!(med_low_trace, manual_stats.med_low_e);
+    assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_e_64_rpo"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs {
+        hash_pixels: hash,
+        input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
+        sum: manual_stats.sum_e,
+        avg_rounded: manual_stats.avg_rounded_e,
+        variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
+        min: min_trace,
+        max: max_trace,
+        med_low: med_low_trace,
+        med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
+    };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_e_griffin(args: &Args) {
@@ -998,34 +2051,27 @@ pub fn stark_e_griffin(args: &Args) {
     use stark::stark_e_griffin as Stark;
     use griffin::p128_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = 4 * args.length + Stark::SIZE_OF_T - 1;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[i] = trace.get(Stark::T_S_HASH.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1035,19 +2081,24 @@ pub fn stark_e_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1058,38 +2109,40 @@ pub fn stark_e_griffin(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(16, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_e_griffin"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_e_griffin_62(args: &Args) {
@@ -1098,34 +2151,27 @@ pub fn stark_e_griffin_62(args: &Args) {
     use stark::stark_e_griffin_62 as Stark;
     use griffin::p62_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = 4 * args.length + Stark::SIZE_OF_T - 1;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[i] = trace.get(Stark::T_S_HASH.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1135,19 +2181,24 @@ pub fn stark_e_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1158,78 +2209,69 @@ pub fn stark_e_griffin_62(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(16, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_e_griffin_62"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_e_opt(args: &Args) {
-    let name = "STARK E (opt)";
+pub fn stark_e_m10_c2(args: &Args) {
+    let name = "STARK E (m10 c2)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_e_opt as Stark;
-    use rescue::p128_m9_c1_s128 as Hash;
+    use stark::stark_e_m10_c2 as Stark;
+    use rescue::p128_m10_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = (4 * args.length + Stark::SIZE_OF_T - 1) / 5;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; 5 * Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[Hash::RATE * 0 + i] = trace.get(Stark::T_S_HASH_1.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 1 + i] = trace.get(Stark::T_S_HASH_2.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 2 + i] = trace.get(Stark::T_S_HASH_3.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 3 + i] = trace.get(Stark::T_S_HASH_4.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 4 + i] = trace.get(Stark::T_S_HASH_5.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1239,19 +2281,24 @@ pub fn stark_e_opt(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1262,78 +2309,69 @@ pub fn stark_e_opt(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_e_m10_c2"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_e_opt_62(args: &Args) {
-    let name = "STARK E (opt) 62";
+pub fn stark_e_m13_c5_62(args: &Args) {
+    let name = "STARK E (m13 c5) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_e_opt_62 as Stark;
-    use rescue::p62_m9_c1_s128 as Hash;
+    use stark::stark_e_m13_c5_62 as Stark;
+    use rescue::p62_m13_c5_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    let ranks = get_ranks(args, Stark::check_ranks);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = (4 * args.length + Stark::SIZE_OF_T - 1) / 5;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_s = [BaseElement::ZERO; 5 * Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[Hash::RATE * 0 + i] = trace.get(Stark::T_S_HASH_1.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 1 + i] = trace.get(Stark::T_S_HASH_2.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 2 + i] = trace.get(Stark::T_S_HASH_3.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 3 + i] = trace.get(Stark::T_S_HASH_4.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 4 + i] = trace.get(Stark::T_S_HASH_5.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1343,19 +2381,24 @@ pub fn stark_e_opt_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
+    let manual_order_statistics = utils::get_plain_order_statistics(&pixels_u16, &ranks);
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1366,78 +2409,68 @@ pub fn stark_e_opt_62(args: &Args) {
     assert_eq!(max_trace, manual_stats.max_e);
     assert_eq!(med_low_trace, manual_stats.med_low_e);
     assert_eq!(med_high_trace, manual_stats.med_high_e);
+    let order_statistics_trace: Vec<BaseElement> = ranks.iter().map(|rank| trace.get(Stark::T_MED.begin(), *rank)).collect();
+    for (value_trace, value) in order_statistics_trace.iter().zip(manual_order_statistics.iter()) {
+        assert_eq!(*value_trace, BaseElement::from(*value));
+    }
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_e_m13_c5_62"].clone();
+    let prover = Stark::TheProver::with_ranks(options.clone(), ranks.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
+        std_dev: manual_stats.std_dev_e,
         min: min_trace,
         max: max_trace,
         med_low: med_low_trace,
         med_high: med_high_trace,
+        ranks,
+        order_statistics: order_statistics_trace,
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_e_opt_griffin(args: &Args) {
-    let name = "STARK E (opt) (Griffin)";
+pub fn stark_e_opt(args: &Args) {
+    let name = "STARK E (opt)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_e_opt_griffin as Stark;
-    use griffin::p128_t12_c4_s100 as Hash;
+    use stark::stark_e_opt as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = (4 * args.length + Stark::SIZE_OF_T - 1) / 5;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-        let mut hash_s = [BaseElement::ZERO; 5 * Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[Hash::RATE * 0 + i] = trace.get(Stark::T_S_HASH_1.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 1 + i] = trace.get(Stark::T_S_HASH_2.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 2 + i] = trace.get(Stark::T_S_HASH_3.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 3 + i] = trace.get(Stark::T_S_HASH_4.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 4 + i] = trace.get(Stark::T_S_HASH_5.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1447,19 +2480,23 @@ pub fn stark_e_opt_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1476,16 +2513,14 @@ pub fn stark_e_opt_griffin(args: &Args) {
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_e_opt"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
@@ -1496,52 +2531,37 @@ pub fn stark_e_opt_griffin(args: &Args) {
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_e_opt_griffin_62(args: &Args) {
-    let name = "STARK E (opt) (Griffin) 62";
+pub fn stark_e_opt_62(args: &Args) {
+    let name = "STARK E (opt) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_e_opt_griffin_62 as Stark;
-    use griffin::p62_t12_c4_s100 as Hash;
+    use stark::stark_e_opt_62 as Stark;
+    use rescue::p62_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
-    let hash_s_result_step = (4 * args.length + Stark::SIZE_OF_T - 1) / 5;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
     let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_l = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_h = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_med = [BaseElement::ZERO; Hash::RATE];
-    let mut hash_omega_m = [BaseElement::ZERO; Hash::RATE];
-        let mut hash_s = [BaseElement::ZERO; 5 * Hash::RATE];
     for i in 0..Hash::RATE {
         hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-        hash_omega_l[i] = trace.get(Stark::T_OMEGA_L_HASH.idx + i, hash_result_step);
-        hash_omega_h[i] = trace.get(Stark::T_OMEGA_H_HASH.idx + i, hash_result_step);
-        hash_med[i] = trace.get(Stark::T_MED_HASH.idx + i, hash_result_step);
-        hash_omega_m[i] = trace.get(Stark::T_OMEGA_M_HASH.idx + i, hash_result_step);
-        hash_s[Hash::RATE * 0 + i] = trace.get(Stark::T_S_HASH_1.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 1 + i] = trace.get(Stark::T_S_HASH_2.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 2 + i] = trace.get(Stark::T_S_HASH_3.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 3 + i] = trace.get(Stark::T_S_HASH_4.idx + i, hash_s_result_step);
-        hash_s[Hash::RATE * 4 + i] = trace.get(Stark::T_S_HASH_5.idx + i, hash_s_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1551,19 +2571,23 @@ pub fn stark_e_opt_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     #[allow(unused_assignments)]
     let mut med_high_trace = BaseElement::ZERO;
     #[allow(unused_assignments)]
     let mut med_low_trace = BaseElement::ZERO;
-    med_low_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2 - 1) % Stark::CYCLE_LENGTH, stat_result_step / 2);
-    med_high_trace = trace.get(Stark::T_MED.begin() + (stat_result_step / 2) % Stark::CYCLE_LENGTH, stat_result_step / 2 + 1);
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
     let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
@@ -1580,16 +2604,14 @@ pub fn stark_e_opt_griffin_62(args: &Args) {
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_e_opt_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
     let public_inputs = Stark::PubInputs {
         hash_pixels: hash,
-        hash_omega_l,
-        hash_omega_h,
-        hash_med,
-        hash_omega_m,
-        hash_s,
         input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
         sum: manual_stats.sum_e,
         avg_rounded: manual_stats.avg_rounded_e,
         variance: manual_stats.var_e,
@@ -1600,37 +2622,37 @@ pub fn stark_e_opt_griffin_62(args: &Args) {
     };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f(args: &Args) {
-    let name = "STARK F";
+pub fn stark_e_opt_griffin(args: &Args) {
+    let name = "STARK E (opt) (Griffin)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f as Stark;
-    use rescue::p128_m9_c1_s128 as Hash;
+    use stark::stark_e_opt_griffin as Stark;
+    use griffin::p128_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
-        let stat_result_step = hash_result_step;
+    let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
-    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
+    let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
-        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+        hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1640,60 +2662,88 @@ pub fn stark_f(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
-    assert_eq!(hash_trace, hash);
+    assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
-    // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    #[allow(unused_assignments)]
+    let mut med_high_trace = BaseElement::ZERO;
+    #[allow(unused_assignments)]
+    let mut med_low_trace = BaseElement::ZERO;
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
+    let max_trace = trace.get(Stark::T_MAX.begin(), stat_result_step);
     assert_eq!(sum_trace, manual_stats.sum_e);
     assert_eq!(var_trace, manual_stats.var_e);
+    assert_eq!(min_trace, manual_stats.min_e);
+    assert_eq!(max_trace, manual_stats.max_e);
+    assert_eq!(med_low_trace, manual_stats.med_low_e);
+    assert_eq!(med_high_trace, manual_stats.med_high_e);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_e_opt_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs {
+        hash_pixels: hash,
+        input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
+        sum: manual_stats.sum_e,
+        avg_rounded: manual_stats.avg_rounded_e,
+        variance: manual_stats.var_e,
+        min: min_trace,
+        max: max_trace,
+        med_low: med_low_trace,
+        med_high: med_high_trace,
+    };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_62(args: &Args) {
-    let name = "STARK F 62";
+pub fn stark_e_opt_griffin_62(args: &Args) {
+    let name = "STARK E (opt) (Griffin) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_62 as Stark;
-    use rescue::p62_m9_c1_s128 as Hash;
+    use stark::stark_e_opt_griffin_62 as Stark;
+    use griffin::p62_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
     let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
-        let stat_result_step = hash_result_step;
+    let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
-    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), max_value);
+    let mut hash_pixels_trace = [BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
-        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+        hash_pixels_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
     }
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
@@ -1703,57 +2753,84 @@ pub fn stark_f_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
-    assert_eq!(hash_trace, hash);
+    assert_eq!(hash_pixels_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
-    // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    #[allow(unused_assignments)]
+    let mut med_high_trace = BaseElement::ZERO;
+    #[allow(unused_assignments)]
+    let mut med_low_trace = BaseElement::ZERO;
+    med_low_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2);
+    med_high_trace = trace.get(Stark::T_MED.begin(), stat_result_step / 2 + 1);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    let min_trace = trace.get(Stark::T_MIN.begin(), stat_result_step);
+    let max_trace = trace.get(Stark::T_MAX.begin(), stat_result_step);
     assert_eq!(sum_trace, manual_stats.sum_e);
     assert_eq!(var_trace, manual_stats.var_e);
+    assert_eq!(min_trace, manual_stats.min_e);
+    assert_eq!(max_trace, manual_stats.max_e);
+    assert_eq!(med_low_trace, manual_stats.med_low_e);
+    assert_eq!(med_high_trace, manual_stats.med_high_e);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_e_opt_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs {
+        hash_pixels: hash,
+        input_length: BaseElement::new(args.length as u64),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u64),
+        sum: manual_stats.sum_e,
+        avg_rounded: manual_stats.avg_rounded_e,
+        variance: manual_stats.var_e,
+        min: min_trace,
+        max: max_trace,
+        med_low: med_low_trace,
+        med_high: med_high_trace,
+    };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_64(args: &Args) {
-    let name = "STARK F 64";
+pub fn stark_f(args: &Args) {
+    let name = "STARK F";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_64 as Stark;
-    use rescue::p64_m9_c1_s128 as Hash;
+    use stark::stark_f as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -1766,57 +2843,63 @@ pub fn stark_f_64(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
-    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
-    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_griffin(args: &Args) {
-    let name = "STARK F (Griffin)";
+pub fn stark_f_62(args: &Args) {
+    let name = "STARK F 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_griffin as Stark;
-    use griffin::p128_t12_c4_s100 as Hash;
+    use stark::stark_f_62 as Stark;
+    use rescue::p62_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -1829,57 +2912,63 @@ pub fn stark_f_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
-    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
-    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_griffin_62(args: &Args) {
-    let name = "STARK F 62 (Griffin)";
-    trace!("BEGIN scenario {}", name);
-    use stark::stark_f_griffin_62 as Stark;
-    use griffin::p62_t12_c4_s100 as Hash;
+pub fn stark_f_64(args: &Args) {
+    let name = "STARK F 64";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_64 as Stark;
+    use rescue::p64_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -1892,57 +2981,63 @@ pub fn stark_f_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_64"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m2(args: &Args) {
-    let name = "STARK F (opt m2)";
+pub fn stark_f_64_rpo(args: &Args) {
+    let name = "STARK F 64 (RPO)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m2 as Stark;
-    use rescue::p128_m17_c1_s128 as Hash;
+    use stark::stark_f_64_rpo as Stark;
+    use rescue::p64_m12_c4_s128_rpo as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -1955,120 +3050,143 @@ pub fn stark_f_opt_m2(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
-    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
-    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_64_rpo"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m2_62(args: &Args) {
-    let name = "STARK F (opt m2) 62";
+pub fn stark_f_frames(args: &Args) {
+    let name = "STARK F frames";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m2_62 as Stark;
-    use rescue::p62_m17_c1_s128 as Hash;
+    use stark::stark_f_frames as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
-        let stat_result_step = hash_result_step;
+    if matches!(&args.command, Some(Command::Prove { opening: Some(_), .. }) | Some(Command::Prove { salt_from: Some(_), .. })) {
+        eprintln!("{} does not support salted commitments", name);
+        std::process::exit(2);
+    }
+    let frame_size = get_frame(args).frame_size();
+    if args.length % frame_size != 0 || args.num_frames == 0 || (args.first_frame + args.num_frames) * frame_size > args.length {
+        eprintln!("Invalid range of {} frames starting at frame {} for an input of {} pixels in frames of {} pixels", args.num_frames, args.first_frame, args.length, frame_size);
+        std::process::exit(2);
+    }
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
-    // TRACE CONSTRUCTION
+    // COMMITMENT to the whole video, a Merkle tree over the frame digests
+    trace!("Starting to build the frame tree ..");
+    let now = Instant::now();
+    let tree = Stark::FrameTree::new(&pixels_u16, frame_size);
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished building the frame tree of {} frames in {} ms!", tree.num_frames(), manual_hash_time);
+    let pixels_range = pixels_u16[args.first_frame * frame_size..(args.first_frame + args.num_frames) * frame_size].to_vec();
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION of the proven range of frames
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
-    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
-    for i in 0..Hash::RATE {
-        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
-    }
+    let trace = Stark::build_trace(&pixels_range, frame_size);
     let build_trace_time = now.elapsed().as_millis();
     trace!("Finished building the trace in {} ms!", build_trace_time);
 
     //------------------------------------------------------------------------------------------
     // STARK COMPUTATION CHECKS
-    // AET computes the same hash value as the native hash function
-    trace!("Starting to calculate hash manually ..");
-    let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
-    let manual_hash_time = now.elapsed().as_millis();
-    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
-    assert_eq!(hash_trace, hash);
-    trace!("AET Hash and manual hash are equal!");
+    // AET computes the leaves of the frame tree
+    for frame in 0..args.num_frames {
+        let step = Stark::TheAir::get_digest_step(frame_size, frame);
+        let mut digest_trace = vec![BaseElement::ZERO; Hash::RATE];
+        for i in 0..Hash::RATE {
+            digest_trace[i] = trace.get(Stark::TheAir::T_PIXELS_HASH.idx + i, step);
+        }
+        assert_eq!(digest_trace, tree.get_leaf(args.first_frame + frame));
+    }
+    trace!("AET frame digests and leaves of the frame tree are equal!");
 
     // comparison of statistics
-    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
-    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
-    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
-    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_range);
+    let stat_result_step = Stark::TheAir::get_result_step(frame_size, args.num_frames);
+    let sum_trace = trace.get(Stark::TheAir::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::TheAir::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding of every frame in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(frame_size, args.num_frames, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_frames"].clone();
+    let prover = Stark::TheProver::new(options.clone(), &tree, args.first_frame, args.num_frames);
+    let public_inputs = prover.get_pub_inputs(&trace);
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    assert!(Stark::verify_paths(&public_inputs).is_ok());
+    trace!("Authentication paths of the frames lead to the root!");
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m2_griffin(args: &Args) {
-    let name = "STARK F (opt m2) (Griffin)";
+pub fn stark_f_griffin(args: &Args) {
+    let name = "STARK F (Griffin)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m2_griffin as Stark;
-    use griffin::p128_t20_c4_s100 as Hash;
+    use stark::stark_f_griffin as Stark;
+    use griffin::p128_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2081,57 +3199,63 @@ pub fn stark_f_opt_m2_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m2_griffin_62(args: &Args) {
-    let name = "STARK F (opt m2) (Griffin) 62";
+pub fn stark_f_griffin_62(args: &Args) {
+    let name = "STARK F 62 (Griffin)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m2_griffin_62 as Stark;
-    use griffin::p62_t20_c4_s100 as Hash;
+    use stark::stark_f_griffin_62 as Stark;
+    use griffin::p62_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2144,57 +3268,63 @@ pub fn stark_f_opt_m2_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m4(args: &Args) {
-    let name = "STARK F (opt m4)";
+pub fn stark_f_m10_c2(args: &Args) {
+    let name = "STARK F (m10 c2)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m4 as Stark;
-    use rescue::p128_m33_c1_s128 as Hash;
+    use stark::stark_f_m10_c2 as Stark;
+    use rescue::p128_m10_c2_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2207,57 +3337,63 @@ pub fn stark_f_opt_m4(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_m10_c2"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m4_62(args: &Args) {
-    let name = "STARK F (opt m4) 62";
+pub fn stark_f_m13_c5_62(args: &Args) {
+    let name = "STARK F (m13 c5) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m4_62 as Stark;
-    use rescue::p62_m33_c1_s128 as Hash;
+    use stark::stark_f_m13_c5_62 as Stark;
+    use rescue::p62_m13_c5_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2270,57 +3406,63 @@ pub fn stark_f_opt_m4_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_m13_c5_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m4_griffin(args: &Args) {
-    let name = "STARK F (opt m4) (Griffin)";
+pub fn stark_f_opt_m2(args: &Args) {
+    let name = "STARK F (opt m2)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m4_griffin as Stark;
-    use griffin::p128_t36_c4_s100 as Hash;
+    use stark::stark_f_opt_m2 as Stark;
+    use rescue::p128_m17_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2333,57 +3475,63 @@ pub fn stark_f_opt_m4_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m2"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m4_griffin_62(args: &Args) {
-    let name = "STARK F (opt m4) (Griffin) 62";
+pub fn stark_f_opt_m2_62(args: &Args) {
+    let name = "STARK F (opt m2) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m4_griffin_62 as Stark;
-    use griffin::p62_t36_c4_s100 as Hash;
+    use stark::stark_f_opt_m2_62 as Stark;
+    use rescue::p62_m17_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2396,57 +3544,63 @@ pub fn stark_f_opt_m4_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m2_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m8(args: &Args) {
-    let name = "STARK F (opt m8)";
+pub fn stark_f_opt_m2_griffin(args: &Args) {
+    let name = "STARK F (opt m2) (Griffin)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m8 as Stark;
-    use rescue::p128_m65_c1_s128 as Hash;
+    use stark::stark_f_opt_m2_griffin as Stark;
+    use griffin::p128_t20_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2459,57 +3613,63 @@ pub fn stark_f_opt_m8(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m2_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m8_62(args: &Args) {
-    let name = "STARK F (opt m8) 62";
+pub fn stark_f_opt_m2_griffin_62(args: &Args) {
+    let name = "STARK F (opt m2) (Griffin) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m8_62 as Stark;
-    use rescue::p62_m65_c1_s128 as Hash;
+    use stark::stark_f_opt_m2_griffin_62 as Stark;
+    use griffin::p62_t20_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2522,57 +3682,63 @@ pub fn stark_f_opt_m8_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m2_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m8_griffin(args: &Args) {
-    let name = "STARK F (opt m8) (Griffin)";
+pub fn stark_f_opt_m4(args: &Args) {
+    let name = "STARK F (opt m4)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m8_griffin as Stark;
-    use griffin::p128_t68_c4_s100 as Hash;
+    use stark::stark_f_opt_m4 as Stark;
+    use rescue::p128_m33_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2585,57 +3751,63 @@ pub fn stark_f_opt_m8_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m4"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_f_opt_m8_griffin_62(args: &Args) {
-    let name = "STARK F (opt m8) (Griffin) 62";
+pub fn stark_f_opt_m4_62(args: &Args) {
+    let name = "STARK F (opt m4) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_f_opt_m8_griffin_62 as Stark;
-    use griffin::p62_t68_c4_s100 as Hash;
+    use stark::stark_f_opt_m4_62 as Stark;
+    use rescue::p62_m33_c1_s128 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = args.length * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
         let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2648,57 +3820,63 @@ pub fn stark_f_opt_m8_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m4_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_g(args: &Args) {
-    let name = "STARK G";
+pub fn stark_f_opt_m4_griffin(args: &Args) {
+    let name = "STARK F (opt m4) (Griffin)";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_g as Stark;
-    use rescue::p128_m9_c1_s128 as Hash;
+    use stark::stark_f_opt_m4_griffin as Stark;
+    use griffin::p128_t36_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = Stark::CYCLE_LENGTH_ROI * (args.length / Stark::FRAME_SIZE) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
-    let stat_result_step = hash_result_step;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2711,64 +3889,63 @@ pub fn stark_g(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
-    let mut roi_pixels = vec![];
-    let stat_mask = Stark::get_stat_mask_roi();
-    for i in 0..pixels_u16.len() {
-        if stat_mask[i % Stark::FRAME_SIZE] == BaseElement::ONE {
-            roi_pixels.push(pixels_u16[i]);
-        }
-    }
-    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(roi_pixels);
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u128(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m4_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_g_62(args: &Args) {
-    let name = "STARK G 62";
+pub fn stark_f_opt_m4_griffin_62(args: &Args) {
+    let name = "STARK F (opt m4) (Griffin) 62";
     trace!("BEGIN scenario {}", name);
-    use stark::stark_g_62 as Stark;
-    use rescue::p62_m9_c1_s128 as Hash;
+    use stark::stark_f_opt_m4_griffin_62 as Stark;
+    use griffin::p62_t36_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = Stark::CYCLE_LENGTH_ROI * (args.length / Stark::FRAME_SIZE) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
-    let stat_result_step = hash_result_step;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2781,64 +3958,875 @@ pub fn stark_g_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
-    let mut roi_pixels = vec![];
-    let stat_mask = Stark::get_stat_mask_roi();
-    for i in 0..pixels_u16.len() {
-        if stat_mask[i % Stark::FRAME_SIZE] == BaseElement::ONE {
-            roi_pixels.push(pixels_u16[i]);
-        }
-    }
-    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(roi_pixels);
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
     let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
     let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
-    assert_eq!(sum_trace, manual_stats.sum_e);
-    assert_eq!(var_trace, manual_stats.var_e);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
     trace!("{}", utils::get_stats_string_u64(&manual_stats));
 
     //------------------------------------------------------------------------------------------
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m4_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
-pub fn stark_g_griffin(args: &Args) {
-    let name = "STARK G (Griffin)";
+pub fn stark_f_opt_m8(args: &Args) {
+    let name = "STARK F (opt m8)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_opt_m8 as Stark;
+    use rescue::p128_m65_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m8"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_opt_m8_62(args: &Args) {
+    let name = "STARK F (opt m8) 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_opt_m8_62 as Stark;
+    use rescue::p62_m65_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m8_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_opt_m8_griffin(args: &Args) {
+    let name = "STARK F (opt m8) (Griffin)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_opt_m8_griffin as Stark;
+    use griffin::p128_t68_c4_s100 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m8_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_opt_m8_griffin_62(args: &Args) {
+    let name = "STARK F (opt m8) (Griffin) 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_opt_m8_griffin_62 as Stark;
+    use griffin::p62_t68_c4_s100 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_opt_m8_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_poseidon(args: &Args) {
+    let name = "STARK F (Poseidon)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_poseidon as Stark;
+    use poseidon::p128_t9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_poseidon"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_poseidon_62(args: &Args) {
+    let name = "STARK F (Poseidon) 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_poseidon_62 as Stark;
+    use poseidon::p62_t9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_poseidon_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_poseidon_64(args: &Args) {
+    let name = "STARK F (Poseidon) 64";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_poseidon_64 as Stark;
+    use poseidon::p64_t9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let hash_result_step = Stark::TheAir::get_num_blocks(args.length) * Stark::CYCLE_LENGTH;
+        let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref());
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::Permutation::hash_padded_salted(&pixels_base, salt),
+        None => Hash::Permutation::hash_padded(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(pixels_u16);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    // the AET includes the padding in the statistics
+    let (padding_sum, padding_var) = Stark::TheAir::get_padding_stats(args.length, manual_stats.avg_rounded_e);
+    assert_eq!(sum_trace, manual_stats.sum_e + padding_sum);
+    assert_eq!(var_trace, manual_stats.var_e + padding_var);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_poseidon_64"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_segmented(args: &Args) {
+    let name = "STARK F segmented";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_segment as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    if matches!(&args.command, Some(Command::Prove { opening: Some(_), .. }) | Some(Command::Prove { salt_from: Some(_), .. })) {
+        eprintln!("{} does not support salted commitments", name);
+        std::process::exit(2);
+    }
+    if let Some(Command::Prove { commitment: Some(_), .. }) = &args.command {
+        eprintln!("{} does not support fixed commitments", name);
+        std::process::exit(2);
+    }
+    let frame_size = get_frame(args).frame_size();
+    let segment_length = args.segment_frames * frame_size;
+    let segments_check = if args.length % frame_size != 0 {
+        Err(format!("the input of {} pixels does not consist of whole frames", args.length))
+    } else {
+        Stark::check_segments(args.length, segment_length)
+    };
+    if let Err(err) = segments_check {
+        eprintln!("Invalid segments of {} frames of {} pixels: {}", args.segment_frames, frame_size, err);
+        std::process::exit(2);
+    }
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // PROVING, the segments concurrently
+    // the traces are built by the workers, building them is part of the prover time
+    let build_trace_time = 0;
+    let options = STARK_PROOF_OPTIONS["stark_f_segmented"].clone();
+    let num_workers = get_num_workers(args, Stark::estimate_memory(segment_length, &options));
+    trace!("Starting to generate the proofs of the segments on {} threads ..", num_workers);
+    let now = Instant::now();
+    let segments = Stark::prove_segments(&pixels_u16, segment_length, options.clone(), num_workers);
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proofs of {} segments in {} ms!", segments.len(), prover_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // the last segment ends with the hash value of the native hash function, computed frame by frame
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let mut hasher = permutation::Hasher::<Hash::Permutation>::new();
+    for frame in pixels_u16.chunks(frame_size) {
+        hasher.update(frame);
+    }
+    let num_pixels = hasher.num_pixels();
+    let hash = hasher.finalize().expect("the segments consist of whole blocks");
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash of {} pixels in {} ms! Comparing Hashes ..", num_pixels, manual_hash_time);
+    assert_eq!(segments.last().unwrap().1.state_out[..Hash::RATE], hash);
+    trace!("Hash of the last segment and manual hash are equal!");
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(pixels_u16);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // VERIFYING the chain of segments
+    let verifier_time = if let Some(Command::Prove { output, .. }) = &args.command {
+        write_segments(args, output, frame_size, options, segments)
+    } else {
+        trace!("Starting verification of the chain of segments ..");
+        let now = Instant::now();
+        let outputs = match Stark::verify_segments(segments) {
+            Ok(outputs) => outputs,
+            Err(err) => {
+                eprintln!("Verification of the chain of segments failed: {}", err);
+                std::process::exit(1);
+            }
+        };
+        let verifier_time = now.elapsed().as_millis();
+        trace!("Chain of segments verified successfully in {} ms!", verifier_time);
+
+        // the combined outputs are the hash and the statistics of the whole video
+        assert_eq!(outputs.hash, hash);
+        assert_eq!(outputs.input_length, args.length);
+        assert_eq!(outputs.sum, manual_stats.sum_e);
+        assert_eq!(outputs.avg_rounded, manual_stats.avg_rounded_e);
+        assert_eq!(outputs.variance, manual_stats.var_e);
+        verifier_time
+    };
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_f_series(args: &Args) {
+    let name = "STARK F series";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_f_series as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    if matches!(&args.command, Some(Command::Prove { opening: Some(_), .. }) | Some(Command::Prove { salt_from: Some(_), .. })) {
+        eprintln!("{} does not support salted commitments", name);
+        std::process::exit(2);
+    }
+    let frame_size = get_frame(args).frame_size();
+    if args.length % frame_size != 0 || args.num_frames == 0 || (args.first_frame + args.num_frames) * frame_size > args.length || frame_size < Hash::RATE {
+        eprintln!("Invalid range of {} frames starting at frame {} for an input of {} pixels in frames of {} pixels (at least {})", args.num_frames, args.first_frame, args.length, frame_size, Hash::RATE);
+        std::process::exit(2);
+    }
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, _) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // COMMITMENT to the whole video, a Merkle tree over the frame digests
+    trace!("Starting to build the frame tree ..");
+    let now = Instant::now();
+    let tree = Stark::FrameTree::new(&pixels_u16, frame_size);
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished building the frame tree of {} frames in {} ms!", tree.num_frames(), manual_hash_time);
+    let pixels_range = pixels_u16[args.first_frame * frame_size..(args.first_frame + args.num_frames) * frame_size].to_vec();
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION of the proven range of frames
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let trace = Stark::build_trace(&pixels_range, frame_size);
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the leaves of the frame tree
+    for frame in 0..args.num_frames {
+        let step = stark::stark_f_frames::TheAir::get_digest_step(frame_size, frame);
+        let mut digest_trace = vec![BaseElement::ZERO; Hash::RATE];
+        for i in 0..Hash::RATE {
+            digest_trace[i] = trace.get(Stark::TheAir::T_PIXELS_HASH.idx + i, step);
+        }
+        assert_eq!(digest_trace, tree.get_leaf(args.first_frame + frame));
+    }
+    trace!("AET frame digests and leaves of the frame tree are equal!");
+
+    // AET computes the digest of the time series
+    let series = Stark::get_series(&pixels_range, frame_size);
+    let series_step = Stark::TheAir::get_series_step(frame_size, args.num_frames);
+    let mut series_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        series_trace[i] = trace.get(Stark::TheAir::T_SERIES_HASH.idx + i, series_step);
+    }
+    assert_eq!(series_trace, Stark::TheAir::hash_series(&series));
+    trace!("AET digest and manual digest of the time series of {} frames are equal!", series.len());
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_f_series"].clone();
+    let prover = Stark::TheProver::new(options.clone(), &tree, args.first_frame, args.num_frames);
+    let public_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    assert!(Stark::verify_paths(&public_inputs).is_ok());
+    assert!(Stark::verify_series(&public_inputs, &series).is_ok());
+    trace!("Authentication paths of the frames lead to the root and the time series opens its digest!");
+    if let Some(Command::Prove { series: Some(path), .. }) = &args.command {
+        if let Err(err) = TimeSeries::new(args.stark.as_ref().unwrap(), args.first_frame, series).write(path) {
+            eprintln!("Failed to write the time series {}: {}", path.display(), err);
+            std::process::exit(1);
+        }
+        trace!("Time series written to {}!", path.display());
+    }
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_g(args: &Args) {
+    let name = "STARK G";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_g as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(roi_pixels);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    assert_eq!(sum_trace, manual_stats.sum_e);
+    assert_eq!(var_trace, manual_stats.var_e);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_g"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_g_62(args: &Args) {
+    let name = "STARK G 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_g_62 as Stark;
+    use rescue::p62_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(roi_pixels);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    assert_eq!(sum_trace, manual_stats.sum_e);
+    assert_eq!(var_trace, manual_stats.var_e);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_g_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_g_griffin(args: &Args) {
+    let name = "STARK G (Griffin)";
     trace!("BEGIN scenario {}", name);
     use stark::stark_g_griffin as Stark;
     use griffin::p128_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = Stark::CYCLE_LENGTH_ROI * (args.length / Stark::FRAME_SIZE) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2851,17 +4839,21 @@ pub fn stark_g_griffin(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let mut roi_pixels = vec![];
-    let stat_mask = Stark::get_stat_mask_roi();
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
     for i in 0..pixels_u16.len() {
-        if stat_mask[i % Stark::FRAME_SIZE] == BaseElement::ONE {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
             roi_pixels.push(pixels_u16[i]);
         }
     }
@@ -2876,17 +4868,15 @@ pub fn stark_g_griffin(args: &Args) {
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::None));
+    let options = STARK_PROOF_OPTIONS["stark_g_griffin"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
-    let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
 
 pub fn stark_g_griffin_62(args: &Args) {
@@ -2895,20 +4885,23 @@ pub fn stark_g_griffin_62(args: &Args) {
     use stark::stark_g_griffin_62 as Stark;
     use griffin::p62_t12_c4_s100 as Hash;
     type BaseElement = Hash::Elem;
-    let hash_result_step = Stark::CYCLE_LENGTH_ROI * (args.length / Stark::FRAME_SIZE) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
     let stat_result_step = hash_result_step;
 
-    trace!("Starting to generate random input sequence of {} elements ..", args.length);
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    let (pixels_u16, pixels_base) = utils::get_rand_values::<BaseElement>(args.begin, args.end, args.length);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
     let rand_time = now.elapsed().as_millis();
-    trace!("Finished generating random input sequence of {} elements in {} ms!", args.length, rand_time);
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
 
     //------------------------------------------------------------------------------------------
     // TRACE CONSTRUCTION
     trace!("Starting to build the trace ..");
     let now = Instant::now();
-    let trace = Stark::build_trace(&pixels_u16);
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
     let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
     for i in 0..Hash::RATE {
         hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
@@ -2921,17 +4914,21 @@ pub fn stark_g_griffin_62(args: &Args) {
     // AET computes the same hash value as the native hash function
     trace!("Starting to calculate hash manually ..");
     let now = Instant::now();
-    let hash = Hash::hash(&pixels_base);
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
     let manual_hash_time = now.elapsed().as_millis();
     trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
     assert_eq!(hash_trace, hash);
     trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
 
     // comparison of statistics
     let mut roi_pixels = vec![];
-    let stat_mask = Stark::get_stat_mask_roi();
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
     for i in 0..pixels_u16.len() {
-        if stat_mask[i % Stark::FRAME_SIZE] == BaseElement::ONE {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
             roi_pixels.push(pixels_u16[i]);
         }
     }
@@ -2946,15 +4943,341 @@ pub fn stark_g_griffin_62(args: &Args) {
     // PROVING and VERIFYING
     trace!("Starting to generate the proof ..");
     let now = Instant::now();
-    let prover = Stark::TheProver::new(utils::get_proof_options(8, FieldExtension::Quadratic));
+    let options = STARK_PROOF_OPTIONS["stark_g_griffin_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
     let proof = prover.prove(trace).unwrap();
-    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e };
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
     let prover_time = now.elapsed().as_millis();
     trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_g_m10_c2(args: &Args) {
+    let name = "STARK G (m10 c2)";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_g_m10_c2 as Stark;
+    use rescue::p128_m10_c2_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
     let now = Instant::now();
-    assert!(winterfell::verify::<Stark::TheAir>(proof, public_inputs).is_ok());
-    let verifier_time = now.elapsed().as_millis();
-    trace!("Proof verified successfully in {} ms!", verifier_time);
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_stats = utils::get_plain_statistics_u128::<BaseElement>(roi_pixels);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    assert_eq!(sum_trace, manual_stats.sum_e);
+    assert_eq!(var_trace, manual_stats.var_e);
+    trace!("{}", utils::get_stats_string_u128(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_g_m10_c2"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u128), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_g_m13_c5_62(args: &Args) {
+    let name = "STARK G (m13 c5) 62";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_g_m13_c5_62 as Stark;
+    use rescue::p62_m13_c5_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of statistics
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_stats = utils::get_plain_statistics_u64::<BaseElement>(roi_pixels);
+    let sum_trace = trace.get(Stark::T_SUM.begin(), stat_result_step);
+    let var_trace = trace.get(Stark::T_VAR.begin(), stat_result_step);
+    assert_eq!(sum_trace, manual_stats.sum_e);
+    assert_eq!(var_trace, manual_stats.var_e);
+    trace!("{}", utils::get_stats_string_u64(&manual_stats));
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_g_m13_c5_62"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs { hash, input_length: BaseElement::new(args.length as u64), salted: salt.is_some(), sum: manual_stats.sum_e, avg_rounded: manual_stats.avg_rounded_e, variance: manual_stats.var_e, frame };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_threshold(args: &Args) {
+    let name = "STARK THRESHOLD";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_threshold as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    if args.threshold as usize > max_value {
+        eprintln!("Invalid threshold {}, must not exceed {}", args.threshold, max_value);
+        std::process::exit(2);
+    }
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), args.threshold, max_value, &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of the outcome
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_stats = utils::get_plain_statistics_threshold_u128::<BaseElement>(roi_pixels, args.threshold);
+    let exceeded = manual_stats.count_above > 0;
+    let exceeded_trace = trace.get(Stark::T_EXCEEDED.begin(), stat_result_step);
+    let count_trace = trace.get(Stark::T_COUNT.begin(), stat_result_step);
+    assert_eq!(exceeded_trace, if exceeded { BaseElement::ONE } else { BaseElement::ZERO });
+    assert_eq!(count_trace, manual_stats.count_above_e);
+    trace!("{} pixels inside the ROIs exceed the threshold {}", manual_stats.count_above, args.threshold);
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_threshold"].clone();
+    let prover = Stark::TheProver::new(options.clone(), args.count);
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs {
+        hash,
+        input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
+        threshold: BaseElement::new(args.threshold as u128),
+        exceeded,
+        count: if args.count { Some(manual_stats.count_above_e) } else { None },
+        frame,
+    };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
+    trace!("END scenario {}", name);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+}
+
+pub fn stark_histogram(args: &Args) {
+    let name = "STARK HISTOGRAM";
+    trace!("BEGIN scenario {}", name);
+    use stark::stark_histogram as Stark;
+    use rescue::p128_m9_c1_s128 as Hash;
+    type BaseElement = Hash::Elem;
+    let salt: Option<[BaseElement; Hash::RATE]> = get_salt(args);
+    let frame = get_frame(args);
+    let max_value = get_max_value(args, Stark::MAX_VALUE_STEP);
+    if let Err(err) = Stark::check_edges(&args.bin_edge, max_value) {
+        eprintln!("Invalid bin edges: {}", err);
+        std::process::exit(2);
+    }
+    let hash_result_step = frame.cycle_length_roi() * (args.length / frame.frame_size()) * Stark::CYCLE_LENGTH / Stark::NUM_ELEMS_PER_CYCLE;
+    let stat_result_step = hash_result_step;
+
+    trace!("Starting to generate input sequence of {} elements ..", args.length);
+    let now = Instant::now();
+    let (pixels_u16, pixels_base) = get_input_values::<BaseElement>(args);
+    let rand_time = now.elapsed().as_millis();
+    trace!("Finished generating input sequence of {} elements in {} ms!", args.length, rand_time);
+
+    //------------------------------------------------------------------------------------------
+    // TRACE CONSTRUCTION
+    trace!("Starting to build the trace ..");
+    let now = Instant::now();
+    let hash_result_step = if salt.is_some() { hash_result_step + Stark::CYCLE_LENGTH } else { hash_result_step };
+    let trace = Stark::build_salted_trace(&pixels_u16, salt.as_ref(), &args.bin_edge, max_value, &frame);
+    let mut hash_trace = vec![BaseElement::ZERO; Hash::RATE];
+    for i in 0..Hash::RATE {
+        hash_trace[i] = trace.get(Stark::T_PIXELS_HASH.idx + i, hash_result_step);
+    }
+    let build_trace_time = now.elapsed().as_millis();
+    trace!("Finished building the trace in {} ms!", build_trace_time);
+
+    //------------------------------------------------------------------------------------------
+    // STARK COMPUTATION CHECKS
+    // AET computes the same hash value as the native hash function
+    trace!("Starting to calculate hash manually ..");
+    let now = Instant::now();
+    let hash = match &salt {
+        Some(salt) => Hash::hash_salted(&pixels_base, salt),
+        None => Hash::hash(&pixels_base),
+    };
+    let manual_hash_time = now.elapsed().as_millis();
+    trace!("Finished calculating hash in {} ms! Comparing Hashes ..", manual_hash_time);
+    assert_eq!(hash_trace, hash);
+    trace!("AET Hash and manual hash are equal!");
+    write_opening(args, &pixels_u16, salt.as_ref());
+
+    // comparison of the histogram
+    let mut roi_pixels = vec![];
+    let stat_mask = frame.get_stat_mask_roi::<BaseElement>();
+    for i in 0..pixels_u16.len() {
+        if stat_mask[i % frame.frame_size()] == BaseElement::ONE {
+            roi_pixels.push(pixels_u16[i]);
+        }
+    }
+    let manual_histogram = utils::get_plain_histogram(&roi_pixels, &args.bin_edge);
+    let counts: Vec<BaseElement> = manual_histogram.iter().map(|count| BaseElement::new(*count as u128)).collect();
+    for (k, count) in counts.iter().enumerate() {
+        assert_eq!(trace.get(Stark::T_COUNTS.begin() + k, stat_result_step), *count);
+    }
+    trace!("Histogram over the bin edges {:?}: {:?}", args.bin_edge, manual_histogram);
+
+    //------------------------------------------------------------------------------------------
+    // PROVING and VERIFYING
+    trace!("Starting to generate the proof ..");
+    let now = Instant::now();
+    let options = STARK_PROOF_OPTIONS["stark_histogram"].clone();
+    let prover = Stark::TheProver::new(options.clone());
+    let proof = prover.prove(trace).unwrap();
+    let public_inputs = Stark::PubInputs {
+        hash,
+        input_length: BaseElement::new(args.length as u128),
+        salted: salt.is_some(),
+        max_value: BaseElement::new(max_value as u128),
+        edges: args.bin_edge.clone(),
+        counts,
+        frame,
+    };
+    let prover_time = now.elapsed().as_millis();
+    trace!("Finished generating the proof in {} ms! Starting verification ..", prover_time);
+    let verifier_time = finish_proof::<Stark::TheAir>(args, options, proof, public_inputs);
     trace!("END scenario {}", name);
-    info!("{};{};{};{};{};{};{};{};{};{}", COMPILE_VARIANT, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
+    info!("{};{};{};{};{};{};{};{};{};{}", args.bit_depth, name, args.begin, args.end, args.length, rand_time, build_trace_time, manual_hash_time, prover_time, verifier_time);
 }
\ No newline at end of file
@@ -0,0 +1,223 @@
+use std::array::TryFromSliceError;
+use std::fmt::Debug;
+use winterfell::math::{fields, ExtensibleField, FieldElement, StarkField};
+use winterfell::TransitionConstraintDegree;
+
+// Common interface of the hash instances (rescue, griffin, poseidon): every instance module exports
+// a unit struct `Permutation` implementing `HashPermutation` by delegating to its free functions.
+// The generic STARK families (see stark::family) are written once against this trait and get
+// instantiated for a field and a hash instance, e.g. StarkF<f62::BaseElement, p62_m9_c1_s128::Permutation>.
+
+pub trait HashPermutation: Send + Sync {
+    type BaseField: StarkBaseField;
+    /// output of the hash function, an array of RATE elements
+    type Digest: Copy + Debug + PartialEq + Send + Sync + AsRef<[Self::BaseField]> + for<'a> TryFrom<&'a [Self::BaseField], Error = TryFromSliceError>;
+
+    const NUM_ROUNDS: usize;
+    const STATE_WIDTH: usize;
+    const RATE: usize;
+    /// number of periodic columns returned by `get_round_constants_periodic`
+    const NUM_PERIODIC_COLUMNS: usize;
+
+    fn hash(input_sequence: &Vec<Self::BaseField>) -> Self::Digest;
+
+    fn hash_salted(input_sequence: &Vec<Self::BaseField>, salt: &Self::Digest) -> Self::Digest;
+
+    /// hash of the input padded with 1 || 0* (see `get_padding`), supports inputs of any length
+    fn hash_padded(input_sequence: &Vec<Self::BaseField>) -> Self::Digest {
+        let mut padded_input = input_sequence.clone();
+        padded_input.append(&mut get_padding(input_sequence.len(), Self::RATE));
+        Self::hash(&padded_input)
+    }
+
+    /// salted hash of the input padded with 1 || 0*, the salt is absorbed after the padding
+    fn hash_padded_salted(input_sequence: &Vec<Self::BaseField>, salt: &Self::Digest) -> Self::Digest {
+        let mut padded_input = input_sequence.clone();
+        padded_input.append(&mut get_padding(input_sequence.len(), Self::RATE));
+        Self::hash_salted(&padded_input, salt)
+    }
+
+    fn apply_round(state: &mut [Self::BaseField], round: usize);
+
+    /// when flag = 1, enforces constraints for a single round
+    fn enforce_round<E: FieldElement + From<Self::BaseField>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    );
+
+    /// when flag = 1, enforces constraints for absorbing the pixels followed by the first round
+    fn enforce_first_round<E: FieldElement + From<Self::BaseField>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    );
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Self::BaseField>>;
+
+    /// degree of the round constraints multiplied by periodic flags with the given cycle lengths, the
+    /// first one being the cycle of the hash
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree;
+}
+
+/// Padding 1 || 0* of an input of `input_length` elements to whole blocks of `rate` elements (as in
+/// Algorithm 2 of Rescue-Prime): the element ONE followed by zeros, a whole block if the input already
+/// fills whole blocks.
+pub fn get_padding<E: FieldElement>(input_length: usize, rate: usize) -> Vec<E> {
+    let mut result = vec![E::ZERO; rate - input_length % rate];
+    result[0] = E::ONE;
+    result
+}
+
+// The base fields of winterfell the hash instances are defined over. `as_u128` unifies `as_int`,
+// which returns u128 for f128 and u64 for f62 and f64.
+pub trait StarkBaseField: StarkField + ExtensibleField<2> + ExtensibleField<3> {
+    fn as_u128(&self) -> u128;
+}
+
+impl StarkBaseField for fields::f128::BaseElement {
+    fn as_u128(&self) -> u128 {
+        self.as_int()
+    }
+}
+
+impl StarkBaseField for fields::f62::BaseElement {
+    fn as_u128(&self) -> u128 {
+        self.as_int() as u128
+    }
+}
+
+impl StarkBaseField for fields::f64::BaseElement {
+    fn as_u128(&self) -> u128 {
+        self.as_int() as u128
+    }
+}
+
+// Incremental computation of the hash of a pixel stream, e.g. of a video frame by frame, without
+// holding the whole input in memory. `Hasher::new` absorbs one pixel per element like STARK A, B and
+// E-G, `Hasher::with_packing` packs several 16-bit pixels into one element like the COMPRESSOR of
+// STARK C and D. The result equals `H::hash` (or `H::hash_salted`) of the packed elements, or
+// `H::hash_padded` (`H::hash_padded_salted`) for a hasher created with `padded`.
+
+const NUM_BITS_PER_PIXEL: usize = 16;
+
+pub struct Hasher<H: HashPermutation> {
+    state: Vec<H::BaseField>,
+    // index of the next element of the rate to be absorbed into
+    absorb_index: usize,
+    // factors of the pixels of one element, 2^(16 * i) for the i-th pixel
+    compressor: Vec<H::BaseField>,
+    // pixels of the next element which is not complete yet
+    pending: Vec<u16>,
+    num_pixels: usize,
+    padded: bool,
+}
+
+impl<H: HashPermutation> Default for Hasher<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: HashPermutation> Hasher<H> {
+    pub fn new() -> Self {
+        Self::with_packing(1)
+    }
+
+    pub fn with_packing(pixels_per_elem: usize) -> Self {
+        assert!(pixels_per_elem > 0);
+        assert!(NUM_BITS_PER_PIXEL * pixels_per_elem <= H::BaseField::MODULUS_BITS as usize);
+        let mut compressor = vec![H::BaseField::ONE; pixels_per_elem];
+        for i in 1..pixels_per_elem {
+            compressor[i] = compressor[i - 1] * H::BaseField::from(1u32 << NUM_BITS_PER_PIXEL);
+        }
+        Hasher {
+            state: vec![H::BaseField::ZERO; H::STATE_WIDTH],
+            absorb_index: 0,
+            compressor,
+            pending: Vec::with_capacity(pixels_per_elem),
+            num_pixels: 0,
+            padded: false,
+        }
+    }
+
+    /// Pads the input with 1 || 0* before finalizing, such that it may have any length.
+    pub fn padded(mut self) -> Self {
+        self.padded = true;
+        self
+    }
+
+    /// Number of pixels absorbed so far.
+    pub fn num_pixels(&self) -> usize {
+        self.num_pixels
+    }
+
+    pub fn update(&mut self, pixels: &[u16]) {
+        for pixel in pixels {
+            self.pending.push(*pixel);
+            if self.pending.len() == self.compressor.len() {
+                self.absorb_pending();
+            }
+        }
+        self.num_pixels += pixels.len();
+    }
+
+    /// Returns the hash of the absorbed pixels. Like `H::hash`, an unpadded (packed) input has to fill
+    /// whole blocks of RATE elements, otherwise an error is returned. An incomplete last element is
+    /// packed as it is.
+    pub fn finalize(mut self) -> Result<H::Digest, String> {
+        self.finish_input();
+        self.squeeze()
+    }
+
+    /// Returns the hash of the absorbed pixels salted with `salt`, which is absorbed as one additional
+    /// block like in `H::hash_salted`.
+    pub fn finalize_salted(mut self, salt: &H::Digest) -> Result<H::Digest, String> {
+        self.finish_input();
+        for elem in salt.as_ref() {
+            self.absorb(*elem);
+        }
+        self.squeeze()
+    }
+
+    // absorbs the incomplete last element and the padding
+    fn finish_input(&mut self) {
+        if !self.pending.is_empty() {
+            self.absorb_pending();
+        }
+        if self.padded {
+            for elem in get_padding::<H::BaseField>(self.absorb_index, H::RATE) {
+                self.absorb(elem);
+            }
+        }
+    }
+
+    fn squeeze(&self) -> Result<H::Digest, String> {
+        if self.absorb_index != 0 {
+            return Err(format!("the input of {} pixels does not fill whole blocks of {} elements", self.num_pixels, H::RATE));
+        }
+        Ok(H::Digest::try_from(&self.state[..H::RATE]).unwrap())
+    }
+
+    fn absorb_pending(&mut self) {
+        let elem = self.pending.iter().zip(self.compressor.iter()).fold(H::BaseField::ZERO, |acc, (pixel, factor)| acc + H::BaseField::from(*pixel) * *factor);
+        self.pending.clear();
+        self.absorb(elem);
+    }
+
+    fn absorb(&mut self, elem: H::BaseField) {
+        self.state[self.absorb_index] += elem;
+        self.absorb_index += 1;
+        if self.absorb_index == H::RATE {
+            for round in 0..H::NUM_ROUNDS {
+                H::apply_round(&mut self.state, round);
+            }
+            self.absorb_index = 0;
+        }
+    }
+}
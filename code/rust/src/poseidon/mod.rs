@@ -0,0 +1,9 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Full and partial rounds share one transition constraint, a periodic column selects the S-boxes
+
+
+pub mod p128_t4_c3_s128;
+pub mod p128_t9_c1_s128;
+pub mod p64_t9_c1_s128;
+pub mod p62_t4_c3_s128;
+pub mod p62_t9_c1_s128;
@@ -0,0 +1,642 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Parameters derived using https://extgit.iaik.tugraz.at/krypto/hadeshash (calc_round_numbers.py and
+// generate_params_poseidon.sage): round numbers include the security margin, round constants and the
+// Cauchy MDS matrix are sampled from the Grain LFSR
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 4
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// POSEIDON CONSTANTS
+// ================================================================================================
+
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 84;
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+pub const STATE_WIDTH: usize = 4;
+pub const CAPACITY: usize = 3;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+pub const ALPHA: u32 = 3;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(140001265879167235029761354321196410983),
+    Elem::new(79327412653669935845323546829715710526),
+    Elem::new(175338453038228275043490308522411232360),
+    Elem::new(100375373446954174687988490208194394175),
+    Elem::new(91305398845757839899294023093113309084),
+    Elem::new(109734953547998628697350790067179163386),
+    Elem::new(119739560611850563545287461016745053221),
+    Elem::new(300157387690767180462078527191347714531),
+    Elem::new(91069245395399236958618377344578977542),
+    Elem::new(182632184013684562590074969354349466100),
+    Elem::new(223186926922743619832695365129539520770),
+    Elem::new(92955314088912515335046510756452054659),
+    Elem::new(91847257469066530002525722510669029711),
+    Elem::new(40668274905516296021018912122133393507),
+    Elem::new(57313768146154612101958032172478492737),
+    Elem::new(5943075575723221958222534186853597130),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(45334889744555977867253324815133748781),
+    Elem::new(146544863696439775912455716647768119179),
+    Elem::new(301369242970095846191952950607242954599),
+    Elem::new(298629397416574851579925296463220409186),
+    Elem::new(48508657175647058804441685579923860602),
+    Elem::new(111224532338972866481338327100744874852),
+    Elem::new(297608449307331747882931251613156159384),
+    Elem::new(273959356172391017011936034849612120301),
+    Elem::new(321280722512257502086968923718133851066),
+    Elem::new(151738726210478233691322120997808434396),
+    Elem::new(162209952280570339388833253066935155870),
+    Elem::new(289888080871075522522206153108001168258),
+    Elem::new(95756101844608760222084562419614001789),
+    Elem::new(196792252036556922671264889365246862909),
+    Elem::new(318514170659803234142690520073980255503),
+    Elem::new(55508333576116448648950693615474562),
+    Elem::new(14092149625613505463488012760534272182),
+    Elem::new(65584851613753841625066978647073761249),
+    Elem::new(136479525179813693603322186051165868374),
+    Elem::new(305530599357625567579962809428318430189),
+    Elem::new(230630562180220443143456199177152545328),
+    Elem::new(317139854949177783368882142702177181180),
+    Elem::new(171377819016679438144933051427324842220),
+    Elem::new(90508715456929768107611740399585857014),
+    Elem::new(273495448317339669187702988743723542283),
+    Elem::new(168278261144485810841880003674516111206),
+    Elem::new(200929240113903554084747866789301896899),
+    Elem::new(143425679490506181880901377813805512059),
+    Elem::new(326602476756538764469248427808043099402),
+    Elem::new(67632191827830881999087414547160713555),
+    Elem::new(249234266989354377881812970436074217524),
+    Elem::new(71423499087334369618482275763489204298),
+    Elem::new(5550091138614982119153402954311269571),
+    Elem::new(100357248344119970506120804578748829178),
+    Elem::new(169176434552411220563050148723743549618),
+    Elem::new(276733805963356272177173192098165093143),
+    Elem::new(88480366888562885708330324538086697014),
+    Elem::new(182273666853771786417098572217450425022),
+    Elem::new(143908337342931573713382439513605666324),
+    Elem::new(73208824352185252431369999129551278797),
+    Elem::new(243086944774881562952843254435008736114),
+    Elem::new(126753511862179970437804025736653508593),
+    Elem::new(204202389340179769720220048783169594313),
+    Elem::new(135237819594876269105386108883319244745),
+    Elem::new(175450435528974791373349051269679805041),
+    Elem::new(88857911687710218447727166448449880566),
+    Elem::new(41390992790428177072380446530964777201),
+    Elem::new(304944025412584780991057362891827082227),
+    Elem::new(170093839351829786282320509648121011882),
+    Elem::new(60666302787433756026880602845060127218),
+    Elem::new(112916028533761609491450160851503206405),
+    Elem::new(84150914241315774706455769785519781567),
+    Elem::new(100323266533921657188181399897404188490),
+    Elem::new(156871746640064518012124745857893085594),
+    Elem::new(77430769951298401389917983293290990687),
+    Elem::new(880132906454936582860715494162770087),
+    Elem::new(116176149845149167636188333221494686905),
+    Elem::new(182932759311234792822575450515195324096),
+    Elem::new(270138114304232822419199578754037208303),
+    Elem::new(76583445594139287928226144073347378937),
+    Elem::new(289946919710153721008866191045892539467),
+    Elem::new(228826791348928051420654973961495366062),
+    Elem::new(156845656300954665449352464404780844493),
+    Elem::new(221315329840249795535230346045651648014),
+    Elem::new(95287334348889521859011284382768027233),
+    Elem::new(115239346965018607174586628170059913690),
+    Elem::new(204009938749386249808330192130246611125),
+    Elem::new(287609698283341218819421326884595259375),
+    Elem::new(8132378270969742926180609180529685489),
+    Elem::new(243093384301391293193128190833108612296),
+    Elem::new(178488704760947626345436676970610507465),
+    Elem::new(24007352820102142144080943063467997197),
+    Elem::new(304748619843393095118463490693681130572),
+    Elem::new(264503860762227138404452399408171102222),
+    Elem::new(84186683954515184519854589902175715347),
+    Elem::new(90127617186845948153526872519397629653),
+    Elem::new(199056015602634465712035078840035107584),
+    Elem::new(218797930175028505625133269399070552942),
+    Elem::new(145623680230859287337490127165039341203),
+    Elem::new(20663417696347005422834027469293765417),
+    Elem::new(190447472057496228016344147092152642051),
+    Elem::new(156359708685390451201963564479369634779),
+    Elem::new(332508553798476906943856360653819082107),
+    Elem::new(22016434428612452487041919868585670279),
+    Elem::new(56417524046741046326048905682059616716),
+    Elem::new(94757437316577227907490996231227408772),
+    Elem::new(300468474797314713637654286394915770387),
+    Elem::new(90172314087335379435178634195217122766),
+    Elem::new(60525145013643958799076110197453778500),
+    Elem::new(138045838453483178193360459638828078576),
+    Elem::new(200247034508770054832594102124224548082),
+    Elem::new(330441130458137472745811681339404744737),
+    Elem::new(11627968811157369469690668683881586145),
+    Elem::new(23385098570751268075515320172464258299),
+    Elem::new(186852542680516854111482395089665988092),
+    Elem::new(182171332216343511020345766003515381608),
+    Elem::new(258066214168148634912019041982073663421),
+    Elem::new(214849987072755197732828165997292520997),
+    Elem::new(222856927262756047276859858277767321917),
+    Elem::new(36750697466647679399486991675813904578),
+    Elem::new(124326565990331150696199373491228969743),
+    Elem::new(106348849341681144988033353393556108872),
+    Elem::new(38565022517178967946602049560117966353),
+    Elem::new(225559814199776035083005885311514914687),
+    Elem::new(29963444772899228882753903844557693427),
+    Elem::new(336653949933869961352034698327850910692),
+    Elem::new(65579519356337123175244492781585540188),
+    Elem::new(296918755064212587274340808918542795655),
+    Elem::new(25209076609525522284000400247698918437),
+    Elem::new(120079872565452483446965995701903897572),
+    Elem::new(877850057378029091751979450258862870),
+    Elem::new(241686336911313769826795346259170010205),
+    Elem::new(239678132010516150397785065538929814645),
+    Elem::new(332088026795764914050887012264180889086),
+    Elem::new(151455873379206760773658372057005491275),
+    Elem::new(294350101615864868156626894882235748256),
+    Elem::new(45287671901832557629415349181140910428),
+    Elem::new(12660475466407760755960232574512868363),
+    Elem::new(65974379080019842419466399954319268254),
+    Elem::new(133309677751165666852541251302626820398),
+    Elem::new(150148161408468718978410427713133731078),
+    Elem::new(204249690593576503918178770100635335349),
+    Elem::new(18468138091999599173634658561056076462),
+    Elem::new(163393602069822197441346199743805548935),
+    Elem::new(122642246833270561477024362135854439239),
+    Elem::new(59921918349336508923449733282702419099),
+    Elem::new(146283105603703990317264685154470801707),
+    Elem::new(283169513271858327245134188755804580739),
+    Elem::new(197924921178993907315132559647222473581),
+    Elem::new(67596810854534343541001137554385763287),
+    Elem::new(12448535129300306264712348332734615214),
+    Elem::new(41304755558388141766837864574621691974),
+    Elem::new(301823910641888557397535813456335462480),
+    Elem::new(193486201556951910667694641594341031988),
+    Elem::new(72051445180039956754384621242733768853),
+    Elem::new(312327330430711932559435849076549601885),
+    Elem::new(317288242195965092427282231951505273844),
+    Elem::new(39903392362963310668190649918895425907),
+    Elem::new(57610021189721353561861312304249309277),
+    Elem::new(101281074245971302257252094861273855102),
+    Elem::new(265704753525918832331284978605730767236),
+    Elem::new(296089097462784463265391555348398734398),
+    Elem::new(247050551101470789538878519643632379083),
+    Elem::new(217288317384415787775011928093179845493),
+    Elem::new(166077214946369576598969085724410334496),
+    Elem::new(185320163019690556961025693358111402198),
+    Elem::new(11989944818647296629392225921000257678),
+    Elem::new(180146918756889303943138551624489766432),
+    Elem::new(276423647080948545841519436127205458048),
+    Elem::new(54342417248278828393732062642056235718),
+    Elem::new(115934432728703038989322656902842083143),
+    Elem::new(179817637819725452328687874770697123351),
+    Elem::new(82889220812084126827793864117999695497),
+    Elem::new(93769018330648000604182192063525923782),
+    Elem::new(176441387028159261713308909597833168087),
+    Elem::new(25460534309183515788847042814830745555),
+    Elem::new(50579967505488935376423948213090342947),
+    Elem::new(34795845546211967139083794042678132463),
+    Elem::new(230371628993539715778196363801167650051),
+    Elem::new(74669647304792103160393686896832641050),
+    Elem::new(334515191235933125499961914947167531921),
+    Elem::new(205397086040105880603342216658287345013),
+    Elem::new(8603347668989616677594067546162580483),
+    Elem::new(223406586301679746571933363561312865154),
+    Elem::new(218006539600654797636081510474998365513),
+    Elem::new(206725868933513039577833634585899577812),
+    Elem::new(279835095971778964409686720709478209014),
+    Elem::new(244413309378565129790313307492152757339),
+    Elem::new(104887280565638376247848641498184600638),
+    Elem::new(202298284792779947565717264553171105170),
+    Elem::new(134605446524405443993501530511653927882),
+    Elem::new(211028484557186058902501367965713922794),
+    Elem::new(166267034721168298882420380145256136792),
+    Elem::new(56039251472549476410392296327045652979),
+    Elem::new(329606334765852942357538622053691567054),
+    Elem::new(88019495292175879336855404600563466908),
+    Elem::new(59373201040345899245958608132435140159),
+    Elem::new(178784220217665640993671499120891478930),
+    Elem::new(185878974837676389918397181547770758765),
+    Elem::new(274071040594662354371928232445391167677),
+    Elem::new(64557007788467392262975955952685582709),
+    Elem::new(228552353936023373941054397313362078792),
+    Elem::new(54815899437513052311698746421131829787),
+    Elem::new(251711515751209951073591676432411888586),
+    Elem::new(50168795214879636384709856643297060068),
+    Elem::new(38494755012513363210483271221047162293),
+    Elem::new(175238517893257564978075272126933961554),
+    Elem::new(217890997686648416719936215213660017990),
+    Elem::new(213832432764562320003424597980191295051),
+    Elem::new(306792474334868102555386703182316093138),
+    Elem::new(10936185282165713970855545719701163555),
+    Elem::new(52557756963263487318661032612453142592),
+    Elem::new(300787855729536227178237965021152567587),
+    Elem::new(182882838593352823821776604603160130407),
+    Elem::new(252468664158038213268819661244344678490),
+    Elem::new(52251330403485881151407809975170450085),
+    Elem::new(209740877607290453533419926861152003871),
+    Elem::new(132305804855364235156055444346920721185),
+    Elem::new(162473894052952084246908433048250321882),
+    Elem::new(122367480013912525564669922531293792663),
+    Elem::new(300300389854321472569734042543984658279),
+    Elem::new(95920601011442851570355598346237961170),
+    Elem::new(17309805370215420304045450692490112318),
+    Elem::new(211462233279262261465375714204481454760),
+    Elem::new(225665517425989082695782261464095349410),
+    Elem::new(284166079416646103946461400973406473985),
+    Elem::new(93180522095327989882696626599144268828),
+    Elem::new(288677343825415367127551153661755447520),
+    Elem::new(33580276360129016859747134133400469444),
+    Elem::new(160323498171736033432763484936690398576),
+    Elem::new(226919540770591263510054378843420185402),
+    Elem::new(44772617092111863320955917059216499417),
+    Elem::new(2742757544661910506313892095329427415),
+    Elem::new(195118950745735494571363979728065248939),
+    Elem::new(222183255086750891221305155349277329935),
+    Elem::new(94498480240934426064867345214273657503),
+    Elem::new(314461378329393122474068272413797290881),
+    Elem::new(99073914010919193535994517265546793307),
+    Elem::new(206137878994839675214485998131133561153),
+    Elem::new(132113413366799089774085809534255603358),
+    Elem::new(39505739512426665728378032599561415858),
+    Elem::new(20224878956224979753179585182269848787),
+    Elem::new(270384265316877706412638125538483548817),
+    Elem::new(279506545912017106233519521800913716539),
+    Elem::new(253938782143833465828071532950092557470),
+    Elem::new(187727881749304665256330021796984610416),
+    Elem::new(172195074155335259673543619677502393913),
+    Elem::new(32383423450719290004224496409467090632),
+    Elem::new(39806397199854774211320328683471344511),
+    Elem::new(291155485786644190600423036700714066490),
+    Elem::new(132425257935151207910205708720180406403),
+    Elem::new(167428405121210210684820743413237585508),
+    Elem::new(47542106719510597602311542943550376286),
+    Elem::new(143515529191330200191477065108062217472),
+    Elem::new(173814654638135938425786717071527205297),
+    Elem::new(50621154057425821313218450453461563506),
+    Elem::new(190533062082795573135298564383059237595),
+    Elem::new(45466224882365306106902439928149102792),
+    Elem::new(163102919405606808964444028205545724513),
+    Elem::new(65674536861165446358610393849054395094),
+    Elem::new(273251636887546447132558276565614178654),
+    Elem::new(26723602757875026668514933084825044649),
+    Elem::new(197287197141664905816688304199747008993),
+    Elem::new(148704687124648827615924349613100062094),
+    Elem::new(60524279511026374471800382326248010422),
+    Elem::new(75281971791332647147339600403567243511),
+    Elem::new(252365879634557684593704222118140197029),
+    Elem::new(40743538228135801370627544610734747862),
+    Elem::new(56178164069845181707160787651761442690),
+    Elem::new(214581500200210187054776662681693508126),
+    Elem::new(115253535340215808813922018484560795005),
+    Elem::new(143990696740184954651887893805807295926),
+    Elem::new(148519815673411819064636337883526431380),
+    Elem::new(45597276936027378489776725570365589501),
+    Elem::new(209983239133091504296488449743303034348),
+    Elem::new(95794987019066724581848838397783180189),
+    Elem::new(72457727941767275856915142197401765160),
+    Elem::new(49252352624068509197819359151304494680),
+    Elem::new(289072698898992208658355335362920213225),
+    Elem::new(210093525716198186108737369772142263180),
+    Elem::new(118153848689785577022641484347146768524),
+    Elem::new(255977563290337619789271676972989467101),
+    Elem::new(167299315358358942143373923132535955094),
+    Elem::new(31202537567676259938255490611280964899),
+    Elem::new(1583090264006501960240891904895633940),
+    Elem::new(133272574724262113925780056937283811232),
+    Elem::new(23956313392576206137119283078888289311),
+    Elem::new(130184560447093371037435891759762430205),
+    Elem::new(331363667599237892738869899942314600242),
+    Elem::new(260770164254858332561358965702222749774),
+    Elem::new(168528369201154083374772447925457995236),
+    Elem::new(242168969576138115553053201441077068975),
+    Elem::new(322197945080049992135827503432383958940),
+    Elem::new(209944552614987808367391979890888533736),
+    Elem::new(283963508316906489282451951747505816989),
+    Elem::new(28121842578452956213185635443964918524),
+    Elem::new(190019309659762964294213233634749998092),
+    Elem::new(295507585487591179558965997985079994279),
+    Elem::new(157094588928899745211943931929098325769),
+    Elem::new(136066484275846822725195397007426974608),
+    Elem::new(95929744171164478292916206112979545163),
+    Elem::new(231793397280043863805961410785762111224),
+    Elem::new(79707166340286254412512691600527083635),
+    Elem::new(255884718315489250567135677101013122551),
+    Elem::new(66299944707002371965047520180072142881),
+    Elem::new(36560537493145541871955755683943340942),
+    Elem::new(110610212974062725271229778287503377012),
+    Elem::new(119658364897951888362179830943847474865),
+    Elem::new(7544320188974688381025468643178819634),
+    Elem::new(140244898845170458454551521087777545098),
+    Elem::new(251974792508716754237837938056830989054),
+    Elem::new(6433829679940753225880598734741056727),
+    Elem::new(237878960544115095556427930503884386005),
+    Elem::new(331868327697643275532024164564913801529),
+    Elem::new(296574092354453167352027610450698269342),
+    Elem::new(90269401535372403703678531586943801799),
+    Elem::new(107795414844348493137809406227070971912),
+    Elem::new(263098587482892759795514443181913562179),
+    Elem::new(49528073976661903379541033442518091573),
+    Elem::new(9103340959227273350340703539473919841),
+    Elem::new(206783509738082233531662280131620429190),
+    Elem::new(160117051868054642258623640930680450499),
+    Elem::new(212123613561605855350691303818240756660),
+    Elem::new(244487992772112417777472255928657489400),
+    Elem::new(227269635713215588246831715675889067992),
+    Elem::new(199145498905904399070115908479136058959),
+    Elem::new(129963133657110800220067452341799429510),
+    Elem::new(122352174517823339185192643620053750793),
+    Elem::new(221510898104806679631573999879716986759),
+    Elem::new(251080245745923986151776003462116615605),
+    Elem::new(129446435628403984457062262502336941522),
+    Elem::new(310173696099038301516757169279728030825),
+    Elem::new(274817965136460835435505744855267754142),
+    Elem::new(126891206924649821726432452985147666719),
+    Elem::new(46177436450039559754733978858003851815),
+    Elem::new(130496668957753265094487083051983802657),
+    Elem::new(7169469396379708971808252461293690802),
+    Elem::new(173072543537559486896879990784635234719),
+    Elem::new(162405252862295468234484261294075101873),
+    Elem::new(329578620882939694345735093671525231780),
+    Elem::new(188399375983864785894900758604751600445),
+    Elem::new(312543374697193056499954583218450376641),
+    Elem::new(276163160378834941478394002501371234957),
+    Elem::new(152061504169518901484826386361596264030),
+    Elem::new(237489596912387758357207606791082066850),
+    Elem::new(167380858347954580064241846454173063215),
+    Elem::new(272409034597292146039867665092917723309),
+    Elem::new(212848291424396281142020721420960740211),
+    Elem::new(71592236883723218403115966665352861240),
+    Elem::new(261821680907248684203229404745703537865),
+    Elem::new(293947473189347257030038447772722501955),
+    Elem::new(176360881586509227266107532884938637081),
+    Elem::new(145809784373679566504371337487162593519),
+    Elem::new(130043066556629515015336861035060781476),
+    Elem::new(312226849619870759433254009404917874784),
+    Elem::new(339274377708187635221980060919892098912),
+    Elem::new(130746412139973159914619304323052813837),
+    Elem::new(171316611494710386437012834318956097342),
+    Elem::new(257840668869895770157958634654985138160),
+    Elem::new(21334338008835533499637360254012123904),
+    Elem::new(202878500441413274054684069686514419652),
+    Elem::new(85284061072936808563967316378190039676),
+    Elem::new(153600310354173987918881874401802600825),
+    Elem::new(184339797734079865564538867713814655442),
+    Elem::new(323771562461565289508712475851778166497),
+    Elem::new(21534698375184006027867192118034766341),
+    Elem::new(155045846658820768950005677128167811040),
+    Elem::new(11031814387411407022134912453997839595),
+    Elem::new(271639270342091884089368459068378224971),
+    Elem::new(257245372344772612013717917719680393145),
+    Elem::new(312143703236108299498981730553878827266),
+    Elem::new(233010320200608256334318331852412755037),
+    Elem::new(321669542789169607228577354525314633996),
+    Elem::new(206368664166370446903639281772453462975),
+    Elem::new(110814380221158313717944288993108179751),
+    Elem::new(49727194722450076848304911006367795715),
+    Elem::new(335590561420368334362888334573536091842),
+    Elem::new(336400663032701748335619757240411261334),
+    Elem::new(265253857745828696840539362767342453779),
+    Elem::new(249870583179940399467650599871978719019),
+    Elem::new(96545086011640952136953464262184493129),
+    Elem::new(306095934840591900764217358368882755187),
+    Elem::new(259346674952034594394288053608016126811),
+    Elem::new(334836610566049443288620856221324573084),
+    Elem::new(320557320413451269900661438361358497802),
+    Elem::new(119840533910238889162062050824446543839),
+    Elem::new(167316314704221923046034062080058338366),
+    Elem::new(29769735054445104474134403947357951811),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    poseidon_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    poseidon_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Poseidon
+// ================================================================================================
+
+pub fn poseidon_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        poseidon_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+/// NUM_FULL_ROUNDS / 2 full rounds, NUM_PARTIAL_ROUNDS partial rounds, NUM_FULL_ROUNDS / 2 full rounds
+pub fn poseidon_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // add round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    // S-boxes, only the first element passes an S-box in partial rounds
+    if is_full_round(tmp_round) {
+        apply_sbox(state);
+    } else {
+        state[0] = state[0].exp(ALPHA.into());
+    }
+    // matrix multiplication
+    matrix_mul(MDS, state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Poseidon
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of Poseidon after absorbing the pixels
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_sbox_and_mds<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut state: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // the full round flag selects between x^ALPHA and x for all but the first element
+    let full_round_flag = round_constants[STATE_WIDTH];
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    state[0] = state[0].exp(ALPHA.into());
+    for i in 1..STATE_WIDTH {
+        state[i] = full_round_flag * state[i].exp(ALPHA.into()) + (E::ONE - full_round_flag) * state[i];
+    }
+    matrix_mul(MDS, &mut state);
+
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (next_slice[i] - state[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// number of periodic columns returned by get_round_constants_periodic
+pub const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH + 1;
+
+/// returns round constants arranged in column-major form for periodic columns, followed by the full
+/// round flag column
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..NUM_PERIODIC_COLUMNS {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+        if is_full_round(i) {
+            constants[STATE_WIDTH][(i + shift) % cycle_length] = Elem::ONE;
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_full_round(round: usize) -> bool {
+    round < NUM_FULL_ROUNDS / 2 || round >= NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = NUM_PERIODIC_COLUMNS;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the S-boxes of the partial rounds are selected by the full round flag, which has the hash cycle
+        let mut cycles = cycles;
+        cycles.push(cycles[0]);
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1167 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Parameters derived using https://extgit.iaik.tugraz.at/krypto/hadeshash (calc_round_numbers.py and
+// generate_params_poseidon.sage): round numbers include the security margin, round constants and the
+// Cauchy MDS matrix are sampled from the Grain LFSR
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   t   .. 9
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// POSEIDON CONSTANTS
+// ================================================================================================
+
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 84;
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+pub const STATE_WIDTH: usize = 9;
+pub const CAPACITY: usize = 1;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+pub const ALPHA: u32 = 3;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(121285622443048766224319037933633125620),
+    Elem::new(302343510274245715401845196636935483726),
+    Elem::new(45848702668295603756484101529395758941),
+    Elem::new(109140487513642102180169000876487447540),
+    Elem::new(242814249840921962526270289707621227573),
+    Elem::new(238197363401329955555656455864940596366),
+    Elem::new(303984813893302472337801302884643742115),
+    Elem::new(59963679142036451582586246260255681461),
+    Elem::new(332468926038490571901490500905471335772),
+    Elem::new(97010744595477773683306691208833384634),
+    Elem::new(160330998475372253171868712715320305972),
+    Elem::new(173171888358066731572559424967064544685),
+    Elem::new(168515678943665587912529103314666543898),
+    Elem::new(1421774999947043618403623535140100122),
+    Elem::new(42297964847748624818786747991140524426),
+    Elem::new(179770084642682522146140792303637386940),
+    Elem::new(175811991362632914905717026123896058501),
+    Elem::new(85871638068181382509652385670795644763),
+    Elem::new(245147239042626725979631288985245827761),
+    Elem::new(311028234169534700593091200049141534136),
+    Elem::new(326154134679473138497699312676231200337),
+    Elem::new(140852762574173958586103936679640806079),
+    Elem::new(323694536886946160724917382032019930853),
+    Elem::new(272840588495511513314717331317435859292),
+    Elem::new(129657416230930291283894872554923918912),
+    Elem::new(237933544990167882659916919427567912703),
+    Elem::new(96377204109894298775046287617122454373),
+    Elem::new(223544263693247892677833598809282331924),
+    Elem::new(55869724647360697919064237136964350399),
+    Elem::new(174236473692169135169532144957087403423),
+    Elem::new(280955459459602586829091013035172367661),
+    Elem::new(12872006627019086574203965323258138834),
+    Elem::new(55903755928072671871208836420443575975),
+    Elem::new(301178898324486704564854172937698822120),
+    Elem::new(321655744222461473805835781119646547937),
+    Elem::new(338136672338383785652114597156307133169),
+    Elem::new(158094459421607436382409449677702561413),
+    Elem::new(237056974712511349505025874343870327589),
+    Elem::new(17236908302860427031860107448197913178),
+    Elem::new(299866874539115471383687415050223561860),
+    Elem::new(334853561245203276391473756572413558591),
+    Elem::new(220372420958740804886426947283273469489),
+    Elem::new(21671989055444122418588124353261726397),
+    Elem::new(328845943207387047375449125692433433530),
+    Elem::new(37234741107802936468336282565367767041),
+    Elem::new(154567664870317544576846337223225734343),
+    Elem::new(216700290377784711123365039358034128382),
+    Elem::new(61961226430393647790520548519589096622),
+    Elem::new(181228355123396536786777840388096923517),
+    Elem::new(243512203780951316787805602157800428440),
+    Elem::new(55207002360358073322301601582732471917),
+    Elem::new(309792365643972019918530490365963369956),
+    Elem::new(5795727267389888370603912287998990625),
+    Elem::new(23543870913206923162252729812018149016),
+    Elem::new(147761121633839579898833864921257712833),
+    Elem::new(158380639244795215994967003579174353564),
+    Elem::new(244728481248700298648586623564372282689),
+    Elem::new(337227912304960727167777739763451709001),
+    Elem::new(214328596451811908386511746591977741921),
+    Elem::new(282044199578745300335432193959501513146),
+    Elem::new(139350734518299884943737307127650379127),
+    Elem::new(228617613392668405523397691804827058322),
+    Elem::new(313194971512612796337295229438261683029),
+    Elem::new(266028271303296851453361088940744836236),
+    Elem::new(126957054504499938546979346997822866833),
+    Elem::new(89090329004020046626079081289256741209),
+    Elem::new(38955713330783212843507955455918184070),
+    Elem::new(80597083592836747780278562373884489078),
+    Elem::new(68714282574018173161208697765151755770),
+    Elem::new(108987104075496463197858626209875860708),
+    Elem::new(261197440365164243334635334192250266759),
+    Elem::new(105741926334977421847586824646488643317),
+    Elem::new(315864970398368620399154007739660973772),
+    Elem::new(268502558251182716110182752834580428854),
+    Elem::new(296626032008360305939208982624975665552),
+    Elem::new(196899698110643111167875687172978805588),
+    Elem::new(338553841755635194171401274170855980096),
+    Elem::new(265489579736127821727434714595157468972),
+    Elem::new(142459917666580180753638648930548555995),
+    Elem::new(132932202766577190053440833717437913168),
+    Elem::new(58275972567257966679029030387828893356),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(131829176760066231060335544533050386164),
+    Elem::new(197315806338643843741365505032668788997),
+    Elem::new(312507873886821230697668192059020775024),
+    Elem::new(37747765714418469655463142290723251831),
+    Elem::new(163680903238377055579025425410739259282),
+    Elem::new(304623617442358937636100099009957173793),
+    Elem::new(285360748729846986972369073584070585773),
+    Elem::new(161979180545074245313748263001599895102),
+    Elem::new(143982144576281818417641482104191386651),
+    Elem::new(273700402066440636169305824264158483858),
+    Elem::new(238934674764376301341885077215679950322),
+    Elem::new(307730851615465154633864786435762412855),
+    Elem::new(222447462671201276118889440104339727525),
+    Elem::new(66578700936291889256305219862715827099),
+    Elem::new(209589594154861820957929322433293379066),
+    Elem::new(328055631136565791540167105476160502015),
+    Elem::new(253656334089795137140517331549725807712),
+    Elem::new(138070153559977984741031646124924758783),
+    Elem::new(185394778806041312086661721806570868511),
+    Elem::new(229941301602336500165184580068822859033),
+    Elem::new(214402898910148857016324179052859959163),
+    Elem::new(225491224598112819677099513859681895032),
+    Elem::new(130126786474784693073969183235664058962),
+    Elem::new(234874073409600394642173882729204895814),
+    Elem::new(201277351679714210437019857105052887025),
+    Elem::new(82293879311141393173871509127122519805),
+    Elem::new(276670422785987539707863131479253815315),
+    Elem::new(327287910423657420393590700838497224013),
+    Elem::new(245749838089312656485642366826337840460),
+    Elem::new(864820351952470895821592784339832933),
+    Elem::new(97128583752651474152570726739038507687),
+    Elem::new(79925528393869343399953157906766104289),
+    Elem::new(184113326044816465422319194520883677402),
+    Elem::new(192442548078987173604938137682481185250),
+    Elem::new(272877188050360653696764377943442919456),
+    Elem::new(183916382471102467342463398129670541836),
+    Elem::new(47817504165177351089603538780019542035),
+    Elem::new(270027324173213785088722802116021662005),
+    Elem::new(34415504575747069819147311164662003300),
+    Elem::new(282241619001305806425772621189640372067),
+    Elem::new(313105017649167769335930932499147782598),
+    Elem::new(272302804986891093963726209421703739806),
+    Elem::new(64174255432621652504082833466224244863),
+    Elem::new(25341543781243224733522465675807898759),
+    Elem::new(292606485048531762103662137342496221849),
+    Elem::new(86271146486239961784245343123319083090),
+    Elem::new(109630779801587332117695596412719419318),
+    Elem::new(322966609114445837274268147195474696537),
+    Elem::new(198538729095235461723790417505275387689),
+    Elem::new(312440724470224071686443063940511830358),
+    Elem::new(289507724634618156929730829744711437665),
+    Elem::new(112572380864512122289980081912872883581),
+    Elem::new(331305731708419977456633435516179147360),
+    Elem::new(112638289183381641277394605954151454176),
+    Elem::new(289773301850760239179347994703466079902),
+    Elem::new(228368503100293247246042904813223031346),
+    Elem::new(256222987668374101633181441964305544361),
+    Elem::new(41060715155315132883676141780286355913),
+    Elem::new(37895130790795202053382131849829203277),
+    Elem::new(35371200249131607756128839799279020327),
+    Elem::new(56208181118273267706474131068068872219),
+    Elem::new(264724274966983382936988313229359037920),
+    Elem::new(30883791299896777002599217990263811866),
+    Elem::new(271981514904726755109328843804934443747),
+    Elem::new(286802012677014469212706530171022805067),
+    Elem::new(18788771794008896072065170127248210956),
+    Elem::new(141979415780290943088949469953171233639),
+    Elem::new(59487553904352838421552919332724376863),
+    Elem::new(144335334580359357563348101685770537677),
+    Elem::new(265285418849261339473321510038902080599),
+    Elem::new(82798872383663799266402677216121289732),
+    Elem::new(152369779556581058590899657221624522820),
+    Elem::new(79248366211274265492784846314225776768),
+    Elem::new(326560655002882055069644445872874332044),
+    Elem::new(198042818689389023585271515364219635785),
+    Elem::new(267378222268735403973326863123716929964),
+    Elem::new(206373267440716784602897285253708540013),
+    Elem::new(147586941618348669135019755157771351677),
+    Elem::new(90828581878597192763303147562339349600),
+    Elem::new(31481146161814971644927933299848222418),
+    Elem::new(207722011023404310006841126550961662215),
+    Elem::new(148489347800153604636369112954481408499),
+    Elem::new(209412468331539841761776527995579930659),
+    Elem::new(314741400827242830503020725197820257346),
+    Elem::new(70098158738492383396457639881601275733),
+    Elem::new(178552747101495975777470662609770705313),
+    Elem::new(226080069122733710717539042326265676536),
+    Elem::new(298998951240096515258990257621567535469),
+    Elem::new(301436895707763861605823676023939449192),
+    Elem::new(200381289935776505100465833531192700496),
+    Elem::new(27833093872399087869687130986874136501),
+    Elem::new(47167410322208570926687527667644954601),
+    Elem::new(121574197236990901925185504916857898893),
+    Elem::new(127543792785464450257050019098755645054),
+    Elem::new(148378819037171415246691846129781694048),
+    Elem::new(233942701501633556261938631083677242001),
+    Elem::new(208417181142033525350393889780045551509),
+    Elem::new(302783814789906305627872438399756894979),
+    Elem::new(169029122874299662521482541589788193815),
+    Elem::new(227579423497114005841421986235601614685),
+    Elem::new(17831044215977943446437699746452391967),
+    Elem::new(318582756261614350831690588454680589860),
+    Elem::new(152207309989604098250635900397737314152),
+    Elem::new(188179000943044679234108342107735582355),
+    Elem::new(249879179971306301187159231247312162392),
+    Elem::new(70162289230776428908220284771740052450),
+    Elem::new(169132595016489337986665824227379826893),
+    Elem::new(193115497186961912707309112683480882566),
+    Elem::new(8611055594513043701379328198414064367),
+    Elem::new(249174329675617245185941076838326634085),
+    Elem::new(45904071273539650555323271438840806075),
+    Elem::new(11924922151337021706509618529253915662),
+    Elem::new(160143566825724050982869719502888091481),
+    Elem::new(180053427801356744383845976809558704243),
+    Elem::new(72583182882246777966741577173243928211),
+    Elem::new(205939604409851960196952065372097106173),
+    Elem::new(63535953958186754285385427615912162660),
+    Elem::new(248891984676037193943669365956023280663),
+    Elem::new(100534229427291073195856413573750036138),
+    Elem::new(139706380656245845828811036467847429096),
+    Elem::new(293904843532808812649879169572831951403),
+    Elem::new(151452777009353346957562871253900607760),
+    Elem::new(55135821018480500397421883616382213404),
+    Elem::new(280122557186605624147748307520738664364),
+    Elem::new(222802664162100875382711786128289427349),
+    Elem::new(323541362689708475513976975842983876700),
+    Elem::new(253168441628983746907452033950100167726),
+    Elem::new(93142304012879987981798681049867576069),
+    Elem::new(89984001074618759037098566191394369997),
+    Elem::new(332624288505937884911041213980608080807),
+    Elem::new(239362850428299436922562595221492635126),
+    Elem::new(166141297906685483656433087059170206818),
+    Elem::new(123718888609629119820867271777334922352),
+    Elem::new(26917396567887886220154116162877826745),
+    Elem::new(228228890943835141769979576933995855498),
+    Elem::new(259037474484198836773878458248169287567),
+    Elem::new(319891326845522110119976591727207104983),
+    Elem::new(210322354049020919819279812956088833699),
+    Elem::new(87816824705054766347137328313064450363),
+    Elem::new(203322031385976465689660982142387090302),
+    Elem::new(257307269449667593319392030232471709967),
+    Elem::new(43236352388169717797782761804075805462),
+    Elem::new(325375898320698405115173180058742061484),
+    Elem::new(87829793348987648688858352866983627813),
+    Elem::new(239624381341729618691381532831919801559),
+    Elem::new(236388492216479872619504058234094055063),
+    Elem::new(198031887635578678478451743299405219479),
+    Elem::new(230036004512350227851632240778186714916),
+    Elem::new(315709021152963134115165765876964291484),
+    Elem::new(255315575725168743066613379543931774400),
+    Elem::new(294577515082145285996450573059563012687),
+    Elem::new(59947320879252506904052119252901666155),
+    Elem::new(70703720759357867959757644601809336079),
+    Elem::new(222620356332002059765265721132698074960),
+    Elem::new(49126335122183791802811968132809360110),
+    Elem::new(299344927569829439636739736611336803363),
+    Elem::new(138183598172246567083291233134822836580),
+    Elem::new(289069598316644730866725625075596383835),
+    Elem::new(334825142347199693749166488229501553337),
+    Elem::new(68230337040115452761937536010402585796),
+    Elem::new(110951825265736608117818150945246621072),
+    Elem::new(276703044965036233798582549256323620790),
+    Elem::new(16444492326822294999344642478969457595),
+    Elem::new(171222477215178441221453892827763867215),
+    Elem::new(50797581411204047241465352753437440153),
+    Elem::new(238702307473812206542472092521220048),
+    Elem::new(145494814221217869168831480359205302583),
+    Elem::new(7324890673414773473212162495129902207),
+    Elem::new(274770486955169361003419903399906698799),
+    Elem::new(242095250570121526835493829118424645974),
+    Elem::new(275009567366655944283002109146422196939),
+    Elem::new(11209572370278120593970942007538283492),
+    Elem::new(87821562449209189161624010531486597537),
+    Elem::new(291319295828955287168465364850517758245),
+    Elem::new(87704160943784721612827046945616224384),
+    Elem::new(335503677627178306964047799979891005787),
+    Elem::new(191418982629685572232712237678311057177),
+    Elem::new(211572945045908100447111081364494890442),
+    Elem::new(208139122498110459157241874571604286322),
+    Elem::new(217161896050903671831133064357741812057),
+    Elem::new(157751914902485743885403714744744117330),
+    Elem::new(217548500790608812558746968259712853304),
+    Elem::new(143612373287275988136682373658471193911),
+    Elem::new(33476342474472294630126824289654312073),
+    Elem::new(253768259562287154965810288806942384799),
+    Elem::new(101456592338593046683914950300021380571),
+    Elem::new(8329897271925154225360209337564643991),
+    Elem::new(118389959869440155005020853281605594837),
+    Elem::new(243441925636301706542552372269435433017),
+    Elem::new(192059096121836500842108171872829859314),
+    Elem::new(220120795863911174627652319588310477178),
+    Elem::new(144634257324357487775703837539357422585),
+    Elem::new(117709119807134594978906461235319542154),
+    Elem::new(82663091600285317300333783664054187145),
+    Elem::new(299631068166642991001206815581412291276),
+    Elem::new(274610392028349393230172319054974652886),
+    Elem::new(338056872013751734156188156835135333722),
+    Elem::new(177750252737028994228757151328000207471),
+    Elem::new(285624321236942333488252180758474925835),
+    Elem::new(283452153785958318659740986660288476537),
+    Elem::new(336704054811085463688479368853850893978),
+    Elem::new(278629495651754271295284246567313740337),
+    Elem::new(293843039245102710020216228757387865475),
+    Elem::new(204965072277171133659519784283454670509),
+    Elem::new(140465673924497131342894763583475695075),
+    Elem::new(338364553405625227576662913317691776728),
+    Elem::new(295537191423770331202414809886734105151),
+    Elem::new(200515156206016322069680008409432275084),
+    Elem::new(55673744366745450092421999801553150421),
+    Elem::new(234390166427536669518491644479781563896),
+    Elem::new(273607526963059817724528095815662421079),
+    Elem::new(141855241923614615772549552037068443616),
+    Elem::new(204341396413512831727567781921074074828),
+    Elem::new(176711985541576353114325668195152464782),
+    Elem::new(279943404373382480756952756541399059594),
+    Elem::new(338530174239731717504541046250085542630),
+    Elem::new(233357340195746883807182508414280860553),
+    Elem::new(54283301278164086651794990956725425365),
+    Elem::new(139222548656567029378681720778741635781),
+    Elem::new(26620411140772935707966125703170934063),
+    Elem::new(174723850150502359135964730452557854187),
+    Elem::new(68836605036434768882780286162897526314),
+    Elem::new(182690769334268595158349973592313322036),
+    Elem::new(236162432896998257222010521862293090412),
+    Elem::new(250634192380503086734691774782869319726),
+    Elem::new(87221883808970539516434084872531725220),
+    Elem::new(324629136987406389737397148676756598139),
+    Elem::new(135692592817575401963754804644717443068),
+    Elem::new(154029958333607031065603138275224438247),
+    Elem::new(246624199527336556875828902041563973617),
+    Elem::new(72464073359581950794485070379210787405),
+    Elem::new(213087202216534364144368681169027536520),
+    Elem::new(4869021150518561865850904182488098364),
+    Elem::new(197815762875521724283954482179534931684),
+    Elem::new(252196624126738801170272302799672534619),
+    Elem::new(57354998900638904517738017596813360427),
+    Elem::new(167120197270477850741520402087376323933),
+    Elem::new(89920806136049669083022823188531563261),
+    Elem::new(232767896835976373041496336795129388565),
+    Elem::new(200166482956266034705146674155892923156),
+    Elem::new(68973637526537469737206192388683905709),
+    Elem::new(180749896031748188510712323403750832749),
+    Elem::new(80956088023428352846078344211527692334),
+    Elem::new(255690714936836661679525428607000117306),
+    Elem::new(188847840999294979171685301523066359624),
+    Elem::new(237703474897434410860116206242551886578),
+    Elem::new(317339097582498061574183889703743225121),
+    Elem::new(178172065176241193178090305792710493414),
+    Elem::new(95324720315352470268989658979597430432),
+    Elem::new(301663559526706751736995954175774476952),
+    Elem::new(82329800404326662137665689065325324257),
+    Elem::new(244862700334231613080410391854462864328),
+    Elem::new(12931587116142944892501273848792108260),
+    Elem::new(289544263709766723080376368530145530981),
+    Elem::new(325205079912109833323332261721763634043),
+    Elem::new(223473625721916534300709472599036925785),
+    Elem::new(296231277477419617076301928342905501806),
+    Elem::new(333958240156513690914981075113636276282),
+    Elem::new(269794407392629965018404308587666787594),
+    Elem::new(105407444682685587023977844209350152883),
+    Elem::new(194024318143787610377229221241189381052),
+    Elem::new(243038062676915930410285818835226885926),
+    Elem::new(331775479673491609961185901105456914100),
+    Elem::new(107329816567937646345730699660876935081),
+    Elem::new(9288461866562266961421055549620875909),
+    Elem::new(104347283907268947213636949425892054683),
+    Elem::new(226465823483535107066453259395650662657),
+    Elem::new(225199471636210133746079433838347055451),
+    Elem::new(7007206706110391223830590989776772930),
+    Elem::new(220166547820495964216711872101495546697),
+    Elem::new(240617816173083151188238143869440547363),
+    Elem::new(18402172361288789724476381978384575126),
+    Elem::new(77487458435490971665277064578764709529),
+    Elem::new(262912607091761188357443007303703654514),
+    Elem::new(40972594566911437013039912422996516130),
+    Elem::new(238893786038783439736317679764709253342),
+    Elem::new(181256285874019612010232196739729820991),
+    Elem::new(75769896757923682622872990678670982319),
+    Elem::new(313542923660765065800904094601025888431),
+    Elem::new(44134045222432286916180303938075997005),
+    Elem::new(140015663023838804535894647114417569135),
+    Elem::new(273212436345993330656523575325009400248),
+    Elem::new(146764796156510244163600497606401734858),
+    Elem::new(6895706274517227855983720248987983770),
+    Elem::new(171822488233730459318135521671231242295),
+    Elem::new(86445377870007138156250399132801806807),
+    Elem::new(154928576390933433490850607881189003973),
+    Elem::new(117230019295021062997545075814472095732),
+    Elem::new(5447998436672974087943152925351502833),
+    Elem::new(82835388100452075928034911036185376819),
+    Elem::new(59487727643534612970456785092980889767),
+    Elem::new(339463806361253821721379366841210356398),
+    Elem::new(218742755123373409230546767487785373647),
+    Elem::new(303491392278402904478569351019756485345),
+    Elem::new(129483455857987725830502921355498055331),
+    Elem::new(5300281867143437326086067026941562450),
+    Elem::new(36709305277840531946442071562701462733),
+    Elem::new(40183037230098464796576849813699781573),
+    Elem::new(133056071165184055501654778250430100821),
+    Elem::new(110549895766763038389376141594603675273),
+    Elem::new(215609278157435401629912872321473324927),
+    Elem::new(156493464975515030958093815018498455722),
+    Elem::new(180445765812751206705270728342663321955),
+    Elem::new(136705487548541955831952104578209799354),
+    Elem::new(68353389425214478125578279324618673276),
+    Elem::new(65633531524320157962169012283971671625),
+    Elem::new(155812274476459958766368934294663046906),
+    Elem::new(103036776782979154151744110246392007098),
+    Elem::new(193617114497372986476881402846470422499),
+    Elem::new(305035962079097378957718967677875346218),
+    Elem::new(290530891291908628880387483195412523977),
+    Elem::new(196602221822426135727846878847149260961),
+    Elem::new(291604928140461551732896014503093298535),
+    Elem::new(337486271633680632649301270710269008409),
+    Elem::new(285233714055456032189438309631882784763),
+    Elem::new(164745373138676188263468433533514449747),
+    Elem::new(208077173865337784370847029909840311888),
+    Elem::new(154732368173017852853764131981626230837),
+    Elem::new(30396699624888734565444055818804708743),
+    Elem::new(268799644864970469013368942475496328142),
+    Elem::new(169003134559377470471663708413132470511),
+    Elem::new(231842699118705806139947954533540053455),
+    Elem::new(260868141224282510637897970566335464763),
+    Elem::new(103758803935016751018452799519918219485),
+    Elem::new(205298611323732637574779119163654555376),
+    Elem::new(144070407412671794283861579257114241549),
+    Elem::new(245004770668472394818760659520991161957),
+    Elem::new(269727543360844519025068583536536005875),
+    Elem::new(15352051357419500736066633474190569749),
+    Elem::new(314889543372015248323883965159559267652),
+    Elem::new(285517464625637561693177279379438251446),
+    Elem::new(338662117653995466863629229460675451139),
+    Elem::new(132490143925587018681613067773049850116),
+    Elem::new(256410188695584424351838806303084046334),
+    Elem::new(175230414158310397042847439735569107044),
+    Elem::new(57017430672021748083754481603833385840),
+    Elem::new(310909048259865098670301570485665046822),
+    Elem::new(305183396288210715893569777465470759717),
+    Elem::new(224353274075468086870738994249793863370),
+    Elem::new(197216224839067850975419875484189809465),
+    Elem::new(224939054680829115457057447595405074659),
+    Elem::new(124108309964831858163974288593937567095),
+    Elem::new(272678675629499874236096859181432694277),
+    Elem::new(164131838988541029289607941164410639953),
+    Elem::new(226430256600686009677017903222297095832),
+    Elem::new(186498246299614415972505294869105812656),
+    Elem::new(53331034524509309617072159909928377134),
+    Elem::new(125423612592140477379550004599208620829),
+    Elem::new(158551582310421189499795069935916501905),
+    Elem::new(28060244795440913522141150234173411376),
+    Elem::new(304202826815470892798278252777317969318),
+    Elem::new(236952650713115751778860901818984640932),
+    Elem::new(32752485308372304881275178042917666074),
+    Elem::new(24728591462849343204728614384318041712),
+    Elem::new(7374124625349072460516328416111368339),
+    Elem::new(253476815932395970725628661300417273226),
+    Elem::new(154305461232209390367795732577011461687),
+    Elem::new(147445672759403954192333903669734416382),
+    Elem::new(110821519599229979515471741339048570980),
+    Elem::new(317555581448497629175910830596441240538),
+    Elem::new(326687465105750742579341187356969946134),
+    Elem::new(231809998028363627593359459005994255276),
+    Elem::new(270743298753552751392631338737466560695),
+    Elem::new(297894984836044890812846673562262031312),
+    Elem::new(324478207534110164443531828210477164945),
+    Elem::new(314559411213537389562852624351876281369),
+    Elem::new(96601412152680466486807418799803547727),
+    Elem::new(55824895792095341610695365541683253406),
+    Elem::new(197663161107190210873584367458377862689),
+    Elem::new(270555107898792731926038588674863801150),
+    Elem::new(326695311351935567932572376086536572603),
+    Elem::new(229535176410682307445277548988168569036),
+    Elem::new(312908279600196779955769535597270956684),
+    Elem::new(20776969180966341926065143434600692724),
+    Elem::new(37095186955090958587934158667474657612),
+    Elem::new(331531106772657349458568473824714276432),
+    Elem::new(229823795844272656580169634635905753790),
+    Elem::new(333314910893402694643452553853442089144),
+    Elem::new(136408509027521288035684087769620773225),
+    Elem::new(86745994882511832002459149765247494575),
+    Elem::new(167144615639570065724197336158767351218),
+    Elem::new(232353474559683132570835096541760252314),
+    Elem::new(310665518190908909692176323627553783521),
+    Elem::new(193331118474320120074041072870715325136),
+    Elem::new(265540526511890996282382125493214426146),
+    Elem::new(198530982836130059824776080011867346020),
+    Elem::new(78866891451318527249350635450838856363),
+    Elem::new(165674983342578748328961893632287718664),
+    Elem::new(310822503057149440663418913862129167903),
+    Elem::new(229713261990358274590009829832089796277),
+    Elem::new(21140668597195373314851716435442661436),
+    Elem::new(124945683220067034093581101520506718693),
+    Elem::new(57328545816158942525001931059189597752),
+    Elem::new(336236791969967920561421892611856143304),
+    Elem::new(248702977002037951079902483138320996512),
+    Elem::new(106750682588627553100597332981581628041),
+    Elem::new(209690252310306646609466149160630818502),
+    Elem::new(337636955481461797296468250441634589260),
+    Elem::new(44135388649205706067670621063848306611),
+    Elem::new(169678086023062035385714258448210373650),
+    Elem::new(54198851339857559628537606701210475194),
+    Elem::new(64164553072265344111789446949850310489),
+    Elem::new(70694898480013578101473081558482295886),
+    Elem::new(251683992396196229843114399042313411373),
+    Elem::new(315639184964189537782139410417127146467),
+    Elem::new(184069016658585662504216616078298350817),
+    Elem::new(182913808538510355053451134768227913985),
+    Elem::new(32036602888508588685372347428129683204),
+    Elem::new(46172808152229348024331653571879939358),
+    Elem::new(76806149854410801998594725978638146292),
+    Elem::new(234226642032870307160066981913005825694),
+    Elem::new(101165208311738552483479001257955329680),
+    Elem::new(207725448261012368920541559072207321540),
+    Elem::new(50837098313355139842044649681922007862),
+    Elem::new(33273206422763895319713753008073980217),
+    Elem::new(297930586178775169533029988899758376041),
+    Elem::new(144050594837583037580936003788453811593),
+    Elem::new(204481840122660107185573676293463710698),
+    Elem::new(99467644138211348577541343345729573301),
+    Elem::new(17796288390417707099227219656092522102),
+    Elem::new(148473618284251983189483612213992731605),
+    Elem::new(206440353870627997463952871579828619004),
+    Elem::new(210894764225052848160509255819169661943),
+    Elem::new(149268880340833527445648929112898366292),
+    Elem::new(127547128061683234355415565167125650950),
+    Elem::new(301298595838003746233800813696085658557),
+    Elem::new(88411703452875064296585774137310778766),
+    Elem::new(243431360545028529333193591335454918210),
+    Elem::new(283899012576173026624866197671183665220),
+    Elem::new(188382121555260349401102200651714794525),
+    Elem::new(107000711300842310831240572900803701740),
+    Elem::new(283022018129908189163392669709869162105),
+    Elem::new(177362266469198064094103578219274116952),
+    Elem::new(239105115169038078421102402182066362608),
+    Elem::new(69553789444764284057351217069318280201),
+    Elem::new(236849795295105848266463332678814519342),
+    Elem::new(222964774842762749025756838313866694141),
+    Elem::new(105277796614730988402005512650842329310),
+    Elem::new(69169660708400775413146501900390058888),
+    Elem::new(40489510945031057754162461031716559190),
+    Elem::new(19670813578544625741179338464152843209),
+    Elem::new(46915880416024353357966510874630724947),
+    Elem::new(114939353160160239950049449380066516084),
+    Elem::new(12043155019332590068120682724426567103),
+    Elem::new(46870504619131754270542613143728865062),
+    Elem::new(313819578561693833474378948325125153449),
+    Elem::new(15815954089623200484917455005679172562),
+    Elem::new(324906161876354291925177847198805172018),
+    Elem::new(82373366430706107445970238553111649820),
+    Elem::new(178034052272949984734783637292796520779),
+    Elem::new(273117960985476218847567847771787859119),
+    Elem::new(293090833266872257442899474263235959353),
+    Elem::new(62139554221019187989002575610214683409),
+    Elem::new(102766284082545169465429943000539956871),
+    Elem::new(39385285765162048991057202052769547601),
+    Elem::new(180401778122716637120238568741784683115),
+    Elem::new(309525149683396549766350329275621767935),
+    Elem::new(84038475583233038991757586478286669916),
+    Elem::new(234062146996337888827492171249677392993),
+    Elem::new(323310510566112029388192149864581963180),
+    Elem::new(301207289982115755322475641545874272257),
+    Elem::new(84049341943627962255937464659790941427),
+    Elem::new(315929073617022517924816045685957667234),
+    Elem::new(162646365929718397116122932489246851295),
+    Elem::new(172499093692276409659563358954287891866),
+    Elem::new(156713249813526233806799559174497023706),
+    Elem::new(109972750528801243631926757967564283978),
+    Elem::new(112512065357306482362551599704570443791),
+    Elem::new(255994930306963543664116794732422441241),
+    Elem::new(214329282343092783438425440203084094068),
+    Elem::new(64976802547582114529278046521294416801),
+    Elem::new(239973314280353238624889319812281555614),
+    Elem::new(235152665813272431562736847284494180627),
+    Elem::new(273773666336199890733233715328278213287),
+    Elem::new(284650346961449863727038329712915931496),
+    Elem::new(61065510835289410981339340178457166136),
+    Elem::new(104649432504539583430565445446230612071),
+    Elem::new(100919080717190190648483532305466105205),
+    Elem::new(67907014973974320952229305004651693783),
+    Elem::new(44509895053912856409674344007711970833),
+    Elem::new(306738297495733679029035559480138009862),
+    Elem::new(178305333938240557912427307596720606469),
+    Elem::new(156237122717252853486129369967118141251),
+    Elem::new(334672967577271588356044999465265227586),
+    Elem::new(28414779698919834472693297496037969293),
+    Elem::new(213943426631885139395265860839332708338),
+    Elem::new(224385703597134682069900174279354156987),
+    Elem::new(66740876278969721096606926779784609147),
+    Elem::new(167356838787115259870211106274044189790),
+    Elem::new(193679879819556009113785819013808190130),
+    Elem::new(94760554754683525856175550353405662625),
+    Elem::new(214730263384750756040835595644527075767),
+    Elem::new(18575177637473184817201773757458372905),
+    Elem::new(276720180780684635091076290714408169553),
+    Elem::new(45530879145430914015555136371148764562),
+    Elem::new(164237404472903349373166417170223880295),
+    Elem::new(109533563427598386855407081867227054145),
+    Elem::new(215195192858504014106231033462023816618),
+    Elem::new(332732760472743796575470439081244783569),
+    Elem::new(189844318280055690918349610101087943049),
+    Elem::new(155745196546445725018524483801207074418),
+    Elem::new(109859830795869308190123354166600875557),
+    Elem::new(134859734143464939453682567965507418181),
+    Elem::new(278233172380630297033924908597880541135),
+    Elem::new(35394423596932030287623014126401678669),
+    Elem::new(121998859549916802654509130634999332692),
+    Elem::new(138826039830746710582717134654550235442),
+    Elem::new(227132008091428277041075932959724624366),
+    Elem::new(202110375088186210463872181871218906899),
+    Elem::new(117382250556058326555947094647094488562),
+    Elem::new(244747250662220572424418356792462592699),
+    Elem::new(189034708736834228733564108622983725340),
+    Elem::new(68037118494838978130867432008686752975),
+    Elem::new(219367858281438268941043800701661095291),
+    Elem::new(305742733670054560835853618512163846220),
+    Elem::new(31790131974902480505337581795885978503),
+    Elem::new(338762973935015584150331150474177642219),
+    Elem::new(211983036459794651783832225306831254260),
+    Elem::new(186267375819053403873437019499348647031),
+    Elem::new(138459544820636366497873765985797376554),
+    Elem::new(29133488541509115166485262030109865932),
+    Elem::new(49897456536387096275271497312522474911),
+    Elem::new(139407083662037595667917393799176746145),
+    Elem::new(167959804118552310902283769008410585753),
+    Elem::new(48523394302450142575330388387134042112),
+    Elem::new(337244918669359053771253154435302547005),
+    Elem::new(186862842971561804162066473730739892343),
+    Elem::new(231415683686585617131091098923228280808),
+    Elem::new(78470167407335545470565037953230775061),
+    Elem::new(180458518409655705127781013809135352606),
+    Elem::new(247046641037336367754210888188375918760),
+    Elem::new(58968428166818331331541810865004463791),
+    Elem::new(6418681148557820377805231709476262277),
+    Elem::new(138651870873716836007369837292046959957),
+    Elem::new(6525120578268629119615533922158024862),
+    Elem::new(194598671913581655633623738435204808400),
+    Elem::new(111258876164968110204205949549192009930),
+    Elem::new(143870461767518838695803932614780162905),
+    Elem::new(209525748347860386769661352515378695023),
+    Elem::new(190192104983719973584696003216572230483),
+    Elem::new(184502538041065282806307108830890899322),
+    Elem::new(321029783365696604774945623084784103851),
+    Elem::new(88624605242155322770205366644888427076),
+    Elem::new(23014086774328006616503033358704544974),
+    Elem::new(226973454938178036736488780365195167250),
+    Elem::new(112693821921865182182894812652302298933),
+    Elem::new(73410872513013108788068981914941827300),
+    Elem::new(184109664907507568280635144527358292101),
+    Elem::new(337738737526431967194483340270271564767),
+    Elem::new(64193937218784807973053186407299648667),
+    Elem::new(283942211838470481696433693445584812224),
+    Elem::new(73743428335000245471991336224267507447),
+    Elem::new(223023217936090433560897983913307250423),
+    Elem::new(209712771891560077174457435450235082308),
+    Elem::new(137949192850949420788212538425591609044),
+    Elem::new(46240390773821387769942817553541965070),
+    Elem::new(333222750842596161622858700879986440003),
+    Elem::new(92319836797987348631437812576690071492),
+    Elem::new(186040831889033022332567283513857361624),
+    Elem::new(136567838399863348052840543140030149412),
+    Elem::new(325562237573219353740693717393965855814),
+    Elem::new(102468090049399499086435507055632024239),
+    Elem::new(60112760858147465824565006079706942834),
+    Elem::new(257464971926344428965975537665086579596),
+    Elem::new(174343678765777448742340461326750478977),
+    Elem::new(213135174244044145010105135014092850513),
+    Elem::new(55565305638719444571941703866118057487),
+    Elem::new(48277870449303849833637180881118052105),
+    Elem::new(274797698816625495273413617448443484093),
+    Elem::new(143890533554932089465435303625764516105),
+    Elem::new(40158832457079308676229570678354706596),
+    Elem::new(60784811396064304468508123873822554480),
+    Elem::new(88933905405294454745144034236300451765),
+    Elem::new(45425287649235949079700729212137317866),
+    Elem::new(269384402892589761407632837628348266565),
+    Elem::new(186433331462085537733915896812212767054),
+    Elem::new(275428509002056923478305635640878423589),
+    Elem::new(143918048015470643019268644128295066717),
+    Elem::new(177286088968351482825754713021761132951),
+    Elem::new(85988304301908765517174574384643732710),
+    Elem::new(294361147438618842519974101639036697074),
+    Elem::new(292924225857714780241039502671300722140),
+    Elem::new(288392104458502430374066781612603440808),
+    Elem::new(168842634998271982566781077612050905880),
+    Elem::new(6807065947284755513071393878045019250),
+    Elem::new(39927878462939011158484329376538488459),
+    Elem::new(247056105776452153334153652697239762864),
+    Elem::new(248983201907965509831813123918201276043),
+    Elem::new(196128649953268753125892393114828917732),
+    Elem::new(21423388697517005821036462592505552383),
+    Elem::new(172574514306368769024003149729289698285),
+    Elem::new(42678349837220973263232593439913878104),
+    Elem::new(121217042160129004371028635575459306617),
+    Elem::new(79381613614188748313136289145652794420),
+    Elem::new(69780019540936805321274375512727115725),
+    Elem::new(209986321924210540613309578473337005518),
+    Elem::new(281670438569337409410884264661979569295),
+    Elem::new(31540505557382677424172205885403219227),
+    Elem::new(206111765968188347579308529469874475520),
+    Elem::new(174231289986109064970535527657379056682),
+    Elem::new(258630806808144949772129172876072107287),
+    Elem::new(187018554009883683604652398647413403550),
+    Elem::new(47411934223364584035742974465905216208),
+    Elem::new(24177092566750910620789065552182759650),
+    Elem::new(180304595578089825874144040049031962249),
+    Elem::new(261816104646547526560618696577388641607),
+    Elem::new(296499814610555090743412394557941821150),
+    Elem::new(210580976050923360054035901328556937176),
+    Elem::new(259005362773164867922287688108098373516),
+    Elem::new(198647564405420589277929827529265652314),
+    Elem::new(94304989054522051446280042616238912884),
+    Elem::new(162987063115151505072537954570051797369),
+    Elem::new(301544686607627753241817198569077386217),
+    Elem::new(246473126097400119255128539563594031733),
+    Elem::new(168662562774975900945167623219916242118),
+    Elem::new(301257460723086751135583011667896166084),
+    Elem::new(154898808920033006371820303371109276879),
+    Elem::new(9239734815874693579024427588985653848),
+    Elem::new(74077510239989504828011005793035162095),
+    Elem::new(300474755528729319156142381334468575616),
+    Elem::new(148108720624079968337508021464281575782),
+    Elem::new(313689112435989904576318541290887527938),
+    Elem::new(111130852495019910995547519817035625578),
+    Elem::new(223222699929702297067471289765119604105),
+    Elem::new(72028351302484278176695777107357474988),
+    Elem::new(297227227822409006931599893168406215903),
+    Elem::new(80933618298611427532327590807681155668),
+    Elem::new(168466764005903451387784567004547558929),
+    Elem::new(251977746186251084992598339451459185001),
+    Elem::new(145040812299527699383165227703234081454),
+    Elem::new(101822393057709513084658217295604234840),
+    Elem::new(288431253451872236632914796547675956522),
+    Elem::new(98990055970627778343398759024650214271),
+    Elem::new(48057007800817870473300520511705402928),
+    Elem::new(14726391725092634656950707336080458624),
+    Elem::new(280831725708512665213183090879589090816),
+    Elem::new(221870072465373929724018503819095336214),
+    Elem::new(100304585234090726059514069161313838944),
+    Elem::new(9165191202000624994058511179157109736),
+    Elem::new(70066139057371755901333131820066407531),
+    Elem::new(320533905756861001493906916812361785817),
+    Elem::new(66993830895017201028853948690338743083),
+    Elem::new(75598440679055545530816290357281390570),
+    Elem::new(242558345336489497524711418285176710182),
+    Elem::new(8298782305959960427030983770240083560),
+    Elem::new(142526248129849722124153514125482617502),
+    Elem::new(53193291083804740396643176533522820582),
+    Elem::new(130698728975551191016790533353711071232),
+    Elem::new(64780300046999983398945358608430781871),
+    Elem::new(109060423885331579977899350103286032390),
+    Elem::new(140499677785429969783248446924785062310),
+    Elem::new(324033523910380948923898058024733822673),
+    Elem::new(120791273061671051869210791246252539246),
+    Elem::new(70383710879219307221158051794377737203),
+    Elem::new(74610547615785834977440093304446522715),
+    Elem::new(309518959654088779730628208997132209588),
+    Elem::new(211247654600164849137419157750748303103),
+    Elem::new(164114179160211213277201509972940754505),
+    Elem::new(284187377944456256208739306565853494188),
+    Elem::new(246181255486265536816268760893853555005),
+    Elem::new(39913826032447665537356945868007657534),
+    Elem::new(186871149716794203173320715898517974446),
+    Elem::new(9624259928743754632986167934578359375),
+    Elem::new(172014537911582150100999483989642985997),
+    Elem::new(119961960323466308897860209075446350055),
+    Elem::new(115402950841089073524896383259708726695),
+    Elem::new(136249173427122927621648618975942504431),
+    Elem::new(89328154998810756524233036382721078713),
+    Elem::new(281871428917872093028130794177025149062),
+    Elem::new(202410203273514045142179794201205969842),
+    Elem::new(48437873660339581614376438284587171633),
+    Elem::new(282462001055555048712285764654025115830),
+    Elem::new(138013842358829230277504978686632095202),
+    Elem::new(57021596199074463333906399540633408414),
+    Elem::new(309006463008568448783821984093459812622),
+    Elem::new(157829240548193107149003438451073012721),
+    Elem::new(119562283037975656533559147997319156334),
+    Elem::new(327135643938181517495522641245086494312),
+    Elem::new(314844721660426881270684623849123392886),
+    Elem::new(82525958924357074723711418635237863491),
+    Elem::new(308773601396406011865158640424567899831),
+    Elem::new(187625333836020963384608578627384114581),
+    Elem::new(26013850211119759008263552815610363025),
+    Elem::new(296152630234095573362835932476662277061),
+    Elem::new(327858168212501988098462619565188713014),
+    Elem::new(192589070660514405178896476534930581246),
+    Elem::new(93790954711975227892130794671721667000),
+    Elem::new(239240627562564149479973190890834180065),
+    Elem::new(7037486369373258134677471843269157014),
+    Elem::new(256521233328010397335480499685069114570),
+    Elem::new(333727227104996474816096763343765286041),
+    Elem::new(188004942242449905886418489608687568104),
+    Elem::new(123973141457616464283905552193048512383),
+    Elem::new(339126845935608291328080041115483312792),
+    Elem::new(8938802799115433034618456252829100189),
+    Elem::new(45066379126369319775831178231792676584),
+    Elem::new(122417943622330532050178978792079337556),
+    Elem::new(12113460097817264068749261063146695112),
+    Elem::new(114342512472554870219518449061110321637),
+    Elem::new(106674245540138924353673839833170423612),
+    Elem::new(216851429122268691748829769952977638433),
+    Elem::new(160027662189031753450931719390640007661),
+    Elem::new(273545529139465991251770854573618108070),
+    Elem::new(5229278065717132754888195407534252785),
+    Elem::new(188530060978371579585265384344843366059),
+    Elem::new(113610972123842263271233156688552999666),
+    Elem::new(122933390206438987211345940424817698064),
+    Elem::new(321317307709650238587194511571109242173),
+    Elem::new(77842785940910926780831218571275170380),
+    Elem::new(76070186333550261383857655361312974014),
+    Elem::new(85803697955633130523539028689083302778),
+    Elem::new(295385614856372300990314484636564818408),
+    Elem::new(186241695117096150118380762813308128435),
+    Elem::new(246833319191450140212444431024829101225),
+    Elem::new(282869867777054078889149601987103030163),
+    Elem::new(231273777084126784907445614120245221137),
+    Elem::new(69563286959812040901242079644366687061),
+    Elem::new(182457868731770684604767048973792214457),
+    Elem::new(290716374084127411118800381476763172196),
+    Elem::new(185700278210255688910230182970546687782),
+    Elem::new(308991674564161672211517823778997818463),
+    Elem::new(289544783022616648659323459254004429914),
+    Elem::new(46611027115575743712887196123800603435),
+    Elem::new(98672740415595742986658238029456836119),
+    Elem::new(288729069043236562597386130894050879268),
+    Elem::new(253443782648123679312235577778599679067),
+    Elem::new(147898659178210302975970122661558643272),
+    Elem::new(294106016161353269120682358529526337859),
+    Elem::new(37386264122149250033663035576982586037),
+    Elem::new(306302773563742726002725315699208808815),
+    Elem::new(216787083777719781437402021821825298348),
+    Elem::new(54585556265855268754336848534370758322),
+    Elem::new(25006191026455115079239036299324963601),
+    Elem::new(338760957173001495465396958105053853997),
+    Elem::new(121854258122213652675163263781613497828),
+    Elem::new(254472955073286109087932855005069977633),
+    Elem::new(156821530720554546488902487659565780579),
+    Elem::new(210094917899424872561755980091957313683),
+    Elem::new(44735834295522659812615559276307983612),
+    Elem::new(17705820215415689671902677371772649176),
+    Elem::new(68292300930778954293567697878517554493),
+    Elem::new(70995249811982663817181367760203266683),
+    Elem::new(214677606525157711469043159403022611191),
+    Elem::new(197549153694679026104233379395066122576),
+    Elem::new(226620372703373976791032787053423526356),
+    Elem::new(277113559389435314358568410743470152809),
+    Elem::new(149517061955872838259802898991650679376),
+    Elem::new(16194045419960193299596203826394296703),
+    Elem::new(71106805268253253075199265789760427187),
+    Elem::new(129238059849325412751389326479040058031),
+    Elem::new(213199594871358680133843143404237687846),
+    Elem::new(328633330102349899490847092784765220570),
+    Elem::new(135240478686287059758835940662717322839),
+    Elem::new(98519975304562622185305817489720088455),
+    Elem::new(60630867009337267852088373047134175500),
+    Elem::new(219774539196245026968231780881459975075),
+    Elem::new(252165523578849515294874156518646944995),
+    Elem::new(40615357448937402983250349180865531205),
+    Elem::new(305849284882452352847051360004110400031),
+    Elem::new(251791437678881285479122334591744769661),
+    Elem::new(180859807037931641412072231533093424462),
+    Elem::new(205615738673369796666450946286784175916),
+    Elem::new(102792299195442865965142115386416489695),
+    Elem::new(277238507939007801057331470343845028171),
+    Elem::new(218816012452866637468006302792571837640),
+    Elem::new(1626029714003143057138203196469808255),
+    Elem::new(26240340880041274547378300746875079484),
+    Elem::new(163850449015423833838068867897921068781),
+    Elem::new(133599049735704294364879656406854538888),
+    Elem::new(308664490164422656128591653433309289610),
+    Elem::new(303312686720490019646459568763063256501),
+    Elem::new(327081675505083289137576479379164574694),
+    Elem::new(75581866834118906967017382201420767852),
+    Elem::new(112790807970315899248732198261033460703),
+    Elem::new(181640565220021421843661060199818697607),
+    Elem::new(185694785063746881854200158131226794812),
+    Elem::new(140715296486761189026663503142623559248),
+    Elem::new(220022839997778747830365462957077951495),
+    Elem::new(332039920828886984325986278879404340199),
+    Elem::new(151968794996395969786107105962574488778),
+    Elem::new(71917954230850555517593962069060611265),
+    Elem::new(195873255580340774422362967879506525075),
+    Elem::new(74211774995374152373806251150037662540),
+    Elem::new(275673653785049449355999254507689122022),
+    Elem::new(136200977670672289609213663002173129095),
+    Elem::new(178197943169171869699621722694541510204),
+    Elem::new(162565551363852801566050348012095866066),
+    Elem::new(4294942762530470566995947688348154549),
+    Elem::new(119146891969298518254723591791008648655),
+    Elem::new(226492858620134418354353566475944274986),
+    Elem::new(310243018180118626831473308977568579180),
+    Elem::new(95530436951054644581033070255291029073),
+    Elem::new(120443410032456112881836488544246212789),
+    Elem::new(26310728516130098974830607069640766859),
+    Elem::new(137793466610640818028693403824209944312),
+    Elem::new(159368908891208528937407296007780903243),
+    Elem::new(84731632072292639743787480195809875662),
+    Elem::new(145467189301514315630077959882229154686),
+    Elem::new(134918588170086948217047885442894188836),
+    Elem::new(132156724457246233486340290578646929811),
+    Elem::new(147126145661599412215876008699283483283),
+    Elem::new(284746463913948809621500518567564031157),
+    Elem::new(276472160005970246749522131372167983796),
+    Elem::new(72977069134978572756379060926176483546),
+    Elem::new(56396167788022964340219829423533266172),
+    Elem::new(216634700752157728546413859043455867907),
+    Elem::new(45148844429094379553182133739343213958),
+    Elem::new(114634850588677535872907322256417320996),
+    Elem::new(13880133174484605474215134315539693465),
+    Elem::new(157797487347252132511951685924807235474),
+    Elem::new(317069810675126919999535126929201773136),
+    Elem::new(279425341161313411799803832520514601420),
+    Elem::new(27440700617349072748913959474406799845),
+    Elem::new(71421482884498299438509788846659021780),
+    Elem::new(290036182473195115452510026374551513229),
+    Elem::new(252816657826654817470441755476369398183),
+    Elem::new(125151066422939935860554148283312017596),
+    Elem::new(6959428755278794389286437861825011266),
+    Elem::new(249205944785848383313308285647317112090),
+    Elem::new(288015592530855978098495762052918790182),
+    Elem::new(335113565293242936978842820824070426557),
+    Elem::new(87496168250772717567713192826105190856),
+    Elem::new(329400638155623238476927042403463188512),
+    Elem::new(237596005332351875450212328821209877333),
+    Elem::new(260541335196768403052680710821646073668),
+    Elem::new(286117506307847366114534404729629721189),
+    Elem::new(147754433749620246570189691382860967941),
+    Elem::new(30643395114278762835951464134895431102),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    poseidon_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    poseidon_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Poseidon
+// ================================================================================================
+
+pub fn poseidon_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        poseidon_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+/// NUM_FULL_ROUNDS / 2 full rounds, NUM_PARTIAL_ROUNDS partial rounds, NUM_FULL_ROUNDS / 2 full rounds
+pub fn poseidon_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // add round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    // S-boxes, only the first element passes an S-box in partial rounds
+    if is_full_round(tmp_round) {
+        apply_sbox(state);
+    } else {
+        state[0] = state[0].exp(ALPHA.into());
+    }
+    // matrix multiplication
+    matrix_mul(MDS, state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Poseidon
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of Poseidon after absorbing the pixels
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_sbox_and_mds<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut state: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // the full round flag selects between x^ALPHA and x for all but the first element
+    let full_round_flag = round_constants[STATE_WIDTH];
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    state[0] = state[0].exp(ALPHA.into());
+    for i in 1..STATE_WIDTH {
+        state[i] = full_round_flag * state[i].exp(ALPHA.into()) + (E::ONE - full_round_flag) * state[i];
+    }
+    matrix_mul(MDS, &mut state);
+
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (next_slice[i] - state[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// number of periodic columns returned by get_round_constants_periodic
+pub const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH + 1;
+
+/// returns round constants arranged in column-major form for periodic columns, followed by the full
+/// round flag column
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..NUM_PERIODIC_COLUMNS {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+        if is_full_round(i) {
+            constants[STATE_WIDTH][(i + shift) % cycle_length] = Elem::ONE;
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_full_round(round: usize) -> bool {
+    round < NUM_FULL_ROUNDS / 2 || round >= NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = NUM_PERIODIC_COLUMNS;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the S-boxes of the partial rounds are selected by the full round flag, which has the hash cycle
+        let mut cycles = cycles;
+        cycles.push(cycles[0]);
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
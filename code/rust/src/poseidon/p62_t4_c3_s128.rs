@@ -0,0 +1,466 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Parameters derived using https://extgit.iaik.tugraz.at/krypto/hadeshash (calc_round_numbers.py and
+// generate_params_poseidon.sage): round numbers include the security margin, round constants and the
+// Cauchy MDS matrix are sampled from the Grain LFSR
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 4
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// POSEIDON CONSTANTS
+// ================================================================================================
+
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 40;
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+pub const STATE_WIDTH: usize = 4;
+pub const CAPACITY: usize = 3;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+pub const ALPHA: u32 = 3;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(417145516052673525),
+    Elem::new(4513003360684910884),
+    Elem::new(2111041363459630907),
+    Elem::new(3777906248065695387),
+    Elem::new(2880388555919196238),
+    Elem::new(1954651995459104437),
+    Elem::new(358365183994774054),
+    Elem::new(2896923151828761526),
+    Elem::new(332121466091230314),
+    Elem::new(4497936472351290362),
+    Elem::new(1797188779304741351),
+    Elem::new(3896739786850038513),
+    Elem::new(3390877686883950117),
+    Elem::new(796185870200421018),
+    Elem::new(3150795107775685776),
+    Elem::new(2441943751745557315),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(4009876925509210433),
+    Elem::new(2983262967377471327),
+    Elem::new(1622461921136347638),
+    Elem::new(1782013043152121947),
+    Elem::new(1221440077555671779),
+    Elem::new(672635732208447949),
+    Elem::new(58650568179863800),
+    Elem::new(723175899792977063),
+    Elem::new(4501048210573015301),
+    Elem::new(3003644722162382758),
+    Elem::new(3357884781193069821),
+    Elem::new(3831366755094007273),
+    Elem::new(3410816636363905628),
+    Elem::new(1223985353086718427),
+    Elem::new(3757000411287949641),
+    Elem::new(1843220517045032251),
+    Elem::new(768424330000709111),
+    Elem::new(1320076956970716579),
+    Elem::new(3527023403045092080),
+    Elem::new(363663091668275117),
+    Elem::new(1640979293741532816),
+    Elem::new(4446270803766387447),
+    Elem::new(2548586232213212633),
+    Elem::new(587391405666660674),
+    Elem::new(4486758279128351481),
+    Elem::new(2043493222451615986),
+    Elem::new(374831084606604474),
+    Elem::new(3395843707437677570),
+    Elem::new(32163285200660724),
+    Elem::new(4393721375692369041),
+    Elem::new(2753034120801227168),
+    Elem::new(1550346410395889523),
+    Elem::new(4519022649951602915),
+    Elem::new(3522320205697824588),
+    Elem::new(3493702036597515707),
+    Elem::new(3625709702210143234),
+    Elem::new(2924652163460150108),
+    Elem::new(2957832035976390325),
+    Elem::new(1469928548061902296),
+    Elem::new(2212151732963535347),
+    Elem::new(1414415663300732005),
+    Elem::new(1704987462696096660),
+    Elem::new(1999660063325807541),
+    Elem::new(3304129063812279311),
+    Elem::new(1214351290500719246),
+    Elem::new(4146577041017488466),
+    Elem::new(755148399932902124),
+    Elem::new(4556632687341967798),
+    Elem::new(1291161545168872093),
+    Elem::new(4301791948218175754),
+    Elem::new(149463204874951917),
+    Elem::new(4359746245486107062),
+    Elem::new(947184560496775919),
+    Elem::new(4264287918569140972),
+    Elem::new(2370161645379213834),
+    Elem::new(4286879095551718428),
+    Elem::new(2892808104428652361),
+    Elem::new(4051407983290556084),
+    Elem::new(4330028060741116222),
+    Elem::new(538041374599419496),
+    Elem::new(1483728233255029894),
+    Elem::new(3333946171366085083),
+    Elem::new(1322564557131647795),
+    Elem::new(3984351251221347110),
+    Elem::new(1070151693830116261),
+    Elem::new(352501703082273261),
+    Elem::new(1807894966711921392),
+    Elem::new(2753561593869063471),
+    Elem::new(4067506979348993304),
+    Elem::new(385264813840999817),
+    Elem::new(1995447519591919807),
+    Elem::new(825276149646435484),
+    Elem::new(4027267990835838400),
+    Elem::new(1467118262604917714),
+    Elem::new(2766669239804881009),
+    Elem::new(2237248056958312748),
+    Elem::new(4005524174897037319),
+    Elem::new(2955499222070190205),
+    Elem::new(1145281743821368178),
+    Elem::new(3715915785099576471),
+    Elem::new(287768315364266794),
+    Elem::new(2289998383024730286),
+    Elem::new(1800836264042259388),
+    Elem::new(825331926324055870),
+    Elem::new(516924868550793464),
+    Elem::new(4575112850077354028),
+    Elem::new(3765208299817939231),
+    Elem::new(809181320384080269),
+    Elem::new(2012167321956690746),
+    Elem::new(3367356304744808615),
+    Elem::new(692374893782730820),
+    Elem::new(4196475861452448193),
+    Elem::new(2599723292020128935),
+    Elem::new(2074369465186794492),
+    Elem::new(706733314794111238),
+    Elem::new(4472552572320532588),
+    Elem::new(2370652446893583306),
+    Elem::new(3484803371174195333),
+    Elem::new(2693520488987041953),
+    Elem::new(2005047564849054729),
+    Elem::new(2745232320687599418),
+    Elem::new(3420075363371685014),
+    Elem::new(2934421192537243383),
+    Elem::new(1084874926537028465),
+    Elem::new(4421195204073814816),
+    Elem::new(3705802579749550505),
+    Elem::new(2614674472976137665),
+    Elem::new(229578995262178324),
+    Elem::new(3398322720392408676),
+    Elem::new(1238303985839885658),
+    Elem::new(2318906436432081684),
+    Elem::new(1468298464828665425),
+    Elem::new(2561611954033895108),
+    Elem::new(2486503294136838332),
+    Elem::new(2353613893752388968),
+    Elem::new(3412246666621128694),
+    Elem::new(4475406304856086471),
+    Elem::new(2369613908554180782),
+    Elem::new(3909343914369198900),
+    Elem::new(4092970003020033499),
+    Elem::new(962856605666376741),
+    Elem::new(2324866177885135166),
+    Elem::new(4206063659779432855),
+    Elem::new(2682765891780528193),
+    Elem::new(676597765754754593),
+    Elem::new(440227543283627225),
+    Elem::new(4093444132952972843),
+    Elem::new(2086962282002129193),
+    Elem::new(2351846362324876125),
+    Elem::new(2763007897220070165),
+    Elem::new(3029236034190689463),
+    Elem::new(1353202457556131068),
+    Elem::new(254438246623741041),
+    Elem::new(3016098328785560696),
+    Elem::new(2458663186643748111),
+    Elem::new(3339144359208726906),
+    Elem::new(4102569128253607407),
+    Elem::new(85408362921875908),
+    Elem::new(2059341710505592784),
+    Elem::new(3426279754816225388),
+    Elem::new(2945653895258331963),
+    Elem::new(543121662714655967),
+    Elem::new(2654205982488231462),
+    Elem::new(236977295873165795),
+    Elem::new(1889305679494439619),
+    Elem::new(3371261599507721812),
+    Elem::new(1362661050277295327),
+    Elem::new(3660389591860805415),
+    Elem::new(3253161334314474100),
+    Elem::new(3214728795658416375),
+    Elem::new(4432259076116649902),
+    Elem::new(1163235240340782923),
+    Elem::new(3962832216528289278),
+    Elem::new(3860373353576084094),
+    Elem::new(668450294756330947),
+    Elem::new(1443073714036609950),
+    Elem::new(2153050684749716805),
+    Elem::new(737934693138524244),
+    Elem::new(113693995974435098),
+    Elem::new(892672531986572597),
+    Elem::new(2520309307674823095),
+    Elem::new(603972582073469674),
+    Elem::new(200167083533082727),
+    Elem::new(597400718645927569),
+    Elem::new(3399673850089907809),
+    Elem::new(2452824359828513519),
+    Elem::new(933033638898339628),
+    Elem::new(1019734265777641879),
+    Elem::new(3761936939409051491),
+    Elem::new(3567775449894359923),
+    Elem::new(816318216305124365),
+    Elem::new(1300764794416531328),
+    Elem::new(2844501669249880640),
+    Elem::new(1046207464227572318),
+    Elem::new(357634801068407684),
+    Elem::new(2693835497589122616),
+    Elem::new(2011194846137104328),
+    Elem::new(241169329616821420),
+    Elem::new(991149037791879479),
+    Elem::new(184987956895863454),
+    Elem::new(2911289921741994456),
+    Elem::new(744622720959905283),
+    Elem::new(2203747002378187734),
+    Elem::new(4531528775275772641),
+    Elem::new(3461799812421082588),
+    Elem::new(1638321320492370432),
+    Elem::new(1568891283865916020),
+    Elem::new(3498580630958364531),
+    Elem::new(3619577157316620646),
+    Elem::new(1249967307293774991),
+    Elem::new(3340136776771699017),
+    Elem::new(2921856262724418457),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    poseidon_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    poseidon_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Poseidon
+// ================================================================================================
+
+pub fn poseidon_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        poseidon_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+/// NUM_FULL_ROUNDS / 2 full rounds, NUM_PARTIAL_ROUNDS partial rounds, NUM_FULL_ROUNDS / 2 full rounds
+pub fn poseidon_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // add round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    // S-boxes, only the first element passes an S-box in partial rounds
+    if is_full_round(tmp_round) {
+        apply_sbox(state);
+    } else {
+        state[0] = state[0].exp(ALPHA.into());
+    }
+    // matrix multiplication
+    matrix_mul(MDS, state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Poseidon
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of Poseidon after absorbing the pixels
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_sbox_and_mds<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut state: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // the full round flag selects between x^ALPHA and x for all but the first element
+    let full_round_flag = round_constants[STATE_WIDTH];
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    state[0] = state[0].exp(ALPHA.into());
+    for i in 1..STATE_WIDTH {
+        state[i] = full_round_flag * state[i].exp(ALPHA.into()) + (E::ONE - full_round_flag) * state[i];
+    }
+    matrix_mul(MDS, &mut state);
+
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (next_slice[i] - state[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// number of periodic columns returned by get_round_constants_periodic
+pub const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH + 1;
+
+/// returns round constants arranged in column-major form for periodic columns, followed by the full
+/// round flag column
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..NUM_PERIODIC_COLUMNS {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+        if is_full_round(i) {
+            constants[STATE_WIDTH][(i + shift) % cycle_length] = Elem::ONE;
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_full_round(round: usize) -> bool {
+    round < NUM_FULL_ROUNDS / 2 || round >= NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = NUM_PERIODIC_COLUMNS;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the S-boxes of the partial rounds are selected by the full round flag, which has the hash cycle
+        let mut cycles = cycles;
+        cycles.push(cycles[0]);
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,771 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Parameters derived using https://extgit.iaik.tugraz.at/krypto/hadeshash (calc_round_numbers.py and
+// generate_params_poseidon.sage): round numbers include the security margin, round constants and the
+// Cauchy MDS matrix are sampled from the Grain LFSR
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   t   .. 9
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// POSEIDON CONSTANTS
+// ================================================================================================
+
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 40;
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+pub const STATE_WIDTH: usize = 9;
+pub const CAPACITY: usize = 1;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+pub const ALPHA: u32 = 3;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(195475115974364993),
+    Elem::new(4422590052287348266),
+    Elem::new(2862516290893188941),
+    Elem::new(3896156649784903874),
+    Elem::new(3141576187953924782),
+    Elem::new(702211618363131603),
+    Elem::new(4440272190548011073),
+    Elem::new(545387189348183814),
+    Elem::new(1412183220415280250),
+    Elem::new(659621057913610212),
+    Elem::new(422783974262293304),
+    Elem::new(286143656787619238),
+    Elem::new(356922945390693388),
+    Elem::new(4075071413507922058),
+    Elem::new(3072396716954139921),
+    Elem::new(3325515298859134522),
+    Elem::new(2455053884394050718),
+    Elem::new(2655230475826621745),
+    Elem::new(758640331984808625),
+    Elem::new(1422860788782138451),
+    Elem::new(2122376173850214945),
+    Elem::new(4339441976758384114),
+    Elem::new(440977097383714611),
+    Elem::new(4022187647241744261),
+    Elem::new(2240567430353049280),
+    Elem::new(3798061284293100870),
+    Elem::new(3106777489619108549),
+    Elem::new(3333152092766362831),
+    Elem::new(3421722510080679036),
+    Elem::new(1036097885048621980),
+    Elem::new(2091242157655626690),
+    Elem::new(788283985466077899),
+    Elem::new(3138247919728375157),
+    Elem::new(2090513237622949998),
+    Elem::new(62979566806373092),
+    Elem::new(2388936763800889754),
+    Elem::new(506127279405471787),
+    Elem::new(2746077234840633354),
+    Elem::new(615308478515225417),
+    Elem::new(2421059706976269347),
+    Elem::new(504101264019930689),
+    Elem::new(1573019839017441495),
+    Elem::new(132042113148852625),
+    Elem::new(707958416552792699),
+    Elem::new(4468268091386729666),
+    Elem::new(1531382084558792180),
+    Elem::new(4341971154604298214),
+    Elem::new(937525439000270277),
+    Elem::new(1869488297972573468),
+    Elem::new(1989075640491100839),
+    Elem::new(3995515632880097967),
+    Elem::new(1041021956580453569),
+    Elem::new(2011795983361482750),
+    Elem::new(4340478578014678671),
+    Elem::new(2749238276068792116),
+    Elem::new(806097626184944096),
+    Elem::new(4556019185660583230),
+    Elem::new(246036773261806116),
+    Elem::new(810801141511690650),
+    Elem::new(2246341019463072307),
+    Elem::new(2492875917812093902),
+    Elem::new(1835278242311537025),
+    Elem::new(3864132953273575532),
+    Elem::new(1079384572328897109),
+    Elem::new(51200494600806706),
+    Elem::new(3116380599242441488),
+    Elem::new(1289171450332165592),
+    Elem::new(4113673030701919702),
+    Elem::new(4293667103630431212),
+    Elem::new(3284775566258152829),
+    Elem::new(2446045688389496040),
+    Elem::new(629850209928147736),
+    Elem::new(717852941014289049),
+    Elem::new(36329281642188006),
+    Elem::new(2699591487656828496),
+    Elem::new(4063416697565223601),
+    Elem::new(3698549827641985830),
+    Elem::new(740254857802321987),
+    Elem::new(4457874848734651219),
+    Elem::new(211366891812669104),
+    Elem::new(153119911584364393),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(1247741853470017067),
+    Elem::new(3824047633450288707),
+    Elem::new(2233623720162179750),
+    Elem::new(2909059260650591698),
+    Elem::new(182148579238150290),
+    Elem::new(1316815461173026475),
+    Elem::new(3792850680998562824),
+    Elem::new(2206963628098967307),
+    Elem::new(1928195465319664295),
+    Elem::new(2266775595000694662),
+    Elem::new(4231496538231783695),
+    Elem::new(4343928747536419555),
+    Elem::new(2374914272800447334),
+    Elem::new(4422445859400956313),
+    Elem::new(1525565872553695654),
+    Elem::new(2780766703146058140),
+    Elem::new(1935505588638672422),
+    Elem::new(1293770192089878298),
+    Elem::new(1135359581469471600),
+    Elem::new(88508059379822047),
+    Elem::new(1207585882566398126),
+    Elem::new(1277365334995975932),
+    Elem::new(2673448541183026185),
+    Elem::new(3021367417598462277),
+    Elem::new(3264341177173398187),
+    Elem::new(4563671684648171469),
+    Elem::new(370667940542501730),
+    Elem::new(3464816294821506912),
+    Elem::new(4254471445412671761),
+    Elem::new(1951046663792807089),
+    Elem::new(1466695881430997760),
+    Elem::new(3888970421315969588),
+    Elem::new(1896706899775054622),
+    Elem::new(4439167891640015868),
+    Elem::new(503955144780451135),
+    Elem::new(1374222582617043553),
+    Elem::new(2316584469893784633),
+    Elem::new(699430921656678153),
+    Elem::new(794783357648022863),
+    Elem::new(2896025011661799498),
+    Elem::new(4572202813903970452),
+    Elem::new(3304130283543166946),
+    Elem::new(3293312361959604711),
+    Elem::new(643707070817705854),
+    Elem::new(1413134329150363953),
+    Elem::new(4361035640249254776),
+    Elem::new(2092927632991167487),
+    Elem::new(3618213524915994082),
+    Elem::new(535000136992217167),
+    Elem::new(2370019493381321458),
+    Elem::new(2731435960609832420),
+    Elem::new(2865965023565806088),
+    Elem::new(40482223184056279),
+    Elem::new(2784626909226986862),
+    Elem::new(3455012259225418621),
+    Elem::new(3828953884084457372),
+    Elem::new(3571710597337059428),
+    Elem::new(1457566588685676730),
+    Elem::new(369079425141549949),
+    Elem::new(3338633566028090025),
+    Elem::new(4478200080914951440),
+    Elem::new(814401767295479879),
+    Elem::new(3745018641294462755),
+    Elem::new(3983697529412329625),
+    Elem::new(3858774819043657610),
+    Elem::new(1710864925320883375),
+    Elem::new(2982122305821579526),
+    Elem::new(3786346233188863485),
+    Elem::new(25569037602385934),
+    Elem::new(2852018649197845081),
+    Elem::new(3170221618089589690),
+    Elem::new(473771895097756054),
+    Elem::new(3218081132616893603),
+    Elem::new(3788282194364815922),
+    Elem::new(406717958556142034),
+    Elem::new(102320103284139162),
+    Elem::new(2992852427013465058),
+    Elem::new(308625439765917121),
+    Elem::new(1238035799914786551),
+    Elem::new(3804450268953406380),
+    Elem::new(945026506255899848),
+    Elem::new(2324788375122216392),
+    Elem::new(2941042614743642229),
+    Elem::new(2317240737584441193),
+    Elem::new(4164739988535746531),
+    Elem::new(2448521744613181533),
+    Elem::new(570898909665946175),
+    Elem::new(1336006837181069446),
+    Elem::new(3833948191788950493),
+    Elem::new(1639909506651213413),
+    Elem::new(1339776350012129782),
+    Elem::new(3796206071213070498),
+    Elem::new(2500543178945453340),
+    Elem::new(615802727512412085),
+    Elem::new(1112303996973833993),
+    Elem::new(3469166837956327423),
+    Elem::new(4133897124115321673),
+    Elem::new(2338226355049483681),
+    Elem::new(4103767147355501699),
+    Elem::new(2930827167098276232),
+    Elem::new(770696658911289205),
+    Elem::new(2277561695510186216),
+    Elem::new(2679275768853018550),
+    Elem::new(3468080372159431385),
+    Elem::new(3697163609329476808),
+    Elem::new(2392070632268165250),
+    Elem::new(2285293816112654524),
+    Elem::new(4518270354659107873),
+    Elem::new(4480755522340052342),
+    Elem::new(3465225437847515509),
+    Elem::new(321282727018713294),
+    Elem::new(1881016126400715227),
+    Elem::new(4442596302139859772),
+    Elem::new(4537031694764432254),
+    Elem::new(725410361166016513),
+    Elem::new(1244184877987784500),
+    Elem::new(1624259737172163772),
+    Elem::new(748070608694549345),
+    Elem::new(2564767294970367242),
+    Elem::new(4269772269084517188),
+    Elem::new(2895637372831470974),
+    Elem::new(3565136075018156249),
+    Elem::new(814255256944980950),
+    Elem::new(558209196083239105),
+    Elem::new(1371639342392858823),
+    Elem::new(3303974316899631802),
+    Elem::new(3485230756748685457),
+    Elem::new(1476899552737949889),
+    Elem::new(715651878780698107),
+    Elem::new(3384742943614633315),
+    Elem::new(1066510321671007677),
+    Elem::new(2120225118672927811),
+    Elem::new(3995323917900665603),
+    Elem::new(2515666386478780183),
+    Elem::new(3457569816861228903),
+    Elem::new(1085399255061791717),
+    Elem::new(2455656928537138888),
+    Elem::new(2103030788848125217),
+    Elem::new(2692431415874608436),
+    Elem::new(4216506668804865613),
+    Elem::new(1029646429719268516),
+    Elem::new(118700020551293309),
+    Elem::new(4157891248918153802),
+    Elem::new(141645363384810015),
+    Elem::new(1086875826447952374),
+    Elem::new(2882285569967004649),
+    Elem::new(4087092233930192497),
+    Elem::new(2806184643079751100),
+    Elem::new(2571844876112862418),
+    Elem::new(276021390262216312),
+    Elem::new(4181000939808858347),
+    Elem::new(192438626181913187),
+    Elem::new(603141385580417697),
+    Elem::new(22940406359577573),
+    Elem::new(596245924077968001),
+    Elem::new(2819003184569958668),
+    Elem::new(3089743440035750621),
+    Elem::new(2948084767369808210),
+    Elem::new(622986095626110924),
+    Elem::new(725870147259667775),
+    Elem::new(1899359389126792355),
+    Elem::new(2549976840117064485),
+    Elem::new(2947231801162029813),
+    Elem::new(2426778728619126822),
+    Elem::new(4401009629610466867),
+    Elem::new(3939150320784330290),
+    Elem::new(2058268309440864682),
+    Elem::new(4403157397401855154),
+    Elem::new(185706977895766927),
+    Elem::new(1286351264085758119),
+    Elem::new(1409351785597312440),
+    Elem::new(3988891467542895880),
+    Elem::new(2005313078538406242),
+    Elem::new(2253290505969565918),
+    Elem::new(3632880516943446456),
+    Elem::new(1327073935253907585),
+    Elem::new(1940573533610986623),
+    Elem::new(3424146798364723577),
+    Elem::new(4247720493316395817),
+    Elem::new(754777856975320832),
+    Elem::new(3466884713320916707),
+    Elem::new(859266565280151926),
+    Elem::new(1339014517041928073),
+    Elem::new(3002717308832097541),
+    Elem::new(252205735550131510),
+    Elem::new(438367284008306935),
+    Elem::new(2557540105168121710),
+    Elem::new(577052746341958048),
+    Elem::new(526408051587655999),
+    Elem::new(414738396522579130),
+    Elem::new(623868229756789107),
+    Elem::new(1544795320361285331),
+    Elem::new(869784061520464990),
+    Elem::new(3125870703194505967),
+    Elem::new(3155721149207239290),
+    Elem::new(3063968729444927491),
+    Elem::new(3881155083945317368),
+    Elem::new(1103975048037018721),
+    Elem::new(3331857335396476239),
+    Elem::new(3161525525100043424),
+    Elem::new(1841228285609513372),
+    Elem::new(2870401558404269116),
+    Elem::new(1529311750713775433),
+    Elem::new(2333411747924754401),
+    Elem::new(3086524547768707929),
+    Elem::new(4132757442862659458),
+    Elem::new(2620463416378803076),
+    Elem::new(2643959799730315394),
+    Elem::new(2036444076868324749),
+    Elem::new(2377616836472134538),
+    Elem::new(3301932311244538175),
+    Elem::new(2644188753907987105),
+    Elem::new(2317188245104657079),
+    Elem::new(2688245987488549583),
+    Elem::new(1891970986173873915),
+    Elem::new(2449328010561228586),
+    Elem::new(813826077123948069),
+    Elem::new(469388124006708441),
+    Elem::new(2659976274995033177),
+    Elem::new(3077399915064630093),
+    Elem::new(2880770296675524683),
+    Elem::new(4227869663342485374),
+    Elem::new(538710935017647190),
+    Elem::new(3506577169827018952),
+    Elem::new(1080295644318162564),
+    Elem::new(1373189358131050145),
+    Elem::new(177470643185774323),
+    Elem::new(4567459472872727072),
+    Elem::new(3696200792066799247),
+    Elem::new(1686832661664207084),
+    Elem::new(792070072711381094),
+    Elem::new(3141137225771166163),
+    Elem::new(1327178942045063105),
+    Elem::new(4176338561620925163),
+    Elem::new(2626636176476116879),
+    Elem::new(1560238398552717798),
+    Elem::new(1090412056493393764),
+    Elem::new(2580445369890031166),
+    Elem::new(607108861551322405),
+    Elem::new(4397283717325101158),
+    Elem::new(1588018277893370745),
+    Elem::new(1750468020756022776),
+    Elem::new(2344651160064040851),
+    Elem::new(315974641530656686),
+    Elem::new(1507097727140705302),
+    Elem::new(3750386203845552166),
+    Elem::new(2948247319731693790),
+    Elem::new(2055441624785127292),
+    Elem::new(4048270531801139371),
+    Elem::new(2271790251328538367),
+    Elem::new(4029886085951349640),
+    Elem::new(4581474874555493409),
+    Elem::new(4331431694185259598),
+    Elem::new(4410676534885600711),
+    Elem::new(1185547570316250901),
+    Elem::new(1160351747885021671),
+    Elem::new(3480528029544059294),
+    Elem::new(1987866215998081502),
+    Elem::new(908215653960097063),
+    Elem::new(3935185201458715967),
+    Elem::new(610427108842441192),
+    Elem::new(3487809667308426039),
+    Elem::new(3303913051154672266),
+    Elem::new(3243994312933304152),
+    Elem::new(2446158281626036637),
+    Elem::new(2050090513867424610),
+    Elem::new(3061614868445440334),
+    Elem::new(3676875055256332254),
+    Elem::new(4492487634099259016),
+    Elem::new(2715659176089657275),
+    Elem::new(313081537952624849),
+    Elem::new(82036545963316336),
+    Elem::new(569942587697439391),
+    Elem::new(2807477477207005216),
+    Elem::new(262413512056476991),
+    Elem::new(2891376740562939267),
+    Elem::new(3081660506945897903),
+    Elem::new(1169661578977574504),
+    Elem::new(3100778091589760067),
+    Elem::new(1382039535634933769),
+    Elem::new(3383640356766834631),
+    Elem::new(3174860523559472778),
+    Elem::new(4461532705734412500),
+    Elem::new(4573321653147498327),
+    Elem::new(2708894432731525938),
+    Elem::new(802136465702192001),
+    Elem::new(2034177144128744141),
+    Elem::new(4503655551511586327),
+    Elem::new(1520439169324090812),
+    Elem::new(2765442338550414639),
+    Elem::new(2693466843532063153),
+    Elem::new(3227768218207681727),
+    Elem::new(3327912665099662672),
+    Elem::new(3902215394093694071),
+    Elem::new(2199099056192182293),
+    Elem::new(2658423092230045462),
+    Elem::new(1549950766076527693),
+    Elem::new(102810003649082027),
+    Elem::new(4368749807880958598),
+    Elem::new(1077944305397223205),
+    Elem::new(3585006757250167364),
+    Elem::new(2649330142729175322),
+    Elem::new(3355804432324720340),
+    Elem::new(4485946266042474148),
+    Elem::new(2545128974351999132),
+    Elem::new(1576106799590919103),
+    Elem::new(2355370438764354570),
+    Elem::new(3722957685450009628),
+    Elem::new(496023003722938689),
+    Elem::new(2937177617134917719),
+    Elem::new(1188561718827004868),
+    Elem::new(698756957448804638),
+    Elem::new(156515951432129372),
+    Elem::new(1298024755285139675),
+    Elem::new(916679673326835311),
+    Elem::new(1632907414449510587),
+    Elem::new(2157768573146754065),
+    Elem::new(3729483202252286494),
+    Elem::new(3303008676609646065),
+    Elem::new(3847248796382602829),
+    Elem::new(3379230510897066022),
+    Elem::new(163819069068430009),
+    Elem::new(1791764261603243439),
+    Elem::new(3172252506008795221),
+    Elem::new(631064671054685898),
+    Elem::new(4274613235244578215),
+    Elem::new(1060443255444544580),
+    Elem::new(1917083809717708256),
+    Elem::new(2772295627063810717),
+    Elem::new(1310306182295728258),
+    Elem::new(1074336811292532946),
+    Elem::new(3794672784653578666),
+    Elem::new(2551264414843178657),
+    Elem::new(3134554448897507634),
+    Elem::new(263856645406712749),
+    Elem::new(1234628073701210669),
+    Elem::new(522161030139411351),
+    Elem::new(58148932025757710),
+    Elem::new(1894495558771048875),
+    Elem::new(1421364334817834147),
+    Elem::new(4408928998258407072),
+    Elem::new(1505131838350993923),
+    Elem::new(3232060968978047470),
+    Elem::new(3096243814738245007),
+    Elem::new(2949541719315614474),
+    Elem::new(3916931121581506644),
+    Elem::new(3777223256901871131),
+    Elem::new(470648266925172377),
+    Elem::new(4131660571554440322),
+    Elem::new(2267263650298385274),
+    Elem::new(1867132156960972568),
+    Elem::new(703755642992221265),
+    Elem::new(3584642264010502929),
+    Elem::new(1476258869198197480),
+    Elem::new(1337388346332937882),
+    Elem::new(446665225143330192),
+    Elem::new(134554100825633526),
+    Elem::new(15010916913750863),
+    Elem::new(33002028334199407),
+    Elem::new(1406523775921977921),
+    Elem::new(4143893479310696519),
+    Elem::new(2158979845328184287),
+    Elem::new(3495626715376918191),
+    Elem::new(1971441041386650179),
+    Elem::new(3480059018228320758),
+    Elem::new(2160148537565723206),
+    Elem::new(3370747662230135650),
+    Elem::new(973981013228987301),
+    Elem::new(3221664553682761086),
+    Elem::new(4462871193979801644),
+    Elem::new(1134414317261586039),
+    Elem::new(151726621208552070),
+    Elem::new(2201139851073144904),
+    Elem::new(1365325420328663378),
+    Elem::new(1969717994452382878),
+    Elem::new(1821709015158449040),
+    Elem::new(3098744637443990284),
+    Elem::new(738934361845961622),
+    Elem::new(2791288870156596269),
+    Elem::new(2405136257357638832),
+    Elem::new(1019761607702550168),
+    Elem::new(1538216470625320088),
+    Elem::new(2780502155491450783),
+    Elem::new(1578311332274702308),
+    Elem::new(851922064876563682),
+    Elem::new(2938340085398826901),
+    Elem::new(785318899931517836),
+    Elem::new(3405333315512644112),
+    Elem::new(4426439462412732314),
+    Elem::new(67781361274094159),
+    Elem::new(198673403978195547),
+    Elem::new(931514801509798878),
+    Elem::new(1772202346901144283),
+    Elem::new(429035118180214028),
+    Elem::new(2174121267844675196),
+    Elem::new(3638906873532210128),
+    Elem::new(3366981175886012204),
+    Elem::new(431483786225158658),
+    Elem::new(1684588875468792767),
+    Elem::new(1942782222187689059),
+    Elem::new(2566220504686535535),
+    Elem::new(1565505211246484759),
+    Elem::new(3007028801541146864),
+    Elem::new(3400867564537355934),
+    Elem::new(893686486016533273),
+    Elem::new(3450512534995658229),
+    Elem::new(1043973890306525606),
+    Elem::new(1824088026156271150),
+    Elem::new(4197559573983777709),
+    Elem::new(3710277894253562748),
+    Elem::new(3324809702398694032),
+    Elem::new(3702670761813102496),
+    Elem::new(3149050948168951262),
+    Elem::new(1708778961023894048),
+    Elem::new(1027058664093566477),
+    Elem::new(2526258576055991379),
+    Elem::new(1726460000938046552),
+    Elem::new(3726423008372922402),
+    Elem::new(730731242662785968),
+    Elem::new(1394289441836106177),
+    Elem::new(3557566216411503082),
+    Elem::new(1787888330219674689),
+    Elem::new(404076212062919646),
+    Elem::new(1751692352864940829),
+    Elem::new(2648992569839315513),
+    Elem::new(914853009639743581),
+    Elem::new(861886752440384097),
+    Elem::new(664938373260435023),
+    Elem::new(3790980808154616015),
+    Elem::new(1270902459629237128),
+    Elem::new(1395701982765399449),
+    Elem::new(726430609809985903),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    poseidon_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    poseidon_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Poseidon
+// ================================================================================================
+
+pub fn poseidon_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        poseidon_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+/// NUM_FULL_ROUNDS / 2 full rounds, NUM_PARTIAL_ROUNDS partial rounds, NUM_FULL_ROUNDS / 2 full rounds
+pub fn poseidon_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // add round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    // S-boxes, only the first element passes an S-box in partial rounds
+    if is_full_round(tmp_round) {
+        apply_sbox(state);
+    } else {
+        state[0] = state[0].exp(ALPHA.into());
+    }
+    // matrix multiplication
+    matrix_mul(MDS, state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Poseidon
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of Poseidon after absorbing the pixels
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_sbox_and_mds<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut state: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // the full round flag selects between x^ALPHA and x for all but the first element
+    let full_round_flag = round_constants[STATE_WIDTH];
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    state[0] = state[0].exp(ALPHA.into());
+    for i in 1..STATE_WIDTH {
+        state[i] = full_round_flag * state[i].exp(ALPHA.into()) + (E::ONE - full_round_flag) * state[i];
+    }
+    matrix_mul(MDS, &mut state);
+
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (next_slice[i] - state[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// number of periodic columns returned by get_round_constants_periodic
+pub const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH + 1;
+
+/// returns round constants arranged in column-major form for periodic columns, followed by the full
+/// round flag column
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..NUM_PERIODIC_COLUMNS {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+        if is_full_round(i) {
+            constants[STATE_WIDTH][(i + shift) % cycle_length] = Elem::ONE;
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_full_round(round: usize) -> bool {
+    round < NUM_FULL_ROUNDS / 2 || round >= NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = NUM_PERIODIC_COLUMNS;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the S-boxes of the partial rounds are selected by the full round flag, which has the hash cycle
+        let mut cycles = cycles;
+        cycles.push(cycles[0]);
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
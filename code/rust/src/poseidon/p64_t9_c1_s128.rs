@@ -0,0 +1,609 @@
+// Poseidon definition according to https://eprint.iacr.org/2019/458
+// Parameters derived using https://extgit.iaik.tugraz.at/krypto/hadeshash (calc_round_numbers.py and
+// generate_params_poseidon.sage): round numbers include the security margin, round constants and the
+// Cauchy MDS matrix are sampled from the Grain LFSR
+
+// Parameters
+//   p   .. 18446744069414584321 (prime from winterfell::math::fields::f64)
+//   t   .. 9
+//   security at least 128 bit
+//   rate and capacity do not influence parameter generation and can be chosen freely.
+
+use winterfell::math::{fields::f64::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// POSEIDON CONSTANTS
+// ================================================================================================
+
+pub const NUM_FULL_ROUNDS: usize = 8;
+pub const NUM_PARTIAL_ROUNDS: usize = 22;
+pub const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+pub const STATE_WIDTH: usize = 9;
+pub const CAPACITY: usize = 1;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+pub const ALPHA: u32 = 7;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(13211765651561782271),
+    Elem::new(12609380613173927684),
+    Elem::new(7785461063633241414),
+    Elem::new(1072110688771053562),
+    Elem::new(14949167956195825775),
+    Elem::new(7859494479920218804),
+    Elem::new(8274278741985482636),
+    Elem::new(11584172098022822139),
+    Elem::new(3327973737902747540),
+    Elem::new(11499962162624056006),
+    Elem::new(11845249540263506855),
+    Elem::new(12649878008679911395),
+    Elem::new(8705712310124584560),
+    Elem::new(9322522746439456466),
+    Elem::new(10271444369844455480),
+    Elem::new(15330079948872591938),
+    Elem::new(6290652061962946484),
+    Elem::new(2050135364249011607),
+    Elem::new(16853764297044972080),
+    Elem::new(13051604362924160698),
+    Elem::new(9346866302169738622),
+    Elem::new(8194324814442820989),
+    Elem::new(14625091970271482227),
+    Elem::new(8442777354373764871),
+    Elem::new(12384982908037091514),
+    Elem::new(10094144013597308685),
+    Elem::new(7957907879225599195),
+    Elem::new(18126192602277132826),
+    Elem::new(5372089493312029324),
+    Elem::new(2389614208541303981),
+    Elem::new(11230822051768185580),
+    Elem::new(5737228852257446684),
+    Elem::new(435029769846194485),
+    Elem::new(12948885361180262644),
+    Elem::new(6499114336049619863),
+    Elem::new(14861406860528382785),
+    Elem::new(15478010975666325748),
+    Elem::new(5959478253409304723),
+    Elem::new(17243490281694138230),
+    Elem::new(13890269247649790551),
+    Elem::new(7981607314305741100),
+    Elem::new(6661048709064340424),
+    Elem::new(7199618644802133403),
+    Elem::new(13165761540677243740),
+    Elem::new(17588588688256895055),
+    Elem::new(3103823679287853053),
+    Elem::new(903616336863537595),
+    Elem::new(7788481473164528606),
+    Elem::new(15092320234417634813),
+    Elem::new(11178259153864109722),
+    Elem::new(6674956534090153018),
+    Elem::new(17094967974840282087),
+    Elem::new(8759831131193409605),
+    Elem::new(7431602398033178960),
+    Elem::new(5705607730306629712),
+    Elem::new(4208736991412202265),
+    Elem::new(8384667828434172555),
+    Elem::new(15378075912110406405),
+    Elem::new(12283108500463985421),
+    Elem::new(13749107979749467948),
+    Elem::new(1907133404073156333),
+    Elem::new(17237139038713698140),
+    Elem::new(4479290073487954006),
+    Elem::new(3694343161250938942),
+    Elem::new(15287127534921788645),
+    Elem::new(10831195596705845430),
+    Elem::new(302018928649416881),
+    Elem::new(1788438786580277719),
+    Elem::new(2186086424774352394),
+    Elem::new(16281298729705802340),
+    Elem::new(4577524562532881338),
+    Elem::new(13513992737379080027),
+    Elem::new(3963216905587494619),
+    Elem::new(2769885796666504186),
+    Elem::new(3735429238638084893),
+    Elem::new(1195682925654639609),
+    Elem::new(1000304641840321908),
+    Elem::new(15820170254649174541),
+    Elem::new(349094813851719009),
+    Elem::new(17903859949645956440),
+    Elem::new(2673499516522811519),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS] = [
+    Elem::new(16466325113823317561),
+    Elem::new(9869965494414777978),
+    Elem::new(5167910307012693928),
+    Elem::new(11761019104842967160),
+    Elem::new(17046586621010663288),
+    Elem::new(391592431383635592),
+    Elem::new(10901854032220842458),
+    Elem::new(11458164449797339352),
+    Elem::new(8518654214655150261),
+    Elem::new(15119586456287526667),
+    Elem::new(6492721475583225842),
+    Elem::new(6803135326910309672),
+    Elem::new(16727488850524399355),
+    Elem::new(10311656892917246142),
+    Elem::new(14508001179510195265),
+    Elem::new(14219457491210339409),
+    Elem::new(14889342277341176748),
+    Elem::new(6388408552786777537),
+    Elem::new(17345910547847305296),
+    Elem::new(14686183847689352596),
+    Elem::new(16497902374101961454),
+    Elem::new(7713468428407799234),
+    Elem::new(11811670824055028441),
+    Elem::new(12515414765214163792),
+    Elem::new(8010099418967778468),
+    Elem::new(10529112439961449400),
+    Elem::new(8135390732500436852),
+    Elem::new(6944670764385294798),
+    Elem::new(8555409002957839623),
+    Elem::new(11826641675927946712),
+    Elem::new(12423410813530218106),
+    Elem::new(8901251284936991859),
+    Elem::new(4069716958403075700),
+    Elem::new(7455423704817957785),
+    Elem::new(9760099575878427260),
+    Elem::new(14214048127444878713),
+    Elem::new(4366645909307631903),
+    Elem::new(18184612323985742028),
+    Elem::new(13199294702377615607),
+    Elem::new(10447905025365104519),
+    Elem::new(6795323795000757932),
+    Elem::new(7769032873938156367),
+    Elem::new(7199267851229617526),
+    Elem::new(4193051677333836431),
+    Elem::new(14860197933840753368),
+    Elem::new(12104199854832297087),
+    Elem::new(11587756643072863359),
+    Elem::new(6462257415765633928),
+    Elem::new(7495897461902870285),
+    Elem::new(12347347031652001232),
+    Elem::new(10346127269686951075),
+    Elem::new(15148293611607726505),
+    Elem::new(3285395054147126933),
+    Elem::new(4481577102026461473),
+    Elem::new(9105300502738863842),
+    Elem::new(14994453730740537933),
+    Elem::new(1317211410171497030),
+    Elem::new(12043208080864037784),
+    Elem::new(15032034519076998843),
+    Elem::new(593811139911988063),
+    Elem::new(760277085809258755),
+    Elem::new(18087636845992977133),
+    Elem::new(13084614781875212934),
+    Elem::new(2961659150883181768),
+    Elem::new(241604051999993481),
+    Elem::new(3855612888412440419),
+    Elem::new(2487152940356914527),
+    Elem::new(17587748162671381348),
+    Elem::new(17641898051492874564),
+    Elem::new(41143831354824896),
+    Elem::new(12718091488888789513),
+    Elem::new(10387237705198302881),
+    Elem::new(3719548517135512850),
+    Elem::new(3383051785273022913),
+    Elem::new(10209774454184711238),
+    Elem::new(141654818717669133),
+    Elem::new(14986424852773532525),
+    Elem::new(11979283869029517335),
+    Elem::new(6690676209446778244),
+    Elem::new(14040582322558160320),
+    Elem::new(2543387634911857351),
+    Elem::new(16699217302432461688),
+    Elem::new(15876938701389309433),
+    Elem::new(1306253794836749677),
+    Elem::new(15626136222627902557),
+    Elem::new(5625863965819824525),
+    Elem::new(6611463030509918716),
+    Elem::new(6448122425770990672),
+    Elem::new(3089494837694029178),
+    Elem::new(16579250029700496740),
+    Elem::new(6214014094753842757),
+    Elem::new(17080924004210301295),
+    Elem::new(6983989473869363290),
+    Elem::new(8257145485837113383),
+    Elem::new(749219305948240872),
+    Elem::new(14756557946712722764),
+    Elem::new(9070659183720903961),
+    Elem::new(12429585463427960763),
+    Elem::new(253778208926900743),
+    Elem::new(14724792776328614959),
+    Elem::new(16156164837762564935),
+    Elem::new(5178738710030476187),
+    Elem::new(12878502310428645910),
+    Elem::new(10019251978719050588),
+    Elem::new(3672729349839774557),
+    Elem::new(654828374237582264),
+    Elem::new(10896479267437075448),
+    Elem::new(12388550154859121944),
+    Elem::new(3632679544403618893),
+    Elem::new(16172929439115676770),
+    Elem::new(16013673196519271312),
+    Elem::new(48584705935218508),
+    Elem::new(851753949273512426),
+    Elem::new(9380917558081120392),
+    Elem::new(13998456429653911791),
+    Elem::new(12608092444044973719),
+    Elem::new(15043343150131844197),
+    Elem::new(6192624818179712490),
+    Elem::new(2111131237820864309),
+    Elem::new(1418944119765190515),
+    Elem::new(4790939071985061587),
+    Elem::new(12588030174067264312),
+    Elem::new(2382613970865206716),
+    Elem::new(112443878842240328),
+    Elem::new(11860868153086772521),
+    Elem::new(6753866933432628798),
+    Elem::new(17755298291136201787),
+    Elem::new(17994343581548920694),
+    Elem::new(16431382564921576765),
+    Elem::new(324697168191032536),
+    Elem::new(15966485016513276046),
+    Elem::new(15445025919968305051),
+    Elem::new(11184405920974636906),
+    Elem::new(14075211959366712607),
+    Elem::new(5322856675355803051),
+    Elem::new(8448392665279139974),
+    Elem::new(2357564642657904266),
+    Elem::new(3760188202206433205),
+    Elem::new(15407419874259764910),
+    Elem::new(3668705958317923942),
+    Elem::new(6852317097565537956),
+    Elem::new(5790797124260961693),
+    Elem::new(15177979576487057257),
+    Elem::new(3980931626067772184),
+    Elem::new(4915315595132859074),
+    Elem::new(7955816379924556920),
+    Elem::new(13114116856006233030),
+    Elem::new(2165876441008935441),
+    Elem::new(5596809860847605853),
+    Elem::new(10148549740500299521),
+    Elem::new(20531995075625354),
+    Elem::new(12481126437438104753),
+    Elem::new(7347494937679944175),
+    Elem::new(2151351372238879180),
+    Elem::new(7031540659448321153),
+    Elem::new(12761807469167042674),
+    Elem::new(16158017218649162981),
+    Elem::new(7151557992303350585),
+    Elem::new(5667124920871651910),
+    Elem::new(4761681273853429620),
+    Elem::new(7996336667257727804),
+    Elem::new(8769591749451644954),
+    Elem::new(18094182650395621085),
+    Elem::new(14490461932877201432),
+    Elem::new(11968284363118592684),
+    Elem::new(1655306324501845722),
+    Elem::new(10084607419046999971),
+    Elem::new(13111301052826735162),
+    Elem::new(8842994609885037731),
+    Elem::new(17815857718025052261),
+    Elem::new(9491941415748026705),
+    Elem::new(7189568347138545058),
+    Elem::new(14590249061396696180),
+    Elem::new(14256025117530515473),
+    Elem::new(7382408386223594510),
+    Elem::new(18306446581256949457),
+    Elem::new(7542290332176318989),
+    Elem::new(457778933989520216),
+    Elem::new(2052324521550105828),
+    Elem::new(1128248768011421561),
+    Elem::new(17295594857099129521),
+    Elem::new(12194799483990771804),
+    Elem::new(7757592311241614201),
+    Elem::new(8264418341800525148),
+    Elem::new(12502362216885978220),
+    Elem::new(2108086242891173635),
+    Elem::new(6968670302009347597),
+    Elem::new(689462054561460903),
+    Elem::new(2509329048989440519),
+    Elem::new(3888476967529555473),
+    Elem::new(8997977926100207957),
+    Elem::new(1946844458832244461),
+    Elem::new(5903004385241058837),
+    Elem::new(16025969582105730274),
+    Elem::new(4034409395511345389),
+    Elem::new(8245900057443391621),
+    Elem::new(15154914973210555515),
+    Elem::new(11102372946376669622),
+    Elem::new(13598940143807372740),
+    Elem::new(13752044507129975467),
+    Elem::new(8188880158545129096),
+    Elem::new(2804808361999832142),
+    Elem::new(7900383205870797505),
+    Elem::new(16176747443949019234),
+    Elem::new(10588319968877091940),
+    Elem::new(3658765213233909243),
+    Elem::new(12148594204012401490),
+    Elem::new(11293009975316673428),
+    Elem::new(18118871144423169920),
+    Elem::new(15172362260726824024),
+    Elem::new(18044018992353267109),
+    Elem::new(9871819692729460844),
+    Elem::new(15558801189075197836),
+    Elem::new(9125962321271141956),
+    Elem::new(339162816917868223),
+    Elem::new(8149563022275429505),
+    Elem::new(8411946686557670555),
+    Elem::new(11589189215678869467),
+    Elem::new(3788087235692511072),
+    Elem::new(14527966227066740956),
+    Elem::new(12694084084629881269),
+    Elem::new(5403916209812964052),
+    Elem::new(398559319492047322),
+    Elem::new(9196443572415093352),
+    Elem::new(213953843603728555),
+    Elem::new(167344920083499621),
+    Elem::new(4802189569195748110),
+    Elem::new(4885231196255004536),
+    Elem::new(16919843998660048356),
+    Elem::new(17377931136440605065),
+    Elem::new(14600172361119321889),
+    Elem::new(2264493634819959395),
+    Elem::new(11398341467736014890),
+    Elem::new(3033535771010902019),
+    Elem::new(1141138640925434681),
+    Elem::new(8275085632083103547),
+    Elem::new(4942563746225091854),
+    Elem::new(1441899369649555430),
+    Elem::new(10958030268544025873),
+    Elem::new(2133521680769944519),
+    Elem::new(6793530297689908849),
+    Elem::new(18197412068096057837),
+    Elem::new(17564825475616444612),
+    Elem::new(13442580500253043558),
+    Elem::new(3906453859723289699),
+    Elem::new(222715636262098870),
+    Elem::new(6963782187503918735),
+    Elem::new(18380354625343185702),
+    Elem::new(18419433000810613729),
+    Elem::new(15341436875184069065),
+    Elem::new(8242294626225128473),
+    Elem::new(11742727903568772690),
+    Elem::new(14532262968566600793),
+    Elem::new(649163233769029144),
+    Elem::new(3751271928200638257),
+    Elem::new(16974096688182381675),
+    Elem::new(9984380893863327958),
+    Elem::new(16791468545866842345),
+    Elem::new(10551362371410103647),
+    Elem::new(6657670725123455184),
+    Elem::new(16136785666171633578),
+    Elem::new(11921142929288599979),
+    Elem::new(10442732256431495324),
+    Elem::new(2061211255798615049),
+    Elem::new(9253737466460486797),
+    Elem::new(896111067653631578),
+    Elem::new(8624734918701678574),
+    Elem::new(1402604687258400451),
+    Elem::new(10973030375956173055),
+    Elem::new(16303891919631110776),
+];
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    poseidon_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    poseidon_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing Poseidon
+// ================================================================================================
+
+pub fn poseidon_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        poseidon_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+/// NUM_FULL_ROUNDS / 2 full rounds, NUM_PARTIAL_ROUNDS partial rounds, NUM_FULL_ROUNDS / 2 full rounds
+pub fn poseidon_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for i in 0..NUM_ROUNDS {
+        apply_round(state, i);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH..(tmp_round + 1) * STATE_WIDTH];
+
+    // add round constants
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    // S-boxes, only the first element passes an S-box in partial rounds
+    if is_full_round(tmp_round) {
+        apply_sbox(state);
+    } else {
+        state[0] = state[0].exp(ALPHA.into());
+    }
+    // matrix multiplication
+    matrix_mul(MDS, state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Poseidon
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of Poseidon after absorbing the pixels
+/// round_constants[STATE_WIDTH] is the full round flag, i.e. 1 for full rounds and 0 for partial rounds
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_sbox_and_mds(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_sbox_and_mds<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut state: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // the full round flag selects between x^ALPHA and x for all but the first element
+    let full_round_flag = round_constants[STATE_WIDTH];
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[i];
+    }
+    state[0] = state[0].exp(ALPHA.into());
+    for i in 1..STATE_WIDTH {
+        state[i] = full_round_flag * state[i].exp(ALPHA.into()) + (E::ONE - full_round_flag) * state[i];
+    }
+    matrix_mul(MDS, &mut state);
+
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (next_slice[i] - state[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// number of periodic columns returned by get_round_constants_periodic
+pub const NUM_PERIODIC_COLUMNS: usize = STATE_WIDTH + 1;
+
+/// returns round constants arranged in column-major form for periodic columns, followed by the full
+/// round flag column
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..NUM_PERIODIC_COLUMNS {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..STATE_WIDTH {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH + j];
+        }
+        if is_full_round(i) {
+            constants[STATE_WIDTH][(i + shift) % cycle_length] = Elem::ONE;
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn is_full_round(round: usize) -> bool {
+    round < NUM_FULL_ROUNDS / 2 || round >= NUM_FULL_ROUNDS / 2 + NUM_PARTIAL_ROUNDS
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = NUM_PERIODIC_COLUMNS;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        // the S-boxes of the partial rounds are selected by the full round flag, which has the hash cycle
+        let mut cycles = cycles;
+        cycles.push(cycles[0]);
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
@@ -5,13 +5,17 @@
 pub mod p128_m4_c2_s128;
 pub mod p128_m4_c3_s128;
 pub mod p128_m9_c1_s128;
+pub mod p128_m10_c2_s128;
 pub mod p128_m17_c1_s128;
 pub mod p128_m33_c1_s128;
 pub mod p128_m65_c1_s128;
 pub mod p64_m9_c1_s128;
+// Rescue Prime Optimized (https://eprint.iacr.org/2022/1577), see template_rpo.rs
+pub mod p64_m12_c4_s128_rpo;
 pub mod p62_m4_c2_s128;
 pub mod p62_m4_c3_s128;
 pub mod p62_m9_c1_s128;
+pub mod p62_m13_c5_s128;
 pub mod p62_m17_c1_s128;
 pub mod p62_m33_c1_s128;
 pub mod p62_m65_c1_s128;
@@ -0,0 +1,650 @@
+// Rescue-Prime definition according to https://eprint.iacr.org/2020/1143
+// Taken and adapted from https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Parameters derived using https://github.com/KULeuven-COSIC/Marvellous
+
+// Parameters
+//   p   .. 340282366920938463463374557953744961537 (prime from winterfell::math::fields::f128)
+//   m   .. 10
+//   c_p .. 2
+//   security at least 128 bit
+
+use winterfell::math::{fields::f128::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// RESCUE CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 8;
+pub const STATE_WIDTH: usize = 10;
+pub const CAPACITY: usize = 2;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const ALPHA: u32 = 3;
+const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(340282366920938460509061851402911262894),
+    Elem::new(4431394012508602048404),
+    Elem::new(340282366920938461801686230065574226956),
+    Elem::new(191704045424825015280),
+    Elem::new(340282366920938463456188943356717055443),
+    Elem::new(88955675043980472),
+    Elem::new(340282366920938463463374192886702486919),
+    Elem::new(494821649520),
+    Elem::new(340282366920938463463374557953527052274),
+    Elem::new(29524),
+    Elem::new(340282366920851240335026351139626225605),
+    Elem::new(130829522512597416043381053),
+    Elem::new(340282366920889408208576000109579240497),
+    Elem::new(5658208548794645580392139),
+    Elem::new(340282366920938251506993240726670457561),
+    Elem::new(2619141735401451549234),
+    Elem::new(340282366920938463452685274266768320177),
+    Elem::new(14244047337953862),
+    Elem::new(340282366920938463463374552015013530245),
+    Elem::new(653757313),
+    Elem::new(340282364989534926666944021219045535278),
+    Elem::new(2896969019333563857736776242520),
+    Elem::new(340282365834728396702253691444877601737),
+    Elem::new(125278866373364987640471021600),
+    Elem::new(340282366916246473580716810925295588254),
+    Elem::new(57943466711536603124687760),
+    Elem::new(340282366920938227417267540292062129337),
+    Elem::new(312803988317446298400),
+    Elem::new(340282366920938463463246342226826225080),
+    Elem::new(13362799477720),
+    Elem::new(340242889032646344423203700697472227577),
+    Elem::new(59213898192384686168883558660132621),
+    Elem::new(340260165009987445328935790514978168737),
+    Elem::new(2560616508012901748839466152201800),
+    Elem::new(340282271026290345575027728425810757457),
+    Elem::new(1184004858135274542243029710557),
+    Elem::new(340282366916118089245573484090113138337),
+    Elem::new(6376156372752743205862200),
+    Elem::new(340282366920938460864300760464441139577),
+    Elem::new(266307564861468823),
+    Elem::new(234091278041967735849105142170512977422),
+    Elem::new(159227169761751615330617480752475289921),
+    Elem::new(238103775581444610851900810439610587532),
+    Elem::new(51030035600226535187503351375451822640),
+    Elem::new(338371344012078950103776252842944455975),
+    Elem::new(23593674553452684694369010514620376),
+    Elem::new(340282270884828229004722292944598837480),
+    Elem::new(126954374306605319423281091760),
+    Elem::new(340282366920886808734557023328633416288),
+    Elem::new(5263390747480701708292),
+    Elem::new(181156361797425444726660416792685108533),
+    Elem::new(77776928392879504988543561289141551262),
+    Elem::new(321933935366764999897861706812002829780),
+    Elem::new(314184798643032083032502347856802332087),
+    Elem::new(1675378865444131758321420859585421799),
+    Elem::new(126015087132588802506429166057343006725),
+    Elem::new(338384470101920867433550623968609449457),
+    Elem::new(2508403581502247951399120155695783),
+    Elem::new(340282365900951239105441061400275344501),
+    Elem::new(103741619611085612124067759),
+    Elem::new(3331854236664404904124298707569925796),
+    Elem::new(136292225100633086491843187422993985711),
+    Elem::new(225065037872822212531498566484908665371),
+    Elem::new(220765813047846485097861506919955260269),
+    Elem::new(71295491487780360039502534341637491605),
+    Elem::new(289969509898759293136585480997574302144),
+    Elem::new(24711562410903403474020258123364096270),
+    Elem::new(49435702520816167439346185415494313600),
+    Elem::new(340262269064648087698958097603974905703),
+    Elem::new(2042880353039758115797506899680),
+    Elem::new(264218221423500649702012455903920982456),
+    Elem::new(21822752097435847800938473144129064194),
+    Elem::new(247583403874343844772269224947459314103),
+    Elem::new(85129983857444039738513028375413254598),
+    Elem::new(219277681646112152088409040294933574438),
+    Elem::new(313398958177656293649756919986909912755),
+    Elem::new(17594862793603207875269847556133622842),
+    Elem::new(247507870356723917981117538739588484980),
+    Elem::new(284837884234619593654391915576300500834),
+    Elem::new(40216143252770054194345243936096486),
+    Elem::new(191478723757586688216071827398117913275),
+    Elem::new(14762287324854113163512501754093503132),
+    Elem::new(305706344303812818145311151072395649295),
+    Elem::new(195179977019111970419049028409709547089),
+    Elem::new(263933685293406656816429617060146164148),
+    Elem::new(46164929217986155333655656776782548145),
+    Elem::new(109494486596544003148643014866972043100),
+    Elem::new(54192932485047098340847917216907373092),
+    Elem::new(69166271739755530435234851529221797523),
+    Elem::new(111049829945648819834742665730633307350),
+    Elem::new(99702428021949524821246608873810921288),
+    Elem::new(175426595141189240877845614733809878569),
+    Elem::new(202644068918629839721688683307541232139),
+    Elem::new(192506263284080806847247659806960164366),
+    Elem::new(226717979240738930446127783108163952098),
+    Elem::new(300137236339059658483651794577389444254),
+    Elem::new(21064159072836481910458340341577588040),
+    Elem::new(128127521351356775623427029459388390271),
+    Elem::new(271345279401434196821347277145799646733),
+    Elem::new(83740303833416861763831998414283589928),
+];
+
+const INV_MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(200781240358720329254524801586892714298),
+    Elem::new(259368301681826843462173310910964157576),
+    Elem::new(299766176513841631914888497232114151361),
+    Elem::new(273450497825390307744749808975962326264),
+    Elem::new(151611462242906986238741899082061292141),
+    Elem::new(254970751952145202579407054878793030263),
+    Elem::new(241062775723249731110416367059757759804),
+    Elem::new(308375921507645644184929213804428918332),
+    Elem::new(216422322540520451973664080269077103962),
+    Elem::new(176167118100322115780126871876163276759),
+    Elem::new(112647301743124197104503753681377750871),
+    Elem::new(160990096171820639353847357769455618118),
+    Elem::new(293634646042035654458969590634877659562),
+    Elem::new(303484912671375192289498299706663559888),
+    Elem::new(197727813697574717575280483668311824913),
+    Elem::new(183564657507697652391975604817123064049),
+    Elem::new(57761145583640763809052787139948523493),
+    Elem::new(292814636133155543075947239343754406259),
+    Elem::new(280630262068953713961861201839034318437),
+    Elem::new(158438729906252706759311029121923043633),
+    Elem::new(207704205238170807874062389651402246184),
+    Elem::new(263986092519752375103692688149879576244),
+    Elem::new(117634915749496738007545254974976578405),
+    Elem::new(63750489345535533145865767249264996627),
+    Elem::new(20889470859209735391923210036731088195),
+    Elem::new(325312491415696352495697425579678314635),
+    Elem::new(50155048271109055656706811225660072854),
+    Elem::new(331184873457821317248820929580853707092),
+    Elem::new(156800295615089557541921505957289937460),
+    Elem::new(163993952132810844850636807362988289990),
+    Elem::new(122315910350536424652436723348347463137),
+    Elem::new(64169039379212846646701388215469733838),
+    Elem::new(15776677497715518242033362671921822851),
+    Elem::new(206712079205689630659801035821028897595),
+    Elem::new(184925095497414633745498516388555927859),
+    Elem::new(3291255404109596110359412777935688390),
+    Elem::new(79775262193934572407819093319966868322),
+    Elem::new(49796014255101945202832413875965510154),
+    Elem::new(257641620290023915336204760455537080219),
+    Elem::new(36444146689076307386436966986505892247),
+    Elem::new(144296484188342796886941411623706642352),
+    Elem::new(183020946418866037642900539930076395190),
+    Elem::new(326361819362181635325844363416226424867),
+    Elem::new(308791138115473927471912525456003132674),
+    Elem::new(119959054038581832835522951811541006412),
+    Elem::new(242475551292231725202469065835677956383),
+    Elem::new(3978373305429030685409491741210611449),
+    Elem::new(181498130150305632259768818748092213236),
+    Elem::new(269528440082716441158612358846003986007),
+    Elem::new(261784264571501721310865820313931400653),
+    Elem::new(339488031881919298435648262641960833361),
+    Elem::new(75799493213069661798678865247319336571),
+    Elem::new(300233723292916012341557479553819466356),
+    Elem::new(228663398810606096985669623588061197196),
+    Elem::new(2756523504640120254879384067036982455),
+    Elem::new(83739636199336151702105076649805378981),
+    Elem::new(232156274489940907845796390446671150346),
+    Elem::new(17079700184469279701884603743279697674),
+    Elem::new(66739279203579008715896599883405289448),
+    Elem::new(14473406903277316071381945993620513761),
+    Elem::new(92119803104584563020483296024949056169),
+    Elem::new(260660835762416534957165105888143352216),
+    Elem::new(159180477137046275776282147356765806899),
+    Elem::new(207671153209811413641275236511420781606),
+    Elem::new(186505196973842171548002064261799158884),
+    Elem::new(110977225202930614757123190070244478085),
+    Elem::new(266870711471990949219866039147630614744),
+    Elem::new(83381159742236169281449600453038204699),
+    Elem::new(8811066214546571453826226469206514107),
+    Elem::new(325234205785287053661399883585526840277),
+    Elem::new(129347322695566971491402234959381755155),
+    Elem::new(90743796364560076512340386627895414306),
+    Elem::new(202219948700195599823750620914843799917),
+    Elem::new(301707632786940107693189180901054052834),
+    Elem::new(302628106658375615808028492270972595447),
+    Elem::new(187702134502785307126728507117427525271),
+    Elem::new(286110860007714194449555798175065694982),
+    Elem::new(30415494294174653408189427085531325302),
+    Elem::new(34863642606422929882975274928238443283),
+    Elem::new(135672895987956861120712866788314201189),
+    Elem::new(114817914284153409918747361756485826038),
+    Elem::new(165929465983975724591516079518993812133),
+    Elem::new(205623983951080315095312819240077256853),
+    Elem::new(61033031767914019207094146329513127854),
+    Elem::new(338892441051751787674650367238823305387),
+    Elem::new(188694022453764076653124590881202253788),
+    Elem::new(83940635673785264144094120303830030484),
+    Elem::new(151607565013215924782566562373048277203),
+    Elem::new(213169009746914564822034082541978822654),
+    Elem::new(177703764678137230427732659584772095292),
+    Elem::new(194664403369901290382441575093185401968),
+    Elem::new(239539467257243641391082640010098727790),
+    Elem::new(90065328117081696244805822647251384918),
+    Elem::new(314290987266454614485747923609855952649),
+    Elem::new(325871640433977182540985015479373579814),
+    Elem::new(111420711447736316271527277314308915719),
+    Elem::new(233068832804208326026205574805322531782),
+    Elem::new(247939230236392247580167786774262022000),
+    Elem::new(19249420551359430965142599475308006387),
+    Elem::new(265584180041276034892141132513503246196),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * 2 * NUM_ROUNDS] = [
+    Elem::new(283585622888945441647576077944520359899),
+    Elem::new(198256363463438566059301181189590441304),
+    Elem::new(311385704194006520637128111217489364326),
+    Elem::new(174414396067110766662002154326930450734),
+    Elem::new(221177367404296114431480991924218519772),
+    Elem::new(113553560928844501412233146728885353065),
+    Elem::new(6607179518821717968667472643671982582),
+    Elem::new(158973818975637820616956294678049256667),
+    Elem::new(240504566820851660030532463454408285045),
+    Elem::new(138499490174907885185680548633302067284),
+    Elem::new(217874001234442737387938570551322028392),
+    Elem::new(276918148955338842418555708612859789611),
+    Elem::new(59283497877565979077092810361398423364),
+    Elem::new(133135439766098974178287337154701419539),
+    Elem::new(199995302474921080715592573169729905729),
+    Elem::new(132097577364535241734367393181891554201),
+    Elem::new(209339259798273816043488400111662871259),
+    Elem::new(86529297913484019467762443260806474123),
+    Elem::new(85149499521181111242630668270388955684),
+    Elem::new(224711726686468306532437765932186044787),
+    Elem::new(78717876353944271086216157485869759893),
+    Elem::new(263030493790443500377913189927628145769),
+    Elem::new(34525598836327062381890853600770954677),
+    Elem::new(129971068418128076916544161074704145274),
+    Elem::new(41665123301233164545567743306939467164),
+    Elem::new(271571939812831546972310411332566844595),
+    Elem::new(142915280800294796503840153715048186151),
+    Elem::new(259966322416969977543120580298620481578),
+    Elem::new(34457270864648378562457651904958856096),
+    Elem::new(42247367518410117413871531279296979843),
+    Elem::new(134436015485878104837896057321072937110),
+    Elem::new(232373058553193709667404667154719969861),
+    Elem::new(82641378926725620252466019545507984666),
+    Elem::new(162152592400827136551216236498084614416),
+    Elem::new(146017691177310498464251521583216441213),
+    Elem::new(59463618724521011491096332330240552954),
+    Elem::new(56843446381768546588187542392059308357),
+    Elem::new(305740700375324173600416577922502347745),
+    Elem::new(278430408503738090595023539291932532590),
+    Elem::new(213662430205753027026319697449421299309),
+    Elem::new(141205107943061836409877265736853223823),
+    Elem::new(143843424192818138723018909321483378439),
+    Elem::new(114568398426275919283811893482502269494),
+    Elem::new(194617649573118395856611217919713680014),
+    Elem::new(27883051356313854705356465233635421210),
+    Elem::new(206987294184392849608461904802416762354),
+    Elem::new(301921172905485154559205541911998983933),
+    Elem::new(268425778996509108298805887086965925778),
+    Elem::new(148621060025764219748457358776880038301),
+    Elem::new(279698247489295839520729563888640847055),
+    Elem::new(275376041980937940240425403098060944433),
+    Elem::new(337727346418219476971083773114880618955),
+    Elem::new(130602765855177638867777288609338845),
+    Elem::new(209244098728261311216111250879688002392),
+    Elem::new(39214995381906420646648780539920757129),
+    Elem::new(48930131250714526600531806405614551614),
+    Elem::new(70228529417256969657320138271549629845),
+    Elem::new(308323651641733615039072704884918145476),
+    Elem::new(302700213698111122623234317014799476754),
+    Elem::new(272817106800489500940659129978749752836),
+    Elem::new(49501379408420090575798510886385374994),
+    Elem::new(156409108566982990153732464588769226228),
+    Elem::new(213720799964228616552500910518907370062),
+    Elem::new(303141233891119523971891641355442498048),
+    Elem::new(54557252668918289334655674345826472183),
+    Elem::new(105633115550853303858649830419046383157),
+    Elem::new(125420927178508661200873583950040146578),
+    Elem::new(339337223533173700660293994370296095252),
+    Elem::new(197509768876820009809060317274309581124),
+    Elem::new(268802036977417690589272646588851517242),
+    Elem::new(3805579130858118097257491768407319158),
+    Elem::new(179250780190811598707540865376581511881),
+    Elem::new(177084980440979136616029487830227036823),
+    Elem::new(33409656906781422308958906655432963249),
+    Elem::new(301220364622283442768096447774611288613),
+    Elem::new(105743217001023803502621847963706432224),
+    Elem::new(25652725578052483946196121361605521344),
+    Elem::new(40150825913208335680871460352167726757),
+    Elem::new(121347071744258359801191066521146880220),
+    Elem::new(306806461169670485363874279134801873627),
+    Elem::new(167325256828704429501986005684205760757),
+    Elem::new(47370626205373122047004875990665871083),
+    Elem::new(69766887123743390047184056781178042052),
+    Elem::new(297191261509200088491492949173424742925),
+    Elem::new(31707978368111428107155144271698167471),
+    Elem::new(35100001403121612439123633750572787224),
+    Elem::new(194260605818102811121506540550681535390),
+    Elem::new(203515128375064799720719780573085428469),
+    Elem::new(7564773947708032929303413008040593439),
+    Elem::new(305844636150153722302017224081071889607),
+    Elem::new(87812472729998635660590440103590885827),
+    Elem::new(321192173845860507713037661965214738634),
+    Elem::new(269048496116859613215850182286296907864),
+    Elem::new(139836100866891960650608682643262445495),
+    Elem::new(312039164899448827352557287592246323902),
+    Elem::new(321923278116469326538275034349489271366),
+    Elem::new(53063519523938347862298367182564913365),
+    Elem::new(227631047614796867707467779165359350821),
+    Elem::new(83507687263964539260830716138798360936),
+    Elem::new(32758306478367635683841522071294025773),
+    Elem::new(198743413703088074454951815325519653763),
+    Elem::new(108523442988540701238011210763438726579),
+    Elem::new(187718439521038346540067305482723874458),
+    Elem::new(214784125096944035988889996197277296713),
+    Elem::new(155944814768432099416305130809838554524),
+    Elem::new(216146442574955736758049543499150409638),
+    Elem::new(92271057140802469431713545327626399687),
+    Elem::new(313021662783746674992638755769087244272),
+    Elem::new(278176351200509615727790753399011744015),
+    Elem::new(281792083994813128502479669218645221587),
+    Elem::new(8568079766372659289001199919138103446),
+    Elem::new(277821721906430884455632420079856557480),
+    Elem::new(275382141657351288991563701720942544956),
+    Elem::new(326684879086940698781560492605950825175),
+    Elem::new(235984454686465893706187801695786315118),
+    Elem::new(188832089228844461975701675443396291566),
+    Elem::new(167821694580365394236710205377336969069),
+    Elem::new(158456436498492403731425056013443572707),
+    Elem::new(103025832544678103115773109135720357910),
+    Elem::new(113637616073097115325241326827026558655),
+    Elem::new(26743912560758266170201368580606220489),
+    Elem::new(338005904679689792528582570393073235634),
+    Elem::new(132419931173642612653587237286152460783),
+    Elem::new(153881483652462198800234620559713696100),
+    Elem::new(75561331855251325186463781189086563490),
+    Elem::new(228248407086868892292026398895405370902),
+    Elem::new(194779950555772580403081241196469625780),
+    Elem::new(277937250607416493984105311239599393074),
+    Elem::new(127182206111382651977152268459458661553),
+    Elem::new(304207509005081394871347332743683641196),
+    Elem::new(314847355180962153551887570831972271593),
+    Elem::new(144462446369340518149218898780796529800),
+    Elem::new(192694647395728602668235419888285626694),
+    Elem::new(259319488361846800562002856290162512270),
+    Elem::new(81336068247443389776266337604947434229),
+    Elem::new(107409232959254164739827528903908139568),
+    Elem::new(195679848039684358935973742653786286798),
+    Elem::new(192570454531282226728500766707491871847),
+    Elem::new(48771179786380703851729990710340210692),
+    Elem::new(108571726032928999862922394369414112911),
+    Elem::new(213980740431828831685284263554697890366),
+    Elem::new(9165246959380923220967023688074597306),
+    Elem::new(184480092621259739792819783812150095912),
+    Elem::new(106109908055059067424508523825404112877),
+    Elem::new(43790831899799976878252990363427544208),
+    Elem::new(300810537063205667104257504508049792194),
+    Elem::new(247825007384942374620211919829782070528),
+    Elem::new(71187582096877071612223725537438400216),
+    Elem::new(30022224743860853672026862302383350853),
+    Elem::new(205042651755384019237241892478622633042),
+    Elem::new(183973490923109938519941090931741682690),
+    Elem::new(285839499406314726582554900360955605195),
+    Elem::new(196376282506458386491434279824912229560),
+    Elem::new(84346550450284567397148477475943570268),
+    Elem::new(224012904272853602667964306445530620367),
+    Elem::new(296119168537298491262255913182002817133),
+    Elem::new(187287719338726190110722816413188900857),
+    Elem::new(222682192484220555701628162679289337740),
+    Elem::new(15832030644482322282971899675108954829),
+    Elem::new(246214033382853054562570124066481722511),
+];
+
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    rescue_prime_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    rescue_prime_hash(&salted_sequence)
+}
+
+// ALGORITHMS FROM https://eprint.iacr.org/2020/1143
+// ================================================================================================
+
+// Algorithm 1
+pub fn rescue_prime_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        rescue_xlix_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+// Algorithm 2
+#[allow(dead_code)]
+pub fn rescue_prime_wrapper(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    let mut padded_input = input_sequence.clone();
+    padded_input.push(Elem::ONE);
+    while (padded_input.len() % RATE) != 0 {
+        padded_input.push(Elem::ZERO);
+    }
+    rescue_prime_hash(&padded_input)
+}
+
+// Algorithm 3
+pub fn rescue_xlix_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for round in 0..NUM_ROUNDS {
+        let round_const = &ROUND_CONSTANTS[round * STATE_WIDTH * 2..(round + 1) * STATE_WIDTH * 2];
+        apply_sbox(state);
+        matrix_mul(MDS, state);
+        add_constants(state, &round_const, 0);
+        apply_inv_sbox(state);
+        matrix_mul(MDS, state);
+        add_constants(state, &round_const, STATE_WIDTH);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH * 2..(tmp_round + 1) * STATE_WIDTH * 2];
+
+    // apply first half of Rescue round
+    apply_sbox(state);
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, 0);
+
+    // apply second half of Rescue round
+    apply_inv_sbox(state);
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, STATE_WIDTH);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Rescue hash functions
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // compute the state that should result from applying the first half of Rescue round
+    // to the current state of the computation
+    let mut step1 = [E::ZERO; STATE_WIDTH];
+    step1.copy_from_slice(current_slice);
+    apply_sbox(&mut step1);
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[i];
+    }
+
+    // compute the state that should result from applying the inverse for the second
+    // half for Rescue round to the next step of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        step2[i] -= round_constants[STATE_WIDTH + i];
+    }
+    matrix_mul(INV_MDS, &mut step2);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (step2[i] - step1[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Rescue hash functions
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // compute the state that should result from applying the first half of Rescue round
+    // to the current state of the computation
+    let mut step1 = [E::ZERO; STATE_WIDTH];
+    step1.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        step1[i] += pixels[i];
+    }
+    apply_sbox(&mut step1);
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[i];
+    }
+
+    // compute the state that should result from applying the inverse for the second
+    // half for Rescue round to the next step of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        step2[i] -= round_constants[STATE_WIDTH + i];
+    }
+    matrix_mul(INV_MDS, &mut step2);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (step2[i] - step1[i]);
+    }
+}
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..(STATE_WIDTH * 2) {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..(STATE_WIDTH * 2) {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH * 2 + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn add_constants(state: &mut [Elem], round_constants: &[Elem], offset: usize) {
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[offset + i];
+    }
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn apply_inv_sbox(state: &mut [Elem]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(INV_ALPHA);
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = 2 * STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
@@ -0,0 +1,836 @@
+// Rescue-Prime definition according to https://eprint.iacr.org/2020/1143
+// Taken and adapted from https://github.com/novifinancial/winterfell/blob/main/examples/src/rescue/rescue.rs
+// Parameters derived using https://github.com/KULeuven-COSIC/Marvellous
+
+// Parameters
+//   p   .. 4611624995532046337 (prime from winterfell::math::fields::f62)
+//   m   .. 13
+//   c_p .. 5
+//   security at least 128 bit
+
+use winterfell::math::{fields::f62::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// RESCUE CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 8;
+pub const STATE_WIDTH: usize = 13;
+pub const CAPACITY: usize = 5;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const ALPHA: u32 = 3;
+const INV_ALPHA: u64 = 3074416663688030891;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(2811143980005552979),
+    Elem::new(725649184171051976),
+    Elem::new(296463537191893971),
+    Elem::new(1304391274781905260),
+    Elem::new(1273211840160077895),
+    Elem::new(3747013336396558694),
+    Elem::new(4541917499813684435),
+    Elem::new(2296419178153430977),
+    Elem::new(1890513642299597094),
+    Elem::new(4161660023867220524),
+    Elem::new(9741639684676410),
+    Elem::new(4611624836665830647),
+    Elem::new(797161),
+    Elem::new(2800541323809707872),
+    Elem::new(2859132397737511520),
+    Elem::new(2560899576314282405),
+    Elem::new(4003592367423025756),
+    Elem::new(2029338456686636373),
+    Elem::new(1603735973212439044),
+    Elem::new(1119590156930712985),
+    Elem::new(3527042052486944223),
+    Elem::new(3886488458785920207),
+    Elem::new(4167513816185818398),
+    Elem::new(3840400224232861026),
+    Elem::new(4494724683851066657),
+    Elem::new(476599444231),
+    Elem::new(1308179631663380574),
+    Elem::new(153116947202265269),
+    Elem::new(1911146523272365769),
+    Elem::new(3515906027579779244),
+    Elem::new(713354856011256897),
+    Elem::new(80607061181563365),
+    Elem::new(3913858625048372152),
+    Elem::new(1856558609366122434),
+    Elem::new(1014844121295279025),
+    Elem::new(4558768927724481591),
+    Elem::new(1819285536236279353),
+    Elem::new(1949471933197437502),
+    Elem::new(263026177881648511),
+    Elem::new(367001485216409670),
+    Elem::new(2350127593616775345),
+    Elem::new(4393224367692897614),
+    Elem::new(3040613005953653209),
+    Elem::new(2066611341351206541),
+    Elem::new(724030158276687945),
+    Elem::new(1480060120378997841),
+    Elem::new(533876182423804006),
+    Elem::new(1877126380863694339),
+    Elem::new(766556863766491668),
+    Elem::new(2397146529700226338),
+    Elem::new(3654964557964076776),
+    Elem::new(4018411385987356731),
+    Elem::new(3233237508824675603),
+    Elem::new(2196954402686993996),
+    Elem::new(4019210328086966630),
+    Elem::new(12805263773148355),
+    Elem::new(380287846271057868),
+    Elem::new(191211100621502671),
+    Elem::new(1001664913826995873),
+    Elem::new(1226640868558926289),
+    Elem::new(4434270400525571563),
+    Elem::new(1252566824229873241),
+    Elem::new(1173261482065961882),
+    Elem::new(1784955887440000851),
+    Elem::new(2151058150748556864),
+    Elem::new(3810299077781913478),
+    Elem::new(790439915905154831),
+    Elem::new(1534167064310483068),
+    Elem::new(1466759273750363039),
+    Elem::new(190687751663151696),
+    Elem::new(1116674964420487786),
+    Elem::new(2513465577574540780),
+    Elem::new(3769249953012295266),
+    Elem::new(2464470540330603327),
+    Elem::new(788796301612011492),
+    Elem::new(4232636975773312294),
+    Elem::new(4062726500069183721),
+    Elem::new(929376076988777245),
+    Elem::new(3771611833435168175),
+    Elem::new(2577343018631233407),
+    Elem::new(115947022740129251),
+    Elem::new(1411688677293281346),
+    Elem::new(2541938898403186751),
+    Elem::new(2002495125230464425),
+    Elem::new(502751875290464154),
+    Elem::new(2501655801303026282),
+    Elem::new(2474269574058368673),
+    Elem::new(659273037875062009),
+    Elem::new(2146824823105315614),
+    Elem::new(2705472553876078157),
+    Elem::new(4258477731950499779),
+    Elem::new(3538203605035862722),
+    Elem::new(1583077560628218298),
+    Elem::new(1753552498751169467),
+    Elem::new(444218708014401432),
+    Elem::new(3548635394796364228),
+    Elem::new(4578054927621170870),
+    Elem::new(2380962613718627580),
+    Elem::new(2001485286062215620),
+    Elem::new(432336067834063143),
+    Elem::new(3464758130906137756),
+    Elem::new(3475645213158152978),
+    Elem::new(952904083788941782),
+    Elem::new(4127540878408998484),
+    Elem::new(3428234053572280245),
+    Elem::new(3626982289644016901),
+    Elem::new(1558740885324337012),
+    Elem::new(2130259052517616599),
+    Elem::new(3237319299343556122),
+    Elem::new(2224625568320430333),
+    Elem::new(692164112680874957),
+    Elem::new(3676788937317362489),
+    Elem::new(4302007810579516063),
+    Elem::new(1937740234029231890),
+    Elem::new(109547050811310607),
+    Elem::new(1214950390638960870),
+    Elem::new(4142015283944830272),
+    Elem::new(2787394308093942150),
+    Elem::new(2163308741240408744),
+    Elem::new(4169299920546828672),
+    Elem::new(2618684638059293304),
+    Elem::new(1945389621733375338),
+    Elem::new(1873708926370691094),
+    Elem::new(2484834918937454720),
+    Elem::new(4493709260779836307),
+    Elem::new(2925426525324187811),
+    Elem::new(2196971383208555169),
+    Elem::new(2101843445516229647),
+    Elem::new(2582514155626699687),
+    Elem::new(4549914118818868054),
+    Elem::new(283356475884892449),
+    Elem::new(3319063720285570871),
+    Elem::new(2296579669571222092),
+    Elem::new(3087418567098697953),
+    Elem::new(57246489103332235),
+    Elem::new(177112340671559880),
+    Elem::new(4458997249721904829),
+    Elem::new(1601980100415773438),
+    Elem::new(2480466740591259821),
+    Elem::new(4373950186539363822),
+    Elem::new(2832085611762083438),
+    Elem::new(1419342879494895292),
+    Elem::new(1282149942051721903),
+    Elem::new(2580116670158876947),
+    Elem::new(4074355595786935595),
+    Elem::new(815572365086704913),
+    Elem::new(840865153434701177),
+    Elem::new(747396701403686344),
+    Elem::new(3620333420850121852),
+    Elem::new(4007433564987003673),
+    Elem::new(841619754971405649),
+    Elem::new(3483368053287072740),
+    Elem::new(1729913657856172771),
+    Elem::new(1983260840859139059),
+    Elem::new(655600185293360275),
+    Elem::new(2289914009217097028),
+    Elem::new(3291876035013041027),
+    Elem::new(4287722580093089278),
+    Elem::new(2044401990964754996),
+    Elem::new(744438214103905255),
+    Elem::new(4183311618818611767),
+    Elem::new(64787660756934825),
+    Elem::new(2861273246016977873),
+    Elem::new(2730630836698059618),
+    Elem::new(3002788281048734163),
+    Elem::new(1332711706438872781),
+    Elem::new(912185737677610699),
+    Elem::new(161751810950055342),
+    Elem::new(2051870254611630399),
+];
+
+const INV_MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(3673407168763191043),
+    Elem::new(3021670488632377552),
+    Elem::new(3797384554804462585),
+    Elem::new(1582821406974155978),
+    Elem::new(3170767910457822846),
+    Elem::new(1647719042764279520),
+    Elem::new(2688487672858116021),
+    Elem::new(2997156743200033153),
+    Elem::new(1891121212053456638),
+    Elem::new(1968110648375797059),
+    Elem::new(3839819954972467003),
+    Elem::new(849422840012275382),
+    Elem::new(1153485324855889580),
+    Elem::new(4112274251676721155),
+    Elem::new(188404297564864426),
+    Elem::new(1236321035999351360),
+    Elem::new(2033284193446519116),
+    Elem::new(4568357829701700308),
+    Elem::new(335802490534662742),
+    Elem::new(3545833588409128370),
+    Elem::new(901963169036878825),
+    Elem::new(1589258436976019985),
+    Elem::new(3914935095557030466),
+    Elem::new(4314583797819022774),
+    Elem::new(3083745630915801501),
+    Elem::new(2456611151086623332),
+    Elem::new(2543214393608729353),
+    Elem::new(3387795088063646195),
+    Elem::new(1154246966899815541),
+    Elem::new(1591503453635494498),
+    Elem::new(94155353547497355),
+    Elem::new(1667222597737889555),
+    Elem::new(1801475456210503418),
+    Elem::new(1886421487181320440),
+    Elem::new(1237148292426670053),
+    Elem::new(2633301472843509741),
+    Elem::new(2645434503771035028),
+    Elem::new(2061751609489272932),
+    Elem::new(354454302244847577),
+    Elem::new(3492653034558655859),
+    Elem::new(697512690025534237),
+    Elem::new(3210073346054602041),
+    Elem::new(603410261894568703),
+    Elem::new(3456668901574831016),
+    Elem::new(3923921995038334439),
+    Elem::new(2499075322331968295),
+    Elem::new(3432432103841057432),
+    Elem::new(1282596629264485933),
+    Elem::new(581296822968939700),
+    Elem::new(494770725366780115),
+    Elem::new(3662655969855032751),
+    Elem::new(332682170417487502),
+    Elem::new(2482659583869205821),
+    Elem::new(730576405578143168),
+    Elem::new(557519958226353812),
+    Elem::new(4205752840568484815),
+    Elem::new(2391979325567771296),
+    Elem::new(1994845069447217902),
+    Elem::new(4325375519566887344),
+    Elem::new(1891232451144581462),
+    Elem::new(1197508803920293350),
+    Elem::new(301913866204575725),
+    Elem::new(2486605946041523974),
+    Elem::new(908090119836326640),
+    Elem::new(4195690083220912714),
+    Elem::new(3316404110116194560),
+    Elem::new(4576896214390804574),
+    Elem::new(2415201200723052307),
+    Elem::new(1607685331923863544),
+    Elem::new(981509062862268760),
+    Elem::new(2745393069126718494),
+    Elem::new(4561056774277419271),
+    Elem::new(2139399065296124398),
+    Elem::new(2363483941741601270),
+    Elem::new(1288868809489116930),
+    Elem::new(483794565220699185),
+    Elem::new(758671800710594767),
+    Elem::new(431386027313819963),
+    Elem::new(2734715429712854367),
+    Elem::new(1855565107714690671),
+    Elem::new(2731830752318160206),
+    Elem::new(4364811552367806368),
+    Elem::new(1802914042325421218),
+    Elem::new(2682764766740506114),
+    Elem::new(1168711143087631514),
+    Elem::new(1571040394358874922),
+    Elem::new(3792603934796683119),
+    Elem::new(2114648017713466853),
+    Elem::new(3365566931836671882),
+    Elem::new(3484903412168741069),
+    Elem::new(611299483582816057),
+    Elem::new(1659390712493628484),
+    Elem::new(1709782657277151699),
+    Elem::new(635853719563638884),
+    Elem::new(1008342058500857516),
+    Elem::new(143954681546787802),
+    Elem::new(477698677585970564),
+    Elem::new(1072046426275685087),
+    Elem::new(2322245392096269723),
+    Elem::new(3151857063299246015),
+    Elem::new(2011332011285104182),
+    Elem::new(980245300467891437),
+    Elem::new(1482868384140783163),
+    Elem::new(1790882897595170793),
+    Elem::new(3646304579003208720),
+    Elem::new(1836234239978397096),
+    Elem::new(2369337852790521212),
+    Elem::new(1139565229673983021),
+    Elem::new(4210796143775318385),
+    Elem::new(4196781338909863356),
+    Elem::new(91406368196451774),
+    Elem::new(3248706633096554029),
+    Elem::new(3398500641334520901),
+    Elem::new(727729857532290686),
+    Elem::new(214538126681508400),
+    Elem::new(4466942565250950034),
+    Elem::new(2734531392500756746),
+    Elem::new(623488416503834779),
+    Elem::new(1580435147106787703),
+    Elem::new(68626730662465508),
+    Elem::new(3325077417016216804),
+    Elem::new(1798550329221554565),
+    Elem::new(4248378711062056253),
+    Elem::new(2149541129855045453),
+    Elem::new(1428992258822400464),
+    Elem::new(128085906589868653),
+    Elem::new(1592590969218185179),
+    Elem::new(3584298768425610186),
+    Elem::new(691252379627848192),
+    Elem::new(1838806813548357947),
+    Elem::new(4376001274373406384),
+    Elem::new(3387626595419856148),
+    Elem::new(3520288609986622836),
+    Elem::new(3502387241017119682),
+    Elem::new(3932046180836618250),
+    Elem::new(1935291890185076554),
+    Elem::new(1670901809173685425),
+    Elem::new(2045535439838369984),
+    Elem::new(1025713506040379717),
+    Elem::new(4000577785631391047),
+    Elem::new(742927584854045500),
+    Elem::new(4435484487758963309),
+    Elem::new(2318217559140835861),
+    Elem::new(2481404209296272506),
+    Elem::new(322960196734625149),
+    Elem::new(1482878781459179477),
+    Elem::new(4308098742026220448),
+    Elem::new(581084441478779200),
+    Elem::new(908323918405347755),
+    Elem::new(2649538044382342100),
+    Elem::new(1454839400391398949),
+    Elem::new(3275771078911533263),
+    Elem::new(2114740631183847325),
+    Elem::new(1930525142977431804),
+    Elem::new(2522773209598940442),
+    Elem::new(3636812176346359605),
+    Elem::new(1387762945294891646),
+    Elem::new(1330701203527903757),
+    Elem::new(4418349416813816270),
+    Elem::new(4144636556330476994),
+    Elem::new(1865359718175516040),
+    Elem::new(3781193665242348671),
+    Elem::new(2923732756194917840),
+    Elem::new(2267215810404684555),
+    Elem::new(3381153169218291066),
+    Elem::new(1372231164493052177),
+    Elem::new(4395830330129255326),
+    Elem::new(3993942772585227580),
+    Elem::new(1630890455845988775),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * 2 * NUM_ROUNDS] = [
+    Elem::new(3692723757389255131),
+    Elem::new(4130381780655102709),
+    Elem::new(1500621562637254932),
+    Elem::new(1050745191770548814),
+    Elem::new(4486254185966903404),
+    Elem::new(442722535976487788),
+    Elem::new(3280138716134787760),
+    Elem::new(4353173044940580772),
+    Elem::new(4109400330490282047),
+    Elem::new(1390293662284377642),
+    Elem::new(2793840262796158086),
+    Elem::new(4352742028213692936),
+    Elem::new(1099668717606764549),
+    Elem::new(2564564604821640204),
+    Elem::new(2505448216463842472),
+    Elem::new(1084288960261219632),
+    Elem::new(4029116776889225724),
+    Elem::new(1505242454005169225),
+    Elem::new(2690699559472688565),
+    Elem::new(3768879292569058701),
+    Elem::new(1884607888451543824),
+    Elem::new(1298049305082625276),
+    Elem::new(4177220485839691625),
+    Elem::new(757252378069539206),
+    Elem::new(1234928578968439907),
+    Elem::new(3129743604782061866),
+    Elem::new(414771131302858973),
+    Elem::new(1991771922652141235),
+    Elem::new(1450984062485371532),
+    Elem::new(3082392452605954600),
+    Elem::new(1862724950816346151),
+    Elem::new(606053755650995382),
+    Elem::new(3280428128337088570),
+    Elem::new(3306625474762051538),
+    Elem::new(408692505711825239),
+    Elem::new(3058737558667204537),
+    Elem::new(4295940260283876741),
+    Elem::new(1622082097629173913),
+    Elem::new(3860325215509998407),
+    Elem::new(344658999518014949),
+    Elem::new(723295408960424436),
+    Elem::new(1349555185026063250),
+    Elem::new(450439677099983303),
+    Elem::new(402019921831911196),
+    Elem::new(579824988633961846),
+    Elem::new(3271150045126293095),
+    Elem::new(3249766767819408230),
+    Elem::new(3851684750925986200),
+    Elem::new(2252686575005623714),
+    Elem::new(1170113187551581561),
+    Elem::new(4534048525713445002),
+    Elem::new(330183422010445195),
+    Elem::new(3167833326617102321),
+    Elem::new(3693930294543789735),
+    Elem::new(3623932274579622006),
+    Elem::new(2359067837458343036),
+    Elem::new(4142209106434621740),
+    Elem::new(4392313457783933010),
+    Elem::new(3769529777683818420),
+    Elem::new(68369154063839289),
+    Elem::new(3368389041616114488),
+    Elem::new(3544660027741640189),
+    Elem::new(4486877489915717958),
+    Elem::new(2669607589627463411),
+    Elem::new(4211986123131775651),
+    Elem::new(171882884178480540),
+    Elem::new(4467922115990825561),
+    Elem::new(3207074618159822174),
+    Elem::new(3061483957305891380),
+    Elem::new(1312320098466467724),
+    Elem::new(1214558150752938503),
+    Elem::new(41483166789630586),
+    Elem::new(2293233327550493947),
+    Elem::new(529901028667082102),
+    Elem::new(112408941958323908),
+    Elem::new(3514799564460986366),
+    Elem::new(1628858905717860169),
+    Elem::new(2002213685593842751),
+    Elem::new(2760266884038126505),
+    Elem::new(1298591233219842926),
+    Elem::new(3079392364874300181),
+    Elem::new(4018471688878184588),
+    Elem::new(575881599133121882),
+    Elem::new(2198606771779661740),
+    Elem::new(4283402559667599964),
+    Elem::new(562871535166627608),
+    Elem::new(950776013059112260),
+    Elem::new(563429781926863118),
+    Elem::new(1632463820117949164),
+    Elem::new(2558146922337987591),
+    Elem::new(1328195248176801498),
+    Elem::new(4062111789498275805),
+    Elem::new(1534940118777576329),
+    Elem::new(4079066611086348934),
+    Elem::new(1769939683168267736),
+    Elem::new(4247722715595311877),
+    Elem::new(3222760238395609664),
+    Elem::new(2116248824351534633),
+    Elem::new(1808101476915132129),
+    Elem::new(3378812737211243374),
+    Elem::new(1074374153945024459),
+    Elem::new(1532004956788800257),
+    Elem::new(361385476802761260),
+    Elem::new(1929223853050954869),
+    Elem::new(4195300428978706816),
+    Elem::new(1932938763849964787),
+    Elem::new(181793055525427114),
+    Elem::new(713842302443593643),
+    Elem::new(1522077801200117388),
+    Elem::new(2190180127612773495),
+    Elem::new(1320160707747127866),
+    Elem::new(1659387487652950209),
+    Elem::new(1104553588259271565),
+    Elem::new(1322854604934787803),
+    Elem::new(3002739358063285236),
+    Elem::new(345490101417603844),
+    Elem::new(341381503911513688),
+    Elem::new(2496681950071660097),
+    Elem::new(3022522827126118829),
+    Elem::new(2760804844148374266),
+    Elem::new(867535152363011014),
+    Elem::new(599536313756146905),
+    Elem::new(1099545071525913427),
+    Elem::new(2089575057538577650),
+    Elem::new(1012076107242380884),
+    Elem::new(2570963584844967239),
+    Elem::new(1905941956592070569),
+    Elem::new(1161019750294980936),
+    Elem::new(2336810884359790846),
+    Elem::new(3312266185323569389),
+    Elem::new(597403809898616466),
+    Elem::new(991229932070887091),
+    Elem::new(3227986574844756083),
+    Elem::new(1178386351495768921),
+    Elem::new(3581135578915516488),
+    Elem::new(3611566633473465554),
+    Elem::new(3217707970553926793),
+    Elem::new(2582142709477903720),
+    Elem::new(4563520247940106509),
+    Elem::new(4157987392471169226),
+    Elem::new(3919195711732793297),
+    Elem::new(1522268853429093370),
+    Elem::new(4549347545333982437),
+    Elem::new(2929706672910456623),
+    Elem::new(2263839246256182317),
+    Elem::new(831554738616759970),
+    Elem::new(721668431151459343),
+    Elem::new(1321197374774468910),
+    Elem::new(2277328342549299510),
+    Elem::new(359059573110694881),
+    Elem::new(4335973856705538408),
+    Elem::new(3890766866291500415),
+    Elem::new(2585121330930729319),
+    Elem::new(3299186264170709629),
+    Elem::new(2752438632882745886),
+    Elem::new(2152311163549142500),
+    Elem::new(386783760392025316),
+    Elem::new(1368444773931148550),
+    Elem::new(1976218619925465794),
+    Elem::new(4412472563942084713),
+    Elem::new(3324576110400695876),
+    Elem::new(2794807960824203866),
+    Elem::new(2790830755177713226),
+    Elem::new(2789971173858600569),
+    Elem::new(803062460147816660),
+    Elem::new(4269198402366370259),
+    Elem::new(4018655924905734729),
+    Elem::new(2230617857390641746),
+    Elem::new(4543393642725474728),
+    Elem::new(2890595822287772363),
+    Elem::new(1746592092922153011),
+    Elem::new(3376412377370469588),
+    Elem::new(3963398689703737868),
+    Elem::new(2120946019831195288),
+    Elem::new(959184234529645564),
+    Elem::new(2668898145347862055),
+    Elem::new(3251223241992705768),
+    Elem::new(4490725294914749116),
+    Elem::new(3720608485517200841),
+    Elem::new(1010243523041545082),
+    Elem::new(1732369546783741241),
+    Elem::new(1077717904736725127),
+    Elem::new(2383496834655957830),
+    Elem::new(1256959658783127237),
+    Elem::new(2208690875996381489),
+    Elem::new(633257539232897743),
+    Elem::new(4197955022691665149),
+    Elem::new(1685591992244309519),
+    Elem::new(2798757952648994412),
+    Elem::new(4415231220871744429),
+    Elem::new(3424992136438772011),
+    Elem::new(82756487601508761),
+    Elem::new(677265121352851853),
+    Elem::new(1637057196782811423),
+    Elem::new(375971786566844945),
+    Elem::new(3794011842416326223),
+    Elem::new(2698654026876876706),
+    Elem::new(4015312213076831680),
+    Elem::new(2702325111282342142),
+    Elem::new(250145638669380901),
+    Elem::new(4094379544676993289),
+    Elem::new(1581544743885015197),
+    Elem::new(2093225231432983749),
+    Elem::new(2852656851598300531),
+    Elem::new(229914131639235002),
+    Elem::new(747858690901129222),
+    Elem::new(1433254464366717384),
+    Elem::new(3952607590553914736),
+];
+
+
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    rescue_prime_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    rescue_prime_hash(&salted_sequence)
+}
+
+// ALGORITHMS FROM https://eprint.iacr.org/2020/1143
+// ================================================================================================
+
+// Algorithm 1
+pub fn rescue_prime_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        rescue_xlix_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+// Algorithm 2
+#[allow(dead_code)]
+pub fn rescue_prime_wrapper(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    let mut padded_input = input_sequence.clone();
+    padded_input.push(Elem::ONE);
+    while (padded_input.len() % RATE) != 0 {
+        padded_input.push(Elem::ZERO);
+    }
+    rescue_prime_hash(&padded_input)
+}
+
+// Algorithm 3
+pub fn rescue_xlix_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for round in 0..NUM_ROUNDS {
+        let round_const = &ROUND_CONSTANTS[round * STATE_WIDTH * 2..(round + 1) * STATE_WIDTH * 2];
+        apply_sbox(state);
+        matrix_mul(MDS, state);
+        add_constants(state, &round_const, 0);
+        apply_inv_sbox(state);
+        matrix_mul(MDS, state);
+        add_constants(state, &round_const, STATE_WIDTH);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH * 2..(tmp_round + 1) * STATE_WIDTH * 2];
+
+    // apply first half of Rescue round
+    apply_sbox(state);
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, 0);
+
+    // apply second half of Rescue round
+    apply_inv_sbox(state);
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, STATE_WIDTH);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Rescue hash functions
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // compute the state that should result from applying the first half of Rescue round
+    // to the current state of the computation
+    let mut step1 = [E::ZERO; STATE_WIDTH];
+    step1.copy_from_slice(current_slice);
+    apply_sbox(&mut step1);
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[i];
+    }
+
+    // compute the state that should result from applying the inverse for the second
+    // half for Rescue round to the next step of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        step2[i] -= round_constants[STATE_WIDTH + i];
+    }
+    matrix_mul(INV_MDS, &mut step2);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (step2[i] - step1[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of Rescue hash functions
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // compute the state that should result from applying the first half of Rescue round
+    // to the current state of the computation
+    let mut step1 = [E::ZERO; STATE_WIDTH];
+    step1.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        step1[i] += pixels[i];
+    }
+    apply_sbox(&mut step1);
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[i];
+    }
+
+    // compute the state that should result from applying the inverse for the second
+    // half for Rescue round to the next step of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next_slice);
+    for i in 0..STATE_WIDTH {
+        step2[i] -= round_constants[STATE_WIDTH + i];
+    }
+    matrix_mul(INV_MDS, &mut step2);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (step2[i] - step1[i]);
+    }
+}
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..(STATE_WIDTH * 2) {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..(STATE_WIDTH * 2) {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH * 2 + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn add_constants(state: &mut [Elem], round_constants: &[Elem], offset: usize) {
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[offset + i];
+    }
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn apply_inv_sbox(state: &mut [Elem]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(INV_ALPHA);
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = 2 * STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
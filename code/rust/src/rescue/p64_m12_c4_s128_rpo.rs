@@ -0,0 +1,569 @@
+// Rescue Prime Optimized (RPO) definition according to https://eprint.iacr.org/2022/1577
+// Taken and adapted from the Rescue-Prime modules of this crate
+// MDS matrix and round constants as in the specification, the round constants are generated from SHAKE256
+// seeded with "RPO(p,m,c_p,security)"
+
+// Parameters
+//   p   .. 18446744069414584321 (prime from winterfell::math::fields::f64)
+//   m   .. 12
+//   c_p .. 4
+//   security at least 128 bit
+
+use winterfell::math::{fields::f64::BaseElement, FieldElement};
+use winterfell::TransitionConstraintDegree;
+use crate::permutation::HashPermutation;
+
+pub type Elem = BaseElement;
+
+// RPO CONSTANTS
+// ================================================================================================
+
+pub const NUM_ROUNDS: usize = 7;
+pub const STATE_WIDTH: usize = 12;
+pub const CAPACITY: usize = 4;
+pub const RATE: usize = STATE_WIDTH - CAPACITY;
+const ALPHA: u32 = 7;
+const INV_ALPHA: u64 = 10540996611094048183;
+
+const MDS: [Elem; STATE_WIDTH * STATE_WIDTH] = [
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+    Elem::new(23),
+    Elem::new(23),
+    Elem::new(8),
+    Elem::new(26),
+    Elem::new(13),
+    Elem::new(10),
+    Elem::new(9),
+    Elem::new(7),
+    Elem::new(6),
+    Elem::new(22),
+    Elem::new(21),
+    Elem::new(8),
+    Elem::new(7),
+];
+
+const ROUND_CONSTANTS: [Elem; STATE_WIDTH * NUM_ROUNDS * 2] = [
+    Elem::new(5789762306288267392),
+    Elem::new(6522564764413701783),
+    Elem::new(17809893479458208203),
+    Elem::new(107145243989736508),
+    Elem::new(6388978042437517382),
+    Elem::new(15844067734406016715),
+    Elem::new(9975000513555218239),
+    Elem::new(3344984123768313364),
+    Elem::new(9959189626657347191),
+    Elem::new(12960773468763563665),
+    Elem::new(9602914297752488475),
+    Elem::new(16657542370200465908),
+    Elem::new(6077062762357204287),
+    Elem::new(15277620170502011191),
+    Elem::new(5358738125714196705),
+    Elem::new(14233283787297595718),
+    Elem::new(13792579614346651365),
+    Elem::new(11614812331536767105),
+    Elem::new(14871063686742261166),
+    Elem::new(10148237148793043499),
+    Elem::new(4457428952329675767),
+    Elem::new(15590786458219172475),
+    Elem::new(10063319113072092615),
+    Elem::new(14200078843431360086),
+    Elem::new(12987190162843096997),
+    Elem::new(653957632802705281),
+    Elem::new(4441654670647621225),
+    Elem::new(4038207883745915761),
+    Elem::new(5613464648874830118),
+    Elem::new(13222989726778338773),
+    Elem::new(3037761201230264149),
+    Elem::new(16683759727265180203),
+    Elem::new(8337364536491240715),
+    Elem::new(3227397518293416448),
+    Elem::new(8110510111539674682),
+    Elem::new(2872078294163232137),
+    Elem::new(6202948458916099932),
+    Elem::new(17690140365333231091),
+    Elem::new(3595001575307484651),
+    Elem::new(373995945117666487),
+    Elem::new(1235734395091296013),
+    Elem::new(14172757457833931602),
+    Elem::new(707573103686350224),
+    Elem::new(15453217512188187135),
+    Elem::new(219777875004506018),
+    Elem::new(17876696346199469008),
+    Elem::new(17731621626449383378),
+    Elem::new(2897136237748376248),
+    Elem::new(18072785500942327487),
+    Elem::new(6200974112677013481),
+    Elem::new(17682092219085884187),
+    Elem::new(10599526828986756440),
+    Elem::new(975003873302957338),
+    Elem::new(8264241093196931281),
+    Elem::new(10065763900435475170),
+    Elem::new(2181131744534710197),
+    Elem::new(6317303992309418647),
+    Elem::new(1401440938888741532),
+    Elem::new(8884468225181997494),
+    Elem::new(13066900325715521532),
+    Elem::new(8023374565629191455),
+    Elem::new(15013690343205953430),
+    Elem::new(4485500052507912973),
+    Elem::new(12489737547229155153),
+    Elem::new(9500452585969030576),
+    Elem::new(2054001340201038870),
+    Elem::new(12420704059284934186),
+    Elem::new(355990932618543755),
+    Elem::new(9071225051243523860),
+    Elem::new(12766199826003448536),
+    Elem::new(9045979173463556963),
+    Elem::new(12934431667190679898),
+    Elem::new(5674685213610121970),
+    Elem::new(5759084860419474071),
+    Elem::new(13943282657648897737),
+    Elem::new(1352748651966375394),
+    Elem::new(17110913224029905221),
+    Elem::new(1003883795902368422),
+    Elem::new(4141870621881018291),
+    Elem::new(8121410972417424656),
+    Elem::new(14300518605864919529),
+    Elem::new(13712227150607670181),
+    Elem::new(17021852944633065291),
+    Elem::new(6252096473787587650),
+    Elem::new(18389244934624494276),
+    Elem::new(16731736864863925227),
+    Elem::new(4440209734760478192),
+    Elem::new(17208448209698888938),
+    Elem::new(8739495587021565984),
+    Elem::new(17000774922218161967),
+    Elem::new(13533282547195532087),
+    Elem::new(525402848358706231),
+    Elem::new(16987541523062161972),
+    Elem::new(5466806524462797102),
+    Elem::new(14512769585918244983),
+    Elem::new(10973956031244051118),
+    Elem::new(4887609836208846458),
+    Elem::new(3027115137917284492),
+    Elem::new(9595098600469470675),
+    Elem::new(10528569829048484079),
+    Elem::new(7864689113198939815),
+    Elem::new(17533723827845969040),
+    Elem::new(5781638039037710951),
+    Elem::new(17024078752430719006),
+    Elem::new(109659393484013511),
+    Elem::new(7158933660534805869),
+    Elem::new(2955076958026921730),
+    Elem::new(7433723648458773977),
+    Elem::new(6982293561042362913),
+    Elem::new(14065426295947720331),
+    Elem::new(16451845770444974180),
+    Elem::new(7139138592091306727),
+    Elem::new(9012006439959783127),
+    Elem::new(14619614108529063361),
+    Elem::new(1394813199588124371),
+    Elem::new(4635111139507788575),
+    Elem::new(16217473952264203365),
+    Elem::new(10782018226466330683),
+    Elem::new(6844229992533662050),
+    Elem::new(7446486531695178711),
+    Elem::new(16308865189192447297),
+    Elem::new(11977192855656444890),
+    Elem::new(12532242556065780287),
+    Elem::new(14594890931430968898),
+    Elem::new(7291784239689209784),
+    Elem::new(5514718540551361949),
+    Elem::new(10025733853830934803),
+    Elem::new(7293794580341021693),
+    Elem::new(6728552937464861756),
+    Elem::new(6332385040983343262),
+    Elem::new(13277683694236792804),
+    Elem::new(2600778905124452676),
+    Elem::new(3736792340494631448),
+    Elem::new(577852220195055341),
+    Elem::new(6689998335515779805),
+    Elem::new(13886063479078013492),
+    Elem::new(14358505101923202168),
+    Elem::new(7744142531772274164),
+    Elem::new(16135070735728404443),
+    Elem::new(12290902521256031137),
+    Elem::new(12059913662657709804),
+    Elem::new(16456018495793751911),
+    Elem::new(4571485474751953524),
+    Elem::new(17200392109565783176),
+    Elem::new(7123075680859040534),
+    Elem::new(1034205548717903090),
+    Elem::new(7717824418247931797),
+    Elem::new(3019070937878604058),
+    Elem::new(11403792746066867460),
+    Elem::new(10280580802233112374),
+    Elem::new(337153209462421218),
+    Elem::new(13333398568519923717),
+    Elem::new(3596153696935337464),
+    Elem::new(8104208463525993784),
+    Elem::new(14345062289456085693),
+    Elem::new(17036731477169661256),
+    Elem::new(17130398059294018733),
+    Elem::new(519782857322261988),
+    Elem::new(9625384390925085478),
+    Elem::new(1664893052631119222),
+    Elem::new(7629576092524553570),
+    Elem::new(3485239601103661425),
+    Elem::new(9755891797164033838),
+    Elem::new(15218148195153269027),
+    Elem::new(16460604813734957368),
+    Elem::new(9643968136937729763),
+    Elem::new(3611348709641382851),
+    Elem::new(18256379591337759196),
+];
+
+pub fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    rpo_hash(input_sequence)
+}
+
+// The salt is absorbed as one additional block after the input, which turns the hash into a hiding
+// commitment to the input as long as the salt is kept secret.
+pub fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+    let mut salted_sequence = input_sequence.clone();
+    salted_sequence.extend_from_slice(salt);
+    rpo_hash(&salted_sequence)
+}
+
+// ALGORITHMS for computing RPO
+// ================================================================================================
+
+// the sponge is the one of the other hash functions of this crate: the input is added to the first RATE
+// elements of the state, which are also the output
+pub fn rpo_hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+    assert_eq!(0, input_sequence.len() % RATE);
+    let mut state = [Elem::ZERO; STATE_WIDTH];
+    let mut absorb_index = 0;
+    while absorb_index < input_sequence.len() {
+        for i in 0..RATE {
+            state[i] += input_sequence[absorb_index];
+            absorb_index += 1;
+        }
+        rpo_permutation(&mut state);
+    }
+    let mut output_sequence = [Elem::ZERO; RATE];
+    output_sequence.copy_from_slice(&state[..RATE]);
+    output_sequence
+}
+
+pub fn rpo_permutation(state: &mut [Elem; STATE_WIDTH]) {
+    for round in 0..NUM_ROUNDS {
+        apply_round(state, round);
+    }
+}
+
+// TRACE CONSTRUCTION
+// ================================================================================================
+
+pub fn apply_round(state: &mut [Elem], round: usize) {
+    // determine which round constants to use
+    let tmp_round = round % NUM_ROUNDS;
+    let round_constants = &ROUND_CONSTANTS[tmp_round * STATE_WIDTH * 2..(tmp_round + 1) * STATE_WIDTH * 2];
+
+    // apply first half of RPO round
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, 0);
+    apply_sbox(state);
+
+    // apply second half of RPO round
+    matrix_mul(MDS, state);
+    add_constants(state, &round_constants, STATE_WIDTH);
+    apply_inv_sbox(state);
+}
+
+// TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// when flag = 1, enforces constraints for a single round of RPO
+pub fn enforce_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    enforce_round_from(result_slice, state, next_slice, round_constants, flag);
+}
+
+/// when flag = 1, enforces constraints for a single round of RPO after absorbing the pixels
+pub fn enforce_first_round<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    current_slice: &[E],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    let mut state = [E::ZERO; STATE_WIDTH];
+    state.copy_from_slice(current_slice);
+    for i in 0..RATE {
+        state[i] += pixels[i];
+    }
+    enforce_round_from(result_slice, state, next_slice, round_constants, flag);
+}
+
+fn enforce_round_from<E: FieldElement + From<Elem>>(
+    result_slice: &mut [E],
+    mut step1: [E; STATE_WIDTH],
+    next_slice: &[E],
+    round_constants: &[E],
+    flag: E,
+) {
+    // compute the state that should result from applying the round up to the inverse S-box
+    // to the current state of the computation
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[i];
+    }
+    apply_sbox(&mut step1);
+    matrix_mul(MDS, &mut step1);
+    for i in 0..STATE_WIDTH {
+        step1[i] += round_constants[STATE_WIDTH + i];
+    }
+
+    // undo the inverse S-box on the next step of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next_slice);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for i in 0..STATE_WIDTH {
+        result_slice[i] += flag * (step2[i] - step1[i]);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// returns round constants arranged in column-major form for periodic columns
+pub fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+    let mut constants = Vec::new();
+    for _ in 0..(STATE_WIDTH * 2) {
+        constants.push(vec![Elem::ZERO; cycle_length]);
+    }
+    for i in 0..NUM_ROUNDS {
+        for j in 0..(STATE_WIDTH * 2) {
+            constants[j][(i + shift) % cycle_length] = ROUND_CONSTANTS[i * STATE_WIDTH * 2 + j];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn add_constants(state: &mut [Elem], round_constants: &[Elem], offset: usize) {
+    for i in 0..STATE_WIDTH {
+        state[i] += round_constants[offset + i];
+    }
+}
+
+fn apply_sbox<E: FieldElement>(state: &mut [E]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(ALPHA.into());
+    }
+}
+
+fn apply_inv_sbox(state: &mut [Elem]) {
+    for i in 0..STATE_WIDTH {
+        state[i] = state[i].exp(INV_ALPHA);
+    }
+}
+
+fn matrix_mul<E: FieldElement + From<Elem>>(matrix: [Elem; STATE_WIDTH * STATE_WIDTH], state: &mut [E]) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for row in 0..STATE_WIDTH {
+        for col in 0..STATE_WIDTH {
+            result[row] += E::from(matrix[row * STATE_WIDTH + col]) * state[col];
+        }
+    }
+    state.copy_from_slice(&result);
+}
+
+// HASH PERMUTATION
+// ================================================================================================
+
+pub struct Permutation;
+
+impl HashPermutation for Permutation {
+    type BaseField = Elem;
+    type Digest = [Elem; RATE];
+
+    const NUM_ROUNDS: usize = NUM_ROUNDS;
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE: usize = RATE;
+    const NUM_PERIODIC_COLUMNS: usize = 2 * STATE_WIDTH;
+
+    fn hash(input_sequence: &Vec<Elem>) -> [Elem; RATE] {
+        hash(input_sequence)
+    }
+
+    fn hash_salted(input_sequence: &Vec<Elem>, salt: &[Elem; RATE]) -> [Elem; RATE] {
+        hash_salted(input_sequence, salt)
+    }
+
+    fn apply_round(state: &mut [Elem], round: usize) {
+        apply_round(state, round)
+    }
+
+    fn enforce_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_round(result_slice, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn enforce_first_round<E: FieldElement + From<Elem>>(
+        result_slice: &mut [E],
+        pixels: &[E],
+        current_slice: &[E],
+        next_slice: &[E],
+        round_constants: &[E],
+        flag: E,
+    ) {
+        enforce_first_round(result_slice, pixels, current_slice, next_slice, round_constants, flag)
+    }
+
+    fn get_round_constants_periodic(cycle_length: usize, shift: usize) -> Vec<Vec<Elem>> {
+        get_round_constants_periodic(cycle_length, shift)
+    }
+
+    fn round_constraint_degree(cycles: Vec<usize>) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::with_cycles(ALPHA as usize, cycles)
+    }
+}
\ No newline at end of file
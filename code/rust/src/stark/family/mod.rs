@@ -0,0 +1,57 @@
+use winterfell::math::FieldElement;
+use crate::utils::{next_power_of_two, IndexDefinition};
+
+// STARK families written once against permutation::HashPermutation. They are generic over the base
+// field B and the hash H (with H::BaseField = B), the modules stark::stark_* instantiate them.
+
+pub mod stark_a;
+pub mod stark_d;
+pub mod stark_e;
+pub mod stark_e_opt;
+pub mod stark_f;
+pub mod stark_f_frames;
+pub mod stark_f_series;
+pub mod stark_f_segment;
+pub mod stark_g;
+pub mod stark_histogram;
+pub mod stark_threshold;
+
+// number of pixels (per FACTOR_M) processed in one hash cycle, one per step
+pub const NUM_IDENTITY_MASKS: usize = 8;
+
+/// Number of steps of one hash cycle: one step per round, but at least NUM_IDENTITY_MASKS. The hash
+/// state is copied in the steps after the last round.
+pub const fn get_cycle_length(num_rounds: usize) -> usize {
+    let result = next_power_of_two(num_rounds);
+    if result < NUM_IDENTITY_MASKS { NUM_IDENTITY_MASKS } else { result }
+}
+
+/// identity[i][j] = 1 if i = j
+pub fn get_identity_masks<B: FieldElement>(cycle_length: usize) -> Vec<Vec<B>> {
+    let mut result = vec![vec![B::ZERO; cycle_length]; NUM_IDENTITY_MASKS];
+    for i in 0..NUM_IDENTITY_MASKS {
+        result[i][i] = B::ONE;
+    }
+    result
+}
+
+/// The first round absorbs the pixels (flag identity[0]), the mask flags the remaining rounds.
+pub fn get_hash_mask<B: FieldElement>(num_rounds: usize, cycle_length: usize) -> Vec<B> {
+    let mut result = vec![B::ZERO; cycle_length];
+    for i in 1..num_rounds {
+        result[i] = B::ONE;
+    }
+    result
+}
+
+/// Fills the columns of a row, where a flag (f_f after the result step, the pixel masks outside of a
+/// pixel) disables their constraints, with the non-bit value 2. The columns are thus never constant,
+/// hence their constraints keep the declared degree also if no pixel sets a bit (winterfell rejects a
+/// vanishing constraint as a degree mismatch).
+pub fn fill_unconstrained_tail<B: FieldElement>(state: &mut [B], columns: &[IndexDefinition]) {
+    for column in columns {
+        for i in column.begin()..column.end() {
+            state[i] = B::ONE.double();
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::marker::PhantomData;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, Trace, TraceInfo, TraceTable, TransitionConstraintDegree};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{create_salted_meta, get_salted_meta, get_salt_pixels, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{get_cycle_length, get_hash_mask};
+use super::stark_f::{check_input_length, enforce_copy};
+
+// STARK A, B and C: the AET computes the hash H of the pixels. STARK A and B absorb one pixel per
+// element, STARK C packs PIXELS_PER_ELEM pixels of NUM_BITS_PER_PIXEL bits into one element. The hash
+// absorbs RATE elements together with its first round at the beginning of a cycle, the remaining
+// rounds follow and the hash state is copied in the steps after the last round.
+//
+// Unlike STARK F, the pixels are not padded: the input has to fill whole blocks of RATE elements.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+
+// number of bits of a pixel packed into an element
+pub(super) const NUM_BITS_PER_PIXEL: usize = 16;
+
+pub struct PubInputs<H: HashPermutation, const PIXELS_PER_ELEM: usize> {
+    pub hash: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> {
+    context: AirContext<B>,
+    hash: H::Digest,
+    input_length: B,
+    salted: bool,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> TheAir<B, H, PIXELS_PER_ELEM> {
+    pub const CYCLE_LENGTH: usize = get_cycle_length(H::NUM_ROUNDS);
+    pub const TRACE_WIDTH: usize = H::RATE * PIXELS_PER_ELEM + H::STATE_WIDTH;
+
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < Self::CYCLE_LENGTH;
+
+    // AET index definitions
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: H::RATE * PIXELS_PER_ELEM };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: H::RATE * PIXELS_PER_ELEM, size: H::STATE_WIDTH };
+
+    // constraint index definitions
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_ABSORB_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: 1 };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + 1, size: 1 };
+
+    // number of pixels being absorbed in one absorption phase
+    pub const NUM_ELEMS_PER_CYCLE: usize = H::RATE * PIXELS_PER_ELEM;
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>) -> TraceTable<B> {
+        Self::build_salted_trace(pixels, None)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> TraceTable<B> {
+        // The desired hash value is located at step i = (pixels.len() / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH
+        assert!(NUM_BITS_PER_PIXEL * PIXELS_PER_ELEM <= B::MODULUS_BITS as usize);
+        assert_eq!(0, pixels.len() % Self::NUM_ELEMS_PER_CYCLE, "the input has to fill whole blocks of {} pixels", Self::NUM_ELEMS_PER_CYCLE);
+        let salt_pixels = get_salt_pixels(salt.map(|s| s.as_ref()), PIXELS_PER_ELEM, NUM_BITS_PER_PIXEL);
+        let trace_len = next_power_of_two(((pixels.len() + salt_pixels.len()) / Self::NUM_ELEMS_PER_CYCLE) * Self::CYCLE_LENGTH + 1);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_salted_meta(pixels.len(), salt.is_some()));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH;
+                if cyclic_step == 0 {
+                    // read elements into trace
+                    for c in 0..Self::NUM_ELEMS_PER_CYCLE {
+                        let next_pixel_idx = Self::NUM_ELEMS_PER_CYCLE * step / Self::CYCLE_LENGTH + c;
+                        if next_pixel_idx < pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                        } else if next_pixel_idx < pixels.len() + salt_pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = salt_pixels[next_pixel_idx - pixels.len()];
+                        }
+                    }
+                    // absorb the packed elements into hash state
+                    let elements = pack::<B>(&state[Self::T_PIXELS.begin()..Self::T_PIXELS.end()], PIXELS_PER_ELEM);
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += elements[c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+            }
+        );
+        table
+    }
+
+    /// Native computation of the commitment to the pixels, which is salted if a salt is given.
+    pub fn commit(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> H::Digest {
+        commit_packed::<H>(pixels, salt, PIXELS_PER_ELEM)
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H, PIXELS_PER_ELEM>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        opening.verify_digest(pub_inputs.hash.as_ref(), pub_inputs.salted, pub_inputs.input_length.as_u128() as usize, |pixels, salt| {
+            let salt = salt.map(|s| H::Digest::try_from(s).unwrap());
+            Self::commit(pixels, salt.as_ref()).as_ref().to_vec()
+        })
+    }
+}
+
+// packs every `pixels_per_elem` pixels into one element, the i-th pixel is multiplied by 2^(16 * i)
+pub(super) fn pack<E: FieldElement>(pixels: &[E], pixels_per_elem: usize) -> Vec<E> {
+    let factor = E::from(1u32 << NUM_BITS_PER_PIXEL);
+    pixels.chunks(pixels_per_elem).map(|chunk| chunk.iter().rev().fold(E::ZERO, |acc, pixel| acc * factor + *pixel)).collect()
+}
+
+// hash of the pixels packed into elements, which is salted if a salt is given
+pub(super) fn commit_packed<H: HashPermutation>(pixels: &Vec<u16>, salt: Option<&H::Digest>, pixels_per_elem: usize) -> H::Digest {
+    let pixels: Vec<H::BaseField> = pixels.iter().map(|pixel| H::BaseField::from(*pixel)).collect();
+    let elements = pack(&pixels, pixels_per_elem);
+    match salt {
+        Some(salt) => H::hash_salted(&elements, salt),
+        None => H::hash(&elements),
+    }
+}
+
+impl<H: HashPermutation, const PIXELS_PER_ELEM: usize> Serializable for PubInputs<H, PIXELS_PER_ELEM> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for elem in self.hash.as_ref() {
+            target.write(*elem);
+        }
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+    }
+}
+
+impl<H: HashPermutation, const PIXELS_PER_ELEM: usize> Deserializable for PubInputs<H, PIXELS_PER_ELEM> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut hash = vec![H::BaseField::ZERO; H::RATE];
+        for elem in hash.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let hash = H::Digest::try_from(&hash[..]).unwrap();
+        let input_length = H::BaseField::read_from(source)?;
+        let salted = read_bool(source)?;
+        check_input_length(input_length.as_u128()).map_err(DeserializationError::InvalidValue)?;
+        if input_length.as_u128() % (H::RATE * PIXELS_PER_ELEM) as u128 != 0 {
+            return Err(DeserializationError::InvalidValue(format!("input length {} does not fill whole blocks of {} pixels", input_length.as_u128(), H::RATE * PIXELS_PER_ELEM)));
+        }
+        Ok(PubInputs { hash, input_length, salted })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> Air for TheAir<B, H, PIXELS_PER_ELEM> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H, PIXELS_PER_ELEM>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees = vec![];
+
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+
+        let num_assertions = H::STATE_WIDTH + H::RATE;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            hash: pub_inputs.hash,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let absorb_flag = periodic_values[Self::P_ABSORB_FLAG.idx];
+        let hash_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let elements = pack(next.id_slice(Self::T_PIXELS), PIXELS_PER_ELEM);
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], &elements, current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, absorb_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_flag);
+        if Self::COPY_HASH {
+            let copy_hash_flag = E::ONE - absorb_flag - hash_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let idx_result = Self::CYCLE_LENGTH * (self.input_length.as_u128() as usize) / Self::NUM_ELEMS_PER_CYCLE;
+        let idx_hash = if self.salted { idx_result + Self::CYCLE_LENGTH } else { idx_result };
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash.as_ref()[c]));
+        }
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        let mut absorb_mask = vec![B::ZERO; Self::CYCLE_LENGTH];
+        absorb_mask[0] = B::ONE;
+        periodic_columns.push(absorb_mask);
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        periodic_columns
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> TheProver<B, H, PIXELS_PER_ELEM> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>, const PIXELS_PER_ELEM: usize> Prover for TheProver<B, H, PIXELS_PER_ELEM> {
+    type BaseField = B;
+    type Air = TheAir<B, H, PIXELS_PER_ELEM>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted) = get_salted_meta(&trace.meta().to_vec());
+        let result_step = input_length * TheAir::<B, H, PIXELS_PER_ELEM>::CYCLE_LENGTH / TheAir::<B, H, PIXELS_PER_ELEM>::NUM_ELEMS_PER_CYCLE;
+        let hash_step = if salted { result_step + TheAir::<B, H, PIXELS_PER_ELEM>::CYCLE_LENGTH } else { result_step };
+        let mut hash = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash[c] = trace.get(TheAir::<B, H, PIXELS_PER_ELEM>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        PubInputs {
+            hash: H::Digest::try_from(&hash[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
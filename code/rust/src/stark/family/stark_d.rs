@@ -0,0 +1,489 @@
+use std::cmp::max;
+use std::marker::PhantomData;
+use winterfell::{Air, Assertion, AuxTraceRandElements, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Matrix, Prover, Serializable, Trace, TraceInfo, TransitionConstraintDegree};
+use winterfell::math::{ExtensionOf, FieldElement};
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{check_lookup_max_value, create_lookup_meta, get_lookup_meta, get_salt_pixels, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use crate::utils::rap_trace_table::{AuxTraceBuilder, RapTraceTable};
+use super::{get_cycle_length, get_hash_mask};
+use super::stark_a::{commit_packed, pack, NUM_BITS_PER_PIXEL};
+use super::stark_e::{helper_s, helper_t, helper_u};
+use super::stark_f::{check_input_length, enforce_copy};
+
+// STARK D: STARK C extended by a plookup range check of the pixels against the table
+// t = (0, 1, .., max_value). One pixel of a cycle is looked up per step, hence a cycle absorbs
+// CYCLE_LENGTH pixels, packed into the RATE elements of a block. The pixels are copied along the cycle.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+pub const AUX_TRACE_WIDTH: usize = 4;
+
+// for plookup: t contains the values 0, 1, .., max_value, where max_value is part of the public inputs
+pub const MAX_VALUE_STEP: usize = 1;
+
+// AET index definitions: auxiliary trace segment (depends on the random elements beta, gamma)
+#[allow(non_upper_case_globals)]
+const T_F_f: IndexDefinition = IndexDefinition { idx: 0, size: 1 };
+#[allow(non_upper_case_globals)]
+const T_F_t: IndexDefinition = IndexDefinition { idx: 1, size: 1 };
+const T_G: IndexDefinition = IndexDefinition { idx: 2, size: 1 };
+const T_R: IndexDefinition = IndexDefinition { idx: 3, size: 1 };
+
+// constraint index definitions: auxiliary trace segment
+const C_R: IndexDefinition = IndexDefinition {idx: 0, size: 1};
+const C_G_TRANSITION: IndexDefinition = IndexDefinition {idx: 1, size: 1};
+#[allow(non_upper_case_globals)]
+const C_F_t_TRANSITION: IndexDefinition = IndexDefinition {idx: 2, size: 1};
+#[allow(non_upper_case_globals)]
+const C_F_f_TRANSITION: IndexDefinition = IndexDefinition {idx: 3, size: 1};
+
+// indices of the random elements drawn by the verifier for the auxiliary trace segment
+const R_BETA: usize = 0;
+const R_GAMMA: usize = 1;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub hash: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+    pub max_value: H::BaseField,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    hash: H::Digest,
+    input_length: B,
+    salted: bool,
+    max_value: B,
+}
+
+#[allow(non_upper_case_globals)]
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    pub const CYCLE_LENGTH: usize = get_cycle_length(H::NUM_ROUNDS);
+    const NUM_PIXELS_PER_ELEM: usize = Self::CYCLE_LENGTH / H::RATE;
+
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < Self::CYCLE_LENGTH;
+
+    // AET index definitions: main trace segment
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: Self::CYCLE_LENGTH };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: Self::CYCLE_LENGTH, size: H::STATE_WIDTH };
+    const T_t: IndexDefinition = IndexDefinition { idx: Self::T_PIXELS_HASH.idx + H::STATE_WIDTH, size: 1 };
+    const T_s: IndexDefinition = IndexDefinition { idx: Self::T_t.idx + 1, size: 1 };
+    const T_f_f: IndexDefinition = IndexDefinition { idx: Self::T_s.idx + 1, size: 1 };
+    const T_f_t: IndexDefinition = IndexDefinition { idx: Self::T_f_f.idx + 1, size: 1 };
+    const T_f_s: IndexDefinition = IndexDefinition { idx: Self::T_f_t.idx + 1, size: 1 };
+    const T_FLAGS: IndexDefinition = IndexDefinition { idx: Self::T_f_f.idx, size: 3 };
+    pub const TRACE_WIDTH: usize = Self::T_f_s.idx + 1;
+
+    // constraint index definitions: main trace segment
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+    const C_COPY_PIXEL: IndexDefinition = IndexDefinition {idx: Self::C_COPY_HASH.idx + Self::C_COPY_HASH.size, size: Self::CYCLE_LENGTH};
+    const C_FLAG_DOMAIN: IndexDefinition = IndexDefinition {idx: Self::C_COPY_PIXEL.idx + Self::C_COPY_PIXEL.size, size: 3};
+    const C_FLAG_TRANSITION: IndexDefinition = IndexDefinition {idx: Self::C_FLAG_DOMAIN.idx + 3, size: 3};
+    const C_T_TRANSITION: IndexDefinition = IndexDefinition {idx: Self::C_FLAG_TRANSITION.idx + 3, size: 1};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_IDENTITY: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: Self::CYCLE_LENGTH };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + Self::CYCLE_LENGTH, size: 1 };
+
+    // number of pixels being absorbed in one absorption phase
+    pub const NUM_ELEMS_PER_CYCLE: usize = Self::CYCLE_LENGTH;
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>, max_value: usize) -> RapTraceTable<B, TheAuxTraceBuilder<H>> {
+        Self::build_salted_trace(pixels, None, max_value)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    /// All pixels must be in the lookup table t = (0, 1, .., max_value).
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>, max_value: usize) -> RapTraceTable<B, TheAuxTraceBuilder<H>> {
+        // The desired hash value is located at step i = (pixels.len() / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH
+        let num_pixels_per_elem = Self::NUM_PIXELS_PER_ELEM;
+        assert_eq!(Self::CYCLE_LENGTH, H::RATE * num_pixels_per_elem, "a block has to pack the pixels of one cycle");
+        assert!(NUM_BITS_PER_PIXEL * num_pixels_per_elem <= B::MODULUS_BITS as usize);
+        assert_eq!(0, pixels.len() % Self::NUM_ELEMS_PER_CYCLE, "the input has to fill whole blocks of {} pixels", Self::NUM_ELEMS_PER_CYCLE);
+        check_lookup_max_value(max_value, MAX_VALUE_STEP).unwrap();
+        assert!(pixels.iter().all(|p| *p as usize <= max_value), "pixel values must not exceed {}", max_value);
+        let size_of_t = max_value + 1;
+        let salt_pixels = get_salt_pixels(salt.map(|s| s.as_ref()), num_pixels_per_elem, NUM_BITS_PER_PIXEL);
+        let len_req_hash = next_power_of_two(((pixels.len() + salt_pixels.len()) / Self::NUM_ELEMS_PER_CYCLE) * Self::CYCLE_LENGTH + 1);
+        let size_f = pixels.len();
+        let size_s = size_of_t + size_f;
+        let len_req_plookup = next_power_of_two(size_s + 1); // make sure we include at least one step where f_s is zero to definitely have all possible combinations of transition constraints
+        let trace_len = max(len_req_hash, len_req_plookup);
+        let mut table = RapTraceTable::<B, TheAuxTraceBuilder<H>>::with_meta(Self::TRACE_WIDTH, trace_len, create_lookup_meta(pixels.len(), salt.is_some(), max_value));
+        let mut s = vec![0u64; size_s];
+        for i in 0..size_of_t {
+            s[i] = i as u64;
+        }
+        for i in 0..pixels.len() {
+            s[size_of_t + i] = pixels[i] as u64;
+        }
+        s.sort();
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+                state[Self::T_f_f.begin()] = B::ONE;
+                state[Self::T_f_t.begin()] = B::ONE;
+                state[Self::T_f_s.begin()] = B::ONE;
+                state[Self::T_s.begin()] = B::from(s[0]);
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH;
+                // load pixels and do hashing
+                if cyclic_step == 0 {
+                    // read elements into trace
+                    for c in 0..Self::NUM_ELEMS_PER_CYCLE {
+                        let next_pixel_idx = Self::NUM_ELEMS_PER_CYCLE * step / Self::CYCLE_LENGTH + c;
+                        if next_pixel_idx < pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                        } else if next_pixel_idx < pixels.len() + salt_pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = salt_pixels[next_pixel_idx - pixels.len()];
+                        }
+                    }
+                    // absorb the packed elements into hash state
+                    let elements = pack::<B>(&state[Self::T_PIXELS.begin()..Self::T_PIXELS.end()], num_pixels_per_elem);
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += elements[c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                // plookup
+                if step + 1 < size_s {
+                    state[Self::T_s.begin()] = B::from(s[step + 1]);
+                }
+                state[Self::T_t.begin()] += B::ONE;
+                if step == pixels.len() {
+                    state[Self::T_f_f.begin()] = B::ZERO;
+                }
+                if step == (size_of_t - 1) {
+                    state[Self::T_f_t.begin()] = B::ZERO;
+                }
+                if step == (size_of_t + pixels.len() - 1) {
+                    state[Self::T_f_s.begin()] = B::ZERO;
+                }
+            }
+        );
+        table
+    }
+
+    /// Native computation of the commitment to the pixels, which is salted if a salt is given.
+    pub fn commit(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> H::Digest {
+        commit_packed::<H>(pixels, salt, Self::NUM_PIXELS_PER_ELEM)
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        opening.verify_digest(pub_inputs.hash.as_ref(), pub_inputs.salted, pub_inputs.input_length.as_u128() as usize, |pixels, salt| {
+            let salt = salt.map(|s| H::Digest::try_from(s).unwrap());
+            Self::commit(pixels, salt.as_ref()).as_ref().to_vec()
+        })
+    }
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for elem in self.hash.as_ref() {
+            target.write(*elem);
+        }
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+        target.write(self.max_value);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut hash = vec![H::BaseField::ZERO; H::RATE];
+        for elem in hash.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let hash = H::Digest::try_from(&hash[..]).unwrap();
+        let input_length = H::BaseField::read_from(source)?;
+        let salted = read_bool(source)?;
+        let max_value = H::BaseField::read_from(source)?;
+        check_lookup_max_value(usize::try_from(max_value.as_u128()).unwrap_or(usize::MAX), MAX_VALUE_STEP).map_err(DeserializationError::InvalidValue)?;
+        check_input_length(input_length.as_u128()).map_err(DeserializationError::InvalidValue)?;
+        let cycle_length = get_cycle_length(H::NUM_ROUNDS);
+        if input_length.as_u128() % cycle_length as u128 != 0 {
+            return Err(DeserializationError::InvalidValue(format!("input length {} is not a multiple of {}", input_length.as_u128(), cycle_length)));
+        }
+        Ok(PubInputs { hash, input_length, salted, max_value })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees = vec![];
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY_PIXEL.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        // flag domain, flag transition and t transition
+        for _ in 0..(Self::C_FLAG_DOMAIN.size + Self::C_FLAG_TRANSITION.size + Self::C_T_TRANSITION.size) {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        let aux_degrees = vec![
+            // R
+            TransitionConstraintDegree::new(2),
+            // G
+            TransitionConstraintDegree::new(3),
+            // F_t
+            TransitionConstraintDegree::new(3),
+            // F_f
+            TransitionConstraintDegree::with_cycles(3, vec![Self::CYCLE_LENGTH]),
+        ];
+        let num_assertions = H::STATE_WIDTH + H::RATE + 10;
+        let num_aux_assertions = 4;
+        TheAir {
+            context: AirContext::new_multi_segment(trace_info, degrees, aux_degrees, num_assertions, num_aux_assertions, options),
+            hash: pub_inputs.hash,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted,
+            max_value: pub_inputs.max_value,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let absorb_flag = periodic_values.id_slice(Self::P_IDENTITY)[0];
+        let hash_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let elements = pack(next.id_slice(Self::T_PIXELS), Self::NUM_PIXELS_PER_ELEM);
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], &elements, current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, absorb_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_flag);
+        if Self::COPY_HASH {
+            let copy_hash_flag = E::ONE - absorb_flag - hash_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag);
+        }
+        // the pixels read at the beginning of a cycle are kept for the lookups of the following steps
+        enforce_copy(&mut result[Self::C_COPY_PIXEL.begin()..Self::C_COPY_PIXEL.end()], current.id_slice(Self::T_PIXELS), next.id_slice(Self::T_PIXELS), E::ONE - absorb_flag);
+        self.enforce_flags(result, current, next);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E]
+    ) where F: FieldElement<BaseField=Self::BaseField>, E: FieldElement<BaseField=Self::BaseField> + ExtensionOf<F> {
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+        self.enforce_plookup(result, periodic_values, main_frame.current(), main_frame.next(), aux_frame.current(), aux_frame.next(), rand_elements[R_BETA], rand_elements[R_GAMMA]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let idx_result = Self::CYCLE_LENGTH * (self.input_length.as_u128() as usize) / Self::NUM_ELEMS_PER_CYCLE;
+        let idx_hash = if self.salted { idx_result + Self::CYCLE_LENGTH } else { idx_result };
+        let mut result = vec![];
+
+        // hash IV must all be 0
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+
+        // computed hash must match the hash from public inputs
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash.as_ref()[c]));
+        }
+
+        // plookup
+        let size_f = self.input_length.as_u128() as usize;
+        let size_t = self.max_value.as_u128() as usize + 1;
+        let size_s = size_f + size_t;
+        result.push(Assertion::single(Self::T_f_f.begin(), 0, B::ONE));
+        result.push(Assertion::single(Self::T_f_t.begin(), 0, B::ONE));
+        result.push(Assertion::single(Self::T_f_s.begin(), 0, B::ONE));
+        result.push(Assertion::single(Self::T_t.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_f_f.begin(), size_f, B::ONE));
+        result.push(Assertion::single(Self::T_f_t.begin(), size_t - 1, B::ONE));
+        result.push(Assertion::single(Self::T_f_s.begin(), size_s - 1, B::ONE));
+        result.push(Assertion::single(Self::T_f_f.begin(), size_f + 1, B::ZERO));
+        result.push(Assertion::single(Self::T_f_t.begin(), size_t, B::ZERO));
+        result.push(Assertion::single(Self::T_f_s.begin(), size_s, B::ZERO));
+
+        result
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxTraceRandElements<E>
+    ) -> Vec<Assertion<E>> {
+        let size_s = self.input_length.as_u128() as usize + self.max_value.as_u128() as usize + 1;
+        let mut result = vec![];
+
+        // plookup
+        result.push(Assertion::single(T_F_f.begin(), 0, E::ONE));
+        result.push(Assertion::single(T_F_t.begin(), 0, E::ONE));
+        result.push(Assertion::single(T_G.begin(), 0, E::ONE));
+        result.push(Assertion::single(T_R.begin(), size_s - 1, E::ZERO));
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        // identity[i] = 1 in the step i of a cycle, which looks up the i-th pixel
+        for i in 0..Self::CYCLE_LENGTH {
+            let mut mask = vec![B::ZERO; Self::CYCLE_LENGTH];
+            mask[i] = B::ONE;
+            periodic_columns.push(mask);
+        }
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        periodic_columns
+    }
+}
+
+#[allow(non_upper_case_globals)]
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    fn enforce_flags<E: FieldElement>(
+        &self,
+        result_slice: &mut [E],
+        current: &[E],
+        next: &[E],
+    ) {
+        // flags: functions U and T
+        for i in 0..Self::C_FLAG_DOMAIN.size {
+            result_slice[Self::C_FLAG_DOMAIN.begin() + i] += helper_u(next[Self::T_FLAGS.begin() + i]);
+        }
+        for i in 0..Self::C_FLAG_TRANSITION.size {
+            result_slice[Self::C_FLAG_TRANSITION.begin() + i] += helper_t(current[Self::T_FLAGS.begin() + i], next[Self::T_FLAGS.begin() + i]);
+        }
+        // transition of t
+        result_slice[Self::C_T_TRANSITION.begin()] += (next[Self::T_t.begin()] - current[Self::T_t.begin()] - E::ONE) * next[Self::T_f_t.begin()];
+    }
+
+    fn enforce_plookup<F, E>(
+        &self,
+        result_slice: &mut [E],
+        periodic_values: &[F],
+        main_current: &[F],
+        main_next: &[F],
+        current: &[E],
+        next: &[E],
+        beta: E,
+        gamma: E,
+    ) where F: FieldElement, E: FieldElement + ExtensionOf<F> {
+        // calculation of R
+        result_slice[C_R.begin()] += next[T_F_f.begin()] * next[T_F_t.begin()] - next[T_G.begin()] - next[T_R.begin()];
+        // calculation of G
+        result_slice[C_G_TRANSITION.begin()] += helper_s(gamma * (E::ONE + beta) + E::from(main_current[Self::T_s.begin()]) + beta * E::from(main_next[Self::T_s.begin()]), E::from(main_next[Self::T_f_s.begin()])) * current[T_G.begin()] - next[T_G.begin()];
+        // calculation of F_t
+        result_slice[C_F_t_TRANSITION.begin()] += helper_s(gamma * (E::ONE + beta) + E::from(main_current[Self::T_t.begin()]) + beta * E::from(main_next[Self::T_t.begin()]), E::from(main_next[Self::T_f_t.begin()])) * current[T_F_t.begin()] - next[T_F_t.begin()];
+        // calculation of F_f
+        #[allow(non_snake_case)]
+        let mut s_sum_F_f = E::ZERO;
+        for i in 0..Self::CYCLE_LENGTH {
+            s_sum_F_f += E::from(periodic_values[Self::P_IDENTITY.begin() + i]) * helper_s((E::ONE + beta) * (gamma + E::from(main_next[Self::T_PIXELS.begin() + i])), E::from(main_next[Self::T_f_f.begin()])) * current[T_F_f.begin()];
+        }
+        result_slice[C_F_f_TRANSITION.begin()] += s_sum_F_f - next[T_F_f.begin()];
+    }
+}
+
+pub struct TheAuxTraceBuilder<H: HashPermutation> {
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> AuxTraceBuilder<B> for TheAuxTraceBuilder<H> {
+    const WIDTH: usize = AUX_TRACE_WIDTH;
+    const NUM_RAND_ELEMENTS: usize = 2;
+
+    // fills the columns dependent on the randomness: F_f, F_t, G, R
+    fn build_aux_columns<E: FieldElement<BaseField=B>>(main: &Matrix<B>, _meta: &[u8], rand_elements: &[E]) -> Vec<Vec<E>> {
+        let beta = rand_elements[R_BETA];
+        let gamma = rand_elements[R_GAMMA];
+        let length = main.num_rows();
+        let mut columns = vec![vec![E::ZERO; length]; AUX_TRACE_WIDTH];
+        columns[T_F_f.begin()][0] = E::ONE;
+        columns[T_F_t.begin()][0] = E::ONE;
+        columns[T_G.begin()][0] = E::ONE;
+        for step in 0..(length - 1) {
+            let cyclic_step = step % TheAir::<B, H>::CYCLE_LENGTH;
+            let current_idx = step;
+            let next_idx = step + 1;
+
+            let next_pixel = E::from(main.get(TheAir::<B, H>::T_PIXELS.begin() + cyclic_step, next_idx));
+            let t_current = E::from(main.get(TheAir::<B, H>::T_t.begin(), current_idx));
+            let t_next = E::from(main.get(TheAir::<B, H>::T_t.begin(), next_idx));
+            let s_current = E::from(main.get(TheAir::<B, H>::T_s.begin(), current_idx));
+            let s_next = E::from(main.get(TheAir::<B, H>::T_s.begin(), next_idx));
+            let next_f_f = columns[T_F_f.begin()][current_idx] * helper_s((E::ONE + beta) * (gamma + next_pixel), E::from(main.get(TheAir::<B, H>::T_f_f.begin(), next_idx)));
+            let next_f_t = columns[T_F_t.begin()][current_idx] * helper_s(gamma * (E::ONE + beta) + t_current + beta * t_next, E::from(main.get(TheAir::<B, H>::T_f_t.begin(), next_idx)));
+            let next_g = columns[T_G.begin()][current_idx] * helper_s(gamma * (E::ONE + beta) + s_current + beta * s_next, E::from(main.get(TheAir::<B, H>::T_f_s.begin(), next_idx)));
+            columns[T_F_f.begin()][next_idx] = next_f_f;
+            columns[T_F_t.begin()][next_idx] = next_f_t;
+            columns[T_G.begin()][next_idx] = next_g;
+            columns[T_R.begin()][next_idx] = next_f_f * next_f_t - next_g;
+        }
+        columns
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = RapTraceTable<Self::BaseField, TheAuxTraceBuilder<H>>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted, max_value) = get_lookup_meta(&trace.meta().to_vec());
+        let result_step = input_length * TheAir::<B, H>::CYCLE_LENGTH / TheAir::<B, H>::NUM_ELEMS_PER_CYCLE;
+        let hash_step = if salted { result_step + TheAir::<B, H>::CYCLE_LENGTH } else { result_step };
+        let mut hash = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        PubInputs {
+            hash: H::Digest::try_from(&hash[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted,
+            max_value: B::from(max_value as u64),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,877 @@
+use std::marker::PhantomData;
+use winterfell::{Air, Assertion, AuxTraceRandElements, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Matrix, Prover, Serializable, Trace, TraceInfo, TransitionConstraintDegree};
+use winterfell::math::{ExtensionOf, FieldElement};
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use rounded_div::RoundedDiv;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::utils::range_check::{apply_range_check, enforce_range_check, get_num_bits, get_rounded_div_remainders, get_rounded_div_remainders_e, NUM_REMAINDERS};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{check_lookup_max_value, create_lookup_meta, get_lookup_meta, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use crate::utils::rap_trace_table::{AuxTraceBuilder, RapTraceTable};
+use super::{get_hash_mask, get_identity_masks, NUM_IDENTITY_MASKS};
+
+// STARK E (opt): STARK E with the sorted lookup values s packed five per step, which shortens the trace
+// to about one step per pixel. The same restrictions on H as for STARK E apply (RATE = 8, at most 8
+// rounds), the columns of the hash state come last.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+pub const CYCLE_LENGTH: usize = 8;
+pub const AUX_TRACE_WIDTH: usize = 8;
+
+// for plookup: t contains the values 0, 1, .., max_value, where max_value is part of the public inputs.
+// max_value must be a multiple of MAX_VALUE_STEP (see utils::get_lookup_max_value).
+pub const MAX_VALUE_STEP: usize = 5 * CYCLE_LENGTH;
+
+// AET index definitions: main trace segment (the hash state follows, see TheAir::T_PIXELS_HASH)
+pub const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 };
+pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8, size: 1 };
+pub const T_VAR: IndexDefinition = IndexDefinition { idx: 9, size: 1 };
+pub const T_MIN: IndexDefinition = IndexDefinition { idx: 10, size: 1 };
+pub const T_MAX: IndexDefinition = IndexDefinition { idx: 11, size: 1 };
+pub const T_OMEGA_L: IndexDefinition = IndexDefinition { idx: 12, size: 1 };
+pub const T_OMEGA_H: IndexDefinition = IndexDefinition { idx: 13, size: 1 };
+pub const T_F_L: IndexDefinition = IndexDefinition { idx: 14, size: 1 };
+pub const T_F_H: IndexDefinition = IndexDefinition { idx: 15, size: 1 };
+pub const T_MED: IndexDefinition = IndexDefinition { idx: 16, size: 1 };
+pub const T_OMEGA_M: IndexDefinition = IndexDefinition { idx: 17, size: 1 };
+pub const T_S: IndexDefinition = IndexDefinition { idx: 18, size: 5 };
+pub const T_F_F: IndexDefinition = IndexDefinition { idx: 23, size: 1 };
+pub const T_F_S: IndexDefinition = IndexDefinition { idx: 24, size: 1 };
+pub const T_AVG_REM: IndexDefinition = IndexDefinition { idx: T_F_S.idx + T_F_S.size, size: NUM_REMAINDERS };
+
+// AET index definitions: auxiliary trace segment (depends on the random elements beta, gamma, lambda)
+pub const T_Z: IndexDefinition = IndexDefinition { idx: 0, size: 1 };
+pub const T_F: IndexDefinition = IndexDefinition { idx: 1, size: 3 };
+pub const T_G: IndexDefinition = IndexDefinition { idx: 4, size: 3 };
+pub const T_R: IndexDefinition = IndexDefinition { idx: 7, size: 1 };
+
+// constraint index definitions: main trace segment (the hash constraints follow, see TheAir)
+const C_PIXEL_COPY: IndexDefinition = IndexDefinition {idx: 0, size: 8};
+const C_SUM: IndexDefinition = IndexDefinition {idx: C_PIXEL_COPY.idx + C_PIXEL_COPY.size, size: 1};
+const C_VAR: IndexDefinition = IndexDefinition {idx: C_SUM.idx + C_SUM.size, size: 1};
+const C_MIN: IndexDefinition = IndexDefinition {idx: C_VAR.idx + C_VAR.size, size: 1};
+const C_MAX: IndexDefinition = IndexDefinition {idx: C_MIN.idx + C_MIN.size, size: 1};
+const C_OMEGA_L: IndexDefinition = IndexDefinition {idx: C_MAX.idx + C_MAX.size, size: 1};
+const C_OMEGA_H: IndexDefinition = IndexDefinition {idx: C_OMEGA_L.idx + C_OMEGA_L.size, size: 1};
+const C_F_L_U: IndexDefinition = IndexDefinition {idx: C_OMEGA_H.idx + C_OMEGA_H.size, size: 1};
+const C_F_H_U: IndexDefinition = IndexDefinition {idx: C_F_L_U.idx + C_F_L_U.size, size: 1};
+const C_OMEGA_M: IndexDefinition = IndexDefinition {idx: C_F_H_U.idx + C_F_H_U.size, size: 1};
+const C_F_F_U: IndexDefinition = IndexDefinition {idx: C_OMEGA_M.idx + C_OMEGA_M.size, size: 1};
+const C_F_F_T: IndexDefinition = IndexDefinition {idx: C_F_F_U.idx + C_F_F_U.size, size: 1};
+const C_F_S_U: IndexDefinition = IndexDefinition {idx: C_F_F_T.idx + C_F_F_T.size, size: 1};
+const C_F_S_T: IndexDefinition = IndexDefinition {idx: C_F_S_U.idx + C_F_S_U.size, size: 1};
+const C_AVG_REM: IndexDefinition = IndexDefinition {idx: C_F_S_T.idx + C_F_S_T.size, size: NUM_REMAINDERS};
+
+// constraint index definitions: auxiliary trace segment
+const C_Z: IndexDefinition = IndexDefinition {idx: 0, size: 1};
+const C_F: IndexDefinition = IndexDefinition {idx: C_Z.idx + C_Z.size, size: 3};
+const C_G: IndexDefinition = IndexDefinition {idx: C_F.idx + C_F.size, size: 3};
+const C_R: IndexDefinition = IndexDefinition {idx: C_G.idx + C_G.size, size: 1};
+
+// periodic column index definitions (the round constants follow)
+const P_IDENTITY: IndexDefinition = IndexDefinition { idx: 0, size: NUM_IDENTITY_MASKS };
+const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: NUM_IDENTITY_MASKS, size: 1 };
+const P_ROUND_CONSTANTS_IDX: usize = NUM_IDENTITY_MASKS + 1;
+
+// indices of the random elements drawn by the verifier for the auxiliary trace segment
+const R_BETA: usize = 0;
+const R_GAMMA: usize = 1;
+const R_LAMBDA: usize = 2;
+
+// number of elements being absorbed in one absorption phase (one pixel per element)
+pub const NUM_ELEMS_PER_CYCLE: usize = 8;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub hash_pixels: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+    pub max_value: H::BaseField,
+    pub sum: H::BaseField,
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+    pub min: H::BaseField,
+    pub max: H::BaseField,
+    pub med_low: H::BaseField,
+    pub med_high: H::BaseField,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    hash_pixels: H::Digest,
+    input_length: B,
+    salted: bool,
+    max_value: B,
+    sum: B,
+    avg_rounded: B,
+    variance: B,
+    min: B,
+    max: B,
+    med_low: B,
+    med_high: B,
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for elem in self.hash_pixels.as_ref() {
+            target.write(*elem);
+        }
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+        target.write(self.max_value);
+        target.write(self.sum);
+        target.write(self.avg_rounded);
+        target.write(self.variance);
+        target.write(self.min);
+        target.write(self.max);
+        target.write(self.med_low);
+        target.write(self.med_high);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut hash_pixels = vec![H::BaseField::ZERO; H::RATE];
+        for elem in hash_pixels.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let hash_pixels = H::Digest::try_from(&hash_pixels[..]).unwrap();
+        let input_length = H::BaseField::read_from(source)?;
+        let salted = read_bool(source)?;
+        let max_value = H::BaseField::read_from(source)?;
+        let sum = H::BaseField::read_from(source)?;
+        let avg_rounded = H::BaseField::read_from(source)?;
+        let variance = H::BaseField::read_from(source)?;
+        let min = H::BaseField::read_from(source)?;
+        let max = H::BaseField::read_from(source)?;
+        let med_low = H::BaseField::read_from(source)?;
+        let med_high = H::BaseField::read_from(source)?;
+        check_lookup_max_value(usize::try_from(max_value.as_u128()).unwrap_or(usize::MAX), MAX_VALUE_STEP).map_err(DeserializationError::InvalidValue)?;
+        let num = usize::try_from(input_length.as_u128()).unwrap_or(usize::MAX);
+        if num == 0 || num % (5 * CYCLE_LENGTH) != 0 {
+            return Err(DeserializationError::InvalidValue(format!("input length {} is not a positive multiple of {}", input_length.as_u128(), 5 * CYCLE_LENGTH)));
+        }
+        Ok(PubInputs { hash_pixels, input_length, salted, max_value, sum, avg_rounded, variance, min, max, med_low, med_high })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < CYCLE_LENGTH;
+
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: T_AVG_REM.idx + T_AVG_REM.size, size: H::STATE_WIDTH };
+    pub const TRACE_WIDTH: usize = Self::T_PIXELS_HASH.idx + Self::T_PIXELS_HASH.size;
+
+    const C_PIXEL_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: C_AVG_REM.idx + C_AVG_REM.size, size: H::STATE_WIDTH};
+    const C_PIXEL_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: Self::C_PIXEL_ROUND_FIRST.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_PIXEL_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_PIXEL_ROUND_REMAINING.idx + H::STATE_WIDTH, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: P_ROUND_CONSTANTS_IDX, size: H::NUM_PERIODIC_COLUMNS };
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>, max_value: usize) -> RapTraceTable<B, TheAuxTraceBuilder> {
+        Self::build_salted_trace(pixels, None, max_value)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    /// All pixels must be in the lookup table t = (0, 1, .., max_value).
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>, max_value: usize) -> RapTraceTable<B, TheAuxTraceBuilder> {
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+        assert_eq!(0, pixels.len() % (5 * CYCLE_LENGTH));
+        check_lookup_max_value(max_value, MAX_VALUE_STEP).unwrap();
+        assert!(pixels.len() >= max_value);
+        assert!(pixels.iter().all(|p| *p as usize <= max_value), "pixel values must not exceed {}", max_value);
+        let size_of_t = max_value + 1;
+        let salt_pixels = salt.map(|s| s.as_ref().to_vec()).unwrap_or_default();
+        let trace_len = next_power_of_two(pixels.len() + salt_pixels.len() + 2);
+        let sum = pixels.iter().map(|e| {*e as u128}).sum::<u128>();
+        let avg = B::from(sum.rounded_div(pixels.len() as u128));
+        let num_bits = get_num_bits(pixels.len() as u128);
+        let avg_remainders = get_rounded_div_remainders(sum, pixels.len() as u128);
+        let mut pixels_sorted = pixels.clone();
+        pixels_sorted.sort();
+        let mut omega_l = vec![0u16; pixels.len()];
+        let mut omega_h = vec![0u16; pixels.len()];
+        let mut omega_m = vec![0u16; pixels.len()];
+        let mut min = max_value as u16;
+        let mut max = 0u16;
+        for step in 0..pixels.len() {
+            // med
+            if step == 0 {
+                omega_m[step] = pixels_sorted[step];
+            } else {
+                omega_m[step] = pixels_sorted[step] - pixels_sorted[step - 1];
+            }
+            // min
+            if pixels[step] < min {
+                omega_l[step] = min - pixels[step];
+                min = pixels[step];
+            } else {
+                omega_l[step] = pixels[step] - min;
+            }
+            // max
+            if pixels[step] > max {
+                omega_h[step] = pixels[step] - max;
+                max = pixels[step];
+            } else {
+                omega_h[step] = max - pixels[step];
+            }
+        }
+        let mut s: Vec<u16> = vec![];
+        s.append(&mut pixels.clone());
+        s.append(&mut omega_l.clone());
+        s.append(&mut omega_h.clone());
+        s.append(&mut omega_m.clone());
+        for t in 1..size_of_t {
+            s.push(t as u16);
+        }
+        s.sort();
+        let mut table = RapTraceTable::<B, TheAuxTraceBuilder>::with_meta(Self::TRACE_WIDTH, trace_len, create_lookup_meta(pixels.len(), salt.is_some(), max_value));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+                state[T_MIN.begin()] = B::from(max_value as u64);
+                state[T_F_F.begin()] = B::ONE;
+                state[T_F_S.begin()] = B::ONE;
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % CYCLE_LENGTH; // cyclic_step can be considered the index in the masks
+                if cyclic_step == 0 {
+                    // read elements into trace
+                    for c in 0..NUM_ELEMS_PER_CYCLE {
+                        let next_pixel_idx = NUM_ELEMS_PER_CYCLE * step / CYCLE_LENGTH + c;
+                        if next_pixel_idx < pixels.len() {
+                            state[T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                        } else if next_pixel_idx < pixels.len() + salt_pixels.len() {
+                            state[T_PIXELS.idx + c] = salt_pixels[next_pixel_idx - pixels.len()];
+                        }
+                    }
+                    // absorb elements into hash state
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += state[T_PIXELS.idx + c];
+                    }
+                }
+                // values which are only part of the lookup arguments: one value per step, no hashing
+                if step < pixels.len() {
+                    state[T_OMEGA_L.begin()] = B::from(omega_l[step]);
+                    state[T_OMEGA_H.begin()] = B::from(omega_h[step]);
+                    state[T_MED.begin()] = B::from(pixels_sorted[step]);
+                    state[T_OMEGA_M.begin()] = B::from(omega_m[step]);
+                }
+                for c in 0..T_S.size {
+                    if T_S.size * step + c < s.len() {
+                        state[T_S.begin() + c] = B::from(s[T_S.size * step + c]);
+                    }
+                }
+                // transition of flags f_f and f_s
+                if step == (size_of_t + 4 * pixels.len() - 1) / 5 {
+                    state[T_F_S.begin()] = B::ZERO;
+                }
+                if step == pixels.len() {
+                    state[T_F_F.begin()] = B::ZERO;
+                }
+                // hashing
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                // simple stats: sum, var
+                let pixel = state[T_PIXELS.begin() + cyclic_step];
+                state[T_SUM.begin()] += pixel;
+                state[T_VAR.begin()] += (pixel - avg) * (pixel - avg);
+                // min, max, and f_l, f_h
+                if pixel.as_u128() < state[T_MIN.begin()].as_u128() {
+                    state[T_F_L.begin()] = B::ZERO;
+                    state[T_MIN.begin()] = pixel;
+                } else {
+                    state[T_F_L.begin()] = B::ONE;
+                }
+                if pixel.as_u128() > state[T_MAX.begin()].as_u128() {
+                    state[T_F_H.begin()] = B::ONE;
+                    state[T_MAX.begin()] = pixel;
+                } else {
+                    state[T_F_H.begin()] = B::ZERO;
+                }
+                // remainders of avg_rounded
+                apply_range_check(&mut state[T_AVG_REM.begin()..T_AVG_REM.end()], &avg_remainders, num_bits, step);
+            }
+        );
+        table
+    }
+
+    /// Native computation of the commitment to the pixels, which is salted if a salt is given.
+    pub fn commit(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> H::Digest {
+        let elements = pixels.iter().map(|pixel| B::from(*pixel)).collect();
+        match salt {
+            Some(salt) => H::hash_salted(&elements, salt),
+            None => H::hash(&elements),
+        }
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        opening.verify_digest(pub_inputs.hash_pixels.as_ref(), pub_inputs.salted, pub_inputs.input_length.as_u128() as usize, |pixels, salt| {
+            let salt = salt.map(|s| H::Digest::try_from(s).unwrap());
+            Self::commit(pixels, salt.as_ref()).as_ref().to_vec()
+        })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+
+        let mut degrees = vec![];
+
+        for _ in 0..C_PIXEL_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]));
+        }
+        // C_SUM
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![CYCLE_LENGTH]));
+        // C_VAR
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_MIN
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_MAX
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_OMEGA_L
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_OMEGA_H
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_F_L_U
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_F_H_U
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_OMEGA_M
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_F_F_U
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_F_F_T
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_F_S_U
+        degrees.push(TransitionConstraintDegree::new(2));
+        // C_F_S_T
+        degrees.push(TransitionConstraintDegree::new(2));
+        for _ in 0..C_AVG_REM.size {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        for _ in 0..Self::C_PIXEL_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_PIXEL_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_PIXEL_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]));
+        }
+
+        let mut aux_degrees = vec![];
+        // C_Z
+        aux_degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_F
+        aux_degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        aux_degrees.push(TransitionConstraintDegree::new(2));
+        // C_G
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        // C_R
+        aux_degrees.push(TransitionConstraintDegree::new(1));
+
+        let num_assertions = H::STATE_WIDTH + H::RATE + 16 + 2 * NUM_REMAINDERS;
+        let num_aux_assertions = 5;
+
+        TheAir {
+            context: AirContext::new_multi_segment(trace_info, degrees, aux_degrees, num_assertions, num_aux_assertions, options),
+            hash_pixels: pub_inputs.hash_pixels,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted,
+            max_value: pub_inputs.max_value,
+            sum: pub_inputs.sum,
+            avg_rounded: pub_inputs.avg_rounded,
+            variance: pub_inputs.variance,
+            min: pub_inputs.min,
+            max: pub_inputs.max,
+            med_low: pub_inputs.med_low,
+            med_high: pub_inputs.med_high,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(P_IDENTITY);
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+
+        H::enforce_first_round(&mut result[Self::C_PIXEL_ROUND_FIRST.begin()..Self::C_PIXEL_ROUND_FIRST.end()], next.id_slice(T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag);
+        H::enforce_round(&mut result[Self::C_PIXEL_ROUND_REMAINING.begin()..Self::C_PIXEL_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag);
+        if Self::COPY_HASH {
+            let copy_hash_flag = copy_flag - hash_remaining_flag;
+            enforce_copy(&mut result[Self::C_PIXEL_COPY_HASH.begin()..Self::C_PIXEL_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag);
+        }
+        enforce_copy(&mut result[C_PIXEL_COPY.begin()..C_PIXEL_COPY.end()], current.id_slice(T_PIXELS), next.id_slice(T_PIXELS), copy_flag);
+
+        enforce_sum(&mut result[C_SUM.begin()..C_SUM.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_SUM)[0], next.id_slice(T_SUM)[0], next.id_slice(T_F_F)[0]);
+        enforce_var(&mut result[C_VAR.begin()..C_VAR.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_VAR)[0], next.id_slice(T_VAR)[0], E::from(self.avg_rounded), next.id_slice(T_F_F)[0]);
+
+        result[C_F_L_U.begin()] += helper_u(next.id_slice(T_F_L)[0]);
+        result[C_F_H_U.begin()] += helper_u(next.id_slice(T_F_H)[0]);
+
+        enforce_min(&mut result[C_MIN.begin()..C_MIN.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_MIN)[0], next.id_slice(T_MIN)[0], next.id_slice(T_F_L)[0], next.id_slice(T_F_F)[0]);
+        enforce_max(&mut result[C_MAX.begin()..C_MAX.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_MAX)[0], next.id_slice(T_MAX)[0], next.id_slice(T_F_H)[0], next.id_slice(T_F_F)[0]);
+
+        enforce_omega_l(&mut result[C_OMEGA_L.begin()..C_OMEGA_L.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_MIN)[0], next.id_slice(T_OMEGA_L)[0], next.id_slice(T_F_L)[0], next.id_slice(T_F_F)[0]);
+        enforce_omega_h(&mut result[C_OMEGA_H.begin()..C_OMEGA_H.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_MAX)[0], next.id_slice(T_OMEGA_H)[0], next.id_slice(T_F_H)[0], next.id_slice(T_F_F)[0]);
+        enforce_omega_m(&mut result[C_OMEGA_M.begin()..C_OMEGA_M.end()], current.id_slice(T_MED)[0], next.id_slice(T_MED)[0], next.id_slice(T_OMEGA_M)[0], next.id_slice(T_F_F)[0]);
+
+        result[C_F_F_U.begin()] += helper_u(next.id_slice(T_F_F)[0]);
+        result[C_F_S_U.begin()] += helper_u(next.id_slice(T_F_S)[0]);
+        result[C_F_F_T.begin()] += helper_t(current.id_slice(T_F_F)[0], next.id_slice(T_F_F)[0]);
+        result[C_F_S_T.begin()] += helper_t(current.id_slice(T_F_S)[0], next.id_slice(T_F_S)[0]);
+        enforce_range_check(&mut result[C_AVG_REM.begin()..C_AVG_REM.end()], current.id_slice(T_AVG_REM), next.id_slice(T_AVG_REM));
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E]
+    ) where F: FieldElement<BaseField=Self::BaseField>, E: FieldElement<BaseField=Self::BaseField> + ExtensionOf<F> {
+        let main_current = main_frame.current();
+        let main_next = main_frame.next();
+        let current = aux_frame.current();
+        let next = aux_frame.next();
+        let identity = periodic_values.id_slice(P_IDENTITY);
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+        let beta = rand_elements[R_BETA];
+        let gamma = rand_elements[R_GAMMA];
+        let lambda = rand_elements[R_LAMBDA];
+
+        let mut next_pixel = F::ZERO;
+        for i in 0..T_PIXELS.size {
+            next_pixel += identity[i % CYCLE_LENGTH] * main_next.id_slice(T_PIXELS)[i];
+        }
+        let next_pixel = E::from(next_pixel);
+        let next_f_f = E::from(main_next.id_slice(T_F_F)[0]);
+
+        enforce_z(&mut result[C_Z.begin()..C_Z.end()], next_pixel, E::from(main_next.id_slice(T_MED)[0]), current.id_slice(T_Z)[0], next.id_slice(T_Z)[0], lambda, next_f_f);
+        let mut next_s = [E::ZERO; 5];
+        for i in 0..T_S.size {
+            next_s[i] = E::from(main_next.id_slice(T_S)[i]);
+        }
+
+        enforce_f(&mut result[C_F.begin()..C_F.end()], beta, gamma, next_pixel, E::from(main_next.id_slice(T_OMEGA_L)[0]), E::from(main_next.id_slice(T_OMEGA_H)[0]), E::from(main_next.id_slice(T_OMEGA_M)[0]), current.id_slice(T_F), next.id_slice(T_F), next_f_f);
+        enforce_g(&mut result[C_G.begin()..C_G.end()], beta, gamma, E::from(main_current.id_slice(T_S)[4]), &next_s, current.id_slice(T_G), next.id_slice(T_G), E::from(main_next.id_slice(T_F_S)[0]));
+
+        result[C_R.begin()] += next.id_slice(T_F)[2] - next.id_slice(T_G)[2] - next.id_slice(T_R)[0];
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let input_length = self.input_length.as_u128() as usize;
+        let idx_result = CYCLE_LENGTH * input_length / NUM_ELEMS_PER_CYCLE;
+        let idx_hash = if self.salted { idx_result + CYCLE_LENGTH } else { idx_result };
+        let idx_result_s = (4 * input_length + self.max_value.as_u128() as usize) / 5;
+
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash_pixels.as_ref()[c]));
+        }
+        result.push(Assertion::single(T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_VAR.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_SUM.begin(), idx_result, self.sum));
+        result.push(Assertion::single(T_VAR.begin(), idx_result, self.variance));
+
+        result.push(Assertion::single(T_MIN.begin(), 0, self.max_value));
+        result.push(Assertion::single(T_MAX.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_MIN.begin(), idx_result, self.min));
+        result.push(Assertion::single(T_MAX.begin(), idx_result, self.max));
+
+        result.push(Assertion::single(T_MED.begin(), 0, B::ZERO));
+
+        result.push(Assertion::single(T_MED.begin(), idx_result / 2, self.med_low));
+        result.push(Assertion::single(T_MED.begin(), idx_result / 2 + 1, self.med_high));
+
+        result.push(Assertion::single(T_S.end() - 1, 0, B::ZERO));
+
+        result.push(Assertion::single(T_F_F.begin(), idx_result, B::ONE)); // implies that it is always 1 before
+        result.push(Assertion::single(T_F_F.begin(), idx_result + 1, B::ZERO));
+
+        result.push(Assertion::single(T_F_S.begin(), idx_result_s, B::ONE)); // implies that it is always 1 before
+        result.push(Assertion::single(T_F_S.begin(), idx_result_s + 1, B::ZERO));
+
+        // avg_rounded = round(sum / input_length), see utils::range_check
+        let num_bits = get_num_bits(self.input_length.as_u128());
+        let avg_remainders = get_rounded_div_remainders_e(self.sum, self.input_length, self.avg_rounded);
+        for c in 0..T_AVG_REM.size {
+            result.push(Assertion::single(T_AVG_REM.begin() + c, 0, B::ZERO));
+            result.push(Assertion::single(T_AVG_REM.begin() + c, num_bits, avg_remainders[c]));
+        }
+
+        result
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>
+    ) -> Vec<Assertion<E>> {
+        let input_length = self.input_length.as_u128() as usize;
+        let idx_result = CYCLE_LENGTH * input_length / NUM_ELEMS_PER_CYCLE;
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+
+        let mut result = vec![];
+
+        result.push(Assertion::single(T_Z.begin(), 0, E::ONE));
+        result.push(Assertion::single(T_Z.begin(), idx_result, E::ONE));
+
+        result.push(Assertion::single(T_F.end() - 1, 0, get_f_t(rand_elements[R_BETA], rand_elements[R_GAMMA], self.max_value.as_u128() as usize)));
+        result.push(Assertion::single(T_G.end() - 1, 0, E::ONE));
+        result.push(Assertion::single(T_R.begin(), idx_result, E::ZERO));
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut periodic_columns = get_identity_masks(CYCLE_LENGTH);
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, CYCLE_LENGTH));
+        periodic_columns.append(&mut H::get_round_constants_periodic(CYCLE_LENGTH, ROUND_CONSTS_SHIFT));
+        periodic_columns
+    }
+}
+
+// F_t: product over all pairs of neighbours in t = (0, 1, .., max_value), this is the initial value of F
+fn get_f_t<E: FieldElement>(
+    beta: E,
+    gamma: E,
+    max_value: usize,
+) -> E {
+    let mut result = E::ONE;
+    let mut t = E::ZERO;
+    for _ in 0..max_value {
+        result *= gamma * (E::ONE + beta) + t + beta * (t + E::ONE);
+        t += E::ONE;
+    }
+    result
+}
+
+// function S(a, f_x)
+fn helper_s<E: FieldElement>(
+    value: E,
+    flag: E
+) -> E {
+    value * flag + E::ONE - flag
+}
+
+// function T(f_x^cu, f_x^ne)
+fn helper_t<E: FieldElement>(
+    flag_current: E,
+    flag_next: E
+) -> E {
+    (flag_current - flag_next) * (flag_current - flag_next - E::ONE)
+}
+
+// function U(f_x)
+fn helper_u<E: FieldElement>(
+    flag: E
+) -> E {
+    (flag - E::ZERO) * (flag - E::ONE)
+}
+
+fn enforce_copy<E: FieldElement>(
+    result_slice: &mut [E],
+    current: &[E],
+    next: &[E],
+    flag: E,
+) {
+    for i in 0..current.len() {
+        result_slice[i] += flag * (current[i] - next[i]);
+    }
+}
+
+fn enforce_sum<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_sum: E,
+    next_sum: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (current_sum - next_sum + sum_part);
+}
+
+fn enforce_var<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_var: E,
+    next_var: E,
+    avg: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * (pixels[i] - avg) * (pixels[i] - avg);
+    }
+    result_slice[0] += flag * (current_var - next_var + sum_part);
+}
+
+fn enforce_min<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_min: E,
+    next_min: E,
+    flag_l: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (- next_min + flag_l * current_min + (E::ONE - flag_l) * sum_part);
+}
+
+fn enforce_max<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_max: E,
+    next_max: E,
+    flag_h: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (- next_max + (E::ONE - flag_h) * current_max + flag_h * sum_part);
+}
+
+fn enforce_omega_l<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_min: E,
+    next_omega_l: E,
+    flag_l: E,
+    flag: E,
+) {
+    let mut sum_part_pixels = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part_pixels += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (flag_l * (- current_min + sum_part_pixels) + (E::ONE - flag_l) * (current_min - sum_part_pixels) - next_omega_l);
+}
+
+fn enforce_omega_h<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_max: E,
+    next_omega_h: E,
+    flag_h: E,
+    flag: E,
+) {
+    let mut sum_part_pixels = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part_pixels += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (flag_h * (- current_max + sum_part_pixels) + (E::ONE - flag_h) * (current_max - sum_part_pixels) - next_omega_h);
+}
+
+fn enforce_omega_m<E: FieldElement>(
+    result_slice: &mut [E],
+    current_med: E,
+    next_med: E,
+    next_omega_m: E,
+    flag: E,
+) {
+    result_slice[0] += flag * (next_med - current_med - next_omega_m);
+}
+
+fn enforce_z<E: FieldElement>(
+    result_slice: &mut [E],
+    next_pixel: E,
+    next_med: E,
+    current_z: E,
+    next_z: E,
+    lambda: E,
+    flag: E,
+) {
+    result_slice[0] += flag * (current_z * (lambda + next_pixel) - next_z * (lambda + next_med));
+}
+
+fn enforce_g<E: FieldElement>(
+    result_slice: &mut [E],
+    beta: E,
+    gamma: E,
+    next_s_m1: E,
+    next_s: &[E],
+    current_g: &[E],
+    next_g: &[E],
+    flag: E,
+) {
+    result_slice[0] += - next_g[0] + (gamma * (E::ONE + beta) + next_s_m1 + beta * next_s[0]) * (gamma * (E::ONE + beta) + next_s[0] + beta * next_s[1]) * (gamma * (E::ONE + beta) + next_s[1] + beta * next_s[2]);
+    result_slice[1] += - next_g[1] + next_g[0] * (gamma * (E::ONE + beta) + next_s[2] + beta * next_s[3]) * (gamma * (E::ONE + beta) + next_s[3] + beta * next_s[4]);
+    result_slice[2] += - next_g[2] + current_g[2] * helper_s(next_g[1], flag);
+}
+
+fn enforce_f<E: FieldElement>(
+    result_slice: &mut [E],
+    beta: E,
+    gamma: E,
+    next_pixel: E,
+    next_omega_l: E,
+    next_omega_h: E,
+    next_omega_m: E,
+    current_f: &[E],
+    next_f: &[E],
+    flag: E,
+) {
+    result_slice[0] += (E::ONE + beta) * (gamma + next_pixel)
+                     * (E::ONE + beta) * (gamma + next_omega_l)
+                     * (E::ONE + beta) * (gamma + next_omega_h) - next_f[0];
+    result_slice[1] += helper_s(next_f[0] * (E::ONE + beta) * (gamma + next_omega_m), flag) - next_f[1];
+    result_slice[2] += current_f[2] * next_f[1] - next_f[2];
+}
+
+// the auxiliary segment does not depend on the hash, one builder serves all instances
+pub struct TheAuxTraceBuilder {}
+
+impl<B: StarkBaseField> AuxTraceBuilder<B> for TheAuxTraceBuilder {
+    const WIDTH: usize = AUX_TRACE_WIDTH;
+    const NUM_RAND_ELEMENTS: usize = 3;
+
+    // fills the columns dependent on the randomness: z, F, G, R
+    fn build_aux_columns<E: FieldElement<BaseField=B>>(main: &Matrix<B>, meta: &[u8], rand_elements: &[E]) -> Vec<Vec<E>> {
+        let (_, _, max_value) = get_lookup_meta(&meta.to_vec());
+        let beta = rand_elements[R_BETA];
+        let gamma = rand_elements[R_GAMMA];
+        let lambda = rand_elements[R_LAMBDA];
+        let length = main.num_rows();
+        let mut columns = vec![vec![E::ZERO; length]; AUX_TRACE_WIDTH];
+        columns[T_Z.begin()][0] = E::ONE;
+        columns[T_F.end() - 1][0] = get_f_t(beta, gamma, max_value);
+        columns[T_G.end() - 1][0] = E::ONE;
+        for step in 0..(length - 1) {
+            let cyclic_step = step % CYCLE_LENGTH; // cyclic_step can be considered the index in the masks
+            let current_idx = step;
+            let next_idx = step + 1;
+
+            let current_z = columns[T_Z.begin()][current_idx];
+            let next_pixel = E::from(main.get(T_PIXELS.begin() + cyclic_step, next_idx));
+            let next_med = E::from(main.get(T_MED.begin(), next_idx));
+            let next_z = current_z * (lambda + next_pixel) / (lambda + next_med);
+            columns[T_Z.begin()][next_idx] = next_z;
+
+            let current_f = columns[T_F.end() - 1][current_idx];
+            let next_omega_l = E::from(main.get(T_OMEGA_L.begin(), next_idx));
+            let next_omega_h = E::from(main.get(T_OMEGA_H.begin(), next_idx));
+            let next_omega_m = E::from(main.get(T_OMEGA_M.begin(), next_idx));
+            let next_f_f = E::from(main.get(T_F_F.begin(), next_idx));
+            let next_f_0 = (E::ONE + beta) * (gamma + next_pixel) * (E::ONE + beta) * (gamma + next_omega_l) * (E::ONE + beta) * (gamma + next_omega_h);
+            let next_f_1 = helper_s(next_f_0 * (E::ONE + beta) * (gamma + next_omega_m), next_f_f);
+            let next_f_2 = current_f * next_f_1;
+            columns[T_F.begin() + 0][next_idx] = next_f_0;
+            columns[T_F.begin() + 1][next_idx] = next_f_1;
+            columns[T_F.begin() + 2][next_idx] = next_f_2;
+
+            let current_g = columns[T_G.end() - 1][current_idx];
+            let next_f_s = E::from(main.get(T_F_S.begin(), next_idx));
+            let next_s_m1 = E::from(main.get(T_S.end() - 1, current_idx));
+            let next_s_0 = E::from(main.get(T_S.begin() + 0, next_idx));
+            let next_s_1 = E::from(main.get(T_S.begin() + 1, next_idx));
+            let next_s_2 = E::from(main.get(T_S.begin() + 2, next_idx));
+            let next_s_3 = E::from(main.get(T_S.begin() + 3, next_idx));
+            let next_s_4 = E::from(main.get(T_S.begin() + 4, next_idx));
+            let next_g_0 = (gamma * (E::ONE + beta) + next_s_m1 + beta * next_s_0) * (gamma * (E::ONE + beta) + next_s_0 + beta * next_s_1) * (gamma * (E::ONE + beta) + next_s_1 + beta * next_s_2);
+            let next_g_1 = next_g_0 * (gamma * (E::ONE + beta) + next_s_2 + beta * next_s_3) * (gamma * (E::ONE + beta) + next_s_3 + beta * next_s_4);
+            let next_g_2 = current_g * helper_s(next_g_1, next_f_s);
+            columns[T_G.begin() + 0][next_idx] = next_g_0;
+            columns[T_G.begin() + 1][next_idx] = next_g_1;
+            columns[T_G.begin() + 2][next_idx] = next_g_2;
+
+            columns[T_R.begin()][next_idx] = next_f_2 - next_g_2;
+        }
+        columns
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = RapTraceTable<Self::BaseField, TheAuxTraceBuilder>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted, max_value) = get_lookup_meta(&trace.meta().to_vec());
+        let result_step = input_length * CYCLE_LENGTH / NUM_ELEMS_PER_CYCLE;
+        let hash_step = if salted { result_step + CYCLE_LENGTH } else { result_step };
+        let mut hash_pixels = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash_pixels[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        let sum = trace.get(T_SUM.begin(), result_step);
+        let variance = trace.get(T_VAR.begin(), result_step);
+        let min = trace.get(T_MIN.begin(), result_step);
+        let max = trace.get(T_MAX.begin(), result_step);
+        let med_low = trace.get(T_MED.begin(), result_step / 2);
+        let med_high = trace.get(T_MED.begin(), result_step / 2 + 1);
+        PubInputs {
+            hash_pixels: H::Digest::try_from(&hash_pixels[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted,
+            max_value: B::from(max_value as u64),
+            sum,
+            avg_rounded: B::from(sum.as_u128().rounded_div(input_length as u128)),
+            variance,
+            min,
+            max,
+            med_low,
+            med_high,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,403 @@
+use std::marker::PhantomData;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, Trace, TraceInfo, TraceTable, TransitionConstraintDegree};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use rounded_div::RoundedDiv;
+use crate::permutation::{get_padding, HashPermutation, Hasher, StarkBaseField};
+use crate::utils::range_check::{apply_range_check, enforce_range_check, get_num_bits, get_rounded_div_remainders, get_rounded_div_remainders_e, NUM_REMAINDERS};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{create_salted_meta, get_salted_meta, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{get_cycle_length, get_hash_mask, get_identity_masks, NUM_IDENTITY_MASKS};
+
+// STARK F and STARK F (opt): the hash H absorbs its RATE = 8 * FACTOR_M pixels at the beginning of a
+// cycle, FACTOR_M of them are added to sum and variance per step.
+//
+// The pixels are padded with 1 || 0* to whole blocks (see permutation::get_padding), therefore the
+// input may have any length. The padding is asserted in the row the last block is read into. It is
+// part of sum and variance in the AET, the assertions add its (public) contribution to the public
+// inputs.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+
+// upper bound of the number of pixels of a proof, such that the AET fits the evaluation domains of the base fields
+pub const MAX_INPUT_LENGTH: usize = 1 << 28;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub hash: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+    pub sum: H::BaseField,
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    hash: H::Digest,
+    input_length: B,
+    salted: bool,
+    sum: B,
+    avg_rounded: B,
+    variance: B,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    // STARK F (opt) parameter m:
+    pub const FACTOR_M: usize = H::RATE / 8;
+
+    pub const CYCLE_LENGTH: usize = get_cycle_length(H::NUM_ROUNDS);
+    pub const TRACE_WIDTH: usize = 8 * Self::FACTOR_M + H::STATE_WIDTH + 2 + NUM_REMAINDERS;
+
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < Self::CYCLE_LENGTH;
+
+    // AET index definitions
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 * Self::FACTOR_M };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M, size: H::STATE_WIDTH };
+    pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH, size: 1 };
+    pub const T_VAR: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH + 1, size: 1 };
+    pub const T_AVG_REM: IndexDefinition = IndexDefinition { idx: Self::T_VAR.idx + Self::T_VAR.size, size: NUM_REMAINDERS };
+
+    // constraint index definitions
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: 8 * Self::FACTOR_M};
+    const C_SUM: IndexDefinition = IndexDefinition {idx: Self::C_COPY.idx + Self::C_COPY.size, size: 1};
+    const C_VAR: IndexDefinition = IndexDefinition {idx: Self::C_SUM.idx + Self::C_SUM.size, size: 1};
+    const C_AVG_REM: IndexDefinition = IndexDefinition {idx: Self::C_VAR.idx + Self::C_VAR.size, size: NUM_REMAINDERS};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_AVG_REM.idx + Self::C_AVG_REM.size, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_IDENTITY: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: NUM_IDENTITY_MASKS };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + NUM_IDENTITY_MASKS, size: 1 };
+
+    // number of elements being absorbed in one absorption phase (one pixel per element)
+    pub const NUM_ELEMS_PER_CYCLE: usize = H::RATE;
+
+    /// Number of blocks the padded pixels are absorbed in, the last one holds the padding.
+    pub fn get_num_blocks(input_length: usize) -> usize {
+        input_length / Self::NUM_ELEMS_PER_CYCLE + 1
+    }
+
+    /// Contribution of the padding to sum and variance in the AET.
+    pub fn get_padding_stats(input_length: usize, avg_rounded: B) -> (B, B) {
+        let padding = get_padding::<B>(input_length, H::RATE);
+        let sum = padding.iter().fold(B::ZERO, |acc, elem| acc + *elem);
+        let variance = padding.iter().fold(B::ZERO, |acc, elem| acc + (*elem - avg_rounded) * (*elem - avg_rounded));
+        (sum, variance)
+    }
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>) -> TraceTable<B> {
+        Self::build_salted_trace(pixels, None)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> TraceTable<B> {
+        // The desired hash value is located at step i = get_num_blocks(pixels.len()) * CYCLE_LENGTH
+        assert_eq!(8 * Self::FACTOR_M, H::RATE);
+        let num_bits = get_num_bits(pixels.len() as u128);
+        // the elements absorbed after the pixels: the padding followed by the salt
+        let mut tail = get_padding::<B>(pixels.len(), H::RATE);
+        tail.extend_from_slice(salt.map(|s| s.as_ref()).unwrap_or_default());
+        let trace_len = next_power_of_two(usize::max(((pixels.len() + tail.len()) / Self::NUM_ELEMS_PER_CYCLE) * Self::CYCLE_LENGTH + 1, num_bits + 1));
+        let sum = pixels.iter().map(|e| {*e as u128}).sum::<u128>();
+        let avg = B::from(sum.rounded_div(pixels.len() as u128));
+        let avg_remainders = get_rounded_div_remainders(sum, pixels.len() as u128);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_salted_meta(pixels.len(), salt.is_some()));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH; // cyclic_step can be considered the index in the masks
+                if cyclic_step == 0 {
+                    // read elements into trace
+                    for c in 0..Self::NUM_ELEMS_PER_CYCLE {
+                        let next_pixel_idx = Self::NUM_ELEMS_PER_CYCLE * step / Self::CYCLE_LENGTH + c;
+                        if next_pixel_idx < pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                        } else if next_pixel_idx < pixels.len() + tail.len() {
+                            state[Self::T_PIXELS.idx + c] = tail[next_pixel_idx - pixels.len()];
+                        }
+                    }
+                    // absorb elements into hash state
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += state[Self::T_PIXELS.idx + c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                if cyclic_step < NUM_IDENTITY_MASKS {
+                    for i in 0..Self::FACTOR_M {
+                        let pixel = state[Self::T_PIXELS.begin() + i * NUM_IDENTITY_MASKS + cyclic_step];
+                        state[Self::T_SUM.begin()] += pixel;
+                        state[Self::T_VAR.begin()] += (pixel - avg) * (pixel - avg);
+                    }
+                }
+                apply_range_check(&mut state[Self::T_AVG_REM.begin()..Self::T_AVG_REM.end()], &avg_remainders, num_bits, step);
+            }
+        );
+        table
+    }
+
+    /// Native computation of the commitment to the pixels, which is salted if a salt is given.
+    pub fn commit(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> H::Digest {
+        let mut hasher = Hasher::<H>::new().padded();
+        hasher.update(pixels);
+        match salt {
+            Some(salt) => hasher.finalize_salted(salt),
+            None => hasher.finalize(),
+        }.expect("a padded input fills whole blocks")
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        opening.verify_digest(pub_inputs.hash.as_ref(), pub_inputs.salted, pub_inputs.input_length.as_u128() as usize, |pixels, salt| {
+            let salt = salt.map(|s| H::Digest::try_from(s).unwrap());
+            Self::commit(pixels, salt.as_ref()).as_ref().to_vec()
+        })
+    }
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for elem in self.hash.as_ref() {
+            target.write(*elem);
+        }
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+        target.write(self.sum);
+        target.write(self.avg_rounded);
+        target.write(self.variance);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut hash = vec![H::BaseField::ZERO; H::RATE];
+        for elem in hash.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let hash = H::Digest::try_from(&hash[..]).unwrap();
+        let input_length = H::BaseField::read_from(source)?;
+        check_input_length(input_length.as_u128()).map_err(DeserializationError::InvalidValue)?;
+        let salted = read_bool(source)?;
+        let sum = H::BaseField::read_from(source)?;
+        let avg_rounded = H::BaseField::read_from(source)?;
+        let variance = H::BaseField::read_from(source)?;
+        Ok(PubInputs { hash, input_length, salted, sum, avg_rounded, variance })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees = vec![];
+
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        // C_SUM
+        degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        // C_VAR
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![Self::CYCLE_LENGTH]));
+        for _ in 0..Self::C_AVG_REM.size {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+
+        let num_padding = get_padding::<B>(pub_inputs.input_length.as_u128() as usize, H::RATE).len();
+        let num_assertions = H::STATE_WIDTH + H::RATE + 4 + 2 * NUM_REMAINDERS + num_padding;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            hash: pub_inputs.hash,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted,
+            sum: pub_inputs.sum,
+            avg_rounded: pub_inputs.avg_rounded,
+            variance: pub_inputs.variance
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(Self::P_IDENTITY);
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(Self::T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag);
+        enforce_copy(&mut result[Self::C_COPY.begin()..Self::C_COPY.end()], current.id_slice(Self::T_PIXELS), next.id_slice(Self::T_PIXELS), copy_flag);
+        enforce_sum(&mut result[Self::C_SUM.begin()..Self::C_SUM.end()], next.id_slice(Self::T_PIXELS), identity, current.id_slice(Self::T_SUM)[0], next.id_slice(Self::T_SUM)[0], E::ONE);
+        enforce_var(&mut result[Self::C_VAR.begin()..Self::C_VAR.end()], next.id_slice(Self::T_PIXELS), identity, current.id_slice(Self::T_VAR)[0], next.id_slice(Self::T_VAR)[0], E::from(self.avg_rounded), E::ONE);
+        enforce_range_check(&mut result[Self::C_AVG_REM.begin()..Self::C_AVG_REM.end()], current.id_slice(Self::T_AVG_REM), next.id_slice(Self::T_AVG_REM));
+        if Self::COPY_HASH {
+            let copy_hash_flag = copy_flag - hash_remaining_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let input_length = self.input_length.as_u128() as usize;
+        let idx_result = Self::CYCLE_LENGTH * Self::get_num_blocks(input_length);
+        let idx_hash = if self.salted { idx_result + Self::CYCLE_LENGTH } else { idx_result };
+        // row the last block (with the padding) is read into
+        let idx_padding = idx_result - Self::CYCLE_LENGTH + 1;
+        let (padding_sum, padding_variance) = Self::get_padding_stats(input_length, self.avg_rounded);
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash.as_ref()[c]));
+        }
+        result.push(Assertion::single(Self::T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_VAR.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_SUM.begin(), idx_result, self.sum + padding_sum));
+        result.push(Assertion::single(Self::T_VAR.begin(), idx_result, self.variance + padding_variance));
+        let padding = get_padding::<B>(input_length, H::RATE);
+        for (c, elem) in padding.iter().enumerate() {
+            result.push(Assertion::single(Self::T_PIXELS.begin() + H::RATE - padding.len() + c, idx_padding, *elem));
+        }
+
+        // avg_rounded = round(sum / input_length), see utils::range_check
+        let num_bits = get_num_bits(self.input_length.as_u128());
+        let avg_remainders = get_rounded_div_remainders_e(self.sum, self.input_length, self.avg_rounded);
+        for c in 0..Self::T_AVG_REM.size {
+            result.push(Assertion::single(Self::T_AVG_REM.begin() + c, 0, B::ZERO));
+            result.push(Assertion::single(Self::T_AVG_REM.begin() + c, num_bits, avg_remainders[c]));
+        }
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        periodic_columns.append(&mut get_identity_masks(Self::CYCLE_LENGTH));
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        periodic_columns
+    }
+}
+
+/// Checks that an input of `input_length` pixels can be proven.
+pub(super) fn check_input_length(input_length: u128) -> Result<(), String> {
+    if input_length == 0 || input_length > MAX_INPUT_LENGTH as u128 {
+        return Err(format!("input length {} is not between 1 and {}", input_length, MAX_INPUT_LENGTH));
+    }
+    Ok(())
+}
+
+pub(super) fn enforce_copy<E: FieldElement>(
+    result_slice: &mut [E],
+    current: &[E],
+    next: &[E],
+    flag: E,
+) {
+    for i in 0..current.len() {
+        result_slice[i] += flag * (current[i] - next[i]);
+    }
+}
+
+pub(super) fn enforce_sum<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_sum: E,
+    next_sum: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % NUM_IDENTITY_MASKS] * pixels[i];
+    }
+    result_slice[0] += flag * (current_sum - next_sum + sum_part);
+}
+
+pub(super) fn enforce_var<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_var: E,
+    next_var: E,
+    avg: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % NUM_IDENTITY_MASKS] * (pixels[i] - avg) * (pixels[i] - avg);
+    }
+    result_slice[0] += flag * (current_var - next_var + sum_part);
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted) = get_salted_meta(&trace.meta().to_vec());
+        let result_step = TheAir::<B, H>::get_num_blocks(input_length) * TheAir::<B, H>::CYCLE_LENGTH;
+        let hash_step = if salted { result_step + TheAir::<B, H>::CYCLE_LENGTH } else { result_step };
+        let mut hash = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        let (padding_sum, _) = TheAir::<B, H>::get_padding_stats(input_length, B::ZERO);
+        let sum = trace.get(TheAir::<B, H>::T_SUM.begin(), result_step) - padding_sum;
+        let avg_rounded = B::from(sum.as_u128().rounded_div(input_length as u128));
+        let (_, padding_variance) = TheAir::<B, H>::get_padding_stats(input_length, avg_rounded);
+        PubInputs {
+            hash: H::Digest::try_from(&hash[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted,
+            sum,
+            avg_rounded,
+            variance: trace.get(TheAir::<B, H>::T_VAR.begin(), result_step) - padding_variance,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
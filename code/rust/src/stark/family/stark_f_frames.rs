@@ -0,0 +1,491 @@
+use std::marker::PhantomData;
+use std::mem::size_of;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, Trace, TraceInfo, TraceTable, TransitionConstraintDegree};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use rounded_div::RoundedDiv;
+use crate::commitment::{verify_frame_path, CommitmentError, FrameTree};
+use crate::permutation::{get_padding, HashPermutation, StarkBaseField};
+use crate::utils::range_check::{apply_range_check, enforce_range_check, get_num_bits, get_rounded_div_remainders, get_rounded_div_remainders_e, NUM_REMAINDERS};
+use crate::utils::{create_meta, get_meta, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{get_cycle_length, get_hash_mask, get_identity_masks, NUM_IDENTITY_MASKS};
+use super::stark_f::{enforce_copy, enforce_sum, enforce_var, MAX_INPUT_LENGTH};
+use super::stark_g::MAX_FRAME_SIZE;
+
+// STARK F over a range of frames of a video committed to with a frame tree (see
+// commitment::FrameTree): the AET hashes every frame of the range on its own, the digests are public
+// inputs together with their authentication paths to the root. The paths are checked natively by
+// `verify_paths`, the proof shows that the statistics belong to the frames with these digests.
+//
+// Every frame occupies FRAME_BLOCKS = next_power_of_two(NUM_BLOCKS + 1) blocks of CYCLE_LENGTH steps
+// (NUM_BLOCKS blocks of the padded frame, the remaining ones are idle), such that the frame boundaries
+// can be expressed by periodic columns. The stats masks (the identity masks restricted to the blocks
+// of the frame) exclude the idle blocks from sum and variance, the hash state is reset to zero in the
+// last step of a frame. The cycle has at least one step after the last round for the reset.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub root: H::Digest,
+    // number of frames of the committed video
+    pub num_frames: H::BaseField,
+    pub frame_size: H::BaseField,
+    // the proven range of frames
+    pub first_frame: H::BaseField,
+    pub digests: Vec<H::Digest>,
+    pub paths: Vec<Vec<H::Digest>>,
+    pub sum: H::BaseField,
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    frame_size: usize,
+    digests: Vec<H::Digest>,
+    input_length: B,
+    sum: B,
+    avg_rounded: B,
+    variance: B,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    // STARK F (opt) parameter m:
+    pub const FACTOR_M: usize = H::RATE / 8;
+
+    pub const CYCLE_LENGTH: usize = get_cycle_length(H::NUM_ROUNDS + 1);
+    pub const TRACE_WIDTH: usize = 8 * Self::FACTOR_M + H::STATE_WIDTH + 2 + NUM_REMAINDERS;
+
+    // AET index definitions
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 * Self::FACTOR_M };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M, size: H::STATE_WIDTH };
+    pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH, size: 1 };
+    pub const T_VAR: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH + 1, size: 1 };
+    pub const T_AVG_REM: IndexDefinition = IndexDefinition { idx: Self::T_VAR.idx + Self::T_VAR.size, size: NUM_REMAINDERS };
+
+    // constraint index definitions
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: 8 * Self::FACTOR_M};
+    const C_SUM: IndexDefinition = IndexDefinition {idx: Self::C_COPY.idx + Self::C_COPY.size, size: 1};
+    const C_VAR: IndexDefinition = IndexDefinition {idx: Self::C_SUM.idx + Self::C_SUM.size, size: 1};
+    const C_AVG_REM: IndexDefinition = IndexDefinition {idx: Self::C_VAR.idx + Self::C_VAR.size, size: NUM_REMAINDERS};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_AVG_REM.idx + Self::C_AVG_REM.size, size: H::STATE_WIDTH};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_IDENTITY: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: NUM_IDENTITY_MASKS };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + NUM_IDENTITY_MASKS, size: 1 };
+    const P_STATS: IndexDefinition = IndexDefinition { idx: Self::P_HASH_FLAG.idx + 1, size: NUM_IDENTITY_MASKS };
+    const P_FRAME_END: IndexDefinition = IndexDefinition { idx: Self::P_STATS.idx + NUM_IDENTITY_MASKS, size: 1 };
+
+    // number of elements being absorbed in one absorption phase (one pixel per element)
+    pub const NUM_ELEMS_PER_CYCLE: usize = H::RATE;
+
+    /// Number of blocks a padded frame is absorbed in.
+    pub fn get_num_blocks(frame_size: usize) -> usize {
+        frame_size / Self::NUM_ELEMS_PER_CYCLE + 1
+    }
+
+    /// Number of blocks a frame occupies in the AET, including the idle ones.
+    pub fn get_frame_blocks(frame_size: usize) -> usize {
+        next_power_of_two(Self::get_num_blocks(frame_size) + 1)
+    }
+
+    /// Row of the AET the digest of frame `frame` (counted from the first proven frame) is located at.
+    pub fn get_digest_step(frame_size: usize, frame: usize) -> usize {
+        (frame * Self::get_frame_blocks(frame_size) + Self::get_num_blocks(frame_size) - 1) * Self::CYCLE_LENGTH + H::NUM_ROUNDS
+    }
+
+    /// Row of the AET sum and variance of `num_frames` frames are located at.
+    pub fn get_result_step(frame_size: usize, num_frames: usize) -> usize {
+        ((num_frames - 1) * Self::get_frame_blocks(frame_size) + Self::get_num_blocks(frame_size)) * Self::CYCLE_LENGTH
+    }
+
+    /// Contribution of the padding of `num_frames` frames to sum and variance in the AET.
+    pub fn get_padding_stats(frame_size: usize, num_frames: usize, avg_rounded: B) -> (B, B) {
+        let padding = get_padding::<B>(frame_size, H::RATE);
+        let sum = padding.iter().fold(B::ZERO, |acc, elem| acc + *elem);
+        let variance = padding.iter().fold(B::ZERO, |acc, elem| acc + (*elem - avg_rounded) * (*elem - avg_rounded));
+        let num_frames = B::from(num_frames as u64);
+        (sum * num_frames, variance * num_frames)
+    }
+
+    /// Builds the trace of the consecutive frames of `frame_size` pixels in `pixels`.
+    pub fn build_trace(pixels: &[u16], frame_size: usize) -> TraceTable<B> {
+        assert_eq!(8 * Self::FACTOR_M, H::RATE);
+        assert!(frame_size > 0 && !pixels.is_empty() && pixels.len() % frame_size == 0, "the input has to consist of whole frames");
+        let num_frames = pixels.len() / frame_size;
+        let num_blocks = Self::get_num_blocks(frame_size);
+        let frame_cycle = Self::get_frame_blocks(frame_size) * Self::CYCLE_LENGTH;
+        let num_bits = get_num_bits(pixels.len() as u128);
+        let padding = get_padding::<B>(frame_size, H::RATE);
+        let trace_len = next_power_of_two(usize::max(Self::get_result_step(frame_size, num_frames) + 1, num_bits + 1));
+        let sum = pixels.iter().map(|e| {*e as u128}).sum::<u128>();
+        let avg = B::from(sum.rounded_div(pixels.len() as u128));
+        let avg_remainders = get_rounded_div_remainders(sum, pixels.len() as u128);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_frames_meta(pixels.len(), frame_size));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH;
+                let frame = step / frame_cycle;
+                let block = (step % frame_cycle) / Self::CYCLE_LENGTH;
+                let active = frame < num_frames && block < num_blocks;
+                if cyclic_step == 0 {
+                    // read elements of the padded frame into trace, the idle blocks read zeros
+                    for c in 0..Self::NUM_ELEMS_PER_CYCLE {
+                        let elem_idx = block * Self::NUM_ELEMS_PER_CYCLE + c;
+                        state[Self::T_PIXELS.idx + c] = if !active {
+                            B::ZERO
+                        } else if elem_idx < frame_size {
+                            B::from(pixels[frame * frame_size + elem_idx])
+                        } else {
+                            padding[elem_idx - frame_size]
+                        };
+                    }
+                    // absorb elements into hash state
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += state[Self::T_PIXELS.idx + c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                if cyclic_step < NUM_IDENTITY_MASKS && block < num_blocks {
+                    for i in 0..Self::FACTOR_M {
+                        let pixel = state[Self::T_PIXELS.begin() + i * NUM_IDENTITY_MASKS + cyclic_step];
+                        state[Self::T_SUM.begin()] += pixel;
+                        state[Self::T_VAR.begin()] += (pixel - avg) * (pixel - avg);
+                    }
+                }
+                if step % frame_cycle == frame_cycle - 1 {
+                    for c in 0..H::STATE_WIDTH {
+                        state[Self::T_PIXELS_HASH.idx + c] = B::ZERO;
+                    }
+                }
+                apply_range_check(&mut state[Self::T_AVG_REM.begin()..Self::T_AVG_REM.end()], &avg_remainders, num_bits, step);
+            }
+        );
+        table
+    }
+
+    /// Checks that the frame digests of the public inputs belong to the committed root.
+    pub fn verify_paths(pub_inputs: &PubInputs<H>) -> Result<(), CommitmentError> {
+        verify_frame_paths::<H>(&pub_inputs.root, pub_inputs.num_frames, pub_inputs.first_frame, &pub_inputs.digests, &pub_inputs.paths)
+    }
+}
+
+/// Checks that the digests of the consecutive frames starting at `first_frame` belong to `root`.
+pub(super) fn verify_frame_paths<H: HashPermutation>(root: &H::Digest, num_frames: H::BaseField, first_frame: H::BaseField, digests: &[H::Digest], paths: &[Vec<H::Digest>]) -> Result<(), CommitmentError> {
+    if paths.len() != digests.len() {
+        return Err(CommitmentError::Path(format!("expected {} paths, got {}", digests.len(), paths.len())));
+    }
+    let num_frames = num_frames.as_u128() as usize;
+    let first_frame = first_frame.as_u128() as usize;
+    for (i, (digest, path)) in digests.iter().zip(paths.iter()).enumerate() {
+        verify_frame_path::<H>(root, num_frames, first_frame + i, digest, path)?;
+    }
+    Ok(())
+}
+
+/// Checks that the proven frames lie within the committed ones, that they hold at most MAX_INPUT_LENGTH
+/// pixels and that there is one path of the depth of the frame tree per frame.
+pub(super) fn check_frame_range<H: HashPermutation>(num_frames: H::BaseField, frame_size: H::BaseField, first_frame: H::BaseField, paths: &[Vec<H::Digest>]) -> Result<(), String> {
+    let to_usize = |value: H::BaseField| usize::try_from(value.as_u128()).unwrap_or(usize::MAX);
+    let (num_frames, frame_size, first_frame) = (to_usize(num_frames), to_usize(frame_size), to_usize(first_frame));
+    if frame_size == 0 || frame_size > MAX_FRAME_SIZE {
+        return Err(format!("frame size {} is not between 1 and {}", frame_size, MAX_FRAME_SIZE));
+    }
+    match frame_size.checked_mul(paths.len()) {
+        Some(input_length) if input_length > 0 && input_length <= MAX_INPUT_LENGTH => (),
+        _ => return Err(format!("{} frames of {} pixels are not between 1 and {} pixels", paths.len(), frame_size, MAX_INPUT_LENGTH)),
+    }
+    match first_frame.checked_add(paths.len()) {
+        Some(end) if end <= num_frames && num_frames <= u32::MAX as usize => (),
+        _ => return Err(format!("frames {} to {} are not within the {} committed frames", first_frame, first_frame.saturating_add(paths.len() - 1), num_frames)),
+    }
+    let depth = next_power_of_two(num_frames).trailing_zeros() as usize;
+    if let Some(path) = paths.iter().find(|path| path.len() != depth) {
+        return Err(format!("expected paths of {} nodes, got {}", depth, path.len()));
+    }
+    Ok(())
+}
+
+// meta of a trace: number of pixels followed by the frame size
+pub(super) fn create_frames_meta(num: usize, frame_size: usize) -> Vec<u8> {
+    let mut result = create_meta(num);
+    result.append(&mut create_meta(frame_size));
+    result
+}
+
+pub(super) fn get_frames_meta(meta: &Vec<u8>) -> (usize, usize) {
+    let num_bytes = size_of::<usize>();
+    (get_meta(&meta[..num_bytes].to_vec()), get_meta(&meta[num_bytes..].to_vec()))
+}
+
+pub(super) fn write_digest<H: HashPermutation, W: ByteWriter>(target: &mut W, digest: &H::Digest) {
+    for elem in digest.as_ref() {
+        target.write(*elem);
+    }
+}
+
+pub(super) fn read_digest<H: HashPermutation, R: ByteReader>(source: &mut R) -> Result<H::Digest, DeserializationError> {
+    let mut digest = vec![H::BaseField::ZERO; H::RATE];
+    for elem in digest.iter_mut() {
+        *elem = H::BaseField::read_from(source)?;
+    }
+    Ok(H::Digest::try_from(&digest[..]).unwrap())
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_digest::<H, W>(target, &self.root);
+        target.write(self.num_frames);
+        target.write(self.frame_size);
+        target.write(self.first_frame);
+        target.write_u32(self.digests.len() as u32);
+        for digest in self.digests.iter() {
+            write_digest::<H, W>(target, digest);
+        }
+        for path in self.paths.iter() {
+            target.write_u32(path.len() as u32);
+            for node in path.iter() {
+                write_digest::<H, W>(target, node);
+            }
+        }
+        target.write(self.sum);
+        target.write(self.avg_rounded);
+        target.write(self.variance);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = read_digest::<H, R>(source)?;
+        let num_frames = H::BaseField::read_from(source)?;
+        let frame_size = H::BaseField::read_from(source)?;
+        let first_frame = H::BaseField::read_from(source)?;
+        let mut digests = vec![];
+        for _ in 0..source.read_u32()? {
+            digests.push(read_digest::<H, R>(source)?);
+        }
+        let mut paths = vec![];
+        for _ in 0..digests.len() {
+            let mut path = vec![];
+            for _ in 0..source.read_u32()? {
+                path.push(read_digest::<H, R>(source)?);
+            }
+            paths.push(path);
+        }
+        check_frame_range::<H>(num_frames, frame_size, first_frame, &paths).map_err(DeserializationError::InvalidValue)?;
+        let sum = H::BaseField::read_from(source)?;
+        let avg_rounded = H::BaseField::read_from(source)?;
+        let variance = H::BaseField::read_from(source)?;
+        Ok(PubInputs { root, num_frames, frame_size, first_frame, digests, paths, sum, avg_rounded, variance })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let frame_size = pub_inputs.frame_size.as_u128() as usize;
+        let frame_cycle = Self::get_frame_blocks(frame_size) * Self::CYCLE_LENGTH;
+        let num_frames = pub_inputs.digests.len();
+        let mut degrees = vec![];
+
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        // C_SUM
+        degrees.push(TransitionConstraintDegree::with_cycles(1, vec![frame_cycle]));
+        // C_VAR
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![frame_cycle]));
+        for _ in 0..Self::C_AVG_REM.size {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH, frame_cycle]));
+        }
+
+        let num_padding = get_padding::<B>(frame_size, H::RATE).len();
+        let num_assertions = H::STATE_WIDTH + num_frames * (H::RATE + num_padding) + 4 + 2 * NUM_REMAINDERS;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            frame_size,
+            input_length: B::from((num_frames * frame_size) as u64),
+            digests: pub_inputs.digests,
+            sum: pub_inputs.sum,
+            avg_rounded: pub_inputs.avg_rounded,
+            variance: pub_inputs.variance
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(Self::P_IDENTITY);
+        let stats = periodic_values.id_slice(Self::P_STATS);
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+        let copy_hash_flag = copy_flag - hash_remaining_flag;
+        let frame_end = periodic_values[Self::P_FRAME_END.idx];
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(Self::T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag);
+        enforce_copy(&mut result[Self::C_COPY.begin()..Self::C_COPY.end()], current.id_slice(Self::T_PIXELS), next.id_slice(Self::T_PIXELS), copy_flag);
+        enforce_sum(&mut result[Self::C_SUM.begin()..Self::C_SUM.end()], next.id_slice(Self::T_PIXELS), stats, current.id_slice(Self::T_SUM)[0], next.id_slice(Self::T_SUM)[0], E::ONE);
+        enforce_var(&mut result[Self::C_VAR.begin()..Self::C_VAR.end()], next.id_slice(Self::T_PIXELS), stats, current.id_slice(Self::T_VAR)[0], next.id_slice(Self::T_VAR)[0], E::from(self.avg_rounded), E::ONE);
+        enforce_range_check(&mut result[Self::C_AVG_REM.begin()..Self::C_AVG_REM.end()], current.id_slice(Self::T_AVG_REM), next.id_slice(Self::T_AVG_REM));
+        enforce_copy_or_reset(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag, frame_end);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let num_frames = self.digests.len();
+        let idx_result = Self::get_result_step(self.frame_size, num_frames);
+        let (padding_sum, padding_variance) = Self::get_padding_stats(self.frame_size, num_frames, self.avg_rounded);
+        let padding = get_padding::<B>(self.frame_size, H::RATE);
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for (frame, digest) in self.digests.iter().enumerate() {
+            let idx_digest = Self::get_digest_step(self.frame_size, frame);
+            for c in 0..H::RATE {
+                result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_digest, digest.as_ref()[c]));
+            }
+            // row the last block (with the padding) of the frame is read into
+            let idx_padding = idx_digest - H::NUM_ROUNDS + 1;
+            for (c, elem) in padding.iter().enumerate() {
+                result.push(Assertion::single(Self::T_PIXELS.begin() + H::RATE - padding.len() + c, idx_padding, *elem));
+            }
+        }
+        result.push(Assertion::single(Self::T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_VAR.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_SUM.begin(), idx_result, self.sum + padding_sum));
+        result.push(Assertion::single(Self::T_VAR.begin(), idx_result, self.variance + padding_variance));
+
+        // avg_rounded = round(sum / input_length), see utils::range_check
+        let num_bits = get_num_bits(self.input_length.as_u128());
+        let avg_remainders = get_rounded_div_remainders_e(self.sum, self.input_length, self.avg_rounded);
+        for c in 0..Self::T_AVG_REM.size {
+            result.push(Assertion::single(Self::T_AVG_REM.begin() + c, 0, B::ZERO));
+            result.push(Assertion::single(Self::T_AVG_REM.begin() + c, num_bits, avg_remainders[c]));
+        }
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let num_blocks = Self::get_num_blocks(self.frame_size);
+        let frame_cycle = Self::get_frame_blocks(self.frame_size) * Self::CYCLE_LENGTH;
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        periodic_columns.append(&mut get_identity_masks(Self::CYCLE_LENGTH));
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        // stats masks: the identity masks in the blocks of the padded frame
+        let mut stats = vec![vec![B::ZERO; frame_cycle]; NUM_IDENTITY_MASKS];
+        for i in 0..NUM_IDENTITY_MASKS {
+            for block in 0..num_blocks {
+                stats[i][block * Self::CYCLE_LENGTH + i] = B::ONE;
+            }
+        }
+        periodic_columns.append(&mut stats);
+        let mut frame_end = vec![B::ZERO; frame_cycle];
+        frame_end[frame_cycle - 1] = B::ONE;
+        periodic_columns.push(frame_end);
+        periodic_columns
+    }
+}
+
+// copies the hash state, resets it to zero at the end of a frame
+pub(super) fn enforce_copy_or_reset<E: FieldElement>(
+    result_slice: &mut [E],
+    current: &[E],
+    next: &[E],
+    flag: E,
+    frame_end: E,
+) {
+    for i in 0..current.len() {
+        result_slice[i] += flag * ((E::ONE - frame_end) * current[i] - next[i]);
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    root: H::Digest,
+    num_frames: usize,
+    first_frame: usize,
+    paths: Vec<Vec<H::Digest>>,
+    _field: PhantomData<B>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    /// Prover of the `num_frames` frames of `tree` starting at `first_frame`.
+    pub fn new(options: ProofOptions, tree: &FrameTree<H>, first_frame: usize, num_frames: usize) -> Self {
+        assert!(num_frames > 0 && first_frame + num_frames <= tree.num_frames(), "frame range out of bounds");
+        let paths = (first_frame..first_frame + num_frames).map(|index| tree.get_path(index)).collect();
+        Self { options, root: tree.root(), num_frames: tree.num_frames(), first_frame, paths, _field: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, frame_size) = get_frames_meta(&trace.meta().to_vec());
+        let num_frames = input_length / frame_size;
+        let digests = (0..num_frames).map(|frame| {
+            let step = TheAir::<B, H>::get_digest_step(frame_size, frame);
+            let digest = (0..H::RATE).map(|c| trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, step)).collect::<Vec<_>>();
+            H::Digest::try_from(&digest[..]).unwrap()
+        }).collect();
+        let result_step = TheAir::<B, H>::get_result_step(frame_size, num_frames);
+        let (padding_sum, _) = TheAir::<B, H>::get_padding_stats(frame_size, num_frames, B::ZERO);
+        let sum = trace.get(TheAir::<B, H>::T_SUM.begin(), result_step) - padding_sum;
+        let avg_rounded = B::from(sum.as_u128().rounded_div(input_length as u128));
+        let (_, padding_variance) = TheAir::<B, H>::get_padding_stats(frame_size, num_frames, avg_rounded);
+        PubInputs {
+            root: self.root,
+            num_frames: B::from(self.num_frames as u64),
+            frame_size: B::from(frame_size as u64),
+            first_frame: B::from(self.first_frame as u64),
+            digests,
+            paths: self.paths.clone(),
+            sum,
+            avg_rounded,
+            variance: trace.get(TheAir::<B, H>::T_VAR.begin(), result_step) - padding_variance,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
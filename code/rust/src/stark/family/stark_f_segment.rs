@@ -0,0 +1,484 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, StarkProof, Trace, TraceInfo, TraceTable, TransitionConstraintDegree, VerifierError};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use rounded_div::RoundedDiv;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::utils::{create_meta, get_meta, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{get_cycle_length, get_hash_mask, get_identity_masks, NUM_IDENTITY_MASKS};
+use super::stark_f::{enforce_copy, enforce_sum, enforce_var};
+
+// STARK F over one segment of a video: a video too large for a single AET is split into segments
+// which are proven one after another. The AET of a segment is the one of STARK F without the range
+// check of the average, but the hash state starts with the full sponge state (capacity included)
+// the previous segment ended with. Both states are public inputs, such that `verify_segments` can
+// check that the proofs link up to a chain over the whole video.
+//
+// The variance of a segment is computed with the average of the whole video, the partial sums and
+// variances of the segments add up to the ones of the video. The average is checked against the
+// combined sum in `verify_segments`, therefore it has to be computed before proving the first segment
+// (see `get_avg_rounded`).
+//
+// The initial state of a segment only depends on the pixels before it, which `prove_segments`
+// absorbs natively (cheap compared to proving), such that the segments can be proven concurrently.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+
+// ================================================================================================
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum SegmentError {
+    /// No segments were given
+    Empty,
+    /// The first segment does not start with the initial (all zero) sponge state
+    InitialState,
+    /// The sponge state the segment starts with differs from the one the previous segment ended with
+    BrokenChain(usize),
+    /// The segment uses a different average than the first one
+    AverageMismatch(usize),
+    /// The average of the segments is not the rounded average of the whole video
+    WrongAverage,
+    /// The proof of the segment was rejected by the verifier
+    Verification(usize, VerifierError),
+}
+
+impl fmt::Display for SegmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SegmentError::Empty => write!(f, "no segments given"),
+            SegmentError::InitialState => write!(f, "the first segment does not start with the initial sponge state"),
+            SegmentError::BrokenChain(idx) => write!(f, "segment {} does not continue the sponge state of segment {}", idx, idx - 1),
+            SegmentError::AverageMismatch(idx) => write!(f, "segment {} uses a different average than segment 0", idx),
+            SegmentError::WrongAverage => write!(f, "the average of the segments is not the rounded average of the video"),
+            SegmentError::Verification(idx, err) => write!(f, "proof verification of segment {} failed: {}", idx, err),
+        }
+    }
+}
+
+impl std::error::Error for SegmentError {}
+
+// ================================================================================================
+// SEGMENT STARK
+// ================================================================================================
+
+pub struct PubInputs<H: HashPermutation> {
+    /// full sponge state before absorbing the segment
+    pub state_in: Vec<H::BaseField>,
+    /// full sponge state after absorbing the segment
+    pub state_out: Vec<H::BaseField>,
+    pub segment_length: H::BaseField,
+    pub sum: H::BaseField,
+    /// rounded average of the whole video
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+}
+
+impl<H: HashPermutation> Clone for PubInputs<H> {
+    fn clone(&self) -> Self {
+        PubInputs {
+            state_in: self.state_in.clone(),
+            state_out: self.state_out.clone(),
+            segment_length: self.segment_length,
+            sum: self.sum,
+            avg_rounded: self.avg_rounded,
+            variance: self.variance,
+        }
+    }
+}
+
+/// Outputs of a video proven in segments, combined from the public inputs of the segments.
+pub struct SegmentOutputs<H: HashPermutation> {
+    pub hash: H::Digest,
+    pub input_length: usize,
+    pub sum: H::BaseField,
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    state_in: Vec<B>,
+    state_out: Vec<B>,
+    segment_length: B,
+    sum: B,
+    avg_rounded: B,
+    variance: B,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    pub const FACTOR_M: usize = H::RATE / 8;
+
+    pub const CYCLE_LENGTH: usize = get_cycle_length(H::NUM_ROUNDS);
+    pub const TRACE_WIDTH: usize = 8 * Self::FACTOR_M + H::STATE_WIDTH + 2;
+
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < Self::CYCLE_LENGTH;
+
+    // AET index definitions
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 * Self::FACTOR_M };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M, size: H::STATE_WIDTH };
+    pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH, size: 1 };
+    pub const T_VAR: IndexDefinition = IndexDefinition { idx: 8 * Self::FACTOR_M + H::STATE_WIDTH + 1, size: 1 };
+
+    // constraint index definitions
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: 8 * Self::FACTOR_M};
+    const C_SUM: IndexDefinition = IndexDefinition {idx: Self::C_COPY.idx + Self::C_COPY.size, size: 1};
+    const C_VAR: IndexDefinition = IndexDefinition {idx: Self::C_SUM.idx + Self::C_SUM.size, size: 1};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_VAR.idx + Self::C_VAR.size, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_IDENTITY: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: NUM_IDENTITY_MASKS };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + NUM_IDENTITY_MASKS, size: 1 };
+
+    // number of elements being absorbed in one absorption phase (one pixel per element)
+    pub const NUM_ELEMS_PER_CYCLE: usize = H::RATE;
+
+    /// Sponge state before absorbing the first segment.
+    pub fn initial_state() -> Vec<B> {
+        vec![B::ZERO; H::STATE_WIDTH]
+    }
+
+    /// Rounded average of the whole video, which all segments compute their variance with.
+    pub fn get_avg_rounded(pixels: &[u16]) -> B {
+        let sum = pixels.iter().map(|e| {*e as u128}).sum::<u128>();
+        B::from(sum.rounded_div(pixels.len() as u128))
+    }
+
+    /// Native computation of the sponge state after absorbing the pixels into `state`.
+    pub fn absorb(state: &mut [B], pixels: &[u16]) {
+        assert_eq!(0, pixels.len() % H::RATE);
+        for block in pixels.chunks(H::RATE) {
+            for (elem, pixel) in state.iter_mut().zip(block) {
+                *elem += B::from(*pixel);
+            }
+            for round in 0..H::NUM_ROUNDS {
+                H::apply_round(state, round);
+            }
+        }
+    }
+
+    /// Rough estimate of the memory (in bytes) needed to prove a segment of `segment_length` pixels:
+    /// the AET, its low degree extension and the constraint evaluations over the extended domain.
+    pub fn estimate_memory(segment_length: usize, options: &ProofOptions) -> usize {
+        let trace_len = next_power_of_two((segment_length / Self::NUM_ELEMS_PER_CYCLE) * Self::CYCLE_LENGTH + 1);
+        let lde_len = trace_len * options.blowup_factor();
+        (trace_len + 2 * lde_len) * Self::TRACE_WIDTH * size_of::<B>()
+    }
+
+    /// Checks that an input of `input_length` pixels splits into segments of `segment_length` pixels
+    /// (the last one possibly shorter), each of them a positive multiple of NUM_ELEMS_PER_CYCLE pixels.
+    pub fn check_segments(input_length: usize, segment_length: usize) -> Result<(), String> {
+        if input_length == 0 || segment_length == 0 {
+            return Err(String::from("the input and the segments must not be empty"));
+        }
+        if segment_length % Self::NUM_ELEMS_PER_CYCLE != 0 || input_length % segment_length % Self::NUM_ELEMS_PER_CYCLE != 0 {
+            return Err(format!("segments of {} pixels of an input of {} pixels are not all multiples of {} pixels", segment_length, input_length, Self::NUM_ELEMS_PER_CYCLE));
+        }
+        Ok(())
+    }
+
+    /// Splits the pixels into segments of `segment_length` pixels and proves them on `num_workers`
+    /// threads, each of them holding the AET of one segment at a time. Returns the proofs and public
+    /// inputs of the segments in order. The segments must be valid, see `check_segments`.
+    pub fn prove_segments(pixels: &[u16], segment_length: usize, options: ProofOptions, num_workers: usize) -> Vec<(StarkProof, PubInputs<H>)> {
+        Self::check_segments(pixels.len(), segment_length).unwrap();
+        let avg_rounded = Self::get_avg_rounded(pixels);
+        let segments: Vec<&[u16]> = pixels.chunks(segment_length).collect();
+        let mut states = vec![Self::initial_state()];
+        for segment in segments[..segments.len() - 1].iter() {
+            let mut state = states.last().unwrap().clone();
+            Self::absorb(&mut state, segment);
+            states.push(state);
+        }
+
+        let prover = TheProver::<B, H>::new(options);
+        let next_segment = AtomicUsize::new(0);
+        let results = Mutex::new((0..segments.len()).map(|_| None).collect::<Vec<_>>());
+        thread::scope(|scope| {
+            for _ in 0..usize::max(num_workers, 1) {
+                scope.spawn(|| loop {
+                    let idx = next_segment.fetch_add(1, Ordering::Relaxed);
+                    if idx >= segments.len() {
+                        break;
+                    }
+                    let trace = Self::build_trace(segments[idx], &states[idx], avg_rounded);
+                    let pub_inputs = prover.get_pub_inputs(&trace);
+                    let proof = prover.prove(trace).unwrap();
+                    results.lock().unwrap()[idx] = Some((proof, pub_inputs));
+                });
+            }
+        });
+        results.into_inner().unwrap().into_iter().map(|result| result.unwrap()).collect()
+    }
+
+    /// Builds the trace of one segment, absorbing its pixels into the sponge state `state_in`.
+    pub fn build_trace(pixels: &[u16], state_in: &[B], avg_rounded: B) -> TraceTable<B> {
+        // The final state is located at step i = (pixels.len() / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH
+        assert_eq!(8 * Self::FACTOR_M, H::RATE);
+        assert_eq!(H::STATE_WIDTH, state_in.len());
+        assert!(!pixels.is_empty());
+        assert_eq!(0, pixels.len() % Self::NUM_ELEMS_PER_CYCLE);
+        let trace_len = next_power_of_two((pixels.len() / Self::NUM_ELEMS_PER_CYCLE) * Self::CYCLE_LENGTH + 1);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_segment_meta(pixels.len(), avg_rounded.as_u128() as usize));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+                state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()].copy_from_slice(state_in);
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH; // cyclic_step can be considered the index in the masks
+                if cyclic_step == 0 {
+                    // read elements into trace
+                    for c in 0..Self::NUM_ELEMS_PER_CYCLE {
+                        let next_pixel_idx = Self::NUM_ELEMS_PER_CYCLE * step / Self::CYCLE_LENGTH + c;
+                        if next_pixel_idx < pixels.len() {
+                            state[Self::T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                        }
+                    }
+                    // absorb elements into hash state
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += state[Self::T_PIXELS.idx + c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                if cyclic_step < NUM_IDENTITY_MASKS {
+                    for i in 0..Self::FACTOR_M {
+                        let pixel = state[Self::T_PIXELS.begin() + i * NUM_IDENTITY_MASKS + cyclic_step];
+                        state[Self::T_SUM.begin()] += pixel;
+                        state[Self::T_VAR.begin()] += (pixel - avg_rounded) * (pixel - avg_rounded);
+                    }
+                }
+            }
+        );
+        table
+    }
+
+    /// Verifies the proofs of the segments of a video in order and checks that they link up: the
+    /// first segment starts with the initial sponge state, every other one with the final state of
+    /// its predecessor, and all of them use the rounded average of the whole video. Returns the hash
+    /// and the statistics of the whole video.
+    pub fn verify_segments(segments: Vec<(StarkProof, PubInputs<H>)>) -> Result<SegmentOutputs<H>, SegmentError> {
+        let (_, first) = segments.first().ok_or(SegmentError::Empty)?;
+        if first.state_in != Self::initial_state() {
+            return Err(SegmentError::InitialState);
+        }
+        let avg_rounded = first.avg_rounded;
+        let mut input_length = 0;
+        let mut sum = B::ZERO;
+        let mut variance = B::ZERO;
+        for (idx, (_, pub_inputs)) in segments.iter().enumerate() {
+            if idx > 0 && pub_inputs.state_in != segments[idx - 1].1.state_out {
+                return Err(SegmentError::BrokenChain(idx));
+            }
+            if pub_inputs.avg_rounded != avg_rounded {
+                return Err(SegmentError::AverageMismatch(idx));
+            }
+            input_length += pub_inputs.segment_length.as_u128() as usize;
+            sum += pub_inputs.sum;
+            variance += pub_inputs.variance;
+        }
+        if avg_rounded.as_u128() != sum.as_u128().rounded_div(input_length as u128) {
+            return Err(SegmentError::WrongAverage);
+        }
+        let (_, last) = segments.last().unwrap();
+        let hash = H::Digest::try_from(&last.state_out[..H::RATE]).unwrap();
+
+        for (idx, (proof, pub_inputs)) in segments.into_iter().enumerate() {
+            winterfell::verify::<Self>(proof, pub_inputs).map_err(|err| SegmentError::Verification(idx, err))?;
+        }
+        Ok(SegmentOutputs { hash, input_length, sum, avg_rounded, variance })
+    }
+}
+
+// meta of a segment trace: segment length followed by the rounded average of the whole video
+fn create_segment_meta(num: usize, avg_rounded: usize) -> Vec<u8> {
+    let mut result = create_meta(num);
+    result.append(&mut create_meta(avg_rounded));
+    result
+}
+
+fn get_segment_meta(meta: &Vec<u8>) -> (usize, usize) {
+    let num_bytes = size_of::<usize>();
+    (get_meta(&meta[..num_bytes].to_vec()), get_meta(&meta[num_bytes..].to_vec()))
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for elem in self.state_in.iter().chain(self.state_out.iter()) {
+            target.write(*elem);
+        }
+        target.write(self.segment_length);
+        target.write(self.sum);
+        target.write(self.avg_rounded);
+        target.write(self.variance);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut state_in = vec![H::BaseField::ZERO; H::STATE_WIDTH];
+        for elem in state_in.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let mut state_out = vec![H::BaseField::ZERO; H::STATE_WIDTH];
+        for elem in state_out.iter_mut() {
+            *elem = H::BaseField::read_from(source)?;
+        }
+        let segment_length = H::BaseField::read_from(source)?;
+        let sum = H::BaseField::read_from(source)?;
+        let avg_rounded = H::BaseField::read_from(source)?;
+        let variance = H::BaseField::read_from(source)?;
+        let num_elems_per_cycle = TheAir::<H::BaseField, H>::NUM_ELEMS_PER_CYCLE as u128;
+        if segment_length.as_u128() == 0 || segment_length.as_u128() % num_elems_per_cycle != 0 {
+            return Err(DeserializationError::InvalidValue(format!("segment length {} is not a positive multiple of {}", segment_length.as_u128(), num_elems_per_cycle)));
+        }
+        Ok(PubInputs { state_in, state_out, segment_length, sum, avg_rounded, variance })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees = vec![];
+
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        // C_SUM
+        degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        // C_VAR
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![Self::CYCLE_LENGTH]));
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+
+        let num_assertions = 2 * H::STATE_WIDTH + 4;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            state_in: pub_inputs.state_in,
+            state_out: pub_inputs.state_out,
+            segment_length: pub_inputs.segment_length,
+            sum: pub_inputs.sum,
+            avg_rounded: pub_inputs.avg_rounded,
+            variance: pub_inputs.variance,
+            _hash: PhantomData,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(Self::P_IDENTITY);
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(Self::T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag);
+        enforce_copy(&mut result[Self::C_COPY.begin()..Self::C_COPY.end()], current.id_slice(Self::T_PIXELS), next.id_slice(Self::T_PIXELS), copy_flag);
+        enforce_sum(&mut result[Self::C_SUM.begin()..Self::C_SUM.end()], next.id_slice(Self::T_PIXELS), identity, current.id_slice(Self::T_SUM)[0], next.id_slice(Self::T_SUM)[0], E::ONE);
+        enforce_var(&mut result[Self::C_VAR.begin()..Self::C_VAR.end()], next.id_slice(Self::T_PIXELS), identity, current.id_slice(Self::T_VAR)[0], next.id_slice(Self::T_VAR)[0], E::from(self.avg_rounded), E::ONE);
+        if Self::COPY_HASH {
+            let copy_hash_flag = copy_flag - hash_remaining_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let idx_result = Self::CYCLE_LENGTH * (self.segment_length.as_u128() as usize) / Self::NUM_ELEMS_PER_CYCLE;
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, self.state_in[c]));
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_result, self.state_out[c]));
+        }
+        result.push(Assertion::single(Self::T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_VAR.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_SUM.begin(), idx_result, self.sum));
+        result.push(Assertion::single(Self::T_VAR.begin(), idx_result, self.variance));
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        periodic_columns.append(&mut get_identity_masks(Self::CYCLE_LENGTH));
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        periodic_columns
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (segment_length, avg_rounded) = get_segment_meta(&trace.meta().to_vec());
+        let result_step = segment_length * TheAir::<B, H>::CYCLE_LENGTH / TheAir::<B, H>::NUM_ELEMS_PER_CYCLE;
+        let mut state_in = vec![B::ZERO; H::STATE_WIDTH];
+        let mut state_out = vec![B::ZERO; H::STATE_WIDTH];
+        for c in 0..H::STATE_WIDTH {
+            state_in[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, 0);
+            state_out[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, result_step);
+        }
+        PubInputs {
+            state_in,
+            state_out,
+            segment_length: B::from(segment_length as u64),
+            sum: trace.get(TheAir::<B, H>::T_SUM.begin(), result_step),
+            avg_rounded: B::from(avg_rounded as u64),
+            variance: trace.get(TheAir::<B, H>::T_VAR.begin(), result_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
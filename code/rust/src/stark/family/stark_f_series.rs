@@ -0,0 +1,537 @@
+use std::marker::PhantomData;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, Trace, TraceInfo, TraceTable, TransitionConstraintDegree};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use crate::commitment::{CommitmentError, FrameTree};
+use crate::permutation::{get_padding, HashPermutation, StarkBaseField};
+use crate::utils::{IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{fill_unconstrained_tail, get_hash_mask, get_identity_masks, NUM_IDENTITY_MASKS};
+use super::stark_f::{enforce_copy, enforce_sum};
+use super::stark_f_frames::{self, check_frame_range, create_frames_meta, enforce_copy_or_reset, get_frames_meta, read_digest, verify_frame_paths, write_digest};
+
+// Time series of per-frame statistics over a range of frames committed to with a frame tree. The
+// frames are laid out and hashed as in stark_f_frames (FRAME_BLOCKS blocks per frame, the hash state
+// is reset at the end of a frame). Instead of statistics of the whole range, the AET computes the sum,
+// minimum and maximum of every frame and absorbs them into an output sponge, whose digest is a public
+// input. The time series itself is opened against this digest (see `verify_series`).
+//
+// The pixel masks select pixel i of a block in step i, restricted to the pixels of the frame (without
+// the padding). The sum is reset to zero at the end of a frame. The minimum and maximum are witnesses
+// which are constant within a frame: every pixel of the frame satisfies min <= pixel <= max, shown by
+// the bit decompositions of pixel - min and max - pixel (the bits are free in the other steps), and the
+// products of max - pixel and pixel - min over the frame are zero, i.e. both are taken by a pixel. The
+// output sponge absorbs (sum, min, max) in the first idle block of a frame, after the last pixel has
+// been added.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+
+// number of bits of the differences to the minimum and maximum
+pub const NUM_DIFF_BITS: usize = 16;
+
+/// Statistics of one frame of the time series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameStats<B: StarkBaseField> {
+    pub sum: B,
+    pub min: B,
+    pub max: B,
+}
+
+pub struct PubInputs<H: HashPermutation> {
+    pub root: H::Digest,
+    // number of frames of the committed video
+    pub num_frames: H::BaseField,
+    pub frame_size: H::BaseField,
+    // the proven range of frames
+    pub first_frame: H::BaseField,
+    pub digests: Vec<H::Digest>,
+    pub paths: Vec<Vec<H::Digest>>,
+    // digest of the time series
+    pub series: H::Digest,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    frame_size: usize,
+    digests: Vec<H::Digest>,
+    series: H::Digest,
+}
+
+type Frames<B, H> = stark_f_frames::TheAir<B, H>;
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    pub const CYCLE_LENGTH: usize = Frames::<B, H>::CYCLE_LENGTH;
+    pub const TRACE_WIDTH: usize = 8 + 2 * H::STATE_WIDTH + 5 + 2 * NUM_DIFF_BITS;
+
+    // AET index definitions
+    const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 };
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: 8, size: H::STATE_WIDTH };
+    pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8 + H::STATE_WIDTH, size: 1 };
+    pub const T_MIN: IndexDefinition = IndexDefinition { idx: Self::T_SUM.idx + 1, size: 1 };
+    pub const T_MAX: IndexDefinition = IndexDefinition { idx: Self::T_MIN.idx + 1, size: 1 };
+    const T_Z_MIN: IndexDefinition = IndexDefinition { idx: Self::T_MAX.idx + 1, size: 1 };
+    const T_Z_MAX: IndexDefinition = IndexDefinition { idx: Self::T_Z_MIN.idx + 1, size: 1 };
+    const T_MIN_BITS: IndexDefinition = IndexDefinition { idx: Self::T_Z_MAX.idx + 1, size: NUM_DIFF_BITS };
+    const T_MAX_BITS: IndexDefinition = IndexDefinition { idx: Self::T_MIN_BITS.idx + NUM_DIFF_BITS, size: NUM_DIFF_BITS };
+    pub const T_SERIES_HASH: IndexDefinition = IndexDefinition { idx: Self::T_MAX_BITS.idx + NUM_DIFF_BITS, size: H::STATE_WIDTH };
+
+    // constraint index definitions
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: 0, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY: IndexDefinition = IndexDefinition {idx: 2 * H::STATE_WIDTH, size: 8};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_COPY.idx + 8, size: H::STATE_WIDTH};
+    const C_SUM: IndexDefinition = IndexDefinition {idx: Self::C_COPY_HASH.idx + H::STATE_WIDTH, size: 1};
+    const C_MIN_MAX: IndexDefinition = IndexDefinition {idx: Self::C_SUM.idx + 1, size: 2};
+    const C_DIFF: IndexDefinition = IndexDefinition {idx: Self::C_MIN_MAX.idx + 2, size: 2};
+    const C_BITS: IndexDefinition = IndexDefinition {idx: Self::C_DIFF.idx + 2, size: 2 * NUM_DIFF_BITS};
+    const C_Z: IndexDefinition = IndexDefinition {idx: Self::C_BITS.idx + 2 * NUM_DIFF_BITS, size: 2};
+    const C_SERIES_FIRST: IndexDefinition = IndexDefinition {idx: Self::C_Z.idx + 2, size: H::STATE_WIDTH};
+    const C_SERIES_REMAINING: IndexDefinition = IndexDefinition {idx: Self::C_SERIES_FIRST.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_SERIES_COPY: IndexDefinition = IndexDefinition {idx: Self::C_SERIES_REMAINING.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+
+    // periodic column index definitions
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: 0, size: H::NUM_PERIODIC_COLUMNS };
+    const P_IDENTITY: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS, size: NUM_IDENTITY_MASKS };
+    const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: H::NUM_PERIODIC_COLUMNS + NUM_IDENTITY_MASKS, size: 1 };
+    const P_PIXELS: IndexDefinition = IndexDefinition { idx: Self::P_HASH_FLAG.idx + 1, size: NUM_IDENTITY_MASKS };
+    const P_FRAME_END: IndexDefinition = IndexDefinition { idx: Self::P_PIXELS.idx + NUM_IDENTITY_MASKS, size: 1 };
+    const P_SERIES_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: Self::P_FRAME_END.idx + 1, size: H::NUM_PERIODIC_COLUMNS };
+    const P_SERIES_FIRST: IndexDefinition = IndexDefinition { idx: Self::P_SERIES_ROUND_CONSTANTS.idx + H::NUM_PERIODIC_COLUMNS, size: 1 };
+    const P_SERIES_FLAG: IndexDefinition = IndexDefinition { idx: Self::P_SERIES_FIRST.idx + 1, size: 1 };
+
+    /// Row of the AET the digest of the time series of `num_frames` frames is located at.
+    pub fn get_series_step(frame_size: usize, num_frames: usize) -> usize {
+        Self::get_series_shift(frame_size, num_frames - 1) + H::NUM_ROUNDS
+    }
+
+    // row the output sponge absorbs the statistics of frame `frame` in, the first idle block
+    fn get_series_shift(frame_size: usize, frame: usize) -> usize {
+        (frame * Frames::<B, H>::get_frame_blocks(frame_size) + Frames::<B, H>::get_num_blocks(frame_size)) * Self::CYCLE_LENGTH
+    }
+
+    /// Per-frame statistics of the consecutive frames of `frame_size` pixels in `pixels`.
+    pub fn get_series(pixels: &[u16], frame_size: usize) -> Vec<FrameStats<B>> {
+        pixels.chunks(frame_size).map(|frame| FrameStats {
+            sum: B::from(frame.iter().map(|p| *p as u64).sum::<u64>()),
+            min: B::from(*frame.iter().min().unwrap()),
+            max: B::from(*frame.iter().max().unwrap()),
+        }).collect()
+    }
+
+    /// Native computation of the digest of a time series: every frame is absorbed as one block
+    /// (sum, min, max, 0, ..).
+    pub fn hash_series(series: &[FrameStats<B>]) -> H::Digest {
+        let mut elements = vec![B::ZERO; series.len() * H::RATE];
+        for (i, stats) in series.iter().enumerate() {
+            elements[i * H::RATE] = stats.sum;
+            elements[i * H::RATE + 1] = stats.min;
+            elements[i * H::RATE + 2] = stats.max;
+        }
+        H::hash(&elements)
+    }
+
+    /// Builds the trace of the consecutive frames of `frame_size` pixels in `pixels`.
+    pub fn build_trace(pixels: &[u16], frame_size: usize) -> TraceTable<B> {
+        assert_eq!(8, H::RATE);
+        assert!(H::NUM_ROUNDS > 1);
+        assert!(frame_size >= H::RATE && !pixels.is_empty() && pixels.len() % frame_size == 0, "the input has to consist of whole frames of at least {} pixels", H::RATE);
+        let num_frames = pixels.len() / frame_size;
+        let num_blocks = Frames::<B, H>::get_num_blocks(frame_size);
+        let frame_cycle = Frames::<B, H>::get_frame_blocks(frame_size) * Self::CYCLE_LENGTH;
+        let series_shift = Self::get_series_shift(frame_size, 0);
+        let padding = get_padding::<B>(frame_size, H::RATE);
+        let series = Self::get_series(pixels, frame_size);
+        let trace_len = next_power_of_two(Self::get_series_step(frame_size, num_frames) + 1);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_frames_meta(pixels.len(), frame_size));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+                state[Self::T_MIN.idx] = series[0].min;
+                state[Self::T_MAX.idx] = series[0].max;
+                state[Self::T_Z_MIN.idx] = B::ONE;
+                state[Self::T_Z_MAX.idx] = B::ONE;
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % Self::CYCLE_LENGTH;
+                let frame = step / frame_cycle;
+                let frame_step = step % frame_cycle;
+                let block = frame_step / Self::CYCLE_LENGTH;
+                // the output sponge absorbs the statistics of the current row
+                if frame_step == series_shift {
+                    state[Self::T_SERIES_HASH.idx] += state[Self::T_SUM.idx];
+                    state[Self::T_SERIES_HASH.idx + 1] += state[Self::T_MIN.idx];
+                    state[Self::T_SERIES_HASH.idx + 2] += state[Self::T_MAX.idx];
+                }
+                if frame_step >= series_shift && frame_step < series_shift + H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_SERIES_HASH.begin()..Self::T_SERIES_HASH.end()], frame_step - series_shift);
+                }
+                if cyclic_step == 0 {
+                    // read elements of the padded frame into trace, the idle blocks read zeros
+                    for c in 0..H::RATE {
+                        let elem_idx = block * H::RATE + c;
+                        state[Self::T_PIXELS.idx + c] = if frame >= num_frames || block >= num_blocks {
+                            B::ZERO
+                        } else if elem_idx < frame_size {
+                            B::from(pixels[frame * frame_size + elem_idx])
+                        } else {
+                            padding[elem_idx - frame_size]
+                        };
+                    }
+                    for c in 0..H::RATE {
+                        state[Self::T_PIXELS_HASH.idx + c] += state[Self::T_PIXELS.idx + c];
+                    }
+                }
+                if cyclic_step < H::NUM_ROUNDS {
+                    H::apply_round(&mut state[Self::T_PIXELS_HASH.begin()..Self::T_PIXELS_HASH.end()], cyclic_step);
+                }
+                if cyclic_step < NUM_IDENTITY_MASKS && block * H::RATE + cyclic_step < frame_size {
+                    // pixel mask: min <= pixel <= max, the products over the frame and the sum
+                    let pixel = state[Self::T_PIXELS.idx + cyclic_step];
+                    let (min, max) = (state[Self::T_MIN.idx], state[Self::T_MAX.idx]);
+                    set_bits(&mut state[Self::T_MIN_BITS.begin()..Self::T_MIN_BITS.end()], pixel - min);
+                    set_bits(&mut state[Self::T_MAX_BITS.begin()..Self::T_MAX_BITS.end()], max - pixel);
+                    state[Self::T_Z_MIN.idx] *= pixel - min;
+                    state[Self::T_Z_MAX.idx] *= max - pixel;
+                    state[Self::T_SUM.idx] += pixel;
+                } else {
+                    fill_unconstrained_tail(state, &[Self::T_MIN_BITS, Self::T_MAX_BITS]);
+                }
+                if frame_step == frame_cycle - 1 {
+                    // reset at the end of a frame
+                    for c in 0..H::STATE_WIDTH {
+                        state[Self::T_PIXELS_HASH.idx + c] = B::ZERO;
+                    }
+                    let next = series.get(frame + 1).copied().unwrap_or(FrameStats { sum: B::ZERO, min: B::ZERO, max: B::ZERO });
+                    state[Self::T_SUM.idx] = B::ZERO;
+                    state[Self::T_MIN.idx] = next.min;
+                    state[Self::T_MAX.idx] = next.max;
+                    state[Self::T_Z_MIN.idx] = B::ONE;
+                    state[Self::T_Z_MAX.idx] = B::ONE;
+                }
+            }
+        );
+        table
+    }
+
+    /// Checks that the frame digests of the public inputs belong to the committed root.
+    pub fn verify_paths(pub_inputs: &PubInputs<H>) -> Result<(), CommitmentError> {
+        verify_frame_paths::<H>(&pub_inputs.root, pub_inputs.num_frames, pub_inputs.first_frame, &pub_inputs.digests, &pub_inputs.paths)
+    }
+
+    /// Checks that a time series is the one the public inputs commit to.
+    pub fn verify_series(pub_inputs: &PubInputs<H>, series: &[FrameStats<B>]) -> Result<(), CommitmentError> {
+        if series.len() != pub_inputs.digests.len() {
+            return Err(CommitmentError::Mismatch(format!("the proof covers {} frames, the time series has {}", pub_inputs.digests.len(), series.len())));
+        }
+        if Self::hash_series(series) != pub_inputs.series {
+            return Err(CommitmentError::Mismatch(String::from("the time series does not hash to its digest")));
+        }
+        Ok(())
+    }
+}
+
+// bit decomposition of a difference of two pixels
+fn set_bits<B: StarkBaseField>(bits: &mut [B], value: B) {
+    let value = value.as_u128();
+    assert!(value < 1 << NUM_DIFF_BITS);
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = B::from(((value >> i) & 1) as u64);
+    }
+}
+
+impl<B: StarkBaseField> Serializable for FrameStats<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.sum);
+        target.write(self.min);
+        target.write(self.max);
+    }
+}
+
+impl<B: StarkBaseField> Deserializable for FrameStats<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(FrameStats { sum: B::read_from(source)?, min: B::read_from(source)?, max: B::read_from(source)? })
+    }
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_digest::<H, W>(target, &self.root);
+        target.write(self.num_frames);
+        target.write(self.frame_size);
+        target.write(self.first_frame);
+        target.write_u32(self.digests.len() as u32);
+        for digest in self.digests.iter() {
+            write_digest::<H, W>(target, digest);
+        }
+        for path in self.paths.iter() {
+            target.write_u32(path.len() as u32);
+            for node in path.iter() {
+                write_digest::<H, W>(target, node);
+            }
+        }
+        write_digest::<H, W>(target, &self.series);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = read_digest::<H, R>(source)?;
+        let num_frames = H::BaseField::read_from(source)?;
+        let frame_size = H::BaseField::read_from(source)?;
+        let first_frame = H::BaseField::read_from(source)?;
+        let mut digests = vec![];
+        for _ in 0..source.read_u32()? {
+            digests.push(read_digest::<H, R>(source)?);
+        }
+        let mut paths = vec![];
+        for _ in 0..digests.len() {
+            let mut path = vec![];
+            for _ in 0..source.read_u32()? {
+                path.push(read_digest::<H, R>(source)?);
+            }
+            paths.push(path);
+        }
+        check_frame_range::<H>(num_frames, frame_size, first_frame, &paths).map_err(DeserializationError::InvalidValue)?;
+        if frame_size.as_u128() < H::RATE as u128 {
+            return Err(DeserializationError::InvalidValue(format!("frames of less than {} pixels are not supported", H::RATE)));
+        }
+        let series = read_digest::<H, R>(source)?;
+        Ok(PubInputs { root, num_frames, frame_size, first_frame, digests, paths, series })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let frame_size = pub_inputs.frame_size.as_u128() as usize;
+        let frame_cycle = Frames::<B, H>::get_frame_blocks(frame_size) * Self::CYCLE_LENGTH;
+        let num_frames = pub_inputs.digests.len();
+        let mut degrees = vec![];
+
+        for _ in 0..2 * H::STATE_WIDTH {
+            degrees.push(H::round_constraint_degree(vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH]));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![Self::CYCLE_LENGTH, frame_cycle]));
+        }
+        // C_SUM, C_MIN_MAX, C_DIFF
+        for _ in 0..5 {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![frame_cycle]));
+        }
+        for _ in 0..Self::C_BITS.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(2, vec![frame_cycle]));
+        }
+        for _ in 0..Self::C_Z.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(2, vec![frame_cycle]));
+        }
+        for _ in 0..2 * H::STATE_WIDTH {
+            degrees.push(H::round_constraint_degree(vec![frame_cycle]));
+        }
+        for _ in 0..Self::C_SERIES_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![frame_cycle]));
+        }
+
+        let num_padding = get_padding::<B>(frame_size, H::RATE).len();
+        let num_assertions = 2 * H::STATE_WIDTH + num_frames * (H::RATE + num_padding + 2) + 3 + H::RATE;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            frame_size,
+            digests: pub_inputs.digests,
+            series: pub_inputs.series,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(Self::P_IDENTITY);
+        let pixel_masks = periodic_values.id_slice(Self::P_PIXELS);
+        let series_constants = periodic_values.id_slice(Self::P_SERIES_ROUND_CONSTANTS);
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[Self::P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+        let copy_hash_flag = copy_flag - hash_remaining_flag;
+        let frame_end = periodic_values[Self::P_FRAME_END.idx];
+        let series_first_flag = periodic_values[Self::P_SERIES_FIRST.idx];
+        let series_remaining_flag = periodic_values[Self::P_SERIES_FLAG.idx];
+
+        // hash of the frames as in stark_f_frames
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(Self::T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag);
+        enforce_copy(&mut result[Self::C_COPY.begin()..Self::C_COPY.end()], current.id_slice(Self::T_PIXELS), next.id_slice(Self::T_PIXELS), copy_flag);
+        enforce_copy_or_reset(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag, frame_end);
+
+        // sum of a frame, reset at the end of a frame (where the pixel masks are zero)
+        let pixels = next.id_slice(Self::T_PIXELS);
+        let current_sum = current[Self::T_SUM.idx];
+        enforce_sum(&mut result[Self::C_SUM.begin()..Self::C_SUM.end()], pixels, pixel_masks, (E::ONE - frame_end) * current_sum, next[Self::T_SUM.idx], E::ONE);
+
+        // minimum and maximum are constant within a frame
+        let (min, max) = (next[Self::T_MIN.idx], next[Self::T_MAX.idx]);
+        result[Self::C_MIN_MAX.idx] += (E::ONE - frame_end) * (current[Self::T_MIN.idx] - min);
+        result[Self::C_MIN_MAX.idx + 1] += (E::ONE - frame_end) * (current[Self::T_MAX.idx] - max);
+
+        // pixel - min and max - pixel are given by their bits
+        let min_diff = compose_bits(next.id_slice(Self::T_MIN_BITS));
+        let max_diff = compose_bits(next.id_slice(Self::T_MAX_BITS));
+        for i in 0..NUM_IDENTITY_MASKS {
+            result[Self::C_DIFF.idx] += pixel_masks[i] * (min_diff - pixels[i] + min);
+            result[Self::C_DIFF.idx + 1] += pixel_masks[i] * (max_diff - max + pixels[i]);
+        }
+        let pixel_flag = pixel_masks.iter().fold(E::ZERO, |acc, mask| acc + *mask);
+        for (c, bit) in next.id_slice(Self::T_MIN_BITS).iter().chain(next.id_slice(Self::T_MAX_BITS).iter()).enumerate() {
+            result[Self::C_BITS.idx + c] += pixel_flag * *bit * (*bit - E::ONE);
+        }
+
+        // products of the differences over a frame, reset to one at the end of a frame
+        let mut min_factor = E::ONE;
+        let mut max_factor = E::ONE;
+        for i in 0..NUM_IDENTITY_MASKS {
+            min_factor += pixel_masks[i] * (pixels[i] - min - E::ONE);
+            max_factor += pixel_masks[i] * (max - pixels[i] - E::ONE);
+        }
+        let (z_min, z_max) = (current[Self::T_Z_MIN.idx], current[Self::T_Z_MAX.idx]);
+        result[Self::C_Z.idx] += z_min * min_factor - frame_end * (z_min - E::ONE) - next[Self::T_Z_MIN.idx];
+        result[Self::C_Z.idx + 1] += z_max * max_factor - frame_end * (z_max - E::ONE) - next[Self::T_Z_MAX.idx];
+
+        // output sponge absorbing (sum, min, max) of a frame
+        let mut stats = vec![E::ZERO; H::RATE];
+        stats[0] = current_sum;
+        stats[1] = current[Self::T_MIN.idx];
+        stats[2] = current[Self::T_MAX.idx];
+        H::enforce_first_round(&mut result[Self::C_SERIES_FIRST.begin()..Self::C_SERIES_FIRST.end()], &stats, current.id_slice(Self::T_SERIES_HASH), next.id_slice(Self::T_SERIES_HASH), series_constants, series_first_flag);
+        H::enforce_round(&mut result[Self::C_SERIES_REMAINING.begin()..Self::C_SERIES_REMAINING.end()], current.id_slice(Self::T_SERIES_HASH), next.id_slice(Self::T_SERIES_HASH), series_constants, series_remaining_flag);
+        enforce_copy(&mut result[Self::C_SERIES_COPY.begin()..Self::C_SERIES_COPY.end()], current.id_slice(Self::T_SERIES_HASH), next.id_slice(Self::T_SERIES_HASH), E::ONE - series_first_flag - series_remaining_flag);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let num_frames = self.digests.len();
+        let padding = get_padding::<B>(self.frame_size, H::RATE);
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+            result.push(Assertion::single(Self::T_SERIES_HASH.begin() + c, 0, B::ZERO));
+        }
+        result.push(Assertion::single(Self::T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(Self::T_Z_MIN.begin(), 0, B::ONE));
+        result.push(Assertion::single(Self::T_Z_MAX.begin(), 0, B::ONE));
+        for (frame, digest) in self.digests.iter().enumerate() {
+            let idx_digest = Frames::<B, H>::get_digest_step(self.frame_size, frame);
+            for c in 0..H::RATE {
+                result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_digest, digest.as_ref()[c]));
+            }
+            // row the last block (with the padding) of the frame is read into
+            let idx_padding = idx_digest - H::NUM_ROUNDS + 1;
+            for (c, elem) in padding.iter().enumerate() {
+                result.push(Assertion::single(Self::T_PIXELS.begin() + H::RATE - padding.len() + c, idx_padding, *elem));
+            }
+            // minimum and maximum are taken by a pixel of the frame
+            let idx_stats = Self::get_series_shift(self.frame_size, frame);
+            result.push(Assertion::single(Self::T_Z_MIN.begin(), idx_stats, B::ZERO));
+            result.push(Assertion::single(Self::T_Z_MAX.begin(), idx_stats, B::ZERO));
+        }
+        let idx_series = Self::get_series_step(self.frame_size, num_frames);
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_SERIES_HASH.begin() + c, idx_series, self.series.as_ref()[c]));
+        }
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let frame_cycle = Frames::<B, H>::get_frame_blocks(self.frame_size) * Self::CYCLE_LENGTH;
+        let series_shift = Self::get_series_shift(self.frame_size, 0);
+        let mut periodic_columns = H::get_round_constants_periodic(Self::CYCLE_LENGTH, ROUND_CONSTS_SHIFT);
+        periodic_columns.append(&mut get_identity_masks(Self::CYCLE_LENGTH));
+        periodic_columns.push(get_hash_mask(H::NUM_ROUNDS, Self::CYCLE_LENGTH));
+        // pixel masks: pixel i of a block in step i, without the padding and the idle blocks
+        let mut pixel_masks = vec![vec![B::ZERO; frame_cycle]; NUM_IDENTITY_MASKS];
+        for idx in 0..self.frame_size {
+            pixel_masks[idx % H::RATE][(idx / H::RATE) * Self::CYCLE_LENGTH + idx % H::RATE] = B::ONE;
+        }
+        periodic_columns.append(&mut pixel_masks);
+        let mut frame_end = vec![B::ZERO; frame_cycle];
+        frame_end[frame_cycle - 1] = B::ONE;
+        periodic_columns.push(frame_end);
+        // rounds of the output sponge in the first idle block of a frame
+        periodic_columns.append(&mut H::get_round_constants_periodic(frame_cycle, series_shift));
+        let mut series_first = vec![B::ZERO; frame_cycle];
+        series_first[series_shift] = B::ONE;
+        periodic_columns.push(series_first);
+        let mut series_flag = vec![B::ZERO; frame_cycle];
+        for i in 1..H::NUM_ROUNDS {
+            series_flag[series_shift + i] = B::ONE;
+        }
+        periodic_columns.push(series_flag);
+        periodic_columns
+    }
+}
+
+fn compose_bits<E: FieldElement>(bits: &[E]) -> E {
+    bits.iter().rev().fold(E::ZERO, |acc, bit| acc.double() + *bit)
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    root: H::Digest,
+    num_frames: usize,
+    first_frame: usize,
+    paths: Vec<Vec<H::Digest>>,
+    _field: PhantomData<B>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    /// Prover of the time series of the `num_frames` frames of `tree` starting at `first_frame`.
+    pub fn new(options: ProofOptions, tree: &FrameTree<H>, first_frame: usize, num_frames: usize) -> Self {
+        assert!(num_frames > 0 && first_frame + num_frames <= tree.num_frames(), "frame range out of bounds");
+        let paths = (first_frame..first_frame + num_frames).map(|index| tree.get_path(index)).collect();
+        Self { options, root: tree.root(), num_frames: tree.num_frames(), first_frame, paths, _field: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, frame_size) = get_frames_meta(&trace.meta().to_vec());
+        let num_frames = input_length / frame_size;
+        let read_digest = |column: usize, step: usize| {
+            let digest = (0..H::RATE).map(|c| trace.get(column + c, step)).collect::<Vec<_>>();
+            H::Digest::try_from(&digest[..]).unwrap()
+        };
+        PubInputs {
+            root: self.root,
+            num_frames: B::from(self.num_frames as u64),
+            frame_size: B::from(frame_size as u64),
+            first_frame: B::from(self.first_frame as u64),
+            digests: (0..num_frames).map(|frame| read_digest(TheAir::<B, H>::T_PIXELS_HASH.idx, Frames::<B, H>::get_digest_step(frame_size, frame))).collect(),
+            paths: self.paths.clone(),
+            series: read_digest(TheAir::<B, H>::T_SERIES_HASH.idx, TheAir::<B, H>::get_series_step(frame_size, num_frames)),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,650 @@
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::str::FromStr;
+use winterfell::{Air, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Prover, Serializable, SliceReader, Trace, TraceInfo, TraceTable, TransitionConstraintDegree};
+use winterfell::math::FieldElement;
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use rounded_div::RoundedDiv;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::utils::range_check::{apply_range_check, enforce_range_check, get_num_bits, get_rounded_div_remainders, get_rounded_div_remainders_e, NUM_REMAINDERS};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{create_salted_meta, get_salted_meta, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use super::{get_hash_mask, NUM_IDENTITY_MASKS};
+
+// STARK G: STARK F restricted to a region of interest (ROI) within each frame of the video. H must
+// absorb 8 pixels (RATE = 8) and have at most 8 rounds, the columns of the hash state come last.
+
+// Major problems with evaluating constants for huge cycles (e.g. a frame size of 110016)
+// ERROR: exceeded interpreter step limit (see `#[const_eval_limit]`)
+// quick hack: change constants to getter functions
+// furthermore stack overflow for 110016, need to replace arrays with vecs
+
+// The frame (resolution of the video) and the ROI are runtime parameters, see FrameConfig. They are
+// part of the public inputs, the cycle of the masks (cycle_length_roi) is derived from them.
+
+const ROUND_CONSTS_SHIFT: usize = 0;
+pub const CYCLE_LENGTH: usize = 8;
+
+// default resolution of the video (camera of the industry partner), the frame size must be a multiple of CYCLE_LENGTH
+pub const FRAME_WIDTH: usize = 382;
+pub const FRAME_HEIGHT: usize = 288;
+// upper bound of the frame size, the periodic columns of the masks have (a power of two of at least) this length
+pub const MAX_FRAME_SIZE: usize = 1 << 24;
+
+/// Rectangular region of interest within a frame: `width` x `height` pixels starting at column `x`
+/// and row `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Roi {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl FromStr for Roi {
+    type Err = String;
+
+    /// Parses `x,y,width,height`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s.split(',').map(|v| v.trim().parse::<usize>()).collect::<Result<Vec<usize>, _>>()
+            .map_err(|_| format!("invalid ROI '{}', expected x,y,width,height", s))?;
+        match values[..] {
+            [x, y, width, height] => Ok(Roi { x, y, width, height }),
+            _ => Err(format!("invalid ROI '{}', expected x,y,width,height", s)),
+        }
+    }
+}
+
+/// Resolution of the frames and the ROIs (their union) to compute the statistics on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameConfig {
+    pub width: usize,
+    pub height: usize,
+    pub rois: Vec<Roi>,
+}
+
+impl Default for FrameConfig {
+    /// Default resolution with the first four pixels of each frame as ROI.
+    fn default() -> Self {
+        FrameConfig { width: FRAME_WIDTH, height: FRAME_HEIGHT, rois: vec![Roi { x: 0, y: 0, width: 4, height: 1 }] }
+    }
+}
+
+impl FrameConfig {
+    pub fn new(width: usize, height: usize, rois: Vec<Roi>) -> Result<Self, String> {
+        let result = FrameConfig { width, height, rois };
+        result.validate()?;
+        Ok(result)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self.width.checked_mul(self.height) {
+            Some(size) if size > 0 && size % CYCLE_LENGTH == 0 && size <= MAX_FRAME_SIZE => (),
+            _ => return Err(format!("frame size {}x{} is not a positive multiple of {} of at most {} pixels", self.width, self.height, CYCLE_LENGTH, MAX_FRAME_SIZE)),
+        }
+        if self.rois.is_empty() {
+            return Err(String::from("at least one ROI is required"));
+        }
+        for roi in self.rois.iter() {
+            if roi.width == 0 || roi.height == 0 || roi.x >= self.width || roi.width > self.width - roi.x || roi.y >= self.height || roi.height > self.height - roi.y {
+                return Err(format!("ROI {},{},{},{} is empty or exceeds the frame of {}x{}", roi.x, roi.y, roi.width, roi.height, self.width, self.height));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a video of `input_length` pixels consists of at least one whole frame.
+    pub fn check_input_length(&self, input_length: u128) -> Result<(), String> {
+        if input_length == 0 || input_length % self.frame_size() as u128 != 0 {
+            return Err(format!("input length {} is not a positive multiple of the frame size {}", input_length, self.frame_size()));
+        }
+        Ok(())
+    }
+
+    /// Number of pixels of one frame
+    pub fn frame_size(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Cycle that fully encapsulates one frame, a power of two
+    pub fn cycle_length_roi(&self) -> usize {
+        next_power_of_two(self.frame_size())
+    }
+
+    pub fn get_identity_mask_roi<B: FieldElement>(&self) -> Vec<Vec<B>> {
+        let mut result = vec![vec![B::ZERO; self.cycle_length_roi()]; NUM_IDENTITY_MASKS];
+        for j in 0..(self.cycle_length_roi() / CYCLE_LENGTH) {
+            for i in 0..NUM_IDENTITY_MASKS {
+                result[i][CYCLE_LENGTH * j + i] = B::ONE;
+            }
+        }
+        result
+    }
+
+    // the first frame_size() pixels are masked with ONE
+    pub fn get_hash_mask_roi<B: FieldElement>(&self) -> Vec<B> {
+        let mut result = vec![B::ZERO; self.cycle_length_roi()];
+        for i in 0..self.frame_size() {
+            result[i] = B::ONE;
+        }
+        result
+    }
+
+    // the pixels inside any of the ROIs are masked with ONE (row-major order within the frame)
+    pub fn get_stat_mask_roi<B: FieldElement>(&self) -> Vec<B> {
+        let mut result = vec![B::ZERO; self.cycle_length_roi()];
+        for roi in self.rois.iter() {
+            for row in roi.y..(roi.y + roi.height) {
+                for col in roi.x..(roi.x + roi.width) {
+                    result[row * self.width + col] = B::ONE;
+                }
+            }
+        }
+        result
+    }
+
+    /// Lays out the frames in cycles of cycle_length_roi() pixels: every frame is followed by filler
+    /// pixels, which are neither hashed nor part of the statistics.
+    pub fn pad_frames(&self, pixels: &[u16]) -> Vec<u16> {
+        let mut result = vec![];
+        for frame in pixels.chunks(self.frame_size()) {
+            result.extend_from_slice(frame);
+            result.resize(result.len() + self.cycle_length_roi() - self.frame_size(), 42u16);
+        }
+        result
+    }
+
+    pub fn get_num_ones_in_stat_mask(&self) -> usize {
+        let mut mask = vec![false; self.frame_size()];
+        for roi in self.rois.iter() {
+            for row in roi.y..(roi.y + roi.height) {
+                for col in roi.x..(roi.x + roi.width) {
+                    mask[row * self.width + col] = true;
+                }
+            }
+        }
+        mask.iter().filter(|m| **m).count()
+    }
+
+    /// Row of the AET the hashing of the frames of a video of `input_length` pixels ends at.
+    pub fn get_result_step(&self, input_length: usize) -> usize {
+        self.cycle_length_roi() * (input_length / self.frame_size()) * CYCLE_LENGTH / NUM_ELEMS_PER_CYCLE
+    }
+
+    // repeats the columns of one hash cycle to the length of the ROI cycle
+    pub(super) fn repeat_to_roi<B: FieldElement>(&self, columns: Vec<Vec<B>>) -> Vec<Vec<B>> {
+        columns.iter().map(|column| column.repeat(self.cycle_length_roi() / column.len())).collect()
+    }
+}
+
+impl Serializable for FrameConfig {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.width as u32);
+        target.write_u32(self.height as u32);
+        target.write_u32(self.rois.len() as u32);
+        for roi in self.rois.iter() {
+            target.write_u32(roi.x as u32);
+            target.write_u32(roi.y as u32);
+            target.write_u32(roi.width as u32);
+            target.write_u32(roi.height as u32);
+        }
+    }
+}
+
+impl Deserializable for FrameConfig {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let width = source.read_u32()? as usize;
+        let height = source.read_u32()? as usize;
+        let mut rois = vec![];
+        for _ in 0..source.read_u32()? {
+            let x = source.read_u32()? as usize;
+            let y = source.read_u32()? as usize;
+            let width = source.read_u32()? as usize;
+            let height = source.read_u32()? as usize;
+            rois.push(Roi { x, y, width, height });
+        }
+        FrameConfig::new(width, height, rois).map_err(DeserializationError::InvalidValue)
+    }
+}
+
+// meta of a trace: input length and salt flag (see utils::create_salted_meta) followed by the frame
+fn create_frame_meta(num: usize, salted: bool, frame: &FrameConfig) -> Vec<u8> {
+    let mut result = create_salted_meta(num, salted);
+    frame.write_into(&mut result);
+    result
+}
+
+fn get_frame_meta(meta: &Vec<u8>) -> (usize, bool, FrameConfig) {
+    let (num, salted) = get_salted_meta(meta);
+    let frame = FrameConfig::read_from(&mut SliceReader::new(&meta[size_of::<usize>() + 1..])).unwrap();
+    (num, salted, frame)
+}
+
+// AET index definitions (the hash state follows, see TheAir::T_PIXELS_HASH)
+const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 };
+pub const T_SUM: IndexDefinition = IndexDefinition { idx: 8, size: 1 };
+pub const T_VAR: IndexDefinition = IndexDefinition { idx: 9, size: 1 };
+pub const T_AVG_REM: IndexDefinition = IndexDefinition { idx: T_VAR.idx + T_VAR.size, size: NUM_REMAINDERS };
+
+// constraint index definitions (the hash constraints follow, see TheAir)
+const C_COPY: IndexDefinition = IndexDefinition {idx: 0, size: 8};
+const C_SUM: IndexDefinition = IndexDefinition {idx: C_COPY.idx + C_COPY.size, size: 1};
+const C_VAR: IndexDefinition = IndexDefinition {idx: C_SUM.idx + C_SUM.size, size: 1};
+const C_COPY_STAT_STATE: IndexDefinition = IndexDefinition {idx: C_VAR.idx + C_VAR.size, size: 2};
+const C_AVG_REM: IndexDefinition = IndexDefinition {idx: C_COPY_STAT_STATE.idx + C_COPY_STAT_STATE.size, size: NUM_REMAINDERS};
+
+// periodic column index definitions (the round constants follow)
+const P_IDENTITY: IndexDefinition = IndexDefinition { idx: 0, size: NUM_IDENTITY_MASKS };
+const P_HASH: IndexDefinition = IndexDefinition { idx: NUM_IDENTITY_MASKS, size: 1 };
+const P_STAT: IndexDefinition = IndexDefinition { idx: NUM_IDENTITY_MASKS + 1, size: 1 };
+const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: NUM_IDENTITY_MASKS + 2, size: 1 };
+const P_ROUND_CONSTANTS_IDX: usize = NUM_IDENTITY_MASKS + 3;
+
+// number of elements being absorbed in one absorption phase (one pixel per element)
+pub const NUM_ELEMS_PER_CYCLE: usize = 8;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub hash: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+    pub sum: H::BaseField,
+    pub avg_rounded: H::BaseField,
+    pub variance: H::BaseField,
+    pub frame: FrameConfig,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    hash: H::Digest,
+    input_length: B,
+    salted: bool,
+    sum: B,
+    avg_rounded: B,
+    variance: B,
+    frame: FrameConfig,
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_digest::<H, W>(target, &self.hash);
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+        target.write(self.sum);
+        target.write(self.avg_rounded);
+        target.write(self.variance);
+        self.frame.write_into(target);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash = read_digest::<H, R>(source)?;
+        let input_length = H::BaseField::read_from(source)?;
+        let salted = read_bool(source)?;
+        let sum = H::BaseField::read_from(source)?;
+        let avg_rounded = H::BaseField::read_from(source)?;
+        let variance = H::BaseField::read_from(source)?;
+        let frame = FrameConfig::read_from(source)?;
+        frame.check_input_length(input_length.as_u128()).map_err(DeserializationError::InvalidValue)?;
+        Ok(PubInputs { hash, input_length, salted, sum, avg_rounded, variance, frame })
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < CYCLE_LENGTH;
+
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: T_AVG_REM.idx + T_AVG_REM.size, size: H::STATE_WIDTH };
+    pub const TRACE_WIDTH: usize = Self::T_PIXELS_HASH.idx + Self::T_PIXELS_HASH.size;
+
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: C_AVG_REM.idx + C_AVG_REM.size, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: Self::C_ROUND_FIRST.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH_STATE: IndexDefinition = IndexDefinition {idx: Self::C_ROUND_REMAINING.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_COPY_HASH_STATE.idx + H::STATE_WIDTH, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: P_ROUND_CONSTANTS_IDX, size: H::NUM_PERIODIC_COLUMNS };
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>, frame: &FrameConfig) -> TraceTable<B> {
+        Self::build_salted_trace(pixels, None, frame)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>, frame: &FrameConfig) -> TraceTable<B> {
+        // The desired hash value is located at step i = (pixels.len() / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH, which is
+        // one more than (pixels.len() / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+        frame.validate().unwrap();
+        assert_eq!(pixels.len() % frame.frame_size(), 0);
+        let cycle_length_roi = frame.cycle_length_roi();
+        let orig_pixels = pixels;
+        let stat_mask = frame.get_stat_mask_roi::<B>();
+        let hash_mask = frame.get_hash_mask_roi::<B>();
+        let pixels = frame.pad_frames(orig_pixels);
+
+        let salt_pixels = salt.map(|s| s.as_ref().to_vec()).unwrap_or_default();
+
+        let trace_len = next_power_of_two(((pixels.len() + salt_pixels.len()) / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH + 1);
+        let mut sum = 0u128;
+        let mut count = 0u128;
+        for idx in 0..pixels.len() {
+            if stat_mask[idx % cycle_length_roi] == B::ONE {
+                sum += pixels[idx] as u128;
+                count += 1;
+            }
+        }
+        let avg = B::from(sum.rounded_div(count));
+        let num_bits = get_num_bits(count);
+        let avg_remainders = get_rounded_div_remainders(sum, count);
+        let mut table = TraceTable::<B>::with_meta(Self::TRACE_WIDTH, trace_len, create_frame_meta(orig_pixels.len(), salt.is_some(), frame));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let cyclic_step = step % CYCLE_LENGTH; // cyclic_step can be considered the index in the masks
+                let roi_cyclic_step = step % cycle_length_roi;
+                apply_hash_step::<B, H>(state, Self::T_PIXELS_HASH, &pixels, &salt_pixels, step, hash_mask[roi_cyclic_step]);
+                if stat_mask[roi_cyclic_step] == B::ONE {
+                    let pixel = state[T_PIXELS.begin() + cyclic_step];
+                    state[T_SUM.begin()] += pixel;
+                    state[T_VAR.begin()] += (pixel - avg) * (pixel - avg);
+                }
+                apply_range_check(&mut state[T_AVG_REM.begin()..T_AVG_REM.end()], &avg_remainders, num_bits, step);
+            }
+        );
+        table
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        verify_opening::<B, H>(&pub_inputs.hash, pub_inputs.salted, pub_inputs.input_length, opening)
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+        let cycle_length_roi = pub_inputs.frame.cycle_length_roi();
+        let mut degrees = vec![];
+
+        for _ in 0..C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]));
+        }
+        // C_SUM
+        degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH, cycle_length_roi]));
+        // C_VAR
+        degrees.push(TransitionConstraintDegree::with_cycles(2, vec![CYCLE_LENGTH, cycle_length_roi]));
+        for _ in 0..C_COPY_STAT_STATE.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![cycle_length_roi]));
+        }
+        for _ in 0..C_AVG_REM.size {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+        for _ in 0..Self::C_COPY_HASH_STATE.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![cycle_length_roi]));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+
+        let num_assertions = H::STATE_WIDTH + H::RATE + 4 + 2 * NUM_REMAINDERS;
+        TheAir {
+            context: AirContext::new(trace_info, degrees, num_assertions, options),
+            hash: pub_inputs.hash,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted,
+            sum: pub_inputs.sum,
+            avg_rounded: pub_inputs.avg_rounded,
+            variance: pub_inputs.variance,
+            frame: pub_inputs.frame,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(P_IDENTITY);
+        let roi_hash = periodic_values.id_slice(P_HASH)[0];
+        let roi_stat = periodic_values.id_slice(P_STAT)[0];
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag * roi_hash);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag * roi_hash);
+        if Self::COPY_HASH {
+            let copy_hash_flag = copy_flag - hash_remaining_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag * roi_hash);
+        }
+        enforce_copy(&mut result[C_COPY.begin()..C_COPY.end()], current.id_slice(T_PIXELS), next.id_slice(T_PIXELS), copy_flag);
+        enforce_sum(&mut result[C_SUM.begin()..C_SUM.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_SUM)[0], next.id_slice(T_SUM)[0], roi_stat);
+        enforce_var(&mut result[C_VAR.begin()..C_VAR.end()], next.id_slice(T_PIXELS), identity, current.id_slice(T_VAR)[0], next.id_slice(T_VAR)[0], E::from(self.avg_rounded), roi_stat);
+        enforce_copy(&mut result[Self::C_COPY_HASH_STATE.begin()..Self::C_COPY_HASH_STATE.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), E::ONE - roi_hash);
+        enforce_copy(&mut result[C_COPY_STAT_STATE.begin()..C_COPY_STAT_STATE.end()], &current[T_SUM.begin()..T_VAR.end()], &next[T_SUM.begin()..T_VAR.end()], E::ONE - roi_stat);
+        enforce_range_check(&mut result[C_AVG_REM.begin()..C_AVG_REM.end()], current.id_slice(T_AVG_REM), next.id_slice(T_AVG_REM));
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let num_frames = self.input_length.as_u128() as usize / self.frame.frame_size();
+        let idx_result = self.frame.get_result_step(self.input_length.as_u128() as usize);
+        let idx_hash = if self.salted { idx_result + CYCLE_LENGTH } else { idx_result };
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash.as_ref()[c]));
+        }
+        result.push(Assertion::single(T_SUM.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_VAR.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_SUM.begin(), idx_result, self.sum));
+        result.push(Assertion::single(T_VAR.begin(), idx_result, self.variance));
+
+        // avg_rounded = round(sum / count), with count the number of pixels inside the ROI, see utils::range_check
+        let count = num_frames as u128 * self.frame.get_num_ones_in_stat_mask() as u128;
+        let num_bits = get_num_bits(count);
+        let avg_remainders = get_rounded_div_remainders_e(self.sum, B::from(count as u64), self.avg_rounded);
+        for c in 0..T_AVG_REM.size {
+            result.push(Assertion::single(T_AVG_REM.begin() + c, 0, B::ZERO));
+            result.push(Assertion::single(T_AVG_REM.begin() + c, num_bits, avg_remainders[c]));
+        }
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        get_periodic_columns::<B, H>(&self.frame)
+    }
+}
+
+// The following helpers are shared with the families laid out as STARK G (the pixel columns come first,
+// the hash state is given by its index definition): stark_threshold and stark_histogram.
+
+pub(super) fn write_digest<H: HashPermutation, W: ByteWriter>(target: &mut W, digest: &H::Digest) {
+    for elem in digest.as_ref() {
+        target.write(*elem);
+    }
+}
+
+pub(super) fn read_digest<H: HashPermutation, R: ByteReader>(source: &mut R) -> Result<H::Digest, DeserializationError> {
+    let mut hash = vec![H::BaseField::ZERO; H::RATE];
+    for elem in hash.iter_mut() {
+        *elem = H::BaseField::read_from(source)?;
+    }
+    Ok(H::Digest::try_from(&hash[..]).unwrap())
+}
+
+/// Native computation of the commitment to the pixels, which is salted if a salt is given.
+pub(super) fn commit<B: StarkBaseField, H: HashPermutation<BaseField = B>>(pixels: &Vec<u16>, salt: Option<&H::Digest>) -> H::Digest {
+    let elements = pixels.iter().map(|pixel| B::from(*pixel)).collect();
+    match salt {
+        Some(salt) => H::hash_salted(&elements, salt),
+        None => H::hash(&elements),
+    }
+}
+
+/// Checks that an opening reveals the video and salt `hash` commits to.
+pub(super) fn verify_opening<B: StarkBaseField, H: HashPermutation<BaseField = B>>(hash: &H::Digest, salted: bool, input_length: B, opening: &Opening<B>) -> Result<(), CommitmentError> {
+    opening.verify_digest(hash.as_ref(), salted, input_length.as_u128() as usize, |pixels, salt| {
+        let salt = salt.map(|s| H::Digest::try_from(s).unwrap());
+        commit::<B, H>(pixels, salt.as_ref()).as_ref().to_vec()
+    })
+}
+
+/// Row of the AET the hashing of the frames ends at, and the number of values looked up in the table
+/// t = (0, 1, .., max_value) (one per pixel inside the ROIs).
+pub(super) fn get_result_steps(input_length: usize, frame: &FrameConfig, max_value: usize) -> (usize, usize) {
+    let num_frames = input_length / frame.frame_size();
+    (frame.get_result_step(input_length), num_frames * frame.get_num_ones_in_stat_mask() + max_value)
+}
+
+/// Hashing of the padded frames (see FrameConfig::pad_frames) and the salt in the step following
+/// `step`: in the steps of the hash mask of the ROI (`roi_hash`), the first step of a cycle reads the
+/// next block into the pixel columns and absorbs it, the first H::NUM_ROUNDS steps apply a round.
+pub(super) fn apply_hash_step<B: StarkBaseField, H: HashPermutation<BaseField = B>>(state: &mut [B], t_pixels_hash: IndexDefinition, pixels: &[u16], salt_pixels: &[B], step: usize, roi_hash: B) {
+    let cyclic_step = step % CYCLE_LENGTH;
+    if roi_hash == B::ONE {
+        if cyclic_step == 0 {
+            // read elements into trace
+            for c in 0..NUM_ELEMS_PER_CYCLE {
+                let next_pixel_idx = NUM_ELEMS_PER_CYCLE * step / CYCLE_LENGTH + c;
+                if next_pixel_idx < pixels.len() {
+                    state[T_PIXELS.idx + c] = B::from(pixels[next_pixel_idx]);
+                } else if next_pixel_idx < pixels.len() + salt_pixels.len() {
+                    state[T_PIXELS.idx + c] = salt_pixels[next_pixel_idx - pixels.len()];
+                }
+            }
+            // absorb elements into hash state
+            for c in 0..H::RATE {
+                state[t_pixels_hash.idx + c] += state[T_PIXELS.idx + c];
+            }
+        }
+        if cyclic_step < H::NUM_ROUNDS {
+            H::apply_round(&mut state[t_pixels_hash.begin()..t_pixels_hash.end()], cyclic_step);
+        }
+    }
+}
+
+/// Periodic columns of the ROI cycle: identity masks, hash and stat mask, the flag of the rounds after
+/// the first one and the round constants of H.
+pub(super) fn get_periodic_columns<B: StarkBaseField, H: HashPermutation<BaseField = B>>(frame: &FrameConfig) -> Vec<Vec<B>> {
+    let mut periodic_columns = frame.get_identity_mask_roi();
+    periodic_columns.push(frame.get_hash_mask_roi());
+    periodic_columns.push(frame.get_stat_mask_roi());
+    periodic_columns.append(&mut frame.repeat_to_roi(vec![get_hash_mask(H::NUM_ROUNDS, CYCLE_LENGTH)]));
+    periodic_columns.append(&mut frame.repeat_to_roi(H::get_round_constants_periodic(CYCLE_LENGTH, ROUND_CONSTS_SHIFT)));
+    periodic_columns
+}
+
+pub(super) fn enforce_copy<E: FieldElement>(
+    result_slice: &mut [E],
+    current: &[E],
+    next: &[E],
+    flag: E,
+) {
+    for i in 0..current.len() {
+        result_slice[i] += flag * (current[i] - next[i]);
+    }
+}
+
+fn enforce_sum<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_sum: E,
+    next_sum: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * pixels[i];
+    }
+    result_slice[0] += flag * (current_sum - next_sum + sum_part);
+}
+
+fn enforce_var<E: FieldElement>(
+    result_slice: &mut [E],
+    pixels: &[E],
+    identity: &[E],
+    current_var: E,
+    next_var: E,
+    avg: E,
+    flag: E,
+) {
+    let mut sum_part = E::ZERO;
+    for i in 0..pixels.len() {
+        sum_part += identity[i % CYCLE_LENGTH] * (pixels[i] - avg) * (pixels[i] - avg);
+    }
+    result_slice[0] += flag * (current_var - next_var + sum_part);
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    _hash: PhantomData<H>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hash: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = TraceTable<Self::BaseField>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted, frame) = get_frame_meta(&trace.meta().to_vec());
+        let num_frames = input_length / frame.frame_size();
+        let result_step = frame.get_result_step(input_length);
+
+        let hash_step = if salted { result_step + CYCLE_LENGTH } else { result_step };
+        let mut hash = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        let sum = trace.get(T_SUM.begin(), result_step);
+        let variance = trace.get(T_VAR.begin(), result_step);
+        PubInputs {
+            hash: H::Digest::try_from(&hash[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted,
+            sum,
+            avg_rounded: B::from(sum.as_u128().rounded_div(num_frames as u128 * frame.get_num_ones_in_stat_mask() as u128)),
+            variance,
+            frame,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
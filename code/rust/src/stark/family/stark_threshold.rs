@@ -0,0 +1,516 @@
+use std::marker::PhantomData;
+use std::mem::size_of;
+use winterfell::{Air, Assertion, AuxTraceRandElements, ByteReader, ByteWriter, Deserializable, DeserializationError, EvaluationFrame, Matrix, Prover, Serializable, SliceReader, Trace, TraceInfo, TransitionConstraintDegree};
+use winterfell::math::{ExtensionOf, FieldElement};
+use winterfell::ProofOptions;
+use winterfell::AirContext;
+use crate::permutation::{HashPermutation, StarkBaseField};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::{check_lookup_max_value, create_lookup_meta, create_meta, get_lookup_meta, get_meta, read_bool, write_bool, IndexDefinition, IndexDefinitionSlice, next_power_of_two};
+use crate::utils::rap_trace_table::{AuxTraceBuilder, RapTraceTable};
+use super::fill_unconstrained_tail;
+use super::stark_e::{enforce_f, enforce_g, get_f_t, helper_t, helper_u};
+use super::stark_g::{apply_hash_step, enforce_copy, get_periodic_columns, get_result_steps, read_digest, verify_opening, write_digest, FrameConfig, CYCLE_LENGTH, NUM_ELEMS_PER_CYCLE};
+
+// Threshold exceedance: the pixels are laid out and hashed as in STARK G (one pixel per step, every
+// frame fills a cycle of cycle_length_roi steps). Instead of statistics, the only output is whether a
+// pixel inside the ROIs exceeds the public threshold T. Every pixel p of the ROIs has a bit b (p > T)
+// and the value b * (p - T - 1) + (1 - b) * (T - p) is shown to be in t = (0, 1, .., max_value) with
+// the plookup argument of STARK E, which pins b down. The column EXCEEDED is the OR of all bits, the
+// column COUNT their sum. The count is only a public input if the prover publishes it.
+//
+// The lookup flag f_l marks the steps of ROI pixels (the stat mask of STARK G while f_f is one), the
+// auxiliary segment consists of F, G and R of STARK E. The bits, EXCEEDED and COUNT are only bound while
+// f_f is one (see fill_unconstrained_tail).
+
+pub const AUX_TRACE_WIDTH: usize = 3;
+
+// for plookup: t contains the values 0, 1, .., max_value (see utils::get_lookup_max_value)
+pub const MAX_VALUE_STEP: usize = CYCLE_LENGTH;
+
+// AET index definitions: main trace segment (the hash state follows, see TheAir::T_PIXELS_HASH)
+const T_PIXELS: IndexDefinition = IndexDefinition { idx: 0, size: 8 };
+pub const T_EXCEEDED: IndexDefinition = IndexDefinition { idx: 8, size: 1 };
+const T_BIT: IndexDefinition = IndexDefinition { idx: 9, size: 1 };
+const T_OMEGA: IndexDefinition = IndexDefinition { idx: 10, size: 1 };
+const T_S: IndexDefinition = IndexDefinition { idx: 11, size: 1 };
+const T_F_L: IndexDefinition = IndexDefinition { idx: 12, size: 1 };
+const T_F_F: IndexDefinition = IndexDefinition { idx: 13, size: 1 };
+const T_F_S: IndexDefinition = IndexDefinition { idx: 14, size: 1 };
+pub const T_COUNT: IndexDefinition = IndexDefinition { idx: 15, size: 1 };
+
+// AET index definitions: auxiliary trace segment (depends on the random elements beta, gamma)
+const T_F: IndexDefinition = IndexDefinition { idx: 0, size: 1 };
+const T_G: IndexDefinition = IndexDefinition { idx: 1, size: 1 };
+const T_R: IndexDefinition = IndexDefinition { idx: 2, size: 1 };
+
+// constraint index definitions: main trace segment (the hash constraints follow, see TheAir)
+const C_COPY: IndexDefinition = IndexDefinition {idx: 0, size: 8};
+const C_F_L: IndexDefinition = IndexDefinition {idx: C_COPY.idx + C_COPY.size, size: 1};
+const C_OMEGA: IndexDefinition = IndexDefinition {idx: C_F_L.idx + C_F_L.size, size: 1};
+const C_BIT_U: IndexDefinition = IndexDefinition {idx: C_OMEGA.idx + C_OMEGA.size, size: 1};
+const C_BIT_L: IndexDefinition = IndexDefinition {idx: C_BIT_U.idx + C_BIT_U.size, size: 1};
+const C_EXCEEDED: IndexDefinition = IndexDefinition {idx: C_BIT_L.idx + C_BIT_L.size, size: 1};
+const C_F_F_U: IndexDefinition = IndexDefinition {idx: C_EXCEEDED.idx + C_EXCEEDED.size, size: 1};
+const C_F_F_T: IndexDefinition = IndexDefinition {idx: C_F_F_U.idx + C_F_F_U.size, size: 1};
+const C_F_S_U: IndexDefinition = IndexDefinition {idx: C_F_F_T.idx + C_F_F_T.size, size: 1};
+const C_F_S_T: IndexDefinition = IndexDefinition {idx: C_F_S_U.idx + C_F_S_U.size, size: 1};
+const C_COUNT: IndexDefinition = IndexDefinition {idx: C_F_S_T.idx + C_F_S_T.size, size: 1};
+
+// constraint index definitions: auxiliary trace segment
+const C_F: IndexDefinition = IndexDefinition {idx: 0, size: 1};
+const C_G: IndexDefinition = IndexDefinition {idx: C_F.idx + C_F.size, size: 1};
+const C_R: IndexDefinition = IndexDefinition {idx: C_G.idx + C_G.size, size: 1};
+
+// periodic column index definitions (the round constants follow)
+const P_IDENTITY: IndexDefinition = IndexDefinition { idx: 0, size: 8 };
+const P_HASH: IndexDefinition = IndexDefinition { idx: 8, size: 1 };
+const P_STAT: IndexDefinition = IndexDefinition { idx: 9, size: 1 };
+const P_HASH_FLAG: IndexDefinition = IndexDefinition { idx: 10, size: 1 };
+const P_ROUND_CONSTANTS_IDX: usize = 11;
+
+// indices of the random elements drawn by the verifier for the auxiliary trace segment
+const R_BETA: usize = 0;
+const R_GAMMA: usize = 1;
+
+pub struct PubInputs<H: HashPermutation> {
+    pub hash: H::Digest,
+    pub input_length: H::BaseField,
+    pub salted: bool,
+    pub max_value: H::BaseField,
+    pub threshold: H::BaseField,
+    // whether a pixel inside the ROIs exceeds the threshold
+    pub exceeded: bool,
+    // number of pixels inside the ROIs that exceed the threshold, if published
+    pub count: Option<H::BaseField>,
+    pub frame: FrameConfig,
+}
+
+pub struct TheAir<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    context: AirContext<B>,
+    hash: H::Digest,
+    input_length: B,
+    salted: bool,
+    max_value: B,
+    threshold: B,
+    exceeded: bool,
+    count: Option<B>,
+    frame: FrameConfig,
+}
+
+impl<H: HashPermutation> Serializable for PubInputs<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_digest::<H, W>(target, &self.hash);
+        target.write(self.input_length);
+        write_bool(target, self.salted);
+        target.write(self.max_value);
+        target.write(self.threshold);
+        write_bool(target, self.exceeded);
+        write_bool(target, self.count.is_some());
+        if let Some(count) = self.count {
+            target.write(count);
+        }
+        self.frame.write_into(target);
+    }
+}
+
+impl<H: HashPermutation> Deserializable for PubInputs<H> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash = read_digest::<H, R>(source)?;
+        let input_length = H::BaseField::read_from(source)?;
+        let salted = read_bool(source)?;
+        let max_value = H::BaseField::read_from(source)?;
+        let threshold = H::BaseField::read_from(source)?;
+        let exceeded = read_bool(source)?;
+        let count = if read_bool(source)? { Some(H::BaseField::read_from(source)?) } else { None };
+        let frame = FrameConfig::read_from(source)?;
+        frame.check_input_length(input_length.as_u128()).map_err(DeserializationError::InvalidValue)?;
+        check_lookup_max_value(usize::try_from(max_value.as_u128()).unwrap_or(usize::MAX), MAX_VALUE_STEP).map_err(DeserializationError::InvalidValue)?;
+        if threshold.as_u128() > max_value.as_u128() {
+            return Err(DeserializationError::InvalidValue(format!("the threshold {} exceeds the largest value {}", threshold.as_u128(), max_value.as_u128())));
+        }
+        Ok(PubInputs { hash, input_length, salted, max_value, threshold, exceeded, count, frame })
+    }
+}
+
+// meta of a trace: lookup meta (see utils::create_lookup_meta), the threshold and the frame
+fn create_threshold_meta(num: usize, salted: bool, max_value: usize, threshold: u16, frame: &FrameConfig) -> Vec<u8> {
+    let mut result = create_lookup_meta(num, salted, max_value);
+    result.append(&mut create_meta(threshold as usize));
+    frame.write_into(&mut result);
+    result
+}
+
+fn get_threshold_meta(meta: &Vec<u8>) -> (usize, bool, usize, u16, FrameConfig) {
+    let num_bytes = size_of::<usize>();
+    let (num, salted, max_value) = get_lookup_meta(&meta[..2 * num_bytes + 1].to_vec());
+    let threshold = get_meta(&meta[2 * num_bytes + 1..3 * num_bytes + 1].to_vec()) as u16;
+    let frame = FrameConfig::read_from(&mut SliceReader::new(&meta[3 * num_bytes + 1..])).unwrap();
+    (num, salted, max_value, threshold, frame)
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheAir<B, H> {
+    // the hash state is copied in the steps of a cycle after the last round
+    const COPY_HASH: bool = H::NUM_ROUNDS < CYCLE_LENGTH;
+
+    pub const T_PIXELS_HASH: IndexDefinition = IndexDefinition { idx: T_COUNT.idx + T_COUNT.size, size: H::STATE_WIDTH };
+    pub const TRACE_WIDTH: usize = Self::T_PIXELS_HASH.idx + Self::T_PIXELS_HASH.size;
+
+    const C_ROUND_FIRST: IndexDefinition = IndexDefinition {idx: C_COUNT.idx + C_COUNT.size, size: H::STATE_WIDTH};
+    const C_ROUND_REMAINING: IndexDefinition = IndexDefinition {idx: Self::C_ROUND_FIRST.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH_STATE: IndexDefinition = IndexDefinition {idx: Self::C_ROUND_REMAINING.idx + H::STATE_WIDTH, size: H::STATE_WIDTH};
+    const C_COPY_HASH: IndexDefinition = IndexDefinition {idx: Self::C_COPY_HASH_STATE.idx + H::STATE_WIDTH, size: if Self::COPY_HASH { H::STATE_WIDTH } else { 0 }};
+
+    const P_ROUND_CONSTANTS: IndexDefinition = IndexDefinition { idx: P_ROUND_CONSTANTS_IDX, size: H::NUM_PERIODIC_COLUMNS };
+
+    #[allow(dead_code)]
+    pub fn build_trace(pixels: &Vec<u16>, threshold: u16, max_value: usize, frame: &FrameConfig) -> RapTraceTable<B, TheAuxTraceBuilder> {
+        Self::build_salted_trace(pixels, None, threshold, max_value, frame)
+    }
+
+    /// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+    /// All pixels and the threshold must be in the lookup table t = (0, 1, .., max_value).
+    pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&H::Digest>, threshold: u16, max_value: usize, frame: &FrameConfig) -> RapTraceTable<B, TheAuxTraceBuilder> {
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+        frame.validate().unwrap();
+        assert_eq!(pixels.len() % frame.frame_size(), 0);
+        check_lookup_max_value(max_value, MAX_VALUE_STEP).unwrap();
+        assert!(threshold as usize <= max_value, "the threshold must not exceed {}", max_value);
+        assert!(pixels.iter().all(|p| *p as usize <= max_value), "pixel values must not exceed {}", max_value);
+        let cycle_length_roi = frame.cycle_length_roi();
+        let stat_mask = frame.get_stat_mask_roi::<B>();
+        let hash_mask = frame.get_hash_mask_roi::<B>();
+        let orig_pixels = pixels;
+        let pixels = frame.pad_frames(orig_pixels);
+        let salt_pixels = salt.map(|s| s.as_ref().to_vec()).unwrap_or_default();
+        let (idx_result, idx_result_s) = get_result_steps(orig_pixels.len(), frame, max_value);
+
+        // looked up values: T - p or p - T - 1 for every pixel inside the ROIs
+        let omega = |pixel: u16| if pixel > threshold { pixel - threshold - 1 } else { threshold - pixel };
+        let mut s: Vec<u16> = pixels.iter().enumerate().filter(|(idx, _)| stat_mask[idx % cycle_length_roi] == B::ONE).map(|(_, p)| omega(*p)).collect();
+        for t in 1..=max_value {
+            s.push(t as u16);
+        }
+        s.sort();
+
+        let trace_len = next_power_of_two(usize::max(((pixels.len() + salt_pixels.len()) / NUM_ELEMS_PER_CYCLE) * CYCLE_LENGTH, usize::max(idx_result, idx_result_s) + 1) + 1);
+        let mut table = RapTraceTable::<B, TheAuxTraceBuilder>::with_meta(Self::TRACE_WIDTH, trace_len, create_threshold_meta(orig_pixels.len(), salt.is_some(), max_value, threshold, frame));
+        table.fill(
+            |state| {
+                for i in 0..Self::TRACE_WIDTH {
+                    state[i] = B::ZERO;
+                }
+                state[T_F_F.begin()] = B::ONE;
+                state[T_F_S.begin()] = B::ONE;
+            },
+            |step, state| {  // step .. index of the last updated row (starting with 0)
+                let roi_cyclic_step = step % cycle_length_roi;
+                apply_hash_step::<B, H>(state, Self::T_PIXELS_HASH, &pixels, &salt_pixels, step, hash_mask[roi_cyclic_step]);
+                // transition of flags f_f and f_s
+                if step == idx_result {
+                    state[T_F_F.begin()] = B::ZERO;
+                }
+                if step == idx_result_s {
+                    state[T_F_S.begin()] = B::ZERO;
+                }
+                if step < s.len() {
+                    state[T_S.begin()] = B::from(s[step]);
+                }
+                // comparison of the pixels inside the ROIs with the threshold
+                state[T_F_L.begin()] = stat_mask[roi_cyclic_step] * state[T_F_F.begin()];
+                if state[T_F_L.begin()] == B::ONE {
+                    let pixel = pixels[step];
+                    state[T_OMEGA.begin()] = B::from(omega(pixel));
+                    if pixel > threshold {
+                        state[T_BIT.begin()] = B::ONE;
+                        state[T_EXCEEDED.begin()] = B::ONE;
+                        state[T_COUNT.begin()] += B::ONE;
+                    } else {
+                        state[T_BIT.begin()] = B::ZERO;
+                    }
+                } else if state[T_F_F.begin()] == B::ONE {
+                    state[T_BIT.begin()] = B::ZERO;
+                    state[T_OMEGA.begin()] = B::ZERO;
+                } else {
+                    fill_unconstrained_tail(state, &[T_BIT, T_EXCEEDED, T_COUNT]);
+                    state[T_OMEGA.begin()] = B::ZERO;
+                }
+            }
+        );
+        table
+    }
+
+    /// Checks that an opening reveals the video and salt the public inputs commit to.
+    pub fn verify_opening(pub_inputs: &PubInputs<H>, opening: &Opening<B>) -> Result<(), CommitmentError> {
+        verify_opening::<B, H>(&pub_inputs.hash, pub_inputs.salted, pub_inputs.input_length, opening)
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Air for TheAir<B, H> {
+    type BaseField = B;
+    type PublicInputs = PubInputs<H>;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(NUM_ELEMS_PER_CYCLE, H::RATE);
+        assert!(H::NUM_ROUNDS <= CYCLE_LENGTH);
+        let cycle_length_roi = pub_inputs.frame.cycle_length_roi();
+        let mut degrees = vec![];
+
+        for _ in 0..C_COPY.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH]));
+        }
+        // C_F_L
+        degrees.push(TransitionConstraintDegree::with_cycles(1, vec![cycle_length_roi]));
+        // C_OMEGA
+        degrees.push(TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]));
+        // C_BIT_U, C_BIT_L, C_EXCEEDED
+        for _ in 0..3 {
+            degrees.push(TransitionConstraintDegree::new(3));
+        }
+        // C_F_F_U, C_F_F_T, C_F_S_U, C_F_S_T
+        for _ in 0..4 {
+            degrees.push(TransitionConstraintDegree::new(2));
+        }
+        // C_COUNT
+        degrees.push(TransitionConstraintDegree::new(2));
+        for _ in 0..Self::C_ROUND_FIRST.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+        for _ in 0..Self::C_ROUND_REMAINING.size {
+            degrees.push(H::round_constraint_degree(vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+        for _ in 0..Self::C_COPY_HASH_STATE.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![cycle_length_roi]));
+        }
+        for _ in 0..Self::C_COPY_HASH.size {
+            degrees.push(TransitionConstraintDegree::with_cycles(1, vec![CYCLE_LENGTH, cycle_length_roi]));
+        }
+
+        let mut aux_degrees = vec![];
+        // C_F
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        // C_G
+        aux_degrees.push(TransitionConstraintDegree::new(3));
+        // C_R
+        aux_degrees.push(TransitionConstraintDegree::new(1));
+
+        let num_assertions = H::STATE_WIDTH + H::RATE + 8 + pub_inputs.count.is_some() as usize;
+        let num_aux_assertions = 3;
+
+        TheAir {
+            context: AirContext::new_multi_segment(trace_info, degrees, aux_degrees, num_assertions, num_aux_assertions, options),
+            hash: pub_inputs.hash,
+            input_length: pub_inputs.input_length,
+            salted: pub_inputs.salted,
+            max_value: pub_inputs.max_value,
+            threshold: pub_inputs.threshold,
+            exceeded: pub_inputs.exceeded,
+            count: pub_inputs.count,
+            frame: pub_inputs.frame,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        return &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E]
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        let round_constants = periodic_values.id_slice(Self::P_ROUND_CONSTANTS);
+        let identity = periodic_values.id_slice(P_IDENTITY);
+        let roi_hash = periodic_values.id_slice(P_HASH)[0];
+        let roi_stat = periodic_values.id_slice(P_STAT)[0];
+
+        let hash_first_flag = identity[0];
+        let hash_remaining_flag = periodic_values[P_HASH_FLAG.idx];
+        let copy_flag = E::ONE - identity[0];
+
+        H::enforce_first_round(&mut result[Self::C_ROUND_FIRST.begin()..Self::C_ROUND_FIRST.end()], next.id_slice(T_PIXELS), current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_first_flag * roi_hash);
+        H::enforce_round(&mut result[Self::C_ROUND_REMAINING.begin()..Self::C_ROUND_REMAINING.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), round_constants, hash_remaining_flag * roi_hash);
+        if Self::COPY_HASH {
+            let copy_hash_flag = copy_flag - hash_remaining_flag;
+            enforce_copy(&mut result[Self::C_COPY_HASH.begin()..Self::C_COPY_HASH.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), copy_hash_flag * roi_hash);
+        }
+        enforce_copy(&mut result[C_COPY.begin()..C_COPY.end()], current.id_slice(T_PIXELS), next.id_slice(T_PIXELS), copy_flag);
+        enforce_copy(&mut result[Self::C_COPY_HASH_STATE.begin()..Self::C_COPY_HASH_STATE.end()], current.id_slice(Self::T_PIXELS_HASH), next.id_slice(Self::T_PIXELS_HASH), E::ONE - roi_hash);
+
+        // comparison with the threshold: omega = T - p if b = 0, p - T - 1 if b = 1
+        let next_f_l = next[T_F_L.idx];
+        let next_bit = next[T_BIT.idx];
+        let mut next_pixel = E::ZERO;
+        for i in 0..T_PIXELS.size {
+            next_pixel += identity[i] * next[T_PIXELS.idx + i];
+        }
+        let threshold = E::from(self.threshold);
+        result[C_F_L.begin()] += next_f_l - roi_stat * next[T_F_F.idx];
+        result[C_OMEGA.begin()] += next_f_l * (threshold - next_pixel + next_bit * ((next_pixel - threshold).double() - E::ONE) - next[T_OMEGA.idx]);
+        let next_f_f = next[T_F_F.idx];
+        result[C_BIT_U.begin()] += next_f_f * helper_u(next_bit);
+        result[C_BIT_L.begin()] += next_bit * (E::ONE - next_f_l) * next_f_f;
+        let current_exceeded = current[T_EXCEEDED.idx];
+        result[C_EXCEEDED.begin()] += next_f_f * (current_exceeded + next_bit - current_exceeded * next_bit - next[T_EXCEEDED.idx]);
+        result[C_COUNT.begin()] += next_f_f * (current[T_COUNT.idx] + next_bit - next[T_COUNT.idx]);
+
+        result[C_F_F_U.begin()] += helper_u(next[T_F_F.idx]);
+        result[C_F_S_U.begin()] += helper_u(next[T_F_S.idx]);
+        result[C_F_F_T.begin()] += helper_t(current[T_F_F.idx], next[T_F_F.idx]);
+        result[C_F_S_T.begin()] += helper_t(current[T_F_S.idx], next[T_F_S.idx]);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E]
+    ) where F: FieldElement<BaseField=Self::BaseField>, E: FieldElement<BaseField=Self::BaseField> + ExtensionOf<F> {
+        let main_current = main_frame.current();
+        let main_next = main_frame.next();
+        let current = aux_frame.current();
+        let next = aux_frame.next();
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+        let beta = rand_elements[R_BETA];
+        let gamma = rand_elements[R_GAMMA];
+
+        enforce_f(&mut result[C_F.begin()..C_F.end()], beta, gamma, &[E::from(main_next[T_OMEGA.idx])], current[T_F.idx], next[T_F.idx], E::from(main_next[T_F_L.idx]));
+        enforce_g(&mut result[C_G.begin()..C_G.end()], beta, gamma, E::from(main_current[T_S.idx]), E::from(main_next[T_S.idx]), current[T_G.idx], next[T_G.idx], E::from(main_next[T_F_S.idx]));
+
+        result[C_R.begin()] += next[T_F.idx] - next[T_G.idx] - next[T_R.idx];
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let (idx_result, idx_result_s) = get_result_steps(self.input_length.as_u128() as usize, &self.frame, self.max_value.as_u128() as usize);
+        let idx_hash = if self.salted { idx_result + CYCLE_LENGTH } else { idx_result };
+        let mut result = vec![];
+
+        for c in 0..H::STATE_WIDTH {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, 0, B::ZERO));
+        }
+        for c in 0..H::RATE {
+            result.push(Assertion::single(Self::T_PIXELS_HASH.begin() + c, idx_hash, self.hash.as_ref()[c]));
+        }
+        result.push(Assertion::single(T_EXCEEDED.begin(), 0, B::ZERO));
+        result.push(Assertion::single(T_EXCEEDED.begin(), idx_result, if self.exceeded { B::ONE } else { B::ZERO }));
+        result.push(Assertion::single(T_COUNT.begin(), 0, B::ZERO));
+        if let Some(count) = self.count {
+            result.push(Assertion::single(T_COUNT.begin(), idx_result, count));
+        }
+
+        result.push(Assertion::single(T_S.begin(), 0, B::ZERO));
+
+        result.push(Assertion::single(T_F_F.begin(), idx_result, B::ONE)); // implies that it is always 1 before
+        result.push(Assertion::single(T_F_F.begin(), idx_result + 1, B::ZERO));
+
+        result.push(Assertion::single(T_F_S.begin(), idx_result_s, B::ONE)); // implies that it is always 1 before
+        result.push(Assertion::single(T_F_S.begin(), idx_result_s + 1, B::ZERO));
+
+        result
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField=Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>
+    ) -> Vec<Assertion<E>> {
+        let (idx_result, idx_result_s) = get_result_steps(self.input_length.as_u128() as usize, &self.frame, self.max_value.as_u128() as usize);
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+
+        let mut result = vec![];
+
+        result.push(Assertion::single(T_F.begin(), 0, get_f_t(rand_elements[R_BETA], rand_elements[R_GAMMA], self.max_value.as_u128() as usize)));
+        result.push(Assertion::single(T_G.begin(), 0, E::ONE));
+        // F and G are final once both the lookups and s are exhausted
+        result.push(Assertion::single(T_R.begin(), usize::max(idx_result, idx_result_s), E::ZERO));
+
+        result
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        get_periodic_columns::<B, H>(&self.frame)
+    }
+}
+
+// the auxiliary segment does not depend on the hash, one builder serves all instances
+pub struct TheAuxTraceBuilder {}
+
+impl<B: StarkBaseField> AuxTraceBuilder<B> for TheAuxTraceBuilder {
+    const WIDTH: usize = AUX_TRACE_WIDTH;
+    const NUM_RAND_ELEMENTS: usize = 2;
+
+    // fills the columns dependent on the randomness: F, G, R
+    fn build_aux_columns<E: FieldElement<BaseField=B>>(main: &Matrix<B>, meta: &[u8], rand_elements: &[E]) -> Vec<Vec<E>> {
+        let (_, _, max_value, _, _) = get_threshold_meta(&meta.to_vec());
+        let beta = rand_elements[R_BETA];
+        let gamma = rand_elements[R_GAMMA];
+        let length = main.num_rows();
+        let mut columns = vec![vec![E::ZERO; length]; AUX_TRACE_WIDTH];
+        columns[T_F.begin()][0] = get_f_t(beta, gamma, max_value);
+        columns[T_G.begin()][0] = E::ONE;
+        for step in 0..(length - 1) {
+            let next_f_l = E::from(main.get(T_F_L.begin(), step + 1));
+            let next_omega = E::from(main.get(T_OMEGA.begin(), step + 1));
+            let next_f = columns[T_F.begin()][step] * (next_f_l * (E::ONE + beta) * (gamma + next_omega) + E::ONE - next_f_l);
+            columns[T_F.begin()][step + 1] = next_f;
+
+            let next_f_s = E::from(main.get(T_F_S.begin(), step + 1));
+            let current_s = E::from(main.get(T_S.begin(), step));
+            let next_s = E::from(main.get(T_S.begin(), step + 1));
+            let next_g = columns[T_G.begin()][step] * (next_f_s * (gamma * (E::ONE + beta) + current_s + beta * next_s) + E::ONE - next_f_s);
+            columns[T_G.begin()][step + 1] = next_g;
+
+            columns[T_R.begin()][step + 1] = next_f - next_g;
+        }
+        columns
+    }
+}
+
+pub struct TheProver<B: StarkBaseField, H: HashPermutation<BaseField = B>> {
+    options: ProofOptions,
+    // whether the count is published
+    count: bool,
+    _hash: PhantomData<H>,
+    _field: PhantomData<B>,
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> TheProver<B, H> {
+    pub fn new(options: ProofOptions, count: bool) -> Self {
+        Self { options, count, _hash: PhantomData, _field: PhantomData }
+    }
+}
+
+impl<B: StarkBaseField, H: HashPermutation<BaseField = B>> Prover for TheProver<B, H> {
+    type BaseField = B;
+    type Air = TheAir<B, H>;
+    type Trace = RapTraceTable<Self::BaseField, TheAuxTraceBuilder>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        let (input_length, salted, max_value, threshold, frame) = get_threshold_meta(&trace.meta().to_vec());
+        let (result_step, _) = get_result_steps(input_length, &frame, max_value);
+        let hash_step = if salted { result_step + CYCLE_LENGTH } else { result_step };
+        let mut hash = vec![B::ZERO; H::RATE];
+        for c in 0..H::RATE {
+            hash[c] = trace.get(TheAir::<B, H>::T_PIXELS_HASH.idx + c, hash_step);
+        }
+        PubInputs {
+            hash: H::Digest::try_from(&hash[..]).unwrap(),
+            input_length: B::from(input_length as u64),
+            salted,
+            max_value: B::from(max_value as u64),
+            threshold: B::from(threshold),
+            exceeded: trace.get(T_EXCEEDED.begin(), result_step) == B::ONE,
+            count: if self.count { Some(trace.get(T_COUNT.begin(), result_step)) } else { None },
+            frame,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
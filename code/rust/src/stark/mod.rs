@@ -1,7 +1,10 @@
+pub mod family;
 pub mod stark_a;
 pub mod stark_a_62;
 pub mod stark_a_griffin;
 pub mod stark_a_griffin_62;
+pub mod stark_a_poseidon;
+pub mod stark_a_poseidon_62;
 pub mod stark_b;
 pub mod stark_b_62;
 pub mod stark_b_griffin;
@@ -12,8 +15,11 @@ pub mod stark_d;
 pub mod stark_d_griffin;
 pub mod stark_e;
 pub mod stark_e_62;
+pub mod stark_e_64_rpo;
 pub mod stark_e_griffin;
 pub mod stark_e_griffin_62;
+pub mod stark_e_m10_c2;
+pub mod stark_e_m13_c5_62;
 pub mod stark_e_opt;
 pub mod stark_e_opt_62;
 pub mod stark_e_opt_griffin;
@@ -21,8 +27,13 @@ pub mod stark_e_opt_griffin_62;
 pub mod stark_f;
 pub mod stark_f_62;
 pub mod stark_f_64;
+pub mod stark_f_64_rpo;
+pub mod stark_f_frames;
+pub mod stark_f_series;
 pub mod stark_f_griffin;
 pub mod stark_f_griffin_62;
+pub mod stark_f_m10_c2;
+pub mod stark_f_m13_c5_62;
 pub mod stark_f_opt_m2;
 pub mod stark_f_opt_m2_62;
 pub mod stark_f_opt_m2_griffin;
@@ -35,7 +46,15 @@ pub mod stark_f_opt_m8;
 pub mod stark_f_opt_m8_62;
 pub mod stark_f_opt_m8_griffin;
 pub mod stark_f_opt_m8_griffin_62;
+pub mod stark_f_poseidon;
+pub mod stark_f_poseidon_62;
+pub mod stark_f_poseidon_64;
+pub mod stark_f_segment;
 pub mod stark_g;
 pub mod stark_g_62;
 pub mod stark_g_griffin;
 pub mod stark_g_griffin_62;
+pub mod stark_g_m10_c2;
+pub mod stark_g_m13_c5_62;
+pub mod stark_histogram;
+pub mod stark_threshold;
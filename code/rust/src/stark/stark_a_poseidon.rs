@@ -0,0 +1,31 @@
+use winterfell::TraceTable;
+use winterfell::math::fields::f128::BaseElement;
+use crate::poseidon::p128_t4_c3_s128::{Permutation, RATE};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::IndexDefinition;
+use super::family::stark_a;
+
+// STARK A over f128 with Poseidon (state width 4, capacity 3) and one pixel per element, an instance of the generic family
+
+pub type TheAir = stark_a::TheAir<BaseElement, Permutation, 1>;
+pub type TheProver = stark_a::TheProver<BaseElement, Permutation, 1>;
+pub type PubInputs = stark_a::PubInputs<Permutation, 1>;
+
+pub const CYCLE_LENGTH: usize = TheAir::CYCLE_LENGTH;
+pub const NUM_ELEMS_PER_CYCLE: usize = TheAir::NUM_ELEMS_PER_CYCLE;
+pub const T_PIXELS_HASH: IndexDefinition = TheAir::T_PIXELS_HASH;
+
+#[allow(dead_code)]
+pub fn build_trace(pixels: &Vec<u16>) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels)
+}
+
+/// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&[BaseElement; RATE]>) -> TraceTable<BaseElement> {
+    TheAir::build_salted_trace(pixels, salt)
+}
+
+/// Checks that an opening reveals the video and salt the public inputs commit to.
+pub fn verify_opening(pub_inputs: &PubInputs, opening: &Opening<BaseElement>) -> Result<(), CommitmentError> {
+    TheAir::verify_opening(pub_inputs, opening)
+}
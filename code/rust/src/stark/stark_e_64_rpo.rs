@@ -0,0 +1,32 @@
+use crate::utils::rap_trace_table::RapTraceTable;
+use winterfell::math::fields::f64::BaseElement;
+use crate::rescue::p64_m12_c4_s128_rpo::{Permutation, RATE};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::IndexDefinition;
+use super::family::stark_e;
+
+pub use super::family::stark_e::{check_ranks, CYCLE_LENGTH, NUM_ELEMS_PER_CYCLE, MAX_VALUE_STEP, T_SUM, T_VAR, T_MIN, T_MAX, T_MED, TheAuxTraceBuilder};
+
+// STARK E over f64 with Rescue Prime Optimized (state width 12, capacity 4), an instance of the generic family
+
+pub type TheAir = stark_e::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_e::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_e::PubInputs<Permutation>;
+
+pub const T_PIXELS_HASH: IndexDefinition = TheAir::T_PIXELS_HASH;
+
+#[allow(dead_code)]
+pub fn build_trace(pixels: &Vec<u16>, max_value: usize) -> RapTraceTable<BaseElement, TheAuxTraceBuilder> {
+    TheAir::build_trace(pixels, max_value)
+}
+
+/// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+/// All pixels must be in the lookup table t = (0, 1, .., max_value).
+pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&[BaseElement; RATE]>, max_value: usize) -> RapTraceTable<BaseElement, TheAuxTraceBuilder> {
+    TheAir::build_salted_trace(pixels, salt, max_value)
+}
+
+/// Checks that an opening reveals the video and salt the public inputs commit to.
+pub fn verify_opening(pub_inputs: &PubInputs, opening: &Opening<BaseElement>) -> Result<(), CommitmentError> {
+    TheAir::verify_opening(pub_inputs, opening)
+}
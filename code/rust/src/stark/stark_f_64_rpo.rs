@@ -0,0 +1,32 @@
+use winterfell::TraceTable;
+use winterfell::math::fields::f64::BaseElement;
+use crate::rescue::p64_m12_c4_s128_rpo::{Permutation, RATE};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::IndexDefinition;
+use super::family::stark_f;
+
+// STARK F over f64 with Rescue Prime Optimized (state width 12, capacity 4), an instance of the generic family
+
+pub type TheAir = stark_f::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_f::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_f::PubInputs<Permutation>;
+
+pub const CYCLE_LENGTH: usize = TheAir::CYCLE_LENGTH;
+pub const T_PIXELS_HASH: IndexDefinition = TheAir::T_PIXELS_HASH;
+pub const T_SUM: IndexDefinition = TheAir::T_SUM;
+pub const T_VAR: IndexDefinition = TheAir::T_VAR;
+
+#[allow(dead_code)]
+pub fn build_trace(pixels: &Vec<u16>) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels)
+}
+
+/// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&[BaseElement; RATE]>) -> TraceTable<BaseElement> {
+    TheAir::build_salted_trace(pixels, salt)
+}
+
+/// Checks that an opening reveals the video and salt the public inputs commit to.
+pub fn verify_opening(pub_inputs: &PubInputs, opening: &Opening<BaseElement>) -> Result<(), CommitmentError> {
+    TheAir::verify_opening(pub_inputs, opening)
+}
@@ -0,0 +1,23 @@
+use winterfell::TraceTable;
+use winterfell::math::fields::f128::BaseElement;
+use crate::commitment::{self, CommitmentError};
+use crate::rescue::p128_m9_c1_s128::Permutation;
+use super::family::stark_f_frames;
+
+// STARK F over a range of frames of a video committed to with a frame tree, f128 with Rescue-Prime
+// (state width 9, capacity 1), an instance of the generic family
+
+pub type TheAir = stark_f_frames::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_f_frames::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_f_frames::PubInputs<Permutation>;
+pub type FrameTree = commitment::FrameTree<Permutation>;
+
+/// Builds the trace of the consecutive frames of `frame_size` pixels in `pixels`.
+pub fn build_trace(pixels: &[u16], frame_size: usize) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels, frame_size)
+}
+
+/// Checks the authentication paths of the frame digests of the public inputs to the root.
+pub fn verify_paths(pub_inputs: &PubInputs) -> Result<(), CommitmentError> {
+    TheAir::verify_paths(pub_inputs)
+}
@@ -0,0 +1,51 @@
+use winterfell::{ProofOptions, StarkProof, TraceTable};
+use winterfell::math::fields::f128::BaseElement;
+use crate::rescue::p128_m9_c1_s128::Permutation;
+use super::family::stark_f_segment;
+
+// STARK F over one segment of a video, f128 with Rescue-Prime (state width 9, capacity 1), an
+// instance of the generic family
+
+pub type TheAir = stark_f_segment::TheAir<BaseElement, Permutation>;
+#[allow(dead_code)]
+pub type TheProver = stark_f_segment::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_f_segment::PubInputs<Permutation>;
+pub type SegmentOutputs = stark_f_segment::SegmentOutputs<Permutation>;
+
+/// Sponge state before absorbing the first segment.
+#[allow(dead_code)]
+pub fn initial_state() -> Vec<BaseElement> {
+    TheAir::initial_state()
+}
+
+/// Rounded average of the whole video, which all segments compute their variance with.
+#[allow(dead_code)]
+pub fn get_avg_rounded(pixels: &[u16]) -> BaseElement {
+    TheAir::get_avg_rounded(pixels)
+}
+
+/// Builds the trace of one segment, absorbing its pixels into the sponge state `state_in`.
+#[allow(dead_code)]
+pub fn build_trace(pixels: &[u16], state_in: &[BaseElement], avg_rounded: BaseElement) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels, state_in, avg_rounded)
+}
+
+/// Rough estimate of the memory (in bytes) needed to prove a segment of `segment_length` pixels.
+pub fn estimate_memory(segment_length: usize, options: &ProofOptions) -> usize {
+    TheAir::estimate_memory(segment_length, options)
+}
+
+/// Checks that an input of `input_length` pixels splits into valid segments of `segment_length` pixels.
+pub fn check_segments(input_length: usize, segment_length: usize) -> Result<(), String> {
+    TheAir::check_segments(input_length, segment_length)
+}
+
+/// Proves the segments of `segment_length` pixels on `num_workers` threads.
+pub fn prove_segments(pixels: &[u16], segment_length: usize, options: ProofOptions, num_workers: usize) -> Vec<(StarkProof, PubInputs)> {
+    TheAir::prove_segments(pixels, segment_length, options, num_workers)
+}
+
+/// Verifies the proofs of the segments of a video and checks that they link up to a chain.
+pub fn verify_segments(segments: Vec<(StarkProof, PubInputs)>) -> Result<SegmentOutputs, stark_f_segment::SegmentError> {
+    TheAir::verify_segments(segments)
+}
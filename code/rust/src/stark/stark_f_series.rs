@@ -0,0 +1,34 @@
+use winterfell::TraceTable;
+use winterfell::math::fields::f128::BaseElement;
+use crate::commitment::CommitmentError;
+use crate::rescue::p128_m9_c1_s128::Permutation;
+use super::family::stark_f_series;
+
+// Time series of per-frame statistics over a range of frames of a video committed to with a frame
+// tree, f128 with Rescue-Prime (state width 9, capacity 1), an instance of the generic family
+
+pub type TheAir = stark_f_series::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_f_series::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_f_series::PubInputs<Permutation>;
+pub type FrameStats = stark_f_series::FrameStats<BaseElement>;
+pub use super::stark_f_frames::FrameTree;
+
+/// Builds the trace of the consecutive frames of `frame_size` pixels in `pixels`.
+pub fn build_trace(pixels: &[u16], frame_size: usize) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels, frame_size)
+}
+
+/// Per-frame statistics of the consecutive frames of `frame_size` pixels in `pixels`.
+pub fn get_series(pixels: &[u16], frame_size: usize) -> Vec<FrameStats> {
+    TheAir::get_series(pixels, frame_size)
+}
+
+/// Checks the authentication paths of the frame digests of the public inputs to the root.
+pub fn verify_paths(pub_inputs: &PubInputs) -> Result<(), CommitmentError> {
+    TheAir::verify_paths(pub_inputs)
+}
+
+/// Checks that a time series is the one the public inputs commit to.
+pub fn verify_series(pub_inputs: &PubInputs, series: &[FrameStats]) -> Result<(), CommitmentError> {
+    TheAir::verify_series(pub_inputs, series)
+}
@@ -0,0 +1,31 @@
+use winterfell::TraceTable;
+use winterfell::math::fields::f128::BaseElement;
+use crate::rescue::p128_m10_c2_s128::{Permutation, RATE};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::IndexDefinition;
+use super::family::stark_g::{self, FrameConfig};
+
+pub use super::family::stark_g::{CYCLE_LENGTH, NUM_ELEMS_PER_CYCLE, T_SUM, T_VAR};
+
+// STARK G over f128 with Rescue-Prime (state width 10, capacity 2), an instance of the generic family
+
+pub type TheAir = stark_g::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_g::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_g::PubInputs<Permutation>;
+
+pub const T_PIXELS_HASH: IndexDefinition = TheAir::T_PIXELS_HASH;
+
+#[allow(dead_code)]
+pub fn build_trace(pixels: &Vec<u16>, frame: &FrameConfig) -> TraceTable<BaseElement> {
+    TheAir::build_trace(pixels, frame)
+}
+
+/// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&[BaseElement; RATE]>, frame: &FrameConfig) -> TraceTable<BaseElement> {
+    TheAir::build_salted_trace(pixels, salt, frame)
+}
+
+/// Checks that an opening reveals the video and salt the public inputs commit to.
+pub fn verify_opening(pub_inputs: &PubInputs, opening: &Opening<BaseElement>) -> Result<(), CommitmentError> {
+    TheAir::verify_opening(pub_inputs, opening)
+}
@@ -0,0 +1,35 @@
+use winterfell::math::fields::f128::BaseElement;
+use crate::rescue::p128_m9_c1_s128::{Permutation, RATE};
+use crate::commitment::{CommitmentError, Opening};
+use crate::utils::IndexDefinition;
+use crate::utils::rap_trace_table::RapTraceTable;
+use super::family::stark_g::FrameConfig;
+use super::family::stark_threshold;
+
+pub use super::family::stark_g::{CYCLE_LENGTH, NUM_ELEMS_PER_CYCLE};
+pub use super::family::stark_threshold::{MAX_VALUE_STEP, T_COUNT, T_EXCEEDED, TheAuxTraceBuilder};
+
+// Threshold exceedance over f128 with Rescue-Prime (state width 9, capacity 1), an instance of the
+// generic family
+
+pub type TheAir = stark_threshold::TheAir<BaseElement, Permutation>;
+pub type TheProver = stark_threshold::TheProver<BaseElement, Permutation>;
+pub type PubInputs = stark_threshold::PubInputs<Permutation>;
+
+pub const T_PIXELS_HASH: IndexDefinition = TheAir::T_PIXELS_HASH;
+
+#[allow(dead_code)]
+pub fn build_trace(pixels: &Vec<u16>, threshold: u16, max_value: usize, frame: &FrameConfig) -> RapTraceTable<BaseElement, TheAuxTraceBuilder> {
+    TheAir::build_trace(pixels, threshold, max_value, frame)
+}
+
+/// Builds the trace of a commitment salted with `salt`, which is absorbed as one additional block after the pixels.
+/// All pixels and the threshold must be in the lookup table t = (0, 1, .., max_value).
+pub fn build_salted_trace(pixels: &Vec<u16>, salt: Option<&[BaseElement; RATE]>, threshold: u16, max_value: usize, frame: &FrameConfig) -> RapTraceTable<BaseElement, TheAuxTraceBuilder> {
+    TheAir::build_salted_trace(pixels, salt, threshold, max_value, frame)
+}
+
+/// Checks that an opening reveals the video and salt the public inputs commit to.
+pub fn verify_opening(pub_inputs: &PubInputs, opening: &Opening<BaseElement>) -> Result<(), CommitmentError> {
+    TheAir::verify_opening(pub_inputs, opening)
+}
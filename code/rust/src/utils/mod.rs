@@ -1,8 +1,12 @@
 use std::mem::size_of;
 use rounded_div::RoundedDiv;
 use winterfell::math::{FieldElement, StarkField};
-use winterfell::{FieldExtension, HashFunction, ProofOptions};
+use winterfell::{ByteReader, ByteWriter, DeserializationError, FieldExtension, HashFunction, ProofOptions};
 use rand::Rng;
+use crate::permutation::StarkBaseField;
+
+pub mod range_check;
+pub mod rap_trace_table;
 
 // index definition helper
 pub struct IndexDefinition {
@@ -64,6 +68,87 @@ pub fn get_meta(meta: &Vec<u8>) -> usize {
     result
 }
 
+// meta of a trace with an optionally salted hash: input length followed by the salt flag
+pub fn create_salted_meta(num: usize, salted: bool) -> Vec<u8> {
+    let mut result = create_meta(num);
+    result.push(salted as u8);
+    result
+}
+
+pub fn get_salted_meta(meta: &Vec<u8>) -> (usize, bool) {
+    let num_bytes = size_of::<usize>();
+    (get_meta(&meta[..num_bytes].to_vec()), meta[num_bytes] == 1)
+}
+
+// meta of a trace with a lookup table: salted meta followed by the largest value of the table
+pub fn create_lookup_meta(num: usize, salted: bool, max_value: usize) -> Vec<u8> {
+    let mut result = create_salted_meta(num, salted);
+    result.append(&mut create_meta(max_value));
+    result
+}
+
+pub fn get_lookup_meta(meta: &Vec<u8>) -> (usize, bool, usize) {
+    let num_bytes = size_of::<usize>();
+    let (num, salted) = get_salted_meta(meta);
+    (num, salted, get_meta(&meta[num_bytes + 1..].to_vec()))
+}
+
+// The lookup tables of STARK D, E and E-opt contain the values 0, 1, .., max_value. The MSBs of the 62, 64 and
+// 128 bit moduli allow values up to 2^16 - 2 (see NUM_BITS_PER_PIXEL of STARK D).
+pub const MAX_LOOKUP_VALUE: usize = (1 << 16) - 2;
+pub const MAX_BIT_DEPTH: usize = 16;
+
+/// Largest value of a lookup table for pixels of `bit_depth` bits, where the STARK requires the largest value to
+/// be a multiple of `step`: 2^bit_depth - 1 rounded up to a multiple of `step`, or rounded down if this exceeds
+/// MAX_LOOKUP_VALUE (the largest pixel values are not allowed then).
+pub fn get_lookup_max_value(bit_depth: usize, step: usize) -> usize {
+    assert!(bit_depth > 0 && bit_depth <= MAX_BIT_DEPTH, "bit depth must be in [1, {}], but was {}", MAX_BIT_DEPTH, bit_depth);
+    let max_pixel = (1usize << bit_depth) - 1;
+    let result = (max_pixel + step - 1) / step * step;
+    if result > MAX_LOOKUP_VALUE { MAX_LOOKUP_VALUE / step * step } else { result }
+}
+
+/// Checks that a STARK requiring the largest value of its lookup table to be a multiple of `step` supports a table
+/// with the largest value `max_value`.
+pub fn check_lookup_max_value(max_value: usize, step: usize) -> Result<(), String> {
+    if max_value == 0 || max_value > MAX_LOOKUP_VALUE {
+        return Err(format!("the largest value of the lookup table must be in [1, {}], but was {}", MAX_LOOKUP_VALUE, max_value));
+    }
+    if max_value % step != 0 {
+        return Err(format!("the largest value of the lookup table must be a multiple of {}, but was {}", step, max_value));
+    }
+    Ok(())
+}
+
+/// Values of the salt as they are read into the pixel columns of the trace, i.e. each element is split
+/// into `num_pixels_per_elem` chunks of `num_bits_per_pixel` bits (see COMPRESSOR of the STARKs).
+pub fn get_salt_pixels<B: StarkBaseField>(salt: Option<&[B]>, num_pixels_per_elem: usize, num_bits_per_pixel: usize) -> Vec<B> {
+    let mut result = vec![];
+    for elem in salt.iter().flat_map(|s| s.iter()) {
+        if num_pixels_per_elem == 1 {
+            result.push(*elem);
+            continue;
+        }
+        let value = elem.as_u128();
+        for d in 0..num_pixels_per_elem {
+            result.push(B::from((value >> (num_bits_per_pixel * d)) & ((1u128 << num_bits_per_pixel) - 1)));
+        }
+    }
+    result
+}
+
+pub fn write_bool<W: ByteWriter>(target: &mut W, value: bool) {
+    target.write_u8(value as u8);
+}
+
+pub fn read_bool<R: ByteReader>(source: &mut R) -> Result<bool, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(DeserializationError::InvalidValue(format!("{} is not a valid boolean", value))),
+    }
+}
+
 pub struct PlainStatisticsU128<E: FieldElement> {
     pub sum: u128,
     pub sum_e: E,
@@ -79,6 +164,12 @@ pub struct PlainStatisticsU128<E: FieldElement> {
     pub med_low_e: E,
     pub med_high: u128,
     pub med_high_e: E,
+    // number of values above the threshold (none for get_plain_statistics_u128)
+    pub count_above: u128,
+    pub count_above_e: E,
+    // floor(sqrt(var / (n - 1))) as proven by STARK E
+    pub std_dev: u128,
+    pub std_dev_e: E,
     pub stark_std_dev: f64,
     pub real_std_dev: f64,
     pub median: f64,
@@ -100,6 +191,9 @@ pub struct PlainStatisticsU64<E: FieldElement> {
     pub med_low_e: E,
     pub med_high: u64,
     pub med_high_e: E,
+    // floor(sqrt(var / (n - 1))) as proven by STARK E
+    pub std_dev: u64,
+    pub std_dev_e: E,
     pub stark_std_dev: f64,
     pub real_std_dev: f64,
     pub median: f64,
@@ -131,6 +225,11 @@ pub fn get_rand_values<E: From<u16>>(begin: u16, end: u16, length: usize) -> (Ve
 }
 
 pub fn get_plain_statistics_u128<E: StarkField + FieldElement<PositiveInteger = u128>>(values: Vec<u16>) -> PlainStatisticsU128<E> {
+    get_plain_statistics_threshold_u128(values, u16::MAX)
+}
+
+/// Plain statistics including the number of values above `threshold`.
+pub fn get_plain_statistics_threshold_u128<E: StarkField + FieldElement<PositiveInteger = u128>>(values: Vec<u16>, threshold: u16) -> PlainStatisticsU128<E> {
     let mut result = PlainStatisticsU128 {
         sum: 0u128,
         sum_e: E::ZERO,
@@ -146,6 +245,10 @@ pub fn get_plain_statistics_u128<E: StarkField + FieldElement<PositiveInteger =
         med_low_e: E::ZERO,
         med_high: 0u128,
         med_high_e: E::ZERO,
+        count_above: 0u128,
+        count_above_e: E::ZERO,
+        std_dev: 0,
+        std_dev_e: E::ZERO,
         stark_std_dev: 0.0,
         real_std_dev: 0.0,
         median: 0.0,
@@ -177,6 +280,12 @@ pub fn get_plain_statistics_u128<E: StarkField + FieldElement<PositiveInteger =
     }
     result.med_low_e = E::from(result.med_low);
     result.med_high_e = E::from(result.med_high);
+    result.count_above = values.iter().filter(|e| **e > threshold).count() as u128;
+    result.count_above_e = E::from(result.count_above);
+    if values.len() > 1 {
+        result.std_dev = range_check::get_std_dev(result.var, values.len() as u128);
+        result.std_dev_e = E::from(result.std_dev);
+    }
     result.stark_std_dev = f64::sqrt(result.var as f64 / (values.len() - 1) as f64);
     {
         let real_avg = result.sum as f64 / values.len() as f64;
@@ -208,6 +317,8 @@ pub fn get_plain_statistics_u64<E: StarkField + FieldElement<PositiveInteger = u
         med_low_e: E::ZERO,
         med_high: 0u64,
         med_high_e: E::ZERO,
+        std_dev: 0,
+        std_dev_e: E::ZERO,
         stark_std_dev: 0.0,
         real_std_dev: 0.0,
         median: 0.0,
@@ -239,6 +350,10 @@ pub fn get_plain_statistics_u64<E: StarkField + FieldElement<PositiveInteger = u
     }
     result.med_low_e = E::from(result.med_low);
     result.med_high_e = E::from(result.med_high);
+    if values.len() > 1 {
+        result.std_dev = range_check::get_std_dev(result.var as u128, values.len() as u128) as u64;
+        result.std_dev_e = E::from(result.std_dev);
+    }
     result.stark_std_dev = f64::sqrt(result.var as f64 / (values.len() - 1) as f64);
     {
         let real_avg = result.sum as f64 / values.len() as f64;
@@ -254,6 +369,30 @@ pub fn get_plain_statistics_u64<E: StarkField + FieldElement<PositiveInteger = u
     result
 }
 
+/// Number of values per bin for ascending bin edges, bin k holds the values v with edges[k - 1] <= v < edges[k].
+pub fn get_plain_histogram(values: &[u16], edges: &[u16]) -> Vec<usize> {
+    let mut result = vec![0; edges.len() + 1];
+    for value in values {
+        result[edges.iter().filter(|edge| *edge <= value).count()] += 1;
+    }
+    result
+}
+
+/// Nearest rank of the p-th percentile of input_length values: the smallest rank k such that at least p percent of
+/// the values are not above the k-th smallest one. The 0-th percentile is the minimum (rank 1).
+pub fn get_percentile_rank(percentile: f64, input_length: usize) -> usize {
+    assert!((0.0..=100.0).contains(&percentile), "percentile {} must be in [0, 100]", percentile);
+    let rank = (percentile * input_length as f64 / 100.0).ceil() as usize;
+    rank.clamp(1, input_length)
+}
+
+/// The k-th smallest values for the ranks k (starting with 1).
+pub fn get_plain_order_statistics(values: &[u16], ranks: &[usize]) -> Vec<u16> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    ranks.iter().map(|rank| sorted[rank - 1]).collect()
+}
+
 pub fn get_proof_options(blowup_factor: usize, field_extension: FieldExtension) -> ProofOptions {
     ProofOptions::new(
         32, // number of queries
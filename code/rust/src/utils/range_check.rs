@@ -0,0 +1,123 @@
+use winterfell::math::{FieldElement, StarkField};
+
+// Range check gadget: a column proves that a value v lies in [0, 2^k) by accumulating its binary
+// decomposition over k rows, most significant bit first:
+//     acc_0 = 0,   acc_{i+1} = 2 * acc_i + b_i   with b_i in {0, 1}
+// The AIR asserts acc_0 = 0 and acc_k = v. After row k the prover appends set bits (b_i = 1), so the
+// constraint holds on every row and needs no periodic column, and it does not vanish if v = 0.
+//
+// It is used to prove avg_rounded = round(sum / n): the remainder r = 2 * sum + n - 2 * n * avg_rounded
+// as well as 2 * n - 1 - r are range checked to [0, 2^k) with 2^k >= 2 * n. As both values are far
+// smaller than the field modulus and add up to 2 * n - 1, this implies 0 <= r < 2 * n, i.e.
+// sum / n - 1/2 <= avg_rounded < sum / n + 1/2.
+
+//
+// The sample standard deviation s = floor(sqrt(var / (n - 1))) is proven with the witnesses
+// q = floor(var / (n - 1)), r = var - (n - 1) * q (division remainder) and a = q - s^2 (square root
+// remainder). The values r, n - 2 - r, a and 2 * s - a are range checked, which implies 0 <= r < n - 1
+// and s^2 <= q < (s + 1)^2. As q is not public, the columns hold linear combinations of the
+// accumulators whose last values the verifier knows (see combine_std_dev_remainders):
+//     a,   (n - 1) * a + r = var - (n - 1) * s^2,   2 * s,   n - 2
+// The constraints recover the accumulators of the single values from them (split_std_dev_remainders).
+
+// number of range checked values (r and 2 * n - 1 - r)
+pub const NUM_REMAINDERS: usize = 2;
+
+// number of range checked values of the standard deviation (a, r, 2 * s - a and n - 2 - r)
+pub const NUM_STD_DEV_REMAINDERS: usize = 4;
+
+/// Number of bits (and thereby rows) needed to range check the remainders of a division by `count`.
+pub fn get_num_bits(count: u128) -> usize {
+    assert!(count > 0);
+    (u128::BITS - (2 * count - 1).leading_zeros()) as usize
+}
+
+/// Remainders of round(sum / count), computed by the prover from the actual values.
+pub fn get_rounded_div_remainders(sum: u128, count: u128) -> [u128; NUM_REMAINDERS] {
+    let avg_rounded = (2 * sum + count) / (2 * count);
+    let remainder = 2 * sum + count - 2 * count * avg_rounded;
+    [remainder, 2 * count - 1 - remainder]
+}
+
+/// Remainders of round(sum / count), computed by the verifier from the public inputs.
+pub fn get_rounded_div_remainders_e<B: StarkField>(sum: B, count: B, avg_rounded: B) -> [B; NUM_REMAINDERS] {
+    let two = B::ONE + B::ONE;
+    let remainder = two * sum + count - two * count * avg_rounded;
+    [remainder, two * count - B::ONE - remainder]
+}
+
+/// Integer square root floor(sqrt(value)).
+pub fn get_isqrt(value: u128) -> u128 {
+    let mut root = (value as f64).sqrt() as u128;
+    while root * root > value {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= value {
+        root += 1;
+    }
+    root
+}
+
+/// Standard deviation floor(sqrt(var / (count - 1))) of `count` values whose squared deviations add up to `var`.
+/// It is 0 for less than two values, as in the plain statistics.
+pub fn get_std_dev(var: u128, count: u128) -> u128 {
+    if count < 2 {
+        return 0;
+    }
+    get_isqrt(var / (count - 1))
+}
+
+/// Number of bits (and thereby rows) needed to range check the remainders of the standard deviation. For
+/// less than two values there is no remainder n - 2 - r, only 2 * s is bounded.
+pub fn get_std_dev_num_bits(std_dev: u128, count: u128) -> usize {
+    (u128::BITS - (2 * std_dev).max(count.saturating_sub(2)).max(1).leading_zeros()) as usize
+}
+
+/// Remainders a, r, 2 * s - a and n - 2 - r of the standard deviation, computed by the prover from the actual values.
+pub fn get_std_dev_remainders(var: u128, count: u128) -> [u128; NUM_STD_DEV_REMAINDERS] {
+    assert!(count > 1, "the standard deviation of less than two values has no remainders");
+    let std_dev = get_std_dev(var, count);
+    let quotient = var / (count - 1);
+    let remainder = var - (count - 1) * quotient;
+    let root_remainder = quotient - std_dev * std_dev;
+    [root_remainder, remainder, 2 * std_dev - root_remainder, count - 2 - remainder]
+}
+
+/// Last values of the columns of the standard deviation but the first one (a), computed by the verifier from
+/// the public inputs.
+pub fn get_std_dev_remainders_e<B: StarkField>(variance: B, count: B, std_dev: B) -> [B; NUM_STD_DEV_REMAINDERS - 1] {
+    let two = B::ONE + B::ONE;
+    [variance - (count - B::ONE) * std_dev * std_dev, two * std_dev, count - two]
+}
+
+/// Columns of the standard deviation from the accumulators of a, r, 2 * s - a and n - 2 - r.
+pub fn combine_std_dev_remainders<F: FieldElement>(acc: &[F], count: F) -> [F; NUM_STD_DEV_REMAINDERS] {
+    [acc[0], (count - F::ONE) * acc[0] + acc[1], acc[0] + acc[2], acc[1] + acc[3]]
+}
+
+/// Accumulators of a, r, 2 * s - a and n - 2 - r from the columns of the standard deviation.
+pub fn split_std_dev_remainders<F: FieldElement>(columns: &[F], count: F) -> [F; NUM_STD_DEV_REMAINDERS] {
+    let remainder = columns[1] - (count - F::ONE) * columns[0];
+    [columns[0], remainder, columns[2] - columns[0], columns[3] - remainder]
+}
+
+pub fn enforce_range_check<E: FieldElement>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+) {
+    for i in 0..current.len() {
+        let bit = next[i] - current[i] - current[i];
+        result[i] += bit * (bit - E::ONE);
+    }
+}
+
+/// Updates the accumulators from row `step` to row `step + 1`.
+pub fn apply_range_check<B: StarkField>(state: &mut [B], values: &[u128], num_bits: usize, step: usize) {
+    for i in 0..state.len() {
+        state[i] = state[i] + state[i];
+        if step >= num_bits || (values[i] >> (num_bits - 1 - step)) & 1 == 1 {
+            state[i] += B::ONE;
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::marker::PhantomData;
+use winterfell::math::{FieldElement, StarkField};
+use winterfell::{EvaluationFrame, Matrix, Trace, TraceLayout};
+
+// Execution trace consisting of a main segment and one auxiliary segment (randomized AIR with
+// preprocessing). The main segment is filled like a TraceTable, the auxiliary segment is built by
+// `A` after the main segment has been committed, using the random elements drawn by the verifier.
+
+pub trait AuxTraceBuilder<B: StarkField> {
+    /// number of columns of the auxiliary segment
+    const WIDTH: usize;
+    /// number of random elements the auxiliary segment depends on
+    const NUM_RAND_ELEMENTS: usize;
+
+    /// Returns the columns of the auxiliary segment for the given main segment and meta of the trace.
+    fn build_aux_columns<E: FieldElement<BaseField = B>>(main: &Matrix<B>, meta: &[u8], rand_elements: &[E]) -> Vec<Vec<E>>;
+}
+
+pub struct RapTraceTable<B: StarkField, A: AuxTraceBuilder<B>> {
+    layout: TraceLayout,
+    trace: Matrix<B>,
+    meta: Vec<u8>,
+    _aux_builder: PhantomData<A>,
+}
+
+impl<B: StarkField, A: AuxTraceBuilder<B>> RapTraceTable<B, A> {
+    pub fn with_meta(width: usize, length: usize, meta: Vec<u8>) -> Self {
+        assert!(length.is_power_of_two(), "trace length must be a power of two, but was {}", length);
+        let columns = (0..width).map(|_| vec![B::ZERO; length]).collect();
+        RapTraceTable {
+            layout: TraceLayout::new(width, [A::WIDTH], [A::NUM_RAND_ELEMENTS]),
+            trace: Matrix::new(columns),
+            meta,
+            _aux_builder: PhantomData,
+        }
+    }
+
+    /// Same semantics as TraceTable::fill: `init` creates the first row, `update` derives row
+    /// `step + 1` from row `step`.
+    pub fn fill<I, U>(&mut self, init: I, mut update: U)
+    where
+        I: Fn(&mut [B]),
+        U: FnMut(usize, &mut [B]),
+    {
+        let mut state = vec![B::ZERO; self.trace.num_cols()];
+        init(&mut state);
+        self.trace.update_row(0, &state);
+        for step in 0..self.length() - 1 {
+            update(step, &mut state);
+            self.trace.update_row(step + 1, &state);
+        }
+    }
+
+    pub fn get(&self, column: usize, step: usize) -> B {
+        self.trace.get(column, step)
+    }
+
+    pub fn set(&mut self, column: usize, step: usize, value: B) {
+        self.trace.set(column, step, value)
+    }
+}
+
+impl<B: StarkField, A: AuxTraceBuilder<B>> Trace for RapTraceTable<B, A> {
+    type BaseField = B;
+
+    fn layout(&self) -> &TraceLayout {
+        &self.layout
+    }
+
+    fn length(&self) -> usize {
+        self.trace.num_rows()
+    }
+
+    fn meta(&self) -> &[u8] {
+        &self.meta
+    }
+
+    fn main_segment(&self) -> &Matrix<B> {
+        &self.trace
+    }
+
+    fn build_aux_segment<E: FieldElement<BaseField = B>>(&mut self, aux_segments: &[Matrix<E>], rand_elements: &[E]) -> Option<Matrix<E>> {
+        // there is only one auxiliary segment
+        if !aux_segments.is_empty() {
+            return None;
+        }
+        let columns = A::build_aux_columns(&self.trace, &self.meta, rand_elements);
+        assert_eq!(A::WIDTH, columns.len());
+        Some(Matrix::new(columns))
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<B>) {
+        let next_row_idx = (row_idx + 1) % self.length();
+        self.trace.read_row_into(row_idx, frame.current_mut());
+        self.trace.read_row_into(next_row_idx, frame.next_mut());
+    }
+}